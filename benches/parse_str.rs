@@ -24,6 +24,16 @@ fn parse_random_hyphenated(b: &mut Bencher) {
     b.iter(|| Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8"));
 }
 
+#[bench]
+fn parse_random_upper_hyphenated(b: &mut Bencher) {
+    b.iter(|| Uuid::parse_str("67E55044-10B1-426F-9247-BB680E5FE0C8"));
+}
+
+#[bench]
+fn try_parse_random_hyphenated(b: &mut Bencher) {
+    b.iter(|| Uuid::try_parse("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+}
+
 #[bench]
 fn parse_urn(b: &mut Bencher) {
     b.iter(|| Uuid::parse_str("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8"));
@@ -0,0 +1,54 @@
+#![cfg(all(uuid_unstable, feature = "v4", feature = "v7", feature = "std"))]
+#![feature(test)]
+extern crate test;
+
+use test::Bencher;
+use uuid::Uuid;
+
+const LEN: usize = 1_000_000;
+
+#[bench]
+fn sort_unstable_v4(b: &mut Bencher) {
+    let uuids: Vec<Uuid> = (0..LEN).map(|_| Uuid::new_v4()).collect();
+
+    b.iter(|| {
+        let mut uuids = uuids.clone();
+        uuid::sort::sort_unstable(&mut uuids);
+        uuids
+    });
+}
+
+#[bench]
+fn slice_sort_unstable_v4(b: &mut Bencher) {
+    let uuids: Vec<Uuid> = (0..LEN).map(|_| Uuid::new_v4()).collect();
+
+    b.iter(|| {
+        let mut uuids = uuids.clone();
+        uuids.sort_unstable();
+        uuids
+    });
+}
+
+#[bench]
+fn sort_unstable_v7(b: &mut Bencher) {
+    let mut uuids: Vec<Uuid> = (0..LEN).map(|_| Uuid::now_v7()).collect();
+    uuids.reverse();
+
+    b.iter(|| {
+        let mut uuids = uuids.clone();
+        uuid::sort::sort_unstable(&mut uuids);
+        uuids
+    });
+}
+
+#[bench]
+fn slice_sort_unstable_v7(b: &mut Bencher) {
+    let mut uuids: Vec<Uuid> = (0..LEN).map(|_| Uuid::now_v7()).collect();
+    uuids.reverse();
+
+    b.iter(|| {
+        let mut uuids = uuids.clone();
+        uuids.sort_unstable();
+        uuids
+    });
+}
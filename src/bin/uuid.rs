@@ -0,0 +1,181 @@
+//! A command-line tool for generating, inspecting, and converting UUIDs.
+//!
+//! This binary is only built when the `cli` feature is enabled:
+//!
+//! ```sh
+//! cargo install uuid --features cli
+//! ```
+//!
+//! Run `uuid help` for usage.
+
+use std::{env, fmt::Display, process};
+
+use uuid::{Namespace, Uuid};
+
+const USAGE: &str = "\
+Generate, inspect, and convert UUIDs.
+
+Usage:
+    uuid [v1|v4|v7] [-n COUNT] [OPTIONS]    Generate random or time-based UUIDs (default v4)
+    uuid v5 NAMESPACE NAME [OPTIONS]        Generate a name-based UUID
+    uuid inspect UUID                       Describe the fields of a UUID
+    uuid convert UUID [OPTIONS]             Reformat a UUID
+    uuid help                               Print this message
+
+Options:
+    -n, --count COUNT    The number of UUIDs to generate
+    -f, --format FORMAT  One of `hyphenated` (default), `simple`, `urn`, or `braced`
+    -u, --upper          Use uppercase hex digits
+
+NAMESPACE is one of `dns`, `url`, `oid`, `x500`, or a UUID.
+";
+
+#[derive(Clone, Copy)]
+enum Format {
+    Hyphenated,
+    Simple,
+    Urn,
+    Braced,
+}
+
+struct Options {
+    count: usize,
+    format: Format,
+    upper: bool,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if let Err(err) = run(&args) {
+        eprintln!("error: {}", err);
+        eprintln!();
+        eprintln!("Run `uuid help` for usage.");
+        process::exit(2);
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let (command, rest) = match args.split_first() {
+        Some((command, rest)) if !command.starts_with('-') => (command.as_str(), rest),
+        _ => ("v4", args),
+    };
+
+    let (positional, options) = parse_options(rest)?;
+
+    match (command, positional.as_slice()) {
+        ("help", []) => print!("{}", USAGE),
+        ("v1", []) => {
+            for _ in 0..options.count {
+                print_uuid(Uuid::now_v1(&random_node_id()), &options);
+            }
+        }
+        ("v4", []) => {
+            for _ in 0..options.count {
+                print_uuid(Uuid::new_v4(), &options);
+            }
+        }
+        ("v7", []) => {
+            for _ in 0..options.count {
+                print_uuid(Uuid::now_v7(), &options);
+            }
+        }
+        ("v5", [namespace, name]) => {
+            let namespace = parse_namespace(namespace)?;
+
+            print_uuid(namespace.uuid_v5(name.as_bytes()), &options);
+        }
+        ("inspect", [uuid]) => println!("{}", parse_uuid(uuid)?.describe()),
+        ("convert", [uuid]) => print_uuid(parse_uuid(uuid)?, &options),
+        ("help" | "v1" | "v4" | "v7" | "v5" | "inspect" | "convert", _) => {
+            return Err(format!("wrong number of arguments to `{}`", command))
+        }
+        _ => return Err(format!("unknown command `{}`", command)),
+    }
+
+    Ok(())
+}
+
+fn parse_options(args: &[String]) -> Result<(Vec<&str>, Options), String> {
+    let mut positional = Vec::new();
+    let mut options = Options {
+        count: 1,
+        format: Format::Hyphenated,
+        upper: false,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-n" | "--count" => {
+                let count = args.next().ok_or("missing value for `--count`")?;
+
+                options.count = count
+                    .parse()
+                    .map_err(|_| format!("invalid count `{}`", count))?;
+            }
+            "-f" | "--format" => {
+                let format = args.next().ok_or("missing value for `--format`")?;
+
+                options.format = match format.as_str() {
+                    "hyphenated" => Format::Hyphenated,
+                    "simple" => Format::Simple,
+                    "urn" => Format::Urn,
+                    "braced" => Format::Braced,
+                    _ => return Err(format!("unknown format `{}`", format)),
+                };
+            }
+            "-u" | "--upper" => options.upper = true,
+            arg if arg.starts_with('-') => return Err(format!("unknown option `{}`", arg)),
+            arg => positional.push(arg),
+        }
+    }
+
+    Ok((positional, options))
+}
+
+fn parse_uuid(input: &str) -> Result<Uuid, String> {
+    Uuid::parse_str(input).map_err(|err| err.to_string())
+}
+
+fn parse_namespace(input: &str) -> Result<Namespace, String> {
+    match input {
+        "dns" => Ok(Namespace::DNS),
+        "url" => Ok(Namespace::URL),
+        "oid" => Ok(Namespace::OID),
+        "x500" => Ok(Namespace::X500),
+        uuid => parse_uuid(uuid).map(Namespace::from_uuid),
+    }
+}
+
+// RFC 9562 allows a random node ID in place of a MAC address, as long as
+// the multicast bit is set so it can't collide with a real one
+fn random_node_id() -> [u8; 6] {
+    let bytes = Uuid::new_v4().into_bytes();
+
+    [
+        bytes[10] | 0x01,
+        bytes[11],
+        bytes[12],
+        bytes[13],
+        bytes[14],
+        bytes[15],
+    ]
+}
+
+fn print_uuid(uuid: Uuid, options: &Options) {
+    fn print(formatted: impl Display + std::fmt::UpperHex, upper: bool) {
+        if upper {
+            println!("{:X}", formatted);
+        } else {
+            println!("{}", formatted);
+        }
+    }
+
+    match options.format {
+        Format::Hyphenated => print(uuid.hyphenated(), options.upper),
+        Format::Simple => print(uuid.simple(), options.upper),
+        Format::Urn => print(uuid.urn(), options.upper),
+        Format::Braced => print(uuid.braced(), options.upper),
+    }
+}
@@ -13,7 +13,12 @@
 //!
 //! [`Uuid`]: ../struct.Uuid.html
 
-use crate::{error::*, timestamp, Bytes, Uuid, Variant, Version};
+use crate::{
+    entropy::{self, EntropySource},
+    error::*,
+    std::ops::Range,
+    timestamp, Bytes, Uuid, Variant, Version,
+};
 
 /// A builder for creating a UUID.
 ///
@@ -99,6 +104,71 @@ impl Uuid {
         Uuid::from_bytes([0xFF; 16])
     }
 
+    /// The smallest UUID with the given version.
+    ///
+    /// The UUID has the version bits set to `version`, the variant bits set to
+    /// [`Variant::RFC4122`], and all other bits set to zero. No UUID with that
+    /// version and variant sorts before it, so it can be used as the lower bound
+    /// of a range scan for UUIDs of that version in an ordered store.
+    ///
+    /// The version bits aren't the most significant bits of a UUID, so the
+    /// ranges for different versions overlap. Filter on [`Uuid::get_version`]
+    /// as well when scanning a store that indexes UUIDs of mixed versions.
+    ///
+    /// The nil and max UUIDs are the only UUIDs of their versions, so they're
+    /// returned as-is for [`Version::Nil`] and [`Version::Max`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let uuid = Uuid::min_for_version(Version::SortRand);
+    ///
+    /// assert_eq!(
+    ///     "00000000-0000-7000-8000-000000000000",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn min_for_version(version: Version) -> Self {
+        match version {
+            Version::Nil => Uuid::nil(),
+            Version::Max => Uuid::max(),
+            version => Builder::from_payload_bits(version, 0).into_uuid(),
+        }
+    }
+
+    /// The largest UUID with the given version.
+    ///
+    /// The UUID has the version bits set to `version`, the variant bits set to
+    /// [`Variant::RFC4122`], and all other bits set to one. No UUID with that
+    /// version and variant sorts after it, so it can be used as the upper bound
+    /// of a range scan for UUIDs of that version in an ordered store.
+    ///
+    /// See [`Uuid::min_for_version`] for caveats when a store indexes UUIDs of
+    /// mixed versions.
+    ///
+    /// The nil and max UUIDs are the only UUIDs of their versions, so they're
+    /// returned as-is for [`Version::Nil`] and [`Version::Max`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let uuid = Uuid::max_for_version(Version::SortRand);
+    ///
+    /// assert_eq!(
+    ///     "ffffffff-ffff-7fff-bfff-ffffffffffff",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn max_for_version(version: Version) -> Self {
+        match version {
+            Version::Nil => Uuid::nil(),
+            Version::Max => Uuid::max(),
+            version => Builder::from_payload_bits(version, u128::MAX).into_uuid(),
+        }
+    }
+
     /// Creates a UUID from four field values.
     ///
     /// # Examples
@@ -267,6 +337,37 @@ impl Uuid {
         ])
     }
 
+    /// Creates a UUID from a signed 128bit value.
+    ///
+    /// The value is interpreted as a two's-complement big-endian integer, so
+    /// the sign bit is the most significant bit of the UUID. This is the same
+    /// as converting the value to a `u128` with `as` and calling
+    /// [`Uuid::from_u128`]. UUIDs with their top bit set, like those using
+    /// hex digits `8` to `f` as their first digit, come from negative values.
+    ///
+    /// This matches the integers produced by systems that exchange UUIDs as
+    /// signed 128bit values, like Java's `BigInteger` built from a UUID's
+    /// 16 bytes in big-endian order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::from_i128(-1);
+    ///
+    /// assert_eq!(Uuid::max(), uuid);
+    ///
+    /// let uuid = Uuid::from_i128(-0x5e5d5c5b4e4d3e3d2e2d2c2b2a292828);
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn from_i128(v: i128) -> Self {
+        Uuid::from_u128(v as u128)
+    }
+
     /// Creates a UUID from two 64bit values.
     ///
     /// # Examples
@@ -306,6 +407,33 @@ impl Uuid {
         ])
     }
 
+    /// Creates a UUID from two 64bit values in little-endian order.
+    ///
+    /// This is the inverse of [`Uuid::to_u64_pair_le`]. Each `u64` will be
+    /// flipped to convert into big-endian order. This is based on the endianness
+    /// of the UUID, rather than the target environment so bytes will be flipped
+    /// on both big and little endian machines.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let hi = 0xc2c1b2b1a4a3a2a1u64;
+    /// let lo = 0xd8d7d6d5d4d3d2d1u64;
+    ///
+    /// let uuid = Uuid::from_u64_pair_le(hi, lo);
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn from_u64_pair_le(high_bits: u64, low_bits: u64) -> Self {
+        Uuid::from_u64_pair(high_bits.swap_bytes(), low_bits.swap_bytes())
+    }
+
     /// Creates a UUID using the supplied bytes.
     ///
     /// # Errors
@@ -335,14 +463,33 @@ impl Uuid {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn from_slice(b: &[u8]) -> Result<Uuid, Error> {
-        if b.len() != 16 {
-            return Err(Error(ErrorKind::ByteLength { len: b.len() }));
+    ///
+    /// This method can also be used in const contexts, like building UUIDs
+    /// from bytes embedded with `include_bytes!`. An [`Error`] can't be
+    /// discarded at compile time, so keep the whole `Result` rather than
+    /// matching on it in a `const` item:
+    ///
+    /// ```
+    /// # use uuid::{Error, Uuid};
+    /// # fn main() -> Result<(), Error> {
+    /// const BYTES: &[u8] = &[
+    ///     0xa1, 0xa2, 0xa3, 0xa4, 0xb1, 0xb2, 0xc1, 0xc2,
+    ///     0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8,
+    /// ];
+    ///
+    /// const UUID: Result<Uuid, Error> = Uuid::from_slice(BYTES);
+    ///
+    /// assert_eq!("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8", UUID?.to_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct.Error.html
+    pub const fn from_slice(b: &[u8]) -> Result<Uuid, Error> {
+        match bytes_from_slice(b) {
+            Ok(bytes) => Ok(Uuid::from_bytes(bytes)),
+            Err(kind) => Err(Error::new(kind)),
         }
-
-        let mut bytes: Bytes = [0; 16];
-        bytes.copy_from_slice(b);
-        Ok(Uuid::from_bytes(bytes))
     }
 
     /// Creates a UUID using the supplied bytes in little endian order.
@@ -376,14 +523,11 @@ impl Uuid {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn from_slice_le(b: &[u8]) -> Result<Uuid, Error> {
-        if b.len() != 16 {
-            return Err(Error(ErrorKind::ByteLength { len: b.len() }));
+    pub const fn from_slice_le(b: &[u8]) -> Result<Uuid, Error> {
+        match bytes_from_slice(b) {
+            Ok(bytes) => Ok(Uuid::from_bytes_le(bytes)),
+            Err(kind) => Err(Error::new(kind)),
         }
-
-        let mut bytes: Bytes = [0; 16];
-        bytes.copy_from_slice(b);
-        Ok(Uuid::from_bytes_le(bytes))
     }
 
     /// Creates a UUID using the supplied bytes.
@@ -450,6 +594,146 @@ impl Uuid {
         ])
     }
 
+    /// Creates a UUID using the supplied bytes in MySQL's time-swapped order.
+    ///
+    /// This reverses the layout produced by MySQL's `UUID_TO_BIN(uuid, 1)`,
+    /// where the `time_hi_and_version` and `time_mid` fields are stored in
+    /// front of `time_low`. Bytes read from a column written this way must be
+    /// converted with this method rather than [`Uuid::from_bytes`], otherwise
+    /// the time fields will be out of order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// # use uuid::Uuid;
+    /// let bytes = [
+    ///     0x10, 0x26,
+    ///     0xba, 0xba,
+    ///     0x6c, 0xcd, 0x78, 0x0c,
+    ///     0x95, 0x64, 0x5b, 0x8c, 0x65, 0x60, 0x24, 0xdb,
+    /// ];
+    ///
+    /// let uuid = Uuid::from_mysql_bin_swapped(bytes);
+    ///
+    /// assert_eq!(
+    ///     "6ccd780c-baba-1026-9564-5b8c656024db",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn from_mysql_bin_swapped(b: Bytes) -> Uuid {
+        Uuid([
+            b[4], b[5], b[6], b[7], b[2], b[3], b[0], b[1], b[8], b[9], b[10], b[11], b[12], b[13],
+            b[14], b[15],
+        ])
+    }
+
+    /// Creates a UUID from the two `UInt64` halves ClickHouse uses to store it.
+    ///
+    /// ClickHouse's native and `RowBinary` formats write a UUID as two
+    /// little-endian `UInt64` values, the high 64 bits first. Values read
+    /// with the client's `UInt64` decoding can be passed to this method
+    /// directly. This is the inverse of [`Uuid::to_clickhouse_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::from_clickhouse_parts(0xa1a2a3a4b1b2c1c2, 0xd1d2d3d4d5d6d7d8);
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn from_clickhouse_parts(high_bits: u64, low_bits: u64) -> Uuid {
+        Uuid::from_u64_pair(high_bits, low_bits)
+    }
+
+    /// Creates a UUID from bytes in ClickHouse's wire order.
+    ///
+    /// ClickHouse's native and `RowBinary` formats write the high and low
+    /// halves of a UUID as little-endian `UInt64` values. Reading those 16
+    /// bytes with [`Uuid::from_bytes`] produces an ID with each half
+    /// byte-reversed. This is the inverse of [`Uuid::to_clickhouse_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let bytes = [
+    ///     0xc2, 0xc1, 0xb2, 0xb1, 0xa4, 0xa3, 0xa2, 0xa1,
+    ///     0xd8, 0xd7, 0xd6, 0xd5, 0xd4, 0xd3, 0xd2, 0xd1,
+    /// ];
+    ///
+    /// let uuid = Uuid::from_clickhouse_bytes(bytes);
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn from_clickhouse_bytes(b: Bytes) -> Uuid {
+        Uuid([
+            b[7], b[6], b[5], b[4], b[3], b[2], b[1], b[0], b[15], b[14], b[13], b[12], b[11],
+            b[10], b[9], b[8],
+        ])
+    }
+
+    /// Creates a UUID from the bytes of a Microsoft GUID.
+    ///
+    /// This is the layout returned by .NET's `Guid.ToByteArray()` and used by
+    /// ADO.NET and ODBC when a `uniqueidentifier` is read as a binary blob:
+    /// the first three fields are little-endian and the last 8 bytes are
+    /// unchanged. It's the same layout as [`Uuid::from_bytes_le`], named for
+    /// the interop it's used for. This is the inverse of
+    /// [`Uuid::to_guid_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// // new Guid("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8").ToByteArray()
+    /// let bytes = [
+    ///     0xa4, 0xa3, 0xa2, 0xa1,
+    ///     0xb2, 0xb1,
+    ///     0xc2, 0xc1,
+    ///     0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8,
+    /// ];
+    ///
+    /// let uuid = Uuid::from_guid_bytes(bytes);
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn from_guid_bytes(b: Bytes) -> Uuid {
+        Uuid::from_bytes_le(b)
+    }
+
+    /// Creates a UUID from a key produced by [`Uuid::to_sqlserver_sort_key`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(uuid, Uuid::from_sqlserver_sort_key(uuid.to_sqlserver_sort_key()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn from_sqlserver_sort_key(b: Bytes) -> Uuid {
+        Uuid([
+            b[15], b[14], b[13], b[12], b[11], b[10], b[9], b[8], b[6], b[7], b[0], b[1], b[2],
+            b[3], b[4], b[5],
+        ])
+    }
+
     /// Creates a reference to a UUID from a reference to the supplied bytes.
     ///
     /// # Examples
@@ -482,8 +766,169 @@ impl Uuid {
     /// ```
     #[inline]
     pub fn from_bytes_ref(bytes: &Bytes) -> &Uuid {
-        // SAFETY: `Bytes` and `Uuid` have the same ABI
-        unsafe { &*(bytes as *const Bytes as *const Uuid) }
+        #[cfg(not(feature = "forbid-unsafe"))]
+        {
+            // SAFETY: `Bytes` and `Uuid` have the same ABI
+            unsafe { &*(bytes as *const Bytes as *const Uuid) }
+        }
+
+        #[cfg(feature = "forbid-unsafe")]
+        {
+            bytemuck::TransparentWrapper::wrap_ref(bytes)
+        }
+    }
+
+    /// Creates a slice of UUIDs from a slice of their bytes without copying.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let bytes = [[0xa1; 16], [0xb2; 16]];
+    ///
+    /// let uuids = Uuid::slice_from_bytes(&bytes);
+    ///
+    /// assert_eq!(2, uuids.len());
+    /// assert_eq!(Uuid::from_bytes([0xb2; 16]), uuids[1]);
+    /// ```
+    #[inline]
+    pub fn slice_from_bytes(bytes: &[Bytes]) -> &[Uuid] {
+        #[cfg(not(feature = "forbid-unsafe"))]
+        {
+            // SAFETY: `Bytes` and `Uuid` have the same ABI
+            unsafe { crate::std::slice::from_raw_parts(bytes.as_ptr() as *const Uuid, bytes.len()) }
+        }
+
+        #[cfg(feature = "forbid-unsafe")]
+        {
+            bytemuck::TransparentWrapper::wrap_slice(bytes)
+        }
+    }
+
+    /// Creates a mutable slice of UUIDs from a mutable slice of their bytes without copying.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let mut bytes = [[0; 16]; 2];
+    ///
+    /// Uuid::slice_from_bytes_mut(&mut bytes)[1] = Uuid::max();
+    ///
+    /// assert_eq!([[0; 16], [0xff; 16]], bytes);
+    /// ```
+    #[inline]
+    pub fn slice_from_bytes_mut(bytes: &mut [Bytes]) -> &mut [Uuid] {
+        #[cfg(not(feature = "forbid-unsafe"))]
+        {
+            // SAFETY: `Bytes` and `Uuid` have the same ABI
+            unsafe {
+                crate::std::slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut Uuid, bytes.len())
+            }
+        }
+
+        #[cfg(feature = "forbid-unsafe")]
+        {
+            bytemuck::TransparentWrapper::wrap_slice_mut(bytes)
+        }
+    }
+
+    /// Views a buffer of packed UUIDs as a slice of UUIDs without copying.
+    ///
+    /// This is useful for reading UUIDs directly out of large buffers, like
+    /// memory-mapped index files, without copying each one out with
+    /// [`Uuid::from_slice`]. UUIDs have no alignment requirements, so any
+    /// buffer whose length is a multiple of 16 can be viewed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `bytes` has a length that isn't a
+    /// multiple of 16.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let mut buf = [0; 32];
+    /// buf[16..].copy_from_slice(Uuid::max().as_bytes());
+    ///
+    /// let uuids = Uuid::try_slice_from_bytes(&buf)?;
+    ///
+    /// assert_eq!([Uuid::nil(), Uuid::max()], uuids);
+    ///
+    /// assert!(Uuid::try_slice_from_bytes(&buf[1..]).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_slice_from_bytes(bytes: &[u8]) -> Result<&[Uuid], Error> {
+        if bytes.len() % 16 != 0 {
+            return Err(Error::new(ErrorKind::SliceLength { len: bytes.len() }));
+        }
+
+        #[cfg(not(feature = "forbid-unsafe"))]
+        {
+            // SAFETY: `Uuid` has an alignment of 1, the length is a multiple of
+            // its size, and any 16 bytes are a valid `Uuid`
+            Ok(unsafe {
+                crate::std::slice::from_raw_parts(bytes.as_ptr() as *const Uuid, bytes.len() / 16)
+            })
+        }
+
+        #[cfg(feature = "forbid-unsafe")]
+        {
+            Ok(bytemuck::cast_slice(bytes))
+        }
+    }
+
+    /// Views a mutable buffer of packed UUIDs as a mutable slice of UUIDs
+    /// without copying.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `bytes` has a length that isn't a
+    /// multiple of 16.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let mut buf = [0; 32];
+    ///
+    /// Uuid::try_slice_from_bytes_mut(&mut buf)?[1] = Uuid::max();
+    ///
+    /// assert_eq!([0xff; 16], buf[16..]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_slice_from_bytes_mut(bytes: &mut [u8]) -> Result<&mut [Uuid], Error> {
+        if bytes.len() % 16 != 0 {
+            return Err(Error::new(ErrorKind::SliceLength { len: bytes.len() }));
+        }
+
+        #[cfg(not(feature = "forbid-unsafe"))]
+        {
+            // SAFETY: `Uuid` has an alignment of 1, the length is a multiple of
+            // its size, and any 16 bytes are a valid `Uuid`
+            Ok(unsafe {
+                crate::std::slice::from_raw_parts_mut(
+                    bytes.as_mut_ptr() as *mut Uuid,
+                    bytes.len() / 16,
+                )
+            })
+        }
+
+        #[cfg(feature = "forbid-unsafe")]
+        {
+            Ok(bytemuck::cast_slice_mut(bytes))
+        }
     }
 
     // NOTE: There is no `from_u128_ref` because in little-endian
@@ -588,6 +1033,30 @@ impl Builder {
             .with_version(Version::Random)
     }
 
+    /// Creates a `Builder` for a version 4 UUID using random bytes from the
+    /// given [`EntropySource`].
+    ///
+    /// This is like [`Uuid::new_v4`], but reads its random bytes from the
+    /// given source instead of the operating system, and doesn't need the `v4`
+    /// feature of this crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Variant, Version};
+    /// let mut entropy = |dest: &mut [u8]| dest.fill(0x2a);
+    ///
+    /// let uuid = Builder::random_with(&mut entropy).into_uuid();
+    ///
+    /// assert_eq!(Some(Version::Random), uuid.get_version());
+    /// assert_eq!(Variant::RFC4122, uuid.get_variant());
+    /// ```
+    ///
+    /// [`Uuid::new_v4`]: struct.Uuid.html#method.new_v4
+    pub fn random_with(entropy: &mut impl EntropySource) -> Self {
+        Builder::from_random_bytes(entropy::u128(entropy).to_ne_bytes())
+    }
+
     /// Creates a `Builder` for a version 5 UUID using the supplied SHA-1 hashed bytes.
     ///
     /// This method assumes the bytes are already a SHA-1 hash, it will only set the appropriate
@@ -617,8 +1086,29 @@ impl Builder {
     /// the data around it. Callers using the counter as a monotonic value should be careful not to
     /// store significant data in the 2 least significant bits of the 3rd byte.
     ///
+    /// The timestamp is a plain number of milliseconds since the Unix epoch, so values
+    /// from databases or tick counters can be passed directly without converting them
+    /// into a `Duration` first.
+    ///
+    /// # Overflow
+    ///
+    /// Version 7 UUIDs only have room for a 48-bit timestamp. If `millis` is wider than
+    /// 48 bits then only its 48 least significant bits are used.
+    ///
     /// # Examples
     ///
+    /// Creating a UUID from a raw millisecond timestamp:
+    ///
+    /// ```
+    /// # use uuid::{Builder, Version};
+    /// let millis = 1_645_557_742_000u64;
+    ///
+    /// let uuid = Builder::from_unix_timestamp_millis(millis, &[0; 10]).into_uuid();
+    ///
+    /// assert_eq!(Some(Version::SortRand), uuid.get_version());
+    /// assert_eq!("017f22e2-79b0-7000-8000-000000000000", uuid.to_string());
+    /// ```
+    ///
     /// Creating a UUID using the current system timestamp:
     ///
     /// ```
@@ -647,6 +1137,143 @@ impl Builder {
         ))
     }
 
+    /// Creates a `Builder` for a version 7 UUID using the supplied Unix timestamp, counter,
+    /// and random bytes.
+    ///
+    /// The 42 least significant bits of `counter` are placed directly after the timestamp
+    /// and version, shifted around the variant field. The remaining bits are filled from
+    /// the start of `random_bytes`. This makes it possible
+    /// to deterministically reconstruct ordered UUIDs from a known sequence number, such
+    /// as when replaying a log of historical events.
+    ///
+    /// # Overflow
+    ///
+    /// Only the 48 least significant bits of `millis` and the 42 least significant bits of
+    /// `counter` are used. Higher bits are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Version};
+    /// let millis = 1_645_557_742_000u64;
+    /// let random_bytes = [0xff; 10];
+    ///
+    /// let uuid1 = Builder::from_unix_timestamp_with_counter(millis, 1, &random_bytes).into_uuid();
+    /// let uuid2 = Builder::from_unix_timestamp_with_counter(millis, 2, &random_bytes).into_uuid();
+    ///
+    /// assert_eq!(Some(Version::SortRand), uuid1.get_version());
+    /// assert!(uuid1 < uuid2);
+    /// ```
+    pub const fn from_unix_timestamp_with_counter(
+        millis: u64,
+        counter: u64,
+        random_bytes: &[u8; 10],
+    ) -> Self {
+        const COUNTER_BITS: u32 = 42;
+
+        let mut random = [0; 16];
+        let mut i = 0;
+
+        while i < 10 {
+            random[i] = random_bytes[i];
+            i += 1;
+        }
+
+        // The random bytes fill in whatever bits the counter doesn't use,
+        // after the version and variant fields
+        let random = u128::from_be_bytes(random) >> (COUNTER_BITS + 6);
+
+        Builder(timestamp::encode_unix_timestamp_counter(
+            millis,
+            (counter & ((1 << COUNTER_BITS) - 1)) as u128,
+            COUNTER_BITS,
+            random,
+        ))
+    }
+
+    /// Creates a `Builder` for a version 7 UUID using the supplied Unix timestamp with
+    /// sub-millisecond precision, and random bytes.
+    ///
+    /// The fraction of a millisecond in `sub_millis_nanos` is stored in the 12 bits
+    /// directly after the millisecond timestamp, as described by method 3 in RFC 9562.
+    /// That gives a resolution of about 244 nanoseconds, so UUIDs created within the
+    /// same millisecond still sort by their time. The rest of the UUID is filled from
+    /// the start of `random_bytes`. The fraction can be read back with
+    /// [`Uuid::get_timestamp_sub_millis`].
+    ///
+    /// # Overflow
+    ///
+    /// Only the 48 least significant bits of `millis` are used. Values of
+    /// `sub_millis_nanos` of a full millisecond or more are treated as `999_999`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Version};
+    /// let millis = 1_645_557_742_000u64;
+    ///
+    /// let uuid1 = Builder::from_unix_timestamp_sub_millis(millis, 250_000, &[0xff; 10]).into_uuid();
+    /// let uuid2 = Builder::from_unix_timestamp_sub_millis(millis, 500_000, &[0; 10]).into_uuid();
+    ///
+    /// assert_eq!(Some(Version::SortRand), uuid1.get_version());
+    /// assert_eq!("017f22e2-79b0-7400-bfff-ffffffffffff", uuid1.to_string());
+    /// assert!(uuid1 < uuid2);
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Monotonicity and Counters in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-6.2)
+    pub const fn from_unix_timestamp_sub_millis(
+        millis: u64,
+        sub_millis_nanos: u32,
+        random_bytes: &[u8; 10],
+    ) -> Self {
+        let mut random = [0; 16];
+        let mut i = 0;
+
+        while i < 10 {
+            random[i] = random_bytes[i];
+            i += 1;
+        }
+
+        // The random bytes fill in the bits after the fraction, skipping
+        // the version and variant fields
+        let random = u128::from_be_bytes(random) >> (timestamp::SUB_MILLIS_BITS + 6);
+
+        Builder(timestamp::encode_unix_timestamp_counter(
+            millis,
+            timestamp::encode_sub_millis(sub_millis_nanos) as u128,
+            timestamp::SUB_MILLIS_BITS,
+            random,
+        ))
+    }
+
+    /// Creates a `Builder` for a version 7 UUID using the supplied Unix timestamp in
+    /// microseconds, and random bytes.
+    ///
+    /// This is a shortcut for [`Builder::from_unix_timestamp_sub_millis`] for timestamps
+    /// that are a plain number of microseconds, such as those stored by many
+    /// time-series databases. The microseconds are recovered exactly by
+    /// [`Uuid::get_unix_timestamp_micros`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Builder;
+    /// let micros = 1_645_557_742_000_123u64;
+    ///
+    /// let uuid = Builder::from_unix_timestamp_micros(micros, &[0; 10]).into_uuid();
+    ///
+    /// assert_eq!(Some(micros), uuid.get_unix_timestamp_micros());
+    /// ```
+    pub const fn from_unix_timestamp_micros(micros: u64, random_bytes: &[u8; 10]) -> Self {
+        Builder::from_unix_timestamp_sub_millis(
+            micros / 1_000,
+            ((micros % 1_000) * 1_000) as u32,
+            random_bytes,
+        )
+    }
+
     /// Creates a `Builder` for a version 8 UUID using the supplied user-defined bytes.
     ///
     /// This method won't interpret the given bytes in any way, except to set the appropriate
@@ -657,6 +1284,70 @@ impl Builder {
             .with_version(Version::Custom)
     }
 
+    /// Creates a `Builder` for a version 8 UUID using the three custom fields
+    /// defined by RFC 9562.
+    ///
+    /// `custom_a` fills the 48 bits before the version field, `custom_b` the
+    /// 12 bits between the version and variant fields, and `custom_c` the 62
+    /// bits after the variant field. Any bits of the fields that don't fit are
+    /// ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Version};
+    /// let uuid = Builder::from_custom_fields(0x0123_4567_89ab, 0xcde, 0x0f1e_2d3c_4b5a_6978).into_uuid();
+    ///
+    /// assert_eq!(Some(Version::Custom), uuid.get_version());
+    /// assert_eq!(
+    ///     "01234567-89ab-8cde-8f1e-2d3c4b5a6978",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [UUID Version 8 in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-5.8)
+    pub const fn from_custom_fields(custom_a: u64, custom_b: u16, custom_c: u64) -> Self {
+        let bits = ((custom_a as u128 & 0xffff_ffff_ffff) << 80)
+            | ((custom_b as u128 & 0xfff) << 64)
+            | (custom_c as u128 & ((1 << 62) - 1));
+
+        Builder::from_u128(bits)
+            .with_variant(Variant::RFC4122)
+            .with_version(Version::Custom)
+    }
+
+    /// Creates a `Builder` using the supplied version and 122 payload bits.
+    ///
+    /// The least significant 122 bits of `payload` are spread around the version
+    /// and variant fields of the RFC 9562 layout, which are set to `version` and
+    /// [`Variant::RFC4122`]. The 6 most significant bits of `payload` are ignored.
+    ///
+    /// This is the inverse of [`Uuid::payload_bits`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Version};
+    /// let uuid = Builder::from_payload_bits(Version::Custom, 0x2a).into_uuid();
+    ///
+    /// assert_eq!(
+    ///     "00000000-0000-8000-8000-00000000002a",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// assert_eq!(0x2a, uuid.payload_bits());
+    /// ```
+    pub const fn from_payload_bits(version: Version, payload: u128) -> Self {
+        let bits = ((payload >> 74) << 80)
+            | (((payload >> 62) & 0xfff) << 64)
+            | (payload & ((1 << 62) - 1));
+
+        Builder::from_u128(bits)
+            .with_variant(Variant::RFC4122)
+            .with_version(version)
+    }
+
     /// Creates a `Builder` using the supplied bytes.
     ///
     /// # Errors
@@ -686,8 +1377,11 @@ impl Builder {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn from_slice(b: &[u8]) -> Result<Self, Error> {
-        Ok(Builder(Uuid::from_slice(b)?))
+    pub const fn from_slice(b: &[u8]) -> Result<Self, Error> {
+        match bytes_from_slice(b) {
+            Ok(bytes) => Ok(Builder::from_bytes(bytes)),
+            Err(kind) => Err(Error::new(kind)),
+        }
     }
 
     /// Creates a `Builder` using the supplied bytes in little endian order.
@@ -721,8 +1415,11 @@ impl Builder {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn from_slice_le(b: &[u8]) -> Result<Self, Error> {
-        Ok(Builder(Uuid::from_slice_le(b)?))
+    pub const fn from_slice_le(b: &[u8]) -> Result<Self, Error> {
+        match bytes_from_slice(b) {
+            Ok(bytes) => Ok(Builder::from_bytes_le(bytes)),
+            Err(kind) => Err(Error::new(kind)),
+        }
     }
 
     /// Creates a `Builder` from four field values.
@@ -867,6 +1564,219 @@ impl Builder {
         self
     }
 
+    /// Sets a byte of the UUID.
+    ///
+    /// Bytes are indexed in the order they appear in the UUID's string
+    /// representation, so index `0` is the first byte.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `index` is `16` or larger. Use
+    /// [`Builder::with_byte_checked`] to get `None` instead.
+    pub fn set_byte(&mut self, index: usize, value: u8) -> &mut Self {
+        *self = Builder(self.0).with_byte(index, value);
+        self
+    }
+
+    /// Sets a byte of the UUID.
+    ///
+    /// Bytes are indexed in the order they appear in the UUID's string
+    /// representation, so index `0` is the first byte.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `index` is `16` or larger. Use
+    /// [`Builder::with_byte_checked`] to get `None` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Builder;
+    /// let uuid = Builder::nil().with_byte(0, 0xa1).with_byte(15, 0xd8).into_uuid();
+    ///
+    /// assert_eq!(
+    ///     "a1000000-0000-0000-0000-0000000000d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn with_byte(mut self, index: usize, value: u8) -> Self {
+        (self.0).0[index] = value;
+
+        self
+    }
+
+    /// Sets a byte of the UUID, returning `None` instead of panicking if
+    /// `index` is `16` or larger.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Builder;
+    /// assert!(Builder::nil().with_byte_checked(15, 0xd8).is_some());
+    /// assert!(Builder::nil().with_byte_checked(16, 0xd8).is_none());
+    /// ```
+    pub const fn with_byte_checked(self, index: usize, value: u8) -> Option<Self> {
+        if index < 16 {
+            Some(self.with_byte(index, value))
+        } else {
+            None
+        }
+    }
+
+    /// Sets a nibble (a single hex digit) of the UUID.
+    ///
+    /// Nibbles are indexed in the order their hex digits appear in the UUID's
+    /// simple string representation, so index `0` is the first digit. Only
+    /// the low 4 bits of `value` are used.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `index` is `32` or larger. Use
+    /// [`Builder::with_nibble_checked`] to get `None` instead.
+    pub fn set_nibble(&mut self, index: usize, value: u8) -> &mut Self {
+        *self = Builder(self.0).with_nibble(index, value);
+        self
+    }
+
+    /// Sets a nibble (a single hex digit) of the UUID.
+    ///
+    /// Nibbles are indexed in the order their hex digits appear in the UUID's
+    /// simple string representation, so index `0` is the first digit. Only
+    /// the low 4 bits of `value` are used.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `index` is `32` or larger. Use
+    /// [`Builder::with_nibble_checked`] to get `None` instead.
+    ///
+    /// # Examples
+    ///
+    /// Setting the version digit directly:
+    ///
+    /// ```
+    /// # use uuid::{Builder, Version};
+    /// let uuid = Builder::nil().with_nibble(12, 8).into_uuid();
+    ///
+    /// assert_eq!(
+    ///     "00000000-0000-8000-0000-000000000000",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn with_nibble(mut self, index: usize, value: u8) -> Self {
+        let byte = (self.0).0[index / 2];
+
+        (self.0).0[index / 2] = if index % 2 == 0 {
+            (byte & 0x0f) | (value << 4)
+        } else {
+            (byte & 0xf0) | (value & 0x0f)
+        };
+
+        self
+    }
+
+    /// Sets a nibble (a single hex digit) of the UUID, returning `None` instead
+    /// of panicking if `index` is `32` or larger.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Builder;
+    /// assert!(Builder::nil().with_nibble_checked(31, 0xa).is_some());
+    /// assert!(Builder::nil().with_nibble_checked(32, 0xa).is_none());
+    /// ```
+    pub const fn with_nibble_checked(self, index: usize, value: u8) -> Option<Self> {
+        if index < 32 {
+            Some(self.with_nibble(index, value))
+        } else {
+            None
+        }
+    }
+
+    /// Sets a range of bits of the UUID.
+    ///
+    /// Bits are numbered from the most significant bit of the first byte, so
+    /// bit `0` is the top bit of the UUID and bit `127` is the bottom one.
+    /// This matches the bit positions in the field layouts of RFC 9562.
+    /// The low `bits.len()` bits of `value` are written into the range, and
+    /// any higher bits are ignored.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the range ends after bit `128` or starts after
+    /// it ends. Use [`Builder::with_bits_checked`] to get `None` instead.
+    pub fn set_bits(&mut self, bits: Range<usize>, value: u128) -> &mut Self {
+        *self = Builder(self.0).with_bits(bits, value);
+        self
+    }
+
+    /// Sets a range of bits of the UUID.
+    ///
+    /// Bits are numbered from the most significant bit of the first byte, so
+    /// bit `0` is the top bit of the UUID and bit `127` is the bottom one.
+    /// This matches the bit positions in the field layouts of RFC 9562.
+    /// The low `bits.len()` bits of `value` are written into the range, and
+    /// any higher bits are ignored.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the range ends after bit `128` or starts after
+    /// it ends. Use [`Builder::with_bits_checked`] to get `None` instead.
+    ///
+    /// # Examples
+    ///
+    /// Building a version 8 UUID with a 48 bit custom field at the start:
+    ///
+    /// ```
+    /// # use uuid::{Builder, Variant, Version};
+    /// let uuid = Builder::nil()
+    ///     .with_bits(0..48, 0xa1a2_a3a4_b1b2)
+    ///     .with_variant(Variant::RFC4122)
+    ///     .with_version(Version::Custom)
+    ///     .into_uuid();
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-8000-8000-000000000000",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn with_bits(self, bits: Range<usize>, value: u128) -> Self {
+        assert!(
+            bits.start <= bits.end && bits.end <= 128,
+            "the range of bits must be within the 128 bits of a UUID"
+        );
+
+        let len = bits.end - bits.start;
+        let shift = 128 - bits.end;
+
+        let mask = if len == 128 {
+            u128::MAX
+        } else {
+            ((1 << len) - 1) << shift
+        };
+
+        let uuid = self.0.as_u128();
+
+        Builder::from_u128((uuid & !mask) | ((value << shift) & mask))
+    }
+
+    /// Sets a range of bits of the UUID, returning `None` instead of panicking
+    /// if the range ends after bit `128` or starts after it ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Builder;
+    /// assert!(Builder::nil().with_bits_checked(120..128, 0xd8).is_some());
+    /// assert!(Builder::nil().with_bits_checked(120..129, 0xd8).is_none());
+    /// ```
+    pub const fn with_bits_checked(self, bits: Range<usize>, value: u128) -> Option<Self> {
+        if bits.start <= bits.end && bits.end <= 128 {
+            Some(self.with_bits(bits, value))
+        } else {
+            None
+        }
+    }
+
     /// Get a reference to the underlying [`Uuid`].
     ///
     /// # Examples
@@ -904,6 +1814,83 @@ impl Builder {
     pub const fn into_uuid(self) -> Uuid {
         self.0
     }
+
+    /// Convert the builder into a [`Uuid`], checking that its fields are
+    /// consistent.
+    ///
+    /// Unlike [`Builder::into_uuid`], which returns whatever bits the builder
+    /// holds, this method only returns UUIDs that:
+    ///
+    /// * use the RFC 9562 variant,
+    /// * have a version number defined by RFC 9562, and
+    /// * have a non-zero timestamp, for the time-based versions 1, 6, and 7.
+    ///   A zero timestamp usually means the timestamp fields were never set.
+    ///
+    /// The nil and max UUIDs are always accepted.
+    ///
+    /// This is useful when building UUIDs from untrusted field values, so
+    /// malformed combinations can be rejected instead of passed along.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error describing the first of the above checks
+    /// that fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Variant, Version};
+    /// let uuid = Builder::from_u128(0xa1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8)
+    ///     .with_variant(Variant::RFC4122)
+    ///     .with_version(Version::Random)
+    ///     .try_build()?;
+    ///
+    /// assert_eq!(Some(Version::Random), uuid.get_version());
+    ///
+    /// // Version 1 UUIDs need a timestamp
+    /// assert!(Builder::nil()
+    ///     .with_variant(Variant::RFC4122)
+    ///     .with_version(Version::Mac)
+    ///     .try_build()
+    ///     .is_err());
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub const fn try_build(self) -> Result<Uuid, Error> {
+        let uuid = self.0;
+        let bytes = uuid.as_bytes();
+
+        if uuid.is_nil() || uuid.is_max() {
+            return Ok(uuid);
+        }
+
+        if !matches!(uuid.get_variant(), Variant::RFC4122) {
+            return Err(Error::new(ErrorKind::BuildVariant));
+        }
+
+        // The first 48 bits hold (part of) the timestamp in every time-based version
+        let millis_unset = bytes[0] == 0
+            && bytes[1] == 0
+            && bytes[2] == 0
+            && bytes[3] == 0
+            && bytes[4] == 0
+            && bytes[5] == 0;
+
+        match uuid.get_version() {
+            Some(Version::Mac) | Some(Version::SortMac)
+                if millis_unset && bytes[6] & 0x0f == 0 && bytes[7] == 0 =>
+            {
+                Err(Error::new(ErrorKind::BuildTimestamp))
+            }
+            Some(Version::SortRand) if millis_unset => Err(Error::new(ErrorKind::BuildTimestamp)),
+            // Only the nil and max UUIDs themselves use these version numbers
+            Some(Version::Nil) | Some(Version::Max) | None => {
+                Err(Error::new(ErrorKind::BuildVersion {
+                    version: uuid.get_version_num() as u8,
+                }))
+            }
+            Some(_) => Ok(uuid),
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -928,3 +1915,23 @@ impl Builder {
         Builder::from_sorted_gregorian_timestamp(ticks, counter, node_id)
     }
 }
+
+// Copy a slice of exactly 16 bytes into an array. This is a loop rather than
+// `copy_from_slice` so it can be called in const contexts
+// This returns the error kind rather than an `Error` so callers can match
+// on it in `const fn`s: dropping an `Error` can't happen at compile time
+const fn bytes_from_slice(b: &[u8]) -> Result<Bytes, ErrorKind> {
+    if b.len() != 16 {
+        return Err(ErrorKind::ByteLength { len: b.len() });
+    }
+
+    let mut bytes: Bytes = [0; 16];
+    let mut i = 0;
+
+    while i < 16 {
+        bytes[i] = b[i];
+        i += 1;
+    }
+
+    Ok(bytes)
+}
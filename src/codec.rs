@@ -0,0 +1,475 @@
+//! Compact encodings for streams of UUIDs.
+//!
+//! [`V7DeltaEncoder`] and [`V7DeltaDecoder`] compress ordered streams of
+//! version 7 UUIDs, like the keys in a write-ahead log or a batch of events
+//! sent over the wire, by encoding each UUID relative to the one before it.
+//!
+//! # Framing
+//!
+//! An encoded stream is a sequence of records with no header, one for each
+//! UUID. Both sides start from a previous timestamp of `0` and a previous
+//! tail of ten zero bytes, where the tail of a version 7 UUID is its 12 bits
+//! of `rand_a` followed by its 62 bits of `rand_b`, padded with 6 zero bits.
+//!
+//! Each record starts with a header, which is an unsigned LEB128 integer:
+//!
+//! ```text
+//! header = (timestamp delta << 4) | shared
+//! ```
+//!
+//! * If `shared` is between `0` and `10`, the UUID is a version 7 UUID whose
+//!   millisecond timestamp is the previous timestamp plus the delta, and whose
+//!   tail starts with `shared` bytes of the previous tail. The remaining
+//!   `10 - shared` bytes of the tail follow the header.
+//! * If `shared` is `15` and the delta is `0`, the record is an escape, and
+//!   the 16 bytes of the UUID follow the header as-is. Escapes are used for
+//!   UUIDs that aren't version 7, and for UUIDs whose timestamp is less than
+//!   the previous one. Its top 48 bits are used as the next previous timestamp,
+//!   and its last 10 bytes as the next previous tail.
+//!
+//! Any other header is invalid.
+//!
+//! # Compression
+//!
+//! The header of a UUID generated within 8 milliseconds of the one before it
+//! fits in a single byte, so each record takes between 1 and 11 bytes. Random
+//! bits can't be compressed, so how small records are depends on how much of
+//! the tail is shared with the previous UUID. Streams from generators that
+//! keep a counter at the start of `rand_a`, like [`ContextV7`], share more of
+//! their tail than streams of fully random version 7 UUIDs.
+//!
+//! [`ContextV7`]: ../struct.ContextV7.html
+
+use crate::{
+    error::{Error, ErrorKind},
+    Uuid,
+};
+
+const TAIL_LEN: usize = 10;
+
+const ESCAPE: u8 = 15;
+
+/// Encodes a stream of version 7 UUIDs relative to each other.
+///
+/// See the [module documentation](index.html) for the format of the encoded
+/// stream.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{codec::{V7DeltaDecoder, V7DeltaEncoder}, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuids = [
+///     Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")?,
+///     Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07399a")?,
+///     Uuid::parse_str("017f22e2-79b2-7cc4-98c4-dc0c0c0739b7")?,
+/// ];
+///
+/// let mut encoder = V7DeltaEncoder::new();
+/// let mut encoded = Vec::new();
+///
+/// for uuid in &uuids {
+///     encoded.extend_from_slice(encoder.encode(uuid, &mut [0; V7DeltaEncoder::MAX_RECORD_LEN]));
+/// }
+///
+/// assert!(encoded.len() < 16 * uuids.len());
+///
+/// let mut decoder = V7DeltaDecoder::new();
+/// let mut input = &encoded[..];
+/// let mut decoded = Vec::new();
+///
+/// while let Some((uuid, len)) = decoder.decode(input)? {
+///     decoded.push(uuid);
+///     input = &input[len..];
+/// }
+///
+/// assert_eq!(&uuids[..], &decoded[..]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct V7DeltaEncoder {
+    state: State,
+}
+
+/// Decodes a stream of version 7 UUIDs encoded by a [`V7DeltaEncoder`].
+///
+/// See the [module documentation](index.html) for the format of the encoded
+/// stream.
+#[derive(Clone, Debug, Default)]
+pub struct V7DeltaDecoder {
+    state: State,
+}
+
+#[derive(Clone, Debug, Default)]
+struct State {
+    millis: u64,
+    tail: [u8; TAIL_LEN],
+}
+
+impl State {
+    fn update(&mut self, millis: u64, tail: [u8; TAIL_LEN]) {
+        self.millis = millis;
+        self.tail = tail;
+    }
+}
+
+impl V7DeltaEncoder {
+    /// The maximum length of an encoded record.
+    ///
+    /// This is a 52-bit header, which takes up to 8 bytes, and 10 bytes of tail.
+    pub const MAX_RECORD_LEN: usize = 18;
+
+    /// Create an encoder for the start of a stream.
+    pub const fn new() -> Self {
+        V7DeltaEncoder {
+            state: State {
+                millis: 0,
+                tail: [0; TAIL_LEN],
+            },
+        }
+    }
+
+    /// Encode the next UUID in the stream into `buffer`, returning the
+    /// subslice of the buffer that contains the record.
+    pub fn encode<'b>(
+        &mut self,
+        uuid: &Uuid,
+        buffer: &'b mut [u8; Self::MAX_RECORD_LEN],
+    ) -> &'b [u8] {
+        let millis = millis(uuid);
+        let tail = tail(uuid);
+
+        let is_v7 = uuid.get_version_num() == 7 && uuid.as_bytes()[8] & 0xc0 == 0x80;
+
+        if !is_v7 || millis < self.state.millis {
+            buffer[0] = ESCAPE;
+            buffer[1..17].copy_from_slice(uuid.as_bytes());
+
+            self.state.update(millis, raw_tail(uuid));
+
+            return &buffer[..17];
+        }
+
+        let shared = tail
+            .iter()
+            .zip(&self.state.tail)
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        let header = ((millis - self.state.millis) << 4) | shared as u64;
+
+        let mut len = write_varint(header, buffer);
+
+        buffer[len..len + TAIL_LEN - shared].copy_from_slice(&tail[shared..]);
+        len += TAIL_LEN - shared;
+
+        self.state.update(millis, tail);
+
+        &buffer[..len]
+    }
+}
+
+impl V7DeltaDecoder {
+    /// Create a decoder for the start of a stream.
+    pub const fn new() -> Self {
+        V7DeltaDecoder {
+            state: State {
+                millis: 0,
+                tail: [0; TAIL_LEN],
+            },
+        }
+    }
+
+    /// Decode the next UUID in the stream from the start of `input`.
+    ///
+    /// This method returns the UUID along with the length of its record, so
+    /// the next record starts at that offset in `input`. If `input` doesn't
+    /// contain a whole record, it returns `Ok(None)` and leaves the decoder
+    /// unchanged, so the same input can be decoded again once more of it
+    /// has been read.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the record is invalid.
+    pub fn decode(&mut self, input: &[u8]) -> Result<Option<(Uuid, usize)>, Error> {
+        let (header, mut len) = match read_varint(input)? {
+            Some(header) => header,
+            None => return Ok(None),
+        };
+
+        let delta = header >> 4;
+        let shared = (header & 0xf) as u8;
+
+        if shared == ESCAPE {
+            if delta != 0 {
+                return Err(Error::new(ErrorKind::DeltaRecord));
+            }
+
+            let bytes = match input.get(len..len + 16) {
+                Some(bytes) => bytes,
+                None => return Ok(None),
+            };
+
+            let mut raw = [0; 16];
+            raw.copy_from_slice(bytes);
+
+            let uuid = Uuid::from_bytes(raw);
+
+            self.state.update(millis(&uuid), raw_tail(&uuid));
+
+            return Ok(Some((uuid, len + 16)));
+        }
+
+        let shared = shared as usize;
+
+        if shared > TAIL_LEN {
+            return Err(Error::new(ErrorKind::DeltaRecord));
+        }
+
+        let millis = self
+            .state
+            .millis
+            .checked_add(delta)
+            .filter(|millis| millis >> 48 == 0)
+            .ok_or(Error::new(ErrorKind::DeltaRecord))?;
+
+        let rest = match input.get(len..len + TAIL_LEN - shared) {
+            Some(rest) => rest,
+            None => return Ok(None),
+        };
+        len += rest.len();
+
+        let mut tail = self.state.tail;
+        tail[shared..].copy_from_slice(rest);
+
+        // The 6 padding bits at the end of the tail must be zero
+        if tail[TAIL_LEN - 1] & 0x3f != 0 {
+            return Err(Error::new(ErrorKind::DeltaRecord));
+        }
+
+        self.state.update(millis, tail);
+
+        Ok(Some((from_parts(millis, &tail), len)))
+    }
+}
+
+fn millis(uuid: &Uuid) -> u64 {
+    (uuid.as_u128() >> 80) as u64
+}
+
+// The 74 bits of `rand_a` and `rand_b`, without the version and variant
+fn tail(uuid: &Uuid) -> [u8; TAIL_LEN] {
+    let bits = uuid.as_u128();
+    let tail = ((bits >> 64) & 0xfff) << 62 | (bits & ((1 << 62) - 1));
+
+    let mut bytes = [0; TAIL_LEN];
+    bytes.copy_from_slice(&(tail << 6).to_be_bytes()[6..]);
+
+    bytes
+}
+
+// The last 10 bytes of a UUID that isn't encoded as version 7
+fn raw_tail(uuid: &Uuid) -> [u8; TAIL_LEN] {
+    let mut bytes = [0; TAIL_LEN];
+    bytes.copy_from_slice(&uuid.as_bytes()[6..]);
+
+    bytes
+}
+
+fn from_parts(millis: u64, tail: &[u8; TAIL_LEN]) -> Uuid {
+    let mut bytes = [0; 16];
+    bytes[6..].copy_from_slice(tail);
+
+    let tail = u128::from_be_bytes(bytes) >> 6;
+
+    Uuid::from_u128(
+        (millis as u128) << 80
+            | 0x7 << 76
+            | (tail >> 62) << 64
+            | 0x2 << 62
+            | (tail & ((1 << 62) - 1)),
+    )
+}
+
+fn write_varint(mut value: u64, buffer: &mut [u8]) -> usize {
+    let mut len = 0;
+
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            buffer[len] = byte;
+            return len + 1;
+        }
+
+        buffer[len] = byte | 0x80;
+        len += 1;
+    }
+}
+
+fn read_varint(input: &[u8]) -> Result<Option<(u64, usize)>, Error> {
+    let mut value = 0u64;
+
+    for (i, byte) in input.iter().enumerate() {
+        // Headers are at most 52 bits, which fit in 8 bytes
+        if i == 8 {
+            return Err(Error::new(ErrorKind::DeltaRecord));
+        }
+
+        value |= ((byte & 0x7f) as u64) << (7 * i);
+
+        if byte & 0x80 == 0 {
+            return Ok(Some((value, i + 1)));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::std::vec::Vec;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    fn encode_all(uuids: &[Uuid]) -> Vec<u8> {
+        let mut encoder = V7DeltaEncoder::new();
+        let mut encoded = Vec::new();
+
+        for uuid in uuids {
+            encoded
+                .extend_from_slice(encoder.encode(uuid, &mut [0; V7DeltaEncoder::MAX_RECORD_LEN]));
+        }
+
+        encoded
+    }
+
+    fn decode_all(mut input: &[u8]) -> Result<Vec<Uuid>, Error> {
+        let mut decoder = V7DeltaDecoder::new();
+        let mut decoded = Vec::new();
+
+        while let Some((uuid, len)) = decoder.decode(input)? {
+            decoded.push(uuid);
+            input = &input[len..];
+        }
+
+        assert!(input.is_empty(), "{} trailing bytes", input.len());
+
+        Ok(decoded)
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_round_trip() {
+        let uuids = [
+            Uuid::from_u128(0x017f22e2_79b0_7cc3_98c4_dc0c0c07398f),
+            // Same millisecond, only the end of the tail changes
+            Uuid::from_u128(0x017f22e2_79b0_7cc3_98c4_dc0c0c0739a0),
+            // A later millisecond
+            Uuid::from_u128(0x017f22e2_79b3_7001_8000_000000000001),
+            // Not version 7
+            Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8),
+            Uuid::nil(),
+            Uuid::max(),
+            // Earlier than the previous UUID
+            Uuid::from_u128(0x017f22e2_79b0_7fff_bfff_ffffffffffff),
+            Uuid::from_u128(0xffffffff_ffff_7fff_bfff_ffffffffffff),
+        ];
+
+        let encoded = encode_all(&uuids);
+
+        assert_eq!(&uuids[..], &decode_all(&encoded).unwrap()[..]);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_record_sizes() {
+        let first = Uuid::from_u128(0x017f22e2_79b0_7cc3_98c4_dc0c0c07398f);
+        let second = Uuid::from_u128(0x017f22e2_79b2_7cc3_98c4_dc0c0c0739a0);
+
+        let mut encoder = V7DeltaEncoder::new();
+
+        // The first record carries the whole timestamp
+        assert_eq!(7 + 10, encoder.encode(&first, &mut [0; 18]).len());
+
+        // 2ms later, sharing 8 bytes of tail
+        assert_eq!(&[0x28, 0x68, 0x00], encoder.encode(&second, &mut [0; 18]));
+
+        // Escapes are always 17 bytes
+        assert_eq!(17, encoder.encode(&Uuid::max(), &mut [0; 18]).len());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_decode_incomplete() {
+        let uuid = Uuid::from_u128(0x017f22e2_79b0_7cc3_98c4_dc0c0c07398f);
+        let encoded = encode_all(&[uuid, Uuid::max()]);
+
+        let mut decoder = V7DeltaDecoder::new();
+
+        for len in 0..17 {
+            assert_eq!(None, decoder.decode(&encoded[..len]).unwrap());
+        }
+
+        assert_eq!(Some((uuid, 17)), decoder.decode(&encoded).unwrap());
+        assert_eq!(None, decoder.decode(&encoded[17..33]).unwrap());
+        assert_eq!(
+            Some((Uuid::max(), 17)),
+            decoder.decode(&encoded[17..]).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_decode_invalid() {
+        // Shared more than 10 bytes
+        assert!(V7DeltaDecoder::new().decode(&[11]).is_err());
+        // An escape with a timestamp delta
+        assert!(V7DeltaDecoder::new().decode(&[0x1f]).is_err());
+        // A header that's too long
+        assert!(V7DeltaDecoder::new().decode(&[0xff; 9]).is_err());
+        // A timestamp that doesn't fit in 48 bits
+        assert!(V7DeltaDecoder::new()
+            .decode(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7f])
+            .is_err());
+        // Padding bits in the tail
+        assert!(V7DeltaDecoder::new().decode(&[9, 0x01]).is_err());
+    }
+}
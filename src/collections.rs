@@ -0,0 +1,13 @@
+//! Collections specialized for UUID keys.
+//!
+//! Random and time-ordered UUIDs are already well-distributed, so these
+//! collections can use the bits of the UUID directly where a general purpose
+//! collection would need to hash its keys first. Sorted UUIDs can also be
+//! stored as the differences between them, which take fewer bits than the
+//! UUIDs themselves.
+
+mod bloom;
+mod interner;
+pub mod sorted_set;
+
+pub use self::{bloom::BloomFilter, interner::UuidInterner, sorted_set::SortedUuidSet};
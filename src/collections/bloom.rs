@@ -0,0 +1,306 @@
+use crate::{std::vec::Vec, Uuid};
+
+/// A Bloom filter of UUIDs.
+///
+/// A Bloom filter is a compact probabilistic set. It can tell you a UUID
+/// definitely hasn't been inserted, or that it probably has, using a few bits
+/// per UUID regardless of how many are inserted. This is useful for things
+/// like deduplicating event IDs at ingest without holding a full set of them
+/// in memory.
+///
+/// Rather than hashing each UUID, the filter derives its bit positions from
+/// the bits of the UUID itself. This works well for UUIDs with random or
+/// hashed bits, like versions 3, 4, 5, and 7, but not for versions 1 and 6,
+/// which share their clock sequence and node ID between UUIDs generated on
+/// the same machine. Hash those first, such as with [`Uuid::new_v5`], if you
+/// need to filter them.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, collections::BloomFilter};
+/// let mut seen = BloomFilter::new(1_000, 0.01);
+///
+/// let a = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+/// let b = Uuid::parse_str("0191aab5-d7a8-7cd4-bb03-0b8e2a9eb2f1").unwrap();
+///
+/// assert!(seen.insert(&a));
+///
+/// // `a` has definitely been seen
+/// assert!(!seen.insert(&a));
+/// assert!(seen.contains(&a));
+///
+/// // `b` probably hasn't been seen
+/// assert!(!seen.contains(&b));
+/// ```
+///
+/// [`Uuid::new_v5`]: ../struct.Uuid.html#method.new_v5
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    num_bits: u64,
+    num_hashes: u32,
+}
+
+impl BloomFilter {
+    /// Create an empty filter sized to hold `expected_items` UUIDs with the
+    /// given `false_positive_rate`.
+    ///
+    /// The rate is a probability between `0.0` and `1.0`, like `0.01` for a
+    /// 1% chance that [`BloomFilter::contains`] returns `true` for a UUID that
+    /// was never inserted. Inserting more than `expected_items` UUIDs will
+    /// increase the rate of false positives.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `false_positive_rate` isn't greater than
+    /// `0.0` and less than `1.0`.
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        assert!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "the false positive rate must be between 0.0 and 1.0"
+        );
+
+        let expected_items = expected_items.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+
+        // The optimal size and number of hashes for a Bloom filter
+        let num_bits = (-expected_items * false_positive_rate.ln() / (ln2 * ln2)).ceil();
+        let num_hashes = (num_bits / expected_items * ln2).round();
+
+        BloomFilter::with_num_bits(num_bits as u64, num_hashes as u32)
+    }
+
+    /// Create an empty filter with `num_bits` bits that sets `num_hashes` bits
+    /// for each UUID.
+    ///
+    /// Prefer [`BloomFilter::new`], which calculates these from the number of
+    /// UUIDs expected and the rate of false positives that's acceptable. The
+    /// number of bits is rounded up to a multiple of 64, and at least 1 bit is
+    /// set for each UUID.
+    pub fn with_num_bits(num_bits: u64, num_hashes: u32) -> Self {
+        let words = ((num_bits.max(1) + 63) / 64) as usize;
+
+        BloomFilter {
+            bits: crate::std::vec![0; words],
+            num_bits: words as u64 * 64,
+            num_hashes: num_hashes.max(1),
+        }
+    }
+
+    /// The number of bits in the filter.
+    pub fn num_bits(&self) -> u64 {
+        self.num_bits
+    }
+
+    /// The number of bits set for each UUID.
+    pub fn num_hashes(&self) -> u32 {
+        self.num_hashes
+    }
+
+    /// Insert a UUID into the filter.
+    ///
+    /// Returns `true` if the UUID definitely wasn't in the filter already,
+    /// or `false` if it probably was.
+    pub fn insert(&mut self, uuid: &Uuid) -> bool {
+        let mut inserted = false;
+
+        for index in self.indexes(uuid) {
+            let (word, mask) = (index / 64, 1 << (index % 64));
+
+            inserted |= self.bits[word] & mask == 0;
+            self.bits[word] |= mask;
+        }
+
+        inserted
+    }
+
+    /// Check whether a UUID is in the filter.
+    ///
+    /// Returns `false` if the UUID definitely isn't in the filter, or `true`
+    /// if it probably is.
+    pub fn contains(&self, uuid: &Uuid) -> bool {
+        self.indexes(uuid)
+            .all(|index| self.bits[index / 64] & (1 << (index % 64)) != 0)
+    }
+
+    /// Remove all UUIDs from the filter.
+    pub fn clear(&mut self) {
+        for word in self.bits.iter_mut() {
+            *word = 0;
+        }
+    }
+
+    fn indexes(&self, uuid: &Uuid) -> impl Iterator<Item = usize> {
+        let (high_bits, low_bits) = uuid.as_u64_pair();
+
+        // The low bits of a version 4 UUID are random, except for the variant
+        // in the top 2 bits. Version 7 UUIDs generated by this crate start them
+        // with a counter that increments within each millisecond, so they're
+        // mixed before they're used to pick the first index. Only the bottom 32
+        // bits are random in both, so those are rotated to the top of the second
+        // hash, along with the high bits, which are mostly a timestamp in version 7
+        // UUIDs. Combining two hashes like this is as good as using `num_hashes`
+        // independent ones
+        let h1 = crate::hash::mix64(low_bits);
+        let h2 = (high_bits ^ low_bits.rotate_left(32)) | 1;
+
+        let num_bits = self.num_bits;
+
+        (0..self.num_hashes as u64).map(move |i| {
+            let hash = h1.wrapping_add(i.wrapping_mul(h2));
+
+            // Map the hash onto the range of bits without dividing
+            ((hash as u128 * num_bits as u128) >> 64) as usize
+        })
+    }
+}
+
+impl Extend<Uuid> for BloomFilter {
+    fn extend<I: IntoIterator<Item = Uuid>>(&mut self, iter: I) {
+        for uuid in iter {
+            self.insert(&uuid);
+        }
+    }
+}
+
+impl<'a> Extend<&'a Uuid> for BloomFilter {
+    fn extend<I: IntoIterator<Item = &'a Uuid>>(&mut self, iter: I) {
+        for uuid in iter {
+            self.insert(uuid);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::random_uuids;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_bloom_filter_sizing() {
+        let filter = BloomFilter::new(1_000, 0.01);
+
+        // About 9.6 bits and 7 hashes per item
+        assert_eq!(9_600, filter.num_bits());
+        assert_eq!(7, filter.num_hashes());
+
+        let filter = BloomFilter::with_num_bits(0, 0);
+
+        assert_eq!(64, filter.num_bits());
+        assert_eq!(1, filter.num_hashes());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_bloom_filter_false_positives() {
+        let inserted = random_uuids(10_000, 0x2545_f491_4f6c_dd1d);
+        let others = random_uuids(10_000, 42);
+
+        let mut filter = BloomFilter::new(inserted.len(), 0.01);
+        filter.extend(&inserted);
+
+        // No false negatives
+        assert!(inserted.iter().all(|uuid| filter.contains(uuid)));
+        assert!(inserted.iter().all(|uuid| !filter.insert(uuid)));
+
+        let false_positives = others.iter().filter(|uuid| filter.contains(uuid)).count();
+        assert!(false_positives < 200, "{}", false_positives);
+
+        filter.clear();
+        assert!(!inserted.iter().any(|uuid| filter.contains(uuid)));
+    }
+
+    #[test]
+    #[cfg(feature = "v7")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_bloom_filter_false_positives_v7() {
+        use crate::{ContextV7, Timestamp};
+
+        // A burst of UUIDs generated within the same millisecond only differ
+        // in their counter and random bits
+        let context = ContextV7::new();
+        let burst: Vec<Uuid> = (0..20_000)
+            .map(|_| Uuid::new_v7(Timestamp::from_unix(&context, 1_645_557_742, 0)))
+            .collect();
+
+        let (inserted, others) = burst.split_at(10_000);
+
+        let mut filter = BloomFilter::new(inserted.len(), 0.01);
+        filter.extend(inserted);
+
+        assert!(inserted.iter().all(|uuid| filter.contains(uuid)));
+
+        let false_positives = others.iter().filter(|uuid| filter.contains(uuid)).count();
+        assert!(false_positives < 200, "{}", false_positives);
+
+        // The counter doesn't pin every UUID in the burst to the same first index
+        let mut first_indexes: Vec<usize> = inserted
+            .iter()
+            .filter_map(|uuid| filter.indexes(uuid).next())
+            .collect();
+        first_indexes.sort_unstable();
+        first_indexes.dedup();
+
+        assert!(first_indexes.len() > 5_000, "{}", first_indexes.len());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_bloom_filter_v7() {
+        // Version 7 UUIDs generated in the same millisecond
+        let v7 = |uuid: &Uuid| {
+            let mut random_bytes = [0; 10];
+            random_bytes.copy_from_slice(&uuid.as_bytes()[..10]);
+
+            crate::timestamp::encode_unix_timestamp_millis(1_645_557_742_000, &random_bytes)
+        };
+
+        let inserted: Vec<Uuid> = random_uuids(10_000, 7).iter().map(v7).collect();
+        let others: Vec<Uuid> = random_uuids(10_000, 8).iter().map(v7).collect();
+
+        let mut filter = BloomFilter::new(inserted.len(), 0.01);
+        filter.extend(inserted.iter().copied());
+
+        assert!(inserted.iter().all(|uuid| filter.contains(uuid)));
+
+        let false_positives = others.iter().filter(|uuid| filter.contains(uuid)).count();
+        assert!(false_positives < 200, "{}", false_positives);
+    }
+}
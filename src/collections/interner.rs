@@ -0,0 +1,191 @@
+use crate::{
+    std::{collections::HashMap, iter::FromIterator, vec::Vec},
+    Uuid,
+};
+
+/// An interner that maps UUIDs to small integer handles.
+///
+/// Each distinct UUID is assigned the next `u32` handle, starting from `0`,
+/// the first time it's interned, and keeps that handle for the life of the
+/// interner. Handles are dense, so they can index into arrays and bitsets in
+/// graph or analytics code that only needs UUIDs at its edges.
+///
+/// Interning takes `&mut self`, but looking up handles and UUIDs only needs
+/// `&self` and never locks, so a built interner can be shared between threads
+/// behind an `Arc` and read concurrently.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, collections::UuidInterner};
+/// let a = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+/// let b = Uuid::parse_str("0191aab5-d7a8-7cd4-bb03-0b8e2a9eb2f1").unwrap();
+///
+/// let mut interner = UuidInterner::new();
+///
+/// assert_eq!(0, interner.intern(a));
+/// assert_eq!(1, interner.intern(b));
+/// assert_eq!(0, interner.intern(a));
+///
+/// assert_eq!(Some(1), interner.get(&b));
+/// assert_eq!(Some(a), interner.resolve(0));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct UuidInterner {
+    handles: HashMap<Uuid, u32>,
+    uuids: Vec<Uuid>,
+}
+
+impl UuidInterner {
+    /// Create an empty interner.
+    pub fn new() -> Self {
+        UuidInterner::default()
+    }
+
+    /// Create an empty interner with space for at least `capacity` UUIDs.
+    pub fn with_capacity(capacity: usize) -> Self {
+        UuidInterner {
+            handles: HashMap::with_capacity(capacity),
+            uuids: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Get the handle for a UUID, assigning it the next handle if it hasn't
+    /// been interned before.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if more than `u32::MAX` distinct UUIDs are
+    /// interned.
+    pub fn intern(&mut self, uuid: Uuid) -> u32 {
+        if let Some(handle) = self.handles.get(&uuid) {
+            return *handle;
+        }
+
+        assert!(
+            self.uuids.len() < u32::MAX as usize,
+            "too many UUIDs to intern"
+        );
+
+        let handle = self.uuids.len() as u32;
+
+        self.handles.insert(uuid, handle);
+        self.uuids.push(uuid);
+
+        handle
+    }
+
+    /// Get the handle for a UUID, if it has been interned.
+    pub fn get(&self, uuid: &Uuid) -> Option<u32> {
+        self.handles.get(uuid).copied()
+    }
+
+    /// Get the UUID for a handle, if it has been assigned.
+    pub fn resolve(&self, handle: u32) -> Option<Uuid> {
+        self.uuids.get(handle as usize).copied()
+    }
+
+    /// The number of UUIDs that have been interned.
+    pub fn len(&self) -> usize {
+        self.uuids.len()
+    }
+
+    /// Whether no UUIDs have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.uuids.is_empty()
+    }
+
+    /// The interned UUIDs, indexed by their handles.
+    pub fn as_slice(&self) -> &[Uuid] {
+        &self.uuids
+    }
+}
+
+impl Extend<Uuid> for UuidInterner {
+    fn extend<I: IntoIterator<Item = Uuid>>(&mut self, iter: I) {
+        for uuid in iter {
+            self.intern(uuid);
+        }
+    }
+}
+
+impl<'a> Extend<&'a Uuid> for UuidInterner {
+    fn extend<I: IntoIterator<Item = &'a Uuid>>(&mut self, iter: I) {
+        for uuid in iter {
+            self.intern(*uuid);
+        }
+    }
+}
+
+impl FromIterator<Uuid> for UuidInterner {
+    fn from_iter<I: IntoIterator<Item = Uuid>>(iter: I) -> Self {
+        let mut interner = UuidInterner::new();
+        interner.extend(iter);
+
+        interner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_interner_handles() {
+        let uuids: Vec<Uuid> = (0..1_000u128)
+            .map(|i| Uuid::from_u128(0x0191aab5_d7a8_7cd4_bb03_0b8e2a9eb2f1 + ((i % 500) << 64)))
+            .collect();
+
+        let mut interner = UuidInterner::with_capacity(500);
+
+        for (i, uuid) in uuids.iter().enumerate() {
+            assert_eq!((i % 500) as u32, interner.intern(*uuid));
+        }
+
+        assert_eq!(500, interner.len());
+        assert_eq!(&uuids[..500], interner.as_slice());
+
+        for (i, uuid) in uuids[..500].iter().enumerate() {
+            assert_eq!(Some(i as u32), interner.get(uuid));
+            assert_eq!(Some(*uuid), interner.resolve(i as u32));
+        }
+
+        assert_eq!(None, interner.get(&Uuid::nil()));
+        assert_eq!(None, interner.resolve(500));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_interner_from_iter() {
+        let interner: UuidInterner = [Uuid::max(), Uuid::nil(), Uuid::max()]
+            .iter()
+            .copied()
+            .collect();
+
+        assert_eq!(&[Uuid::max(), Uuid::nil()], interner.as_slice());
+        assert!(!interner.is_empty());
+        assert!(UuidInterner::new().is_empty());
+    }
+}
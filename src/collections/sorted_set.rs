@@ -0,0 +1,506 @@
+//! A compact, immutable set of UUIDs.
+//!
+//! See [`SortedUuidSet`] for details.
+
+use crate::{
+    std::{
+        fmt,
+        iter::{FromIterator, FusedIterator},
+        ops::{Bound, RangeBounds},
+        vec::Vec,
+    },
+    Uuid,
+};
+
+// The number of UUIDs in each compressed block. Larger blocks compress
+// slightly better, but make lookups scan further
+const BLOCK_LEN: usize = 128;
+
+/// A compact, immutable set of UUIDs.
+///
+/// UUIDs are stored in sorted blocks. The first UUID in each block is stored
+/// in full, and the rest are stored as the difference from the UUID before
+/// them, bit-packed at the width of the largest difference in the block. The
+/// more densely a set of UUIDs is packed, the smaller those differences are,
+/// so large sets of UUIDs, like hundreds of millions of IDs held in memory
+/// during a reconciliation job, take noticeably less than 16 bytes each.
+///
+/// Lookups binary search the first UUID of each block, and then decode at
+/// most one block.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, collections::SortedUuidSet};
+/// let uuids = [
+///     Uuid::from_u128(0x0191aab5_d7a8_7cd4_bb03_0b8e2a9eb2f1),
+///     Uuid::from_u128(0x0191aab5_d7a9_7cd4_bb03_0b8e2a9eb2f1),
+///     Uuid::from_u128(0x0191aab5_d7aa_7cd4_bb03_0b8e2a9eb2f1),
+/// ];
+///
+/// let set = SortedUuidSet::from_sorted_slice(&uuids);
+///
+/// assert_eq!(3, set.len());
+/// assert!(set.contains(&uuids[1]));
+/// assert!(!set.contains(&Uuid::nil()));
+///
+/// assert_eq!(
+///     vec![uuids[1], uuids[2]],
+///     set.range(uuids[1]..).collect::<Vec<_>>(),
+/// );
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SortedUuidSet {
+    len: usize,
+    // The first UUID in each block
+    heads: Vec<Uuid>,
+    // The bit offset of each block's differences in `packed`
+    offsets: Vec<u64>,
+    // The width in bits of each block's differences
+    widths: Vec<u8>,
+    packed: Vec<u64>,
+}
+
+impl SortedUuidSet {
+    /// Create an empty set.
+    pub fn new() -> Self {
+        SortedUuidSet::default()
+    }
+
+    /// Create a set from a slice of UUIDs sorted in ascending order.
+    ///
+    /// Duplicate UUIDs are only stored once.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `uuids` isn't sorted.
+    pub fn from_sorted_slice(uuids: &[Uuid]) -> Self {
+        let mut set = SortedUuidSet::new();
+
+        let mut uuids = uuids.iter().map(Uuid::as_u128).peekable();
+        let mut block = Vec::with_capacity(BLOCK_LEN);
+        let mut offset = 0;
+
+        while let Some(head) = uuids.next() {
+            // Collect the differences between each UUID in the block
+            block.clear();
+
+            let mut prev = head;
+            while block.len() < BLOCK_LEN - 1 {
+                let next = match uuids.next() {
+                    Some(next) => next,
+                    None => break,
+                };
+
+                assert!(next >= prev, "the UUIDs must be sorted");
+
+                if next != prev {
+                    block.push(next - prev);
+                    prev = next;
+                }
+            }
+
+            let width = block.iter().map(|delta| 128 - delta.leading_zeros()).max();
+            let width = width.unwrap_or(0);
+
+            set.heads.push(Uuid::from_u128(head));
+            set.offsets.push(offset as u64);
+            set.widths.push(width as u8);
+
+            for (i, delta) in block.iter().enumerate() {
+                write_bits(&mut set.packed, offset + i * width as usize, *delta, width);
+            }
+            offset += block.len() * width as usize;
+
+            set.len += 1 + block.len();
+
+            // Skip any duplicates of the last UUID in the block
+            while uuids.peek() == Some(&prev) {
+                uuids.next();
+            }
+
+            assert!(
+                uuids.peek().map_or(true, |next| *next > prev),
+                "the UUIDs must be sorted"
+            );
+        }
+
+        set
+    }
+
+    /// The number of UUIDs in the set.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Check whether a UUID is in the set.
+    pub fn contains(&self, uuid: &Uuid) -> bool {
+        let index = self.rank(uuid, false);
+
+        index < self.len && self.get(index) == Some(*uuid)
+    }
+
+    /// Get the UUID at the given position in the set, in ascending order.
+    pub fn get(&self, index: usize) -> Option<Uuid> {
+        if index >= self.len {
+            return None;
+        }
+
+        let (block, at) = (index / BLOCK_LEN, index % BLOCK_LEN);
+
+        let mut value = self.heads[block].as_u128();
+        for i in 0..at {
+            value += self.delta(block, i);
+        }
+
+        Some(Uuid::from_u128(value))
+    }
+
+    /// Iterate over the UUIDs in the set in ascending order.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter::new(self, 0, self.len)
+    }
+
+    /// Iterate over the UUIDs in the set within `range` in ascending order.
+    pub fn range<R: RangeBounds<Uuid>>(&self, range: R) -> Iter<'_> {
+        let start = match range.start_bound() {
+            Bound::Included(uuid) => self.rank(uuid, false),
+            Bound::Excluded(uuid) => self.rank(uuid, true),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(uuid) => self.rank(uuid, true),
+            Bound::Excluded(uuid) => self.rank(uuid, false),
+            Bound::Unbounded => self.len,
+        };
+
+        Iter::new(self, start, end.max(start))
+    }
+
+    /// The approximate number of bytes of memory used by the set.
+    pub fn memory_usage(&self) -> usize {
+        self.heads.len() * 16 + self.offsets.len() * 8 + self.widths.len() + self.packed.len() * 8
+    }
+
+    // The number of UUIDs in the set that are less than `uuid`,
+    // or also equal to it if `inclusive`
+    fn rank(&self, uuid: &Uuid, inclusive: bool) -> usize {
+        let target = uuid.as_u128();
+        let in_range = |value: u128| value < target || (inclusive && value == target);
+
+        // Find the last block that could contain `uuid`
+        let blocks = self.heads.partition_point(|head| in_range(head.as_u128()));
+        if blocks == 0 {
+            return 0;
+        }
+
+        let block = blocks - 1;
+        let block_len = (self.len - block * BLOCK_LEN).min(BLOCK_LEN);
+
+        let mut value = self.heads[block].as_u128();
+        let mut at = 1;
+        while at < block_len {
+            value += self.delta(block, at - 1);
+
+            if !in_range(value) {
+                break;
+            }
+
+            at += 1;
+        }
+
+        block * BLOCK_LEN + at
+    }
+
+    fn delta(&self, block: usize, i: usize) -> u128 {
+        let width = self.widths[block] as u32;
+
+        read_bits(
+            &self.packed,
+            self.offsets[block] as usize + i * width as usize,
+            width,
+        )
+    }
+}
+
+impl fmt::Debug for SortedUuidSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> IntoIterator for &'a SortedUuidSet {
+    type Item = Uuid;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Uuid> for SortedUuidSet {
+    /// Create a set from UUIDs in any order.
+    ///
+    /// The UUIDs are collected and sorted first, so this needs enough
+    /// memory to hold them uncompressed.
+    fn from_iter<I: IntoIterator<Item = Uuid>>(iter: I) -> Self {
+        let mut uuids: Vec<Uuid> = iter.into_iter().collect();
+        crate::sort::sort_unstable(&mut uuids);
+
+        SortedUuidSet::from_sorted_slice(&uuids)
+    }
+}
+
+/// An iterator over the UUIDs in a [`SortedUuidSet`], in ascending order.
+///
+/// This type is returned by [`SortedUuidSet::iter`] and [`SortedUuidSet::range`].
+#[derive(Clone)]
+pub struct Iter<'a> {
+    set: &'a SortedUuidSet,
+    next: usize,
+    end: usize,
+    value: u128,
+}
+
+impl<'a> Iter<'a> {
+    fn new(set: &'a SortedUuidSet, start: usize, end: usize) -> Self {
+        // Decode the UUID before `start` so iteration can continue from it
+        let value = match start % BLOCK_LEN {
+            0 => 0,
+            _ => set.get(start - 1).map_or(0, |uuid| uuid.as_u128()),
+        };
+
+        Iter {
+            set,
+            next: start,
+            end,
+            value,
+        }
+    }
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = Uuid;
+
+    fn next(&mut self) -> Option<Uuid> {
+        if self.next >= self.end {
+            return None;
+        }
+
+        let (block, at) = (self.next / BLOCK_LEN, self.next % BLOCK_LEN);
+
+        self.value = if at == 0 {
+            self.set.heads[block].as_u128()
+        } else {
+            self.value + self.set.delta(block, at - 1)
+        };
+        self.next += 1;
+
+        Some(Uuid::from_u128(self.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.next;
+
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for Iter<'a> {}
+
+impl<'a> FusedIterator for Iter<'a> {}
+
+impl<'a> fmt::Debug for Iter<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+fn write_bits(words: &mut Vec<u64>, offset: usize, value: u128, width: u32) {
+    if width > 64 {
+        write_u64(words, offset, value as u64, 64);
+        write_u64(words, offset + 64, (value >> 64) as u64, width - 64);
+    } else {
+        write_u64(words, offset, value as u64, width);
+    }
+}
+
+fn write_u64(words: &mut Vec<u64>, offset: usize, value: u64, width: u32) {
+    let (word, shift) = (offset / 64, offset % 64);
+
+    if words.len() < word + 2 {
+        words.resize(word + 2, 0);
+    }
+
+    words[word] |= value << shift;
+    if shift as u32 + width > 64 {
+        words[word + 1] |= value >> (64 - shift);
+    }
+}
+
+fn read_bits(words: &[u64], offset: usize, width: u32) -> u128 {
+    if width > 64 {
+        read_u64(words, offset, 64) as u128
+            | (read_u64(words, offset + 64, width - 64) as u128) << 64
+    } else {
+        read_u64(words, offset, width) as u128
+    }
+}
+
+fn read_u64(words: &[u64], offset: usize, width: u32) -> u64 {
+    if width == 0 {
+        return 0;
+    }
+
+    let (word, shift) = (offset / 64, offset % 64);
+
+    let mut value = words[word] >> shift;
+    if shift as u32 + width > 64 {
+        value |= words[word + 1] << (64 - shift);
+    }
+
+    value & (u64::MAX >> (64 - width))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    fn sorted_uuids(len: usize, seed: u64, spread_bits: u32) -> Vec<Uuid> {
+        let mut uuids: Vec<Uuid> = crate::tests::random_uuids(len, seed)
+            .into_iter()
+            .map(|uuid| {
+                let bits = uuid.as_u128() >> (128 - spread_bits);
+
+                Uuid::from_u128((0x0191_aab5_d7a8_u128 << 80) | bits)
+            })
+            .collect();
+
+        uuids.sort_unstable();
+        uuids
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_sorted_set_roundtrip() {
+        for len in [0, 1, 2, BLOCK_LEN - 1, BLOCK_LEN, BLOCK_LEN + 1, 10_000] {
+            for spread_bits in [4, 40, 80] {
+                let mut uuids = sorted_uuids(len, 0x2545_f491_4f6c_dd1d, spread_bits);
+                let set = SortedUuidSet::from_sorted_slice(&uuids);
+
+                uuids.dedup();
+
+                assert_eq!(uuids.len(), set.len());
+                assert_eq!(uuids, set.iter().collect::<Vec<_>>());
+                assert_eq!(uuids.len(), set.iter().len());
+
+                for (i, uuid) in uuids.iter().enumerate().step_by(7) {
+                    assert!(set.contains(uuid));
+                    assert_eq!(Some(*uuid), set.get(i));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_sorted_set_contains() {
+        let uuids = sorted_uuids(1_000, 42, 80);
+        let set = SortedUuidSet::from_sorted_slice(&uuids);
+
+        assert!(!set.contains(&Uuid::nil()));
+        assert!(!set.contains(&Uuid::max()));
+
+        for uuid in &uuids {
+            let next = Uuid::from_u128(uuid.as_u128() + 1);
+
+            assert_eq!(uuids.binary_search(&next).is_ok(), set.contains(&next));
+        }
+
+        assert!(!SortedUuidSet::new().contains(&Uuid::nil()));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_sorted_set_range() {
+        let uuids = sorted_uuids(1_000, 7, 80);
+        let set = SortedUuidSet::from_sorted_slice(&uuids);
+
+        let (a, b) = (uuids[100], uuids[400]);
+
+        assert_eq!(&uuids[100..400], &set.range(a..b).collect::<Vec<_>>()[..]);
+        assert_eq!(&uuids[100..=400], &set.range(a..=b).collect::<Vec<_>>()[..]);
+        assert_eq!(
+            &uuids[101..400],
+            &set.range((Bound::Excluded(a), Bound::Excluded(b)))
+                .collect::<Vec<_>>()[..]
+        );
+        assert_eq!(&uuids[..400], &set.range(..b).collect::<Vec<_>>()[..]);
+        assert_eq!(&uuids[100..], &set.range(a..).collect::<Vec<_>>()[..]);
+        assert_eq!(0, set.range(b..a).count());
+
+        // Bounds that aren't in the set
+        let between = Uuid::from_u128(a.as_u128() + 1);
+        assert_eq!(uuids[101], set.range(between..).next().unwrap());
+        assert_eq!(1_000, set.range(Uuid::nil()..=Uuid::max()).len());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_sorted_set_compresses() {
+        let uuids = sorted_uuids(100_000, 42, 64);
+        let set = SortedUuidSet::from_sorted_slice(&uuids);
+
+        assert!(set.memory_usage() < uuids.len() * 16 / 2);
+
+        let unsorted = uuids.iter().rev().copied().collect::<SortedUuidSet>();
+        assert_eq!(set, unsorted);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sorted_set_unsorted() {
+        SortedUuidSet::from_sorted_slice(&[Uuid::max(), Uuid::nil()]);
+    }
+}
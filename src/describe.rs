@@ -0,0 +1,392 @@
+//! Human-readable descriptions of UUIDs.
+//!
+//! [`Uuid::describe`] decodes the fields of a UUID, like its version and any
+//! embedded timestamp, into a [`Description`]. Its `Display` output is a
+//! multi-line summary suitable for admin and debug endpoints:
+//!
+//! ```text
+//! uuid:      017f22e2-79b0-7cc3-98c4-dc0c0c07398f
+//! variant:   RFC4122
+//! version:   7 (Unix time-based)
+//! timestamp: 2022-02-22T19:22:22.000Z
+//! ```
+
+use crate::{
+    std::fmt,
+    timestamp::{self, Timestamp, UUID_TICKS_BETWEEN_EPOCHS},
+    Uuid, Variant, Version,
+};
+
+/// A decoded summary of the fields in a UUID.
+///
+/// This type is returned by [`Uuid::describe`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Description {
+    uuid: Uuid,
+    variant: Variant,
+    version_num: usize,
+    version: Option<Version>,
+    timestamp: Option<Rfc3339>,
+    counter: Option<u16>,
+    node_id: Option<[u8; 6]>,
+}
+
+/// A timestamp embedded in a UUID, formatted as an RFC 3339 date and time in UTC.
+///
+/// The fractional seconds are formatted to the precision of the UUID: 100ns
+/// for versions 1 and 6, and milliseconds for version 7.
+///
+/// This type is returned by [`Description::timestamp_rfc3339`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rfc3339 {
+    seconds: i64,
+    subsec_nanos: u32,
+    digits: u8,
+}
+
+impl Description {
+    /// The described UUID.
+    pub const fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// The variant of the UUID.
+    pub const fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    /// The raw value of the version bits of the UUID.
+    pub const fn version_num(&self) -> usize {
+        self.version_num
+    }
+
+    /// The version of the UUID, if it's known.
+    ///
+    /// This is the same as [`Uuid::get_version_for_variant`], so it's `None`
+    /// for variants that don't define a version field.
+    pub const fn version(&self) -> Option<Version> {
+        self.version
+    }
+
+    /// The timestamp in a version 1, 6, or 7 UUID.
+    ///
+    /// This is the same as [`Uuid::get_timestamp`], except that it's `None`
+    /// for variants that don't define a version field.
+    pub const fn timestamp(&self) -> Option<Timestamp> {
+        match self.version {
+            Some(_) => self.uuid.get_timestamp(),
+            None => None,
+        }
+    }
+
+    /// The timestamp in a version 1, 6, or 7 UUID, formatted as RFC 3339.
+    pub const fn timestamp_rfc3339(&self) -> Option<Rfc3339> {
+        self.timestamp
+    }
+
+    /// The clock sequence in a version 1 or 6 UUID.
+    pub const fn counter(&self) -> Option<u16> {
+        self.counter
+    }
+
+    /// The node ID in a version 1 or 6 UUID.
+    pub const fn node_id(&self) -> Option<[u8; 6]> {
+        self.node_id
+    }
+}
+
+impl fmt::Display for Description {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let version = match self.version {
+            Some(Version::Nil) => "nil",
+            Some(Version::Mac) => "time-based",
+            Some(Version::Dce) => "DCE security",
+            Some(Version::Md5) => "name-based, MD5",
+            Some(Version::Random) => "random",
+            Some(Version::Sha1) => "name-based, SHA-1",
+            Some(Version::SortMac) => "reordered time-based",
+            Some(Version::SortRand) => "Unix time-based",
+            Some(Version::Custom) => "custom",
+            Some(Version::Max) => "max",
+            None if self.variant != Variant::RFC4122 => "not defined by variant",
+            None => "unknown",
+        };
+
+        writeln!(f, "uuid:      {}", self.uuid)?;
+        writeln!(f, "variant:   {}", self.variant)?;
+        write!(f, "version:   {} ({})", self.version_num, version)?;
+
+        if let Some(timestamp) = self.timestamp {
+            write!(f, "\ntimestamp: {}", timestamp)?;
+        }
+
+        if let Some(counter) = self.counter {
+            write!(f, "\ncounter:   {}", counter)?;
+        }
+
+        if let Some(node_id) = self.node_id {
+            write!(
+                f,
+                "\nnode id:   {:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+                node_id[0], node_id[1], node_id[2], node_id[3], node_id[4], node_id[5]
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Rfc3339 {
+    const fn from_gregorian(ticks: u64) -> Self {
+        // Version 1 and 6 timestamps can be before the Unix epoch
+        let ticks = ticks as i64 - UUID_TICKS_BETWEEN_EPOCHS as i64;
+
+        Rfc3339 {
+            seconds: ticks.div_euclid(10_000_000),
+            subsec_nanos: ticks.rem_euclid(10_000_000) as u32 * 100,
+            digits: 7,
+        }
+    }
+
+    const fn from_unix_millis(millis: u64) -> Self {
+        Rfc3339 {
+            seconds: (millis / 1_000) as i64,
+            subsec_nanos: (millis % 1_000) as u32 * 1_000_000,
+            digits: 3,
+        }
+    }
+}
+
+impl fmt::Display for Rfc3339 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let days = self.seconds.div_euclid(86_400);
+        let secs_of_day = self.seconds.rem_euclid(86_400);
+
+        let (year, month, day) = civil_from_days(days);
+
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:0width$}Z",
+            year,
+            month,
+            day,
+            secs_of_day / 3_600,
+            secs_of_day / 60 % 60,
+            secs_of_day % 60,
+            self.subsec_nanos / 10u32.pow(9 - self.digits as u32),
+            width = self.digits as usize,
+        )
+    }
+}
+
+// Converts days since the Unix epoch into a proleptic Gregorian date,
+// using Howard Hinnant's `civil_from_days` algorithm
+const fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+impl Uuid {
+    /// Describe the fields of this UUID.
+    ///
+    /// The returned [`Description`] includes the version and variant, along
+    /// with the timestamp, clock sequence, and node ID for the versions that
+    /// have them. Its `Display` output is a multi-line summary of those fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")?;
+    /// let description = uuid.describe();
+    ///
+    /// assert_eq!(
+    ///     "2022-02-22T19:22:22.000Z",
+    ///     description.timestamp_rfc3339().unwrap().to_string(),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     "uuid:      017f22e2-79b0-7cc3-98c4-dc0c0c07398f
+    /// variant:   RFC4122
+    /// version:   7 (Unix time-based)
+    /// timestamp: 2022-02-22T19:22:22.000Z",
+    ///     description.to_string(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn describe(&self) -> Description {
+        let version = self.get_version_for_variant();
+
+        let (timestamp, counter) = match version {
+            Some(Version::Mac) => {
+                let (ticks, counter) = timestamp::decode_gregorian_timestamp(self);
+
+                (Some(Rfc3339::from_gregorian(ticks)), Some(counter))
+            }
+            Some(Version::SortMac) => {
+                let (ticks, counter) = timestamp::decode_sorted_gregorian_timestamp(self);
+
+                (Some(Rfc3339::from_gregorian(ticks)), Some(counter))
+            }
+            Some(Version::SortRand) => {
+                let millis = timestamp::decode_unix_timestamp_millis(self);
+
+                (Some(Rfc3339::from_unix_millis(millis)), None)
+            }
+            _ => (None, None),
+        };
+
+        Description {
+            uuid: *self,
+            variant: self.get_variant(),
+            version_num: self.get_version_num(),
+            version,
+            timestamp,
+            counter,
+            node_id: match version {
+                Some(_) => self.get_node_id(),
+                None => None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::std::string::ToString;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_describe_v1() {
+        let ticks = UUID_TICKS_BETWEEN_EPOCHS + 1_645_557_742 * 10_000_000 + 1_234;
+        let uuid = timestamp::encode_gregorian_timestamp(ticks, 42, &[1, 2, 3, 4, 5, 0xab]);
+
+        let description = uuid.describe();
+
+        assert_eq!(uuid, description.uuid());
+        assert_eq!(Some(Version::Mac), description.version());
+        assert_eq!(Some(42), description.counter());
+        assert_eq!(Some([1, 2, 3, 4, 5, 0xab]), description.node_id());
+        assert_eq!(uuid.get_timestamp(), description.timestamp());
+
+        assert_eq!(
+            format_args!(
+                "uuid:      {}\nvariant:   RFC4122\nversion:   1 (time-based)\ntimestamp: 2022-02-22T19:22:22.0001234Z\ncounter:   42\nnode id:   01:02:03:04:05:ab",
+                uuid
+            )
+            .to_string(),
+            description.to_string()
+        );
+
+        let uuid = timestamp::encode_sorted_gregorian_timestamp(ticks, 42, &[0; 6]);
+
+        assert_eq!(
+            "2022-02-22T19:22:22.0001234Z",
+            uuid.describe().timestamp_rfc3339().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_describe_not_time_based() {
+        let description = crate::tests::new().describe();
+
+        assert_eq!(Some(Version::Random), description.version());
+        assert_eq!(None, description.timestamp());
+        assert_eq!(None, description.counter());
+        assert_eq!(None, description.node_id());
+
+        let description = Uuid::from_u128(0x67e55044_10b1_b26f_9247_bb680e5fe0c8).describe();
+
+        assert_eq!(None, description.version());
+        assert_eq!(
+            "uuid:      67e55044-10b1-b26f-9247-bb680e5fe0c8\nvariant:   RFC4122\nversion:   11 (unknown)",
+            description.to_string()
+        );
+
+        // A legacy Microsoft GUID whose version bits look like a version 1 UUID
+        let description = Uuid::from_u128(0x67e55044_10b1_126f_c247_bb680e5fe0c8).describe();
+
+        assert_eq!(Variant::Microsoft, description.variant());
+        assert_eq!(1, description.version_num());
+        assert_eq!(None, description.version());
+        assert_eq!(None, description.timestamp());
+        assert_eq!(None, description.node_id());
+        assert_eq!(
+            "uuid:      67e55044-10b1-126f-c247-bb680e5fe0c8\nvariant:   Microsoft\nversion:   1 (not defined by variant)",
+            description.to_string()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_rfc3339() {
+        let cases = [
+            (0, "1582-10-15T00:00:00.0000000Z"),
+            (UUID_TICKS_BETWEEN_EPOCHS, "1970-01-01T00:00:00.0000000Z"),
+            (
+                UUID_TICKS_BETWEEN_EPOCHS - 1,
+                "1969-12-31T23:59:59.9999999Z",
+            ),
+            (
+                UUID_TICKS_BETWEEN_EPOCHS + 951_782_400 * 10_000_000,
+                "2000-02-29T00:00:00.0000000Z",
+            ),
+        ];
+
+        for (ticks, expected) in cases {
+            assert_eq!(expected, Rfc3339::from_gregorian(ticks).to_string());
+        }
+
+        assert_eq!(
+            "1970-01-01T00:00:00.001Z",
+            Rfc3339::from_unix_millis(1).to_string()
+        );
+        assert_eq!(
+            "10889-08-02T05:31:50.655Z",
+            Rfc3339::from_unix_millis((1 << 48) - 1).to_string()
+        );
+    }
+}
@@ -0,0 +1,280 @@
+//! Sources of random bytes.
+//!
+//! By default, UUIDs are generated with random bytes from the operating system,
+//! or from the hook installed for `getrandom` on targets without one. An
+//! [`EntropySource`] can be passed explicitly to [`Builder::random_with`] or
+//! to a [`Generator`] instead, so firmware with its own hardware RNG, or several
+//! independent drivers each with their own RNG, don't need to share a global hook.
+//!
+//! When a single source should be used for the whole program, it can be installed
+//! with [`set_random_source`] instead. Methods like [`Uuid::new_v4`] and
+//! [`Uuid::now_v7`] then read their random bytes from it instead of `getrandom`.
+//! On targets `getrandom` doesn't support, the `rng-custom` feature enables those
+//! methods without depending on `getrandom` at all.
+//!
+//! [`Builder::random_with`]: ../struct.Builder.html#method.random_with
+//! [`Generator`]: ../v7/struct.Generator.html
+//! [`Uuid::new_v4`]: ../struct.Uuid.html#method.new_v4
+//! [`Uuid::now_v7`]: ../struct.Uuid.html#method.now_v7
+
+#[cfg(all(
+    any(feature = "rng", feature = "rng-custom"),
+    not(feature = "forbid-unsafe")
+))]
+use crate::std::{
+    mem, ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+/// A source of random bytes for generating UUIDs.
+///
+/// This trait is implemented for any `FnMut(&mut [u8])` closure, which makes
+/// it easy to wrap a hardware RNG peripheral.
+///
+/// Random bytes are used as-is in UUIDs, so they should come from a source
+/// that's suitable for generating unique identifiers, like a cryptographically
+/// secure RNG.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Builder, Version};
+/// # struct Hrng;
+/// # impl Hrng { fn fill(&mut self, dest: &mut [u8]) { dest.fill(0x7e) } }
+/// let mut hrng = Hrng;
+///
+/// let uuid = Builder::random_with(&mut |dest: &mut [u8]| hrng.fill(dest)).into_uuid();
+///
+/// assert_eq!(Some(Version::Random), uuid.get_version());
+/// ```
+pub trait EntropySource {
+    /// Fill `dest` with random bytes.
+    fn fill_bytes(&mut self, dest: &mut [u8]);
+}
+
+impl<F: FnMut(&mut [u8])> EntropySource for F {
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self(dest)
+    }
+}
+
+/// An [`EntropySource`] that uses the same source of randomness as [`Uuid::new_v4`].
+///
+/// This is the source installed with [`set_random_source`] if there is one,
+/// otherwise the operating system's RNG, or a faster userspace RNG if the
+/// `fast-rng` feature is enabled.
+///
+/// Note that usage of this type requires the `v4` or `v7` feature of this crate
+/// to be enabled.
+///
+/// # Panics
+///
+/// Filling bytes from this source panics if the source of randomness fails.
+///
+/// [`Uuid::new_v4`]: ../struct.Uuid.html#method.new_v4
+#[cfg(any(feature = "v4", feature = "v7", feature = "rng-custom"))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemEntropy;
+
+#[cfg(any(feature = "v4", feature = "v7", feature = "rng-custom"))]
+impl EntropySource for SystemEntropy {
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(16) {
+            chunk.copy_from_slice(&crate::rng::u128().to_ne_bytes()[..chunk.len()]);
+        }
+    }
+}
+
+// Stores a `fn(&mut [u8])`, or null if no source is set
+#[cfg(all(
+    any(feature = "rng", feature = "rng-custom"),
+    not(feature = "forbid-unsafe")
+))]
+static RANDOM_SOURCE: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+#[cfg(all(
+    any(feature = "rng", feature = "rng-custom"),
+    feature = "forbid-unsafe"
+))]
+type RandomSource = fn(&mut [u8]);
+
+// A function pointer can't be loaded from an atomic without unsafe code, so
+// it's kept behind a spin lock, which also works without `std`
+#[cfg(all(
+    any(feature = "rng", feature = "rng-custom"),
+    feature = "forbid-unsafe"
+))]
+static RANDOM_SOURCE: spin::RwLock<Option<RandomSource>> = spin::RwLock::new(None);
+
+/// Set the source of random bytes used to generate UUIDs in place of the
+/// operating system's RNG.
+///
+/// This replaces any source that was previously set. Once a source is set,
+/// [`Uuid::new_v4`], [`Uuid::now_v7`], [`SystemEntropy`], and everything else
+/// that would otherwise read from `getrandom` or `rand` read from `source`
+/// instead, on every thread. This makes it possible to generate UUIDs on
+/// bare-metal targets from a hardware TRNG or a CSPRNG seeded by one.
+///
+/// The source can't fail, so methods like `Uuid::try_new_v4` always succeed
+/// while it's set. It must be set before any UUIDs are generated to avoid them
+/// reaching for the operating system's RNG.
+///
+/// On targets that `getrandom` doesn't support, enable the `rng-custom` feature
+/// of this crate instead of `v4` or `v7`. It compiles the same methods for
+/// generating UUIDs without depending on `getrandom` at all, so random bytes
+/// can only come from this source, and generating a UUID before it's set panics
+/// or returns an error.
+///
+/// Note that usage of this function requires the `v4`, `v7`, or `rng-custom`
+/// feature of this crate to be enabled.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{entropy, Uuid, Version};
+/// fn hrng_fill(dest: &mut [u8]) {
+///     // Read from the hardware RNG peripheral
+///     # for (i, byte) in dest.iter_mut().enumerate() { *byte = i as u8 ^ 0x5a; }
+/// }
+///
+/// entropy::set_random_source(hrng_fill);
+/// # #[cfg(feature = "v4")]
+/// # {
+///
+/// let uuid = Uuid::new_v4();
+///
+/// assert_eq!(Some(Version::Random), uuid.get_version());
+/// # }
+/// # entropy::clear_random_source();
+/// ```
+///
+/// [`Uuid::new_v4`]: ../struct.Uuid.html#method.new_v4
+/// [`Uuid::now_v7`]: ../struct.Uuid.html#method.now_v7
+#[cfg(any(feature = "rng", feature = "rng-custom"))]
+pub fn set_random_source(source: fn(&mut [u8])) {
+    #[cfg(not(feature = "forbid-unsafe"))]
+    RANDOM_SOURCE.store(source as *mut (), Ordering::Release);
+
+    #[cfg(feature = "forbid-unsafe")]
+    {
+        *RANDOM_SOURCE.write() = Some(source);
+    }
+}
+
+/// Remove the source set with [`set_random_source`], if one is set.
+///
+/// UUIDs are generated from the operating system's RNG again after this is called.
+///
+/// Note that usage of this function requires the same features of this crate
+/// as [`set_random_source`].
+#[cfg(any(feature = "rng", feature = "rng-custom"))]
+pub fn clear_random_source() {
+    #[cfg(not(feature = "forbid-unsafe"))]
+    RANDOM_SOURCE.store(ptr::null_mut(), Ordering::Release);
+
+    #[cfg(feature = "forbid-unsafe")]
+    {
+        *RANDOM_SOURCE.write() = None;
+    }
+}
+
+/// Fill `dest` from the source set with [`set_random_source`].
+///
+/// This returns `false`, leaving `dest` untouched, if no source is set.
+#[cfg(all(
+    any(feature = "rng", feature = "rng-custom"),
+    not(feature = "forbid-unsafe")
+))]
+#[inline]
+pub(crate) fn fill_from_random_source(dest: &mut [u8]) -> bool {
+    let source = RANDOM_SOURCE.load(Ordering::Acquire);
+
+    if source.is_null() {
+        return false;
+    }
+
+    // SAFETY: The only non-null values stored are `fn(&mut [u8])` pointers
+    let source = unsafe { mem::transmute::<*mut (), fn(&mut [u8])>(source) };
+    source(dest);
+
+    true
+}
+
+#[cfg(all(
+    any(feature = "rng", feature = "rng-custom"),
+    feature = "forbid-unsafe"
+))]
+#[inline]
+pub(crate) fn fill_from_random_source(dest: &mut [u8]) -> bool {
+    let source = *RANDOM_SOURCE.read();
+
+    match source {
+        Some(source) => {
+            source(dest);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Read a random `u128` from an entropy source.
+pub(crate) fn u128(entropy: &mut impl EntropySource) -> u128 {
+    let mut bytes = [0; 16];
+    entropy.fill_bytes(&mut bytes);
+
+    u128::from_ne_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_closure_source() {
+        let mut next = 0u8;
+        let mut counting = |dest: &mut [u8]| {
+            for byte in dest {
+                *byte = next;
+                next = next.wrapping_add(1);
+            }
+        };
+
+        assert_eq!(
+            u128::from_ne_bytes([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]),
+            u128(&mut counting)
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "v4", feature = "v7", feature = "rng-custom"))]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_system_entropy_partial_chunks() {
+        let mut bytes = [0u8; 37];
+        SystemEntropy.fill_bytes(&mut bytes);
+
+        // 37 zero bytes in a row from a working RNG is vanishingly unlikely
+        assert_ne!([0u8; 37], bytes);
+    }
+}
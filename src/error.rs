@@ -1,8 +1,31 @@
-use crate::std::fmt;
+use crate::std::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+#[cfg(feature = "std")]
+use crate::std::boxed::Box;
 
 /// A general error that can occur when working with UUIDs.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct Error(pub(crate) ErrorKind);
+///
+/// When the `std` feature is enabled, errors produced while parsing a string
+/// also keep a copy of (the start of) that string, which is included in the
+/// error's `Display` output and can be retrieved with [`Error::input`]. The
+/// input is only informational: it doesn't
+/// participate in equality or hashing.
+///
+/// Since the input is allocated, an `Error` can't be discarded at compile
+/// time. A `const` item can hold a `Result<_, Error>`, but can't match on
+/// one; use [`Uuid::from_str_const`] or the `uuid!` macro to parse UUIDs
+/// in `const` items instead.
+///
+/// [`Uuid::from_str_const`]: struct.Uuid.html#method.from_str_const
+#[derive(Clone, Debug)]
+pub struct Error {
+    pub(crate) kind: ErrorKind,
+    #[cfg(feature = "std")]
+    input: Option<Input>,
+}
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub(crate) enum ErrorKind {
@@ -16,6 +39,8 @@ pub(crate) enum ErrorKind {
     SimpleLength { len: usize },
     /// A byte array didn't contain 16 bytes
     ByteLength { len: usize },
+    /// A byte slice didn't contain a whole number of 16 byte UUIDs
+    SliceLength { len: usize },
     /// A hyphenated [`Uuid`] didn't contain 5 groups
     ///
     /// [`Uuid`]: ../struct.Uuid.html
@@ -32,10 +57,222 @@ pub(crate) enum ErrorKind {
     InvalidUTF8,
     /// The UUID is nil.
     Nil,
+    /// A buffer was too small to encode a [`Uuid`] into.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    BufferTooSmall { len: usize, required: usize },
+    /// A checksummed [`Uuid`] didn't contain 39 characters.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ChecksumLength { len: usize },
+    /// Invalid character in the check symbols of a checksummed [`Uuid`].
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ChecksumChar { character: char, index: usize },
+    /// The check symbols of a checksummed [`Uuid`] didn't match.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    Checksum,
+    /// A [`Uuid`] didn't contain a valid version 8 payload.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    #[cfg(feature = "v8")]
+    Payload,
+    /// An alphabet for a radix encoding wasn't valid.
+    Alphabet,
+    /// Invalid character in a radix encoded [`Uuid`] string.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    RadixChar { character: char, index: usize },
+    /// A radix encoded [`Uuid`] string was empty or too long.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    RadixLength { len: usize, max: usize },
+    /// A radix encoded [`Uuid`] string had a value larger than 128 bits.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    RadixOverflow,
+    /// A record in a delta encoded stream of UUIDs wasn't valid.
+    DeltaRecord,
+    /// The name of a [`Version`] wasn't recognized.
+    ///
+    /// [`Version`]: ../enum.Version.html
+    VersionName,
+    /// The name of a [`Variant`] wasn't recognized.
+    ///
+    /// [`Variant`]: ../enum.Variant.html
+    VariantName,
+    /// A mnemonic didn't contain 12 words.
+    #[cfg(feature = "bip39")]
+    MnemonicWordCount { count: usize },
+    /// A word in a mnemonic wasn't in the wordlist.
+    #[cfg(feature = "bip39")]
+    MnemonicWord { index: usize },
+    /// The checksum of a mnemonic didn't match.
+    #[cfg(feature = "bip39")]
+    MnemonicChecksum,
+    /// A ULID string wasn't 26 characters long.
+    #[cfg(feature = "ulid")]
+    UlidLength { len: usize },
+    /// A [`Builder`] didn't use the RFC 9562 variant.
+    ///
+    /// [`Builder`]: ../struct.Builder.html
+    BuildVariant,
+    /// A [`Builder`] didn't have a version defined by RFC 9562.
+    ///
+    /// [`Builder`]: ../struct.Builder.html
+    BuildVersion { version: u8 },
+    /// A [`Builder`] for a time-based version had a zero timestamp.
+    ///
+    /// [`Builder`]: ../struct.Builder.html
+    BuildTimestamp,
+    /// The system clock couldn't be read.
+    ///
+    /// Reading `SystemTime` can't fail, so this is only produced when JavaScript's
+    /// `Date` is used on `wasm32-unknown-unknown` with the `js` feature.
+    #[cfg(all(feature = "std", any(feature = "v7", feature = "rng-custom")))]
+    #[cfg_attr(
+        not(all(
+            feature = "js",
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        )),
+        allow(dead_code)
+    )]
+    Clock,
+    /// The system clock is set before the Unix epoch.
+    #[cfg(all(feature = "std", any(feature = "v7", feature = "rng-custom")))]
+    ClockBeforeEpoch,
+    /// The source of randomness couldn't produce random bytes.
+    #[cfg(any(
+        feature = "v4",
+        feature = "rng-custom",
+        all(feature = "std", feature = "v7")
+    ))]
+    Rng { raw_os_error: Option<i32> },
     /// Some other error occurred.
     Other,
 }
 
+impl Error {
+    pub(crate) const fn new(kind: ErrorKind) -> Self {
+        Error {
+            kind,
+            #[cfg(feature = "std")]
+            input: None,
+        }
+    }
+
+    /// Attach a truncated copy of the string that failed to parse.
+    #[cfg(feature = "std")]
+    pub(crate) fn with_input(mut self, input: &str) -> Self {
+        self.input = Some(Input::new(input));
+        self
+    }
+
+    /// The string that failed to parse, if it was retained.
+    ///
+    /// Long inputs are truncated, so this may only be a prefix of the
+    /// original string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let err = Uuid::parse_str("not-a-uuid").unwrap_err();
+    ///
+    /// assert_eq!(Some("not-a-uuid"), err.input());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn input(&self) -> Option<&str> {
+        self.input.as_ref().map(Input::as_str)
+    }
+
+    /// The error code from the operating system, if this error was caused by
+    /// its source of randomness failing.
+    ///
+    /// This is the same code as [`std::io::Error::raw_os_error`], so it can be
+    /// turned into an `io::Error` with [`std::io::Error::from_raw_os_error`].
+    #[cfg(any(
+        feature = "v4",
+        feature = "rng-custom",
+        all(feature = "std", feature = "v7")
+    ))]
+    pub fn raw_os_error(&self) -> Option<i32> {
+        match self.kind {
+            ErrorKind::Rng { raw_os_error } => raw_os_error,
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+}
+
+impl Eq for Error {}
+
+impl Hash for Error {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.kind.hash(state)
+    }
+}
+
+/// A truncated copy of an input string.
+///
+/// This is only allocated when parsing fails, so `Error`, and any `Result`
+/// containing one, only grows by the size of a boxed `str`. When the input is
+/// truncated, the character after the cut is kept too, so the copy is longer
+/// than `MAX_LEN` exactly when the input was truncated.
+#[cfg(feature = "std")]
+#[derive(Clone)]
+struct Input(Box<str>);
+
+#[cfg(feature = "std")]
+impl Input {
+    // Long enough for any well-formed UUID string, including URNs
+    const MAX_LEN: usize = 45;
+
+    fn new(input: &str) -> Self {
+        let len = Self::prefix_len(input);
+        let end = input[len..]
+            .chars()
+            .next()
+            .map_or(len, |next| len + next.len_utf8());
+
+        Input(input[..end].into())
+    }
+
+    fn prefix_len(input: &str) -> usize {
+        let mut len = input.len().min(Self::MAX_LEN);
+
+        // Don't split a multi-byte character
+        while !input.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        len
+    }
+
+    fn as_str(&self) -> &str {
+        &self.0[..Self::prefix_len(&self.0)]
+    }
+
+    fn is_truncated(&self) -> bool {
+        self.0.len() > Self::MAX_LEN
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
 /// A string that is guaranteed to fail to parse to a [`Uuid`].
 ///
 /// This type acts as a lightweight error indicator, suggesting
@@ -52,7 +289,7 @@ impl<'a> InvalidUuid<'a> {
         // Check whether or not the input was ever actually a valid UTF8 string
         let input_str = match std::str::from_utf8(self.0) {
             Ok(s) => s,
-            Err(_) => return Error(ErrorKind::InvalidUTF8),
+            Err(_) => return Error::new(ErrorKind::InvalidUTF8),
         };
 
         let (uuid_str, offset, simple) = match input_str.as_bytes() {
@@ -66,15 +303,18 @@ impl<'a> InvalidUuid<'a> {
         let mut hyphen_count = 0;
         let mut group_bounds = [0; 4];
 
-        // SAFETY: the byte array came from a valid utf8 string,
+        // The byte array came from a valid utf8 string,
         // and is aligned along char boundaries.
-        let uuid_str = unsafe { std::str::from_utf8_unchecked(uuid_str) };
+        let uuid_str = match std::str::from_utf8(uuid_str) {
+            Ok(s) => s,
+            Err(_) => return Error::new(ErrorKind::InvalidUTF8),
+        };
 
         for (index, character) in uuid_str.char_indices() {
             let byte = character as u8;
             if character as u32 - byte as u32 > 0 {
                 // Multibyte char
-                return Error(ErrorKind::Char {
+                return Error::new(ErrorKind::Char {
                     character,
                     index: index + offset + 1,
                 });
@@ -86,7 +326,7 @@ impl<'a> InvalidUuid<'a> {
                 hyphen_count += 1;
             } else if !byte.is_ascii_hexdigit() {
                 // Non-hex char
-                return Error(ErrorKind::Char {
+                return Error::new(ErrorKind::Char {
                     character: byte as char,
                     index: index + offset + 1,
                 });
@@ -97,13 +337,13 @@ impl<'a> InvalidUuid<'a> {
             // This means that we tried and failed to parse a simple uuid.
             // Since we verified that all the characters are valid, this means
             // that it MUST have an invalid length.
-            Error(ErrorKind::SimpleLength {
+            Error::new(ErrorKind::SimpleLength {
                 len: input_str.len(),
             })
         } else if hyphen_count != 4 {
             // We tried to parse a hyphenated variant, but there weren't
             // 5 groups (4 hyphen splits).
-            Error(ErrorKind::GroupCount {
+            Error::new(ErrorKind::GroupCount {
                 count: hyphen_count + 1,
             })
         } else {
@@ -111,7 +351,7 @@ impl<'a> InvalidUuid<'a> {
             const BLOCK_STARTS: [usize; 5] = [0, 9, 14, 19, 24];
             for i in 0..4 {
                 if group_bounds[i] != BLOCK_STARTS[i + 1] - 1 {
-                    return Error(ErrorKind::GroupLength {
+                    return Error::new(ErrorKind::GroupLength {
                         group: i,
                         len: group_bounds[i] - BLOCK_STARTS[i],
                         index: offset + BLOCK_STARTS[i] + 1,
@@ -120,7 +360,7 @@ impl<'a> InvalidUuid<'a> {
             }
 
             // The last group must be too long
-            Error(ErrorKind::GroupLength {
+            Error::new(ErrorKind::GroupLength {
                 group: 4,
                 len: input_str.len() - BLOCK_STARTS[4],
                 index: offset + BLOCK_STARTS[4] + 1,
@@ -132,7 +372,7 @@ impl<'a> InvalidUuid<'a> {
 // NOTE: This impl is part of the public API. Breaking changes to it should be carefully considered
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self.0 {
+        match self.kind {
             ErrorKind::Char {
                 character, index, ..
             } => {
@@ -148,6 +388,13 @@ impl fmt::Display for Error {
             ErrorKind::ByteLength { len } => {
                 write!(f, "invalid length: expected 16 bytes, found {}", len)
             }
+            ErrorKind::SliceLength { len } => {
+                write!(
+                    f,
+                    "invalid length: expected a multiple of 16 bytes, found {}",
+                    len
+                )
+            }
             ErrorKind::GroupCount { count } => {
                 write!(f, "invalid group count: expected 5, found {}", count)
             }
@@ -161,7 +408,162 @@ impl fmt::Display for Error {
             }
             ErrorKind::InvalidUTF8 => write!(f, "non-UTF8 input"),
             ErrorKind::Nil => write!(f, "the UUID is nil"),
+            ErrorKind::BufferTooSmall { len, required } => write!(
+                f,
+                "buffer too small: expected at least {} bytes, found {}",
+                required, len
+            ),
+            ErrorKind::ChecksumLength { len } => write!(
+                f,
+                "invalid length: expected length 39 for checksummed format, found {}",
+                len
+            ),
+            ErrorKind::ChecksumChar { character, index } => write!(
+                f,
+                "invalid character: expected a `-` followed by 2 check symbols, found `{}` at {}",
+                character, index
+            ),
+            ErrorKind::Checksum => write!(
+                f,
+                "invalid checksum: the check symbols don't match the UUID"
+            ),
+            #[cfg(feature = "v8")]
+            ErrorKind::Payload => write!(
+                f,
+                "invalid payload: expected a version 8 UUID with a valid payload"
+            ),
+            ErrorKind::Alphabet => write!(
+                f,
+                "invalid alphabet: expected between 2 and 62 unique printable ASCII symbols"
+            ),
+            ErrorKind::RadixChar { character, index } => write!(
+                f,
+                "invalid character: expected a symbol from the alphabet, found `{}` at {}",
+                character, index
+            ),
+            ErrorKind::RadixLength { len, max } => write!(
+                f,
+                "invalid length: expected between 1 and {} symbols, found {}",
+                max, len
+            ),
+            ErrorKind::RadixOverflow => {
+                write!(f, "invalid value: the input doesn't fit in 128 bits")
+            }
+            ErrorKind::DeltaRecord => write!(f, "invalid record in a delta encoded UUID stream"),
+            ErrorKind::VersionName => {
+                write!(f, "invalid version: expected `nil`, `max`, or `v1` to `v8`")
+            }
+            ErrorKind::VariantName => write!(
+                f,
+                "invalid variant: expected `NCS`, `RFC4122`, `Microsoft`, or `Future`"
+            ),
+            ErrorKind::BuildVariant => {
+                write!(f, "invalid UUID: expected the RFC 9562 variant")
+            }
+            ErrorKind::BuildVersion { version } => write!(
+                f,
+                "invalid UUID: expected a version defined by RFC 9562, found {}",
+                version
+            ),
+            ErrorKind::BuildTimestamp => write!(
+                f,
+                "invalid UUID: expected a non-zero timestamp for a time-based version"
+            ),
+            #[cfg(feature = "bip39")]
+            ErrorKind::MnemonicWordCount { count } => {
+                write!(f, "invalid mnemonic: expected 12 words, found {}", count)
+            }
+            #[cfg(feature = "bip39")]
+            ErrorKind::MnemonicWord { index } => write!(
+                f,
+                "invalid mnemonic: word {} isn't in the wordlist",
+                index + 1
+            ),
+            #[cfg(feature = "bip39")]
+            ErrorKind::MnemonicChecksum => {
+                write!(f, "invalid mnemonic: the checksum doesn't match")
+            }
+            #[cfg(feature = "ulid")]
+            ErrorKind::UlidLength { len } => {
+                write!(
+                    f,
+                    "invalid length: expected 26 characters for a ULID, found {}",
+                    len
+                )
+            }
+            #[cfg(all(feature = "std", any(feature = "v7", feature = "rng-custom")))]
+            ErrorKind::Clock => write!(f, "failed to read the system clock"),
+            #[cfg(all(feature = "std", any(feature = "v7", feature = "rng-custom")))]
+            ErrorKind::ClockBeforeEpoch => write!(
+                f,
+                "invalid system clock: the current time is before the Unix epoch"
+            ),
+            #[cfg(any(
+                feature = "v4",
+                feature = "rng-custom",
+                all(feature = "std", feature = "v7")
+            ))]
+            ErrorKind::Rng { raw_os_error } => {
+                write!(f, "failed to get random bytes")?;
+
+                if let Some(code) = raw_os_error {
+                    write!(f, " (os error {})", code)?;
+                }
+
+                Ok(())
+            }
             ErrorKind::Other => write!(f, "failed to parse a UUID"),
+        }?;
+
+        #[cfg(feature = "std")]
+        {
+            if let Some(ref input) = self.input {
+                write!(f, " (input: {:?}", input.as_str())?;
+
+                if input.is_truncated() {
+                    f.write_str("…")?;
+                }
+
+                f.write_str(")")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Without `std`, `Error` can still implement the error trait from `core`
+// on toolchains where it's stable. This is opted into with the `core-error` feature
+#[cfg(all(not(feature = "std"), feature = "core-error"))]
+mod core_support {
+    use super::*;
+
+    impl core::error::Error for Error {}
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ))]
+        use wasm_bindgen_test::*;
+
+        #[test]
+        #[cfg_attr(
+            all(
+                target_arch = "wasm32",
+                target_vendor = "unknown",
+                target_os = "unknown"
+            ),
+            wasm_bindgen_test
+        )]
+        fn test_core_error() {
+            fn assert_error<E: core::error::Error>(_: &E) {}
+
+            assert_error(&Error::new(ErrorKind::Nil));
         }
     }
 }
@@ -172,4 +574,112 @@ mod std_support {
     use crate::std::error;
 
     impl error::Error for Error {}
+
+    #[cfg(test)]
+    mod tests {
+        use crate::{std::string::ToString, Uuid};
+
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ))]
+        use wasm_bindgen_test::*;
+
+        #[test]
+        #[cfg_attr(
+            all(
+                target_arch = "wasm32",
+                target_vendor = "unknown",
+                target_os = "unknown"
+            ),
+            wasm_bindgen_test
+        )]
+        fn test_input() {
+            let err = Uuid::parse_str("67e5504410b1426%9247bb680e5fe0c8").unwrap_err();
+
+            assert_eq!(Some("67e5504410b1426%9247bb680e5fe0c8"), err.input());
+            assert_eq!(
+                "invalid character: expected an optional prefix of `urn:uuid:` followed by [0-9a-fA-F-], found `%` at 16 (input: \"67e5504410b1426%9247bb680e5fe0c8\")",
+                err.to_string()
+            );
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                target_arch = "wasm32",
+                target_vendor = "unknown",
+                target_os = "unknown"
+            ),
+            wasm_bindgen_test
+        )]
+        fn test_truncate_input() {
+            let input = "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8-and-then-some";
+            let err = Uuid::parse_str(input).unwrap_err();
+
+            assert_eq!(Some(&input[..45]), err.input());
+            assert_eq!(
+                "invalid character: expected an optional prefix of `urn:uuid:` followed by [0-9a-fA-F-], found `n` at 48 (input: \"urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8\"…)",
+                err.to_string()
+            );
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                target_arch = "wasm32",
+                target_vendor = "unknown",
+                target_os = "unknown"
+            ),
+            wasm_bindgen_test
+        )]
+        fn test_truncate_on_char_boundary() {
+            let input = "0000000000000000000000000000000000000000000\u{1f600}";
+            let err = Uuid::parse_str(input).unwrap_err();
+
+            assert_eq!(Some(&input[..43]), err.input());
+            assert!(err.to_string().ends_with("0000\"…)"));
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                target_arch = "wasm32",
+                target_vendor = "unknown",
+                target_os = "unknown"
+            ),
+            wasm_bindgen_test
+        )]
+        fn test_input_ignored_by_eq() {
+            let a = Uuid::parse_str("00").unwrap_err();
+            let b = Uuid::parse_str("11").unwrap_err();
+
+            assert_eq!(a, b);
+            assert_ne!(a.to_string(), b.to_string());
+        }
+
+        #[test]
+        #[cfg_attr(
+            all(
+                target_arch = "wasm32",
+                target_vendor = "unknown",
+                target_os = "unknown"
+            ),
+            wasm_bindgen_test
+        )]
+        fn test_input_debug() {
+            let err = Uuid::parse_str("not-a-uuid").unwrap_err();
+
+            assert!(format!("{:?}", err).contains("input: Some(\"not-a-uuid\")"));
+        }
+
+        #[test]
+        #[cfg(target_pointer_width = "64")]
+        fn test_input_size() {
+            // The input only adds a boxed `str` to the error
+            assert_eq!(48, crate::std::mem::size_of::<crate::Error>());
+            assert_eq!(48, crate::std::mem::size_of::<Result<Uuid, crate::Error>>());
+        }
+    }
 }
@@ -1,8 +1,47 @@
 #[cfg(feature = "arbitrary")]
 pub(crate) mod arbitrary_support;
+#[cfg(feature = "bincode")]
+pub(crate) mod bincode_support;
+#[cfg(feature = "bip39")]
+pub(crate) mod bip39_support;
 #[cfg(feature = "borsh")]
 pub(crate) mod borsh_support;
+#[cfg(feature = "bytes")]
+pub(crate) mod bytes_support;
+#[cfg(feature = "clap")]
+pub(crate) mod clap_support;
+#[cfg(feature = "embassy")]
+pub(crate) mod embassy_support;
+#[cfg(feature = "fake")]
+pub(crate) mod fake_support;
+#[cfg(feature = "parquet")]
+pub(crate) mod parquet_support;
+#[cfg(all(
+    feature = "rayon",
+    any(feature = "v4", feature = "v7", feature = "rng-custom")
+))]
+pub mod rayon_support;
+#[cfg(feature = "rmp-serde")]
+pub mod rmp_support;
+#[cfg(feature = "rocket")]
+pub(crate) mod rocket_support;
+#[cfg(feature = "scale")]
+pub(crate) mod scale_support;
+#[cfg(feature = "scylla")]
+pub(crate) mod scylla_support;
+#[cfg(feature = "sea-orm")]
+pub(crate) mod sea_orm_support;
 #[cfg(feature = "serde")]
 pub(crate) mod serde_support;
 #[cfg(feature = "slog")]
 pub(crate) mod slog_support;
+#[cfg(any(
+    feature = "sqlx-postgres",
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite"
+))]
+pub(crate) mod sqlx_support;
+#[cfg(feature = "tokio")]
+pub(crate) mod tokio_support;
+#[cfg(feature = "ufmt")]
+pub(crate) mod ufmt_support;
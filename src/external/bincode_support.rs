@@ -0,0 +1,107 @@
+use crate::{non_nil::NonNilUuid, Uuid};
+use bincode2::{
+    de::{BorrowDecoder, Decoder},
+    enc::Encoder,
+    error::{DecodeError, EncodeError},
+    BorrowDecode, Decode, Encode,
+};
+
+// UUIDs are encoded as their 16 bytes, without a length prefix
+
+impl Encode for Uuid {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.as_bytes().encode(encoder)
+    }
+}
+
+impl<Context> Decode<Context> for Uuid {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        Ok(Uuid::from_bytes(Decode::decode(decoder)?))
+    }
+}
+
+impl<'de, Context> BorrowDecode<'de, Context> for Uuid {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        Decode::decode(decoder)
+    }
+}
+
+impl Encode for NonNilUuid {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        Uuid::from(*self).encode(encoder)
+    }
+}
+
+impl<Context> Decode<Context> for NonNilUuid {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        NonNilUuid::new(Decode::decode(decoder)?).ok_or(DecodeError::Other("the UUID is nil"))
+    }
+}
+
+impl<'de, Context> BorrowDecode<'de, Context> for NonNilUuid {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        Decode::decode(decoder)
+    }
+}
+
+#[cfg(test)]
+mod bincode_tests {
+    use super::*;
+
+    use crate::tests::new;
+
+    #[test]
+    fn test_round_trip() {
+        let uuid = new();
+
+        let mut buf = [0; 32];
+        let len =
+            bincode2::encode_into_slice(uuid, &mut buf, bincode2::config::standard()).unwrap();
+
+        assert_eq!(16, len);
+        assert_eq!(uuid.as_bytes(), &buf[..16]);
+
+        let (decoded, len) =
+            bincode2::decode_from_slice::<Uuid, _>(&buf, bincode2::config::standard()).unwrap();
+
+        assert_eq!(16, len);
+        assert_eq!(uuid, decoded);
+
+        let (decoded, _) =
+            bincode2::borrow_decode_from_slice::<Uuid, _>(&buf, bincode2::config::standard())
+                .unwrap();
+
+        assert_eq!(uuid, decoded);
+    }
+
+    #[test]
+    fn test_decode_too_short() {
+        assert!(matches!(
+            bincode2::decode_from_slice::<Uuid, _>(&[0; 15], bincode2::config::standard()),
+            Err(DecodeError::UnexpectedEnd { additional: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_non_nil_uuid() {
+        let uuid = NonNilUuid::new(new()).unwrap();
+
+        let mut buf = [0; 16];
+        bincode2::encode_into_slice(uuid, &mut buf, bincode2::config::standard()).unwrap();
+
+        let (decoded, _) =
+            bincode2::decode_from_slice::<NonNilUuid, _>(&buf, bincode2::config::standard())
+                .unwrap();
+
+        assert_eq!(uuid, decoded);
+
+        assert!(matches!(
+            bincode2::decode_from_slice::<NonNilUuid, _>(&[0; 16], bincode2::config::standard()),
+            Err(DecodeError::Other("the UUID is nil"))
+        ));
+    }
+}
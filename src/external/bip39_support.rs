@@ -0,0 +1,197 @@
+use crate::{
+    error::{Error, ErrorKind},
+    Uuid,
+};
+use bip39::{Language, Mnemonic};
+
+// 128 bits of entropy and a 4 bit checksum, at 11 bits per word
+const WORD_COUNT: usize = 12;
+
+// The longest word in the English wordlist has 8 letters
+const MAX_WORD_LEN: usize = 8;
+
+impl Uuid {
+    /// Encode this UUID as a BIP39 mnemonic of 12 English words.
+    ///
+    /// The 16 bytes of the UUID are used as the mnemonic's entropy, so the
+    /// words include a 4 bit checksum that catches most mistyped or
+    /// reordered words. This is useful for recovery codes and reading UUIDs
+    /// out to a person, where hex strings are easy to get wrong.
+    ///
+    /// The mnemonic's `Display` implementation writes its words separated by
+    /// single spaces. This is the inverse of [`Uuid::from_mnemonic`].
+    ///
+    /// Note that usage of this method requires the `bip39` feature of this
+    /// crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// let words = uuid.to_mnemonic().to_string();
+    ///
+    /// assert_eq!(12, words.split(' ').count());
+    /// assert_eq!(uuid, Uuid::from_mnemonic(&words)?);
+    /// # Ok::<(), uuid::Error>(())
+    /// ```
+    pub fn to_mnemonic(&self) -> Mnemonic {
+        match Mnemonic::from_entropy_in(Language::English, self.as_bytes()) {
+            Ok(mnemonic) => mnemonic,
+            Err(_) => unreachable!("16 bytes is a valid amount of entropy"),
+        }
+    }
+
+    /// Decode a UUID from a BIP39 mnemonic of 12 English words.
+    ///
+    /// Words may be separated by any amount of whitespace and are matched
+    /// without regard to ASCII case. The mnemonic's checksum must be valid.
+    ///
+    /// Note that usage of this method requires the `bip39` feature of this
+    /// crate to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `words` doesn't contain exactly 12
+    /// words, if any of them isn't in the English wordlist, or if the
+    /// checksum doesn't match.
+    pub fn from_mnemonic(words: &str) -> Result<Uuid, Error> {
+        // A normalized mnemonic fits in a fixed buffer, so it can be
+        // checked without allocating
+        let mut buf = [0u8; WORD_COUNT * (MAX_WORD_LEN + 1)];
+        let mut len = 0;
+        let mut count = 0;
+
+        for (index, word) in words.split_whitespace().enumerate() {
+            count += 1;
+
+            if count > WORD_COUNT {
+                continue;
+            }
+
+            if word.len() > MAX_WORD_LEN {
+                return Err(Error::new(ErrorKind::MnemonicWord { index }));
+            }
+
+            if index > 0 {
+                buf[len] = b' ';
+                len += 1;
+            }
+
+            for b in word.bytes() {
+                buf[len] = b.to_ascii_lowercase();
+                len += 1;
+            }
+        }
+
+        if count != WORD_COUNT {
+            return Err(Error::new(ErrorKind::MnemonicWordCount { count }));
+        }
+
+        // The buffer only contains bytes copied from whole words
+        let normalized = crate::std::str::from_utf8(&buf[..len])
+            .map_err(|_| Error::new(ErrorKind::InvalidUTF8))?;
+
+        let mnemonic =
+            Mnemonic::parse_in_normalized(Language::English, normalized).map_err(|e| match e {
+                bip39::Error::UnknownWord(index) => Error::new(ErrorKind::MnemonicWord { index }),
+                bip39::Error::InvalidChecksum => Error::new(ErrorKind::MnemonicChecksum),
+                _ => Error::new(ErrorKind::MnemonicWordCount { count }),
+            })?;
+
+        let (entropy, len) = mnemonic.to_entropy_array();
+
+        Uuid::from_slice(&entropy[..len])
+    }
+}
+
+impl From<Uuid> for Mnemonic {
+    fn from(uuid: Uuid) -> Self {
+        uuid.to_mnemonic()
+    }
+}
+
+#[cfg(test)]
+mod bip39_tests {
+    use super::*;
+
+    use crate::{std::string::ToString, tests::new};
+
+    #[test]
+    fn test_round_trip() {
+        let uuid = new();
+
+        let mnemonic = uuid.to_mnemonic();
+
+        assert_eq!(WORD_COUNT, mnemonic.word_count());
+        assert_eq!(uuid, Uuid::from_mnemonic(&mnemonic.to_string()).unwrap());
+        assert_eq!(mnemonic, Mnemonic::from(uuid));
+    }
+
+    #[test]
+    fn test_known_vectors() {
+        // Test vectors from the BIP39 specification
+        assert_eq!(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            Uuid::nil().to_mnemonic().to_string()
+        );
+        assert_eq!(
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+            Uuid::max().to_mnemonic().to_string()
+        );
+    }
+
+    #[test]
+    fn test_lenient_whitespace_and_case() {
+        let uuid = new();
+        let words = uuid.to_mnemonic().to_string().to_uppercase();
+
+        let spaced = words
+            .split(' ')
+            .collect::<crate::std::vec::Vec<_>>()
+            .join("  \n\t");
+
+        assert_eq!(uuid, Uuid::from_mnemonic(&spaced).unwrap());
+    }
+
+    #[test]
+    fn test_invalid() {
+        let words = new().to_mnemonic().to_string();
+
+        assert_eq!(
+            ErrorKind::MnemonicWordCount { count: 11 },
+            Uuid::from_mnemonic(words.rsplit_once(' ').unwrap().0)
+                .unwrap_err()
+                .kind
+        );
+        assert_eq!(
+            ErrorKind::MnemonicWordCount { count: 13 },
+            Uuid::from_mnemonic(&format!("{} zoo", words))
+                .unwrap_err()
+                .kind
+        );
+        assert_eq!(
+            ErrorKind::MnemonicWord { index: 0 },
+            Uuid::from_mnemonic(&format!("notaword {}", words.split_once(' ').unwrap().1))
+                .unwrap_err()
+                .kind
+        );
+        assert_eq!(
+            ErrorKind::MnemonicWord { index: 1 },
+            Uuid::from_mnemonic(
+                "abandon abandonment abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+            )
+            .unwrap_err()
+            .kind
+        );
+        assert_eq!(
+            ErrorKind::MnemonicChecksum,
+            Uuid::from_mnemonic(
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon"
+            )
+            .unwrap_err()
+            .kind
+        );
+    }
+}
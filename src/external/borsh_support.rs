@@ -1,7 +1,6 @@
 #[cfg(test)]
 mod borsh_tests {
     use crate::Uuid;
-    use std::string::ToString;
 
     #[test]
     fn test_serialize() {
@@ -17,7 +16,7 @@ mod borsh_tests {
         let uuid_str = "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4";
         let uuid = Uuid::parse_str(uuid_str).unwrap();
         let uuid_bytes = uuid.as_bytes().to_vec();
-        let deserialized = borsh::from_slice::<Uuid>(&uuid_bytes).unwrap().to_string();
-        assert_eq!(uuid_str, deserialized);
+        let deserialized = borsh::from_slice::<Uuid>(&uuid_bytes).unwrap();
+        assert_eq!(uuid, deserialized);
     }
 }
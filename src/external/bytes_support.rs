@@ -0,0 +1,139 @@
+use crate::{Bytes, Uuid};
+use bytes::{Buf, BufMut};
+
+impl Uuid {
+    /// Read a UUID from the next 16 bytes of a buffer.
+    ///
+    /// This is the inverse of [`Uuid::put_to`].
+    ///
+    /// Note that usage of this method requires the `bytes` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if there are fewer than 16 bytes remaining in `buf`,
+    /// like the methods on [`Buf`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let mut buf = &b"\x67\xe5\x50\x44\x10\xb1\x42\x6f\x92\x47\xbb\x68\x0e\x5f\xe0\xc8\xff"[..];
+    ///
+    /// let uuid = Uuid::get_from(&mut buf);
+    ///
+    /// assert_eq!("67e55044-10b1-426f-9247-bb680e5fe0c8", uuid.to_string());
+    /// assert_eq!(&[0xff], buf);
+    /// ```
+    pub fn get_from(buf: &mut impl Buf) -> Uuid {
+        Uuid::from_bytes(get_bytes(buf))
+    }
+
+    /// Read a UUID from the next 16 bytes of a buffer in little endian order.
+    ///
+    /// The individual fields encoded in the buffer will be flipped, like
+    /// [`Uuid::from_bytes_le`]. This is the inverse of [`Uuid::put_to_le`].
+    ///
+    /// Note that usage of this method requires the `bytes` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if there are fewer than 16 bytes remaining in `buf`,
+    /// like the methods on [`Buf`].
+    pub fn get_from_le(buf: &mut impl Buf) -> Uuid {
+        Uuid::from_bytes_le(get_bytes(buf))
+    }
+
+    /// Write this UUID as 16 bytes to a buffer.
+    ///
+    /// Note that usage of this method requires the `bytes` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if there isn't enough space remaining in `buf`,
+    /// like the methods on [`BufMut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+    ///
+    /// let mut buf = Vec::new();
+    /// uuid.put_to(&mut buf);
+    ///
+    /// assert_eq!(uuid.as_bytes(), &buf[..]);
+    /// ```
+    pub fn put_to(&self, buf: &mut impl BufMut) {
+        buf.put_slice(self.as_bytes());
+    }
+
+    /// Write this UUID as 16 bytes to a buffer in little endian order.
+    ///
+    /// The individual fields will be flipped, like [`Uuid::to_bytes_le`].
+    ///
+    /// Note that usage of this method requires the `bytes` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if there isn't enough space remaining in `buf`,
+    /// like the methods on [`BufMut`].
+    pub fn put_to_le(&self, buf: &mut impl BufMut) {
+        buf.put_slice(&self.to_bytes_le());
+    }
+}
+
+fn get_bytes(buf: &mut impl Buf) -> Bytes {
+    let mut bytes = [0; 16];
+    buf.copy_to_slice(&mut bytes);
+
+    bytes
+}
+
+#[cfg(test)]
+mod bytes_tests {
+    use super::*;
+
+    use crate::tests::new;
+
+    #[test]
+    fn test_round_trip() {
+        let uuid = new();
+
+        let mut buf = bytes::BytesMut::new();
+        uuid.put_to(&mut buf);
+        uuid.put_to_le(&mut buf);
+
+        assert_eq!(32, buf.len());
+        assert_eq!(uuid.as_bytes(), &buf[..16]);
+        assert_eq!(&uuid.to_bytes_le(), &buf[16..]);
+
+        let mut buf = buf.freeze();
+
+        assert_eq!(uuid, Uuid::get_from(&mut buf));
+        assert_eq!(uuid, Uuid::get_from_le(&mut buf));
+        assert!(!buf.has_remaining());
+    }
+
+    #[test]
+    fn test_chained_buf() {
+        let uuid = new();
+        let bytes = uuid.as_bytes();
+
+        // The UUID is split across two chunks
+        let mut buf = (&bytes[..5]).chain(&bytes[5..]);
+
+        assert_eq!(uuid, Uuid::get_from(&mut buf));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_get_from_too_short() {
+        let mut buf = &[0u8; 15][..];
+
+        let _ = Uuid::get_from(&mut buf);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{
+    std::{
+        ffi::OsStr,
+        fmt::Write,
+        string::{String, ToString},
+    },
+    Uuid, Version,
+};
+use clap::{
+    builder::{TypedValueParser, ValueParserFactory},
+    error::ErrorKind,
+    Arg, Command, Error,
+};
+
+/// A `clap` value parser for [`Uuid`] arguments.
+///
+/// This parser accepts the same formats as [`Uuid::parse_str`], and can be
+/// restricted to UUIDs of certain versions with [`UuidValueParser::version`].
+/// Invalid values are reported with the name of the argument and the reason
+/// the UUID couldn't be parsed.
+///
+/// [`Uuid`] also implements `ValueParserFactory` with this parser, so
+/// `value_parser!(Uuid)` and derived `Uuid` arguments use it automatically.
+///
+/// Note that usage of this type requires the `clap` feature of this crate
+/// to be enabled.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, UuidValueParser, Version};
+/// use clap::{Arg, Command};
+///
+/// let cmd = Command::new("get").arg(
+///     Arg::new("id")
+///         .long("id")
+///         .value_parser(UuidValueParser::new().version(Version::Random)),
+/// );
+///
+/// let matches = cmd
+///     .clone()
+///     .try_get_matches_from(["get", "--id", "67e55044-10b1-426f-9247-bb680e5fe0c8"])
+///     .unwrap();
+///
+/// assert_eq!(
+///     Some(&Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()),
+///     matches.get_one::<Uuid>("id"),
+/// );
+///
+/// // A version 1 UUID isn't accepted
+/// assert!(cmd
+///     .try_get_matches_from(["get", "--id", "a1a2a3a4-b1b2-11d1-80b4-00c04fd430c8"])
+///     .is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UuidValueParser {
+    // A bit for each accepted version number, or zero to accept any
+    versions: u16,
+}
+
+impl UuidValueParser {
+    /// Create a parser that accepts UUIDs of any version.
+    pub const fn new() -> Self {
+        UuidValueParser { versions: 0 }
+    }
+
+    /// Accept UUIDs of the given version.
+    ///
+    /// Once any version is added, only UUIDs with one of the added versions
+    /// are accepted. This can be called more than once to accept several
+    /// versions.
+    pub const fn version(mut self, version: Version) -> Self {
+        self.versions |= version_bit(version);
+        self
+    }
+
+    fn accepts(&self, uuid: &Uuid) -> bool {
+        match uuid.get_version() {
+            _ if self.versions == 0 => true,
+            Some(version) => self.versions & version_bit(version) != 0,
+            None => false,
+        }
+    }
+
+    fn expected_versions(&self) -> String {
+        let mut versions = String::new();
+        let mut nums = (0..16)
+            .filter(|num| self.versions & (1 << num) != 0)
+            .peekable();
+
+        while let Some(num) = nums.next() {
+            if !versions.is_empty() {
+                versions.push_str(if nums.peek().is_some() { ", " } else { " or " });
+            }
+
+            let _ = write!(versions, "{}", num);
+        }
+
+        versions
+    }
+}
+
+// The max UUID's version is `0xff`, but its version number is `0xf`
+const fn version_bit(version: Version) -> u16 {
+    1 << (version as u8 & 0xf)
+}
+
+impl TypedValueParser for UuidValueParser {
+    type Value = Uuid;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<Uuid, Error> {
+        let invalid = |reason: &dyn crate::std::fmt::Display| {
+            let arg = arg.map_or_else(|| String::from("..."), |arg| arg.to_string());
+
+            Error::raw(
+                ErrorKind::ValueValidation,
+                format_args!(
+                    "invalid value '{}' for '{}': {}\n",
+                    value.to_string_lossy(),
+                    arg,
+                    reason
+                ),
+            )
+            .with_cmd(cmd)
+        };
+
+        let input = value
+            .to_str()
+            .ok_or_else(|| invalid(&"the value isn't valid UTF-8"))?;
+
+        let uuid = Uuid::parse_str(input).map_err(|err| invalid(&err))?;
+
+        if !self.accepts(&uuid) {
+            return Err(invalid(&format_args!(
+                "expected a UUID with version {}, found version {}",
+                self.expected_versions(),
+                uuid.get_version_num()
+            )));
+        }
+
+        Ok(uuid)
+    }
+}
+
+impl ValueParserFactory for Uuid {
+    type Parser = UuidValueParser;
+
+    fn value_parser() -> UuidValueParser {
+        UuidValueParser::new()
+    }
+}
+
+#[cfg(test)]
+mod clap_tests {
+    use super::*;
+
+    use crate::tests::new;
+
+    fn command(parser: UuidValueParser) -> Command {
+        Command::new("test").arg(Arg::new("id").long("id").value_parser(parser))
+    }
+
+    fn parse(parser: UuidValueParser, input: &str) -> Result<Uuid, Error> {
+        command(parser)
+            .try_get_matches_from(["test", "--id", input])
+            .map(|matches| *matches.get_one::<Uuid>("id").unwrap())
+    }
+
+    #[test]
+    fn test_parse_any_version() {
+        let uuid = new();
+
+        assert_eq!(
+            uuid,
+            parse(UuidValueParser::new(), &uuid.to_string()).unwrap()
+        );
+        assert_eq!(
+            uuid,
+            parse(UuidValueParser::new(), &uuid.braced().to_string()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_versions() {
+        let parser = UuidValueParser::new()
+            .version(Version::Random)
+            .version(Version::SortRand);
+
+        assert!(parse(parser, &new().to_string()).is_ok());
+
+        let err = parse(parser, "a1a2a3a4-b1b2-11d1-80b4-00c04fd430c8").unwrap_err();
+
+        assert_eq!(ErrorKind::ValueValidation, err.kind());
+        assert!(err.to_string().contains(
+            "invalid value 'a1a2a3a4-b1b2-11d1-80b4-00c04fd430c8' for '--id <id>': expected a UUID with version 4 or 7, found version 1"
+        ));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let err = parse(UuidValueParser::new(), "not-a-uuid").unwrap_err();
+
+        assert_eq!(ErrorKind::ValueValidation, err.kind());
+        assert!(err
+            .to_string()
+            .contains("invalid value 'not-a-uuid' for '--id <id>': invalid "));
+    }
+
+    #[test]
+    fn test_value_parser_factory() {
+        let cmd = Command::new("test").arg(Arg::new("id").value_parser(clap::value_parser!(Uuid)));
+
+        let uuid = new();
+        let matches = cmd
+            .try_get_matches_from(["test", &uuid.to_string()])
+            .unwrap();
+
+        assert_eq!(Some(&uuid), matches.get_one::<Uuid>("id"));
+    }
+}
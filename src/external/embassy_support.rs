@@ -0,0 +1,110 @@
+use crate::v7::Clock;
+use embassy_time::Instant;
+
+/// A [`Clock`] for version 7 UUIDs that reads `embassy_time::Instant`.
+///
+/// Embassy's clock counts time since boot rather than since the Unix epoch,
+/// so this clock adds the Unix time at which the device booted to it. That
+/// offset usually comes from an RTC, or from a time server once the device
+/// is online, and can be updated with [`EmbassyClock::set_unix_millis`] when
+/// the time is synchronized.
+///
+/// Note that usage of this type requires the `embassy` feature of this crate
+/// to be enabled.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use uuid::v7::{EmbassyClock, Generator};
+/// # let rtc_unix_millis = || 1_645_557_742_000;
+/// let clock = EmbassyClock::from_unix_millis(rtc_unix_millis());
+///
+/// let mut generator = Generator::with_clock(clock);
+///
+/// let uuid = generator.generate();
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EmbassyClock {
+    boot_unix_millis: u64,
+}
+
+impl EmbassyClock {
+    /// Create a clock from the Unix time in milliseconds at which the device booted.
+    pub const fn from_boot_unix_millis(boot_unix_millis: u64) -> Self {
+        EmbassyClock { boot_unix_millis }
+    }
+
+    /// Create a clock from the current Unix time in milliseconds.
+    ///
+    /// The time since boot is subtracted from `unix_millis` to find the Unix
+    /// time at which the device booted.
+    pub fn from_unix_millis(unix_millis: u64) -> Self {
+        let mut clock = EmbassyClock::default();
+        clock.set_unix_millis(unix_millis);
+
+        clock
+    }
+
+    /// Set the current Unix time in milliseconds.
+    ///
+    /// A [`Generator`] holds its timestamp if this moves the clock backwards,
+    /// depending on its [`RollbackPolicy`].
+    ///
+    /// [`Generator`]: struct.Generator.html
+    /// [`RollbackPolicy`]: enum.RollbackPolicy.html
+    pub fn set_unix_millis(&mut self, unix_millis: u64) {
+        self.boot_unix_millis = unix_millis.saturating_sub(Instant::now().as_millis());
+    }
+
+    /// The Unix time in milliseconds at which the device booted.
+    pub const fn boot_unix_millis(&self) -> u64 {
+        self.boot_unix_millis
+    }
+}
+
+impl Clock for EmbassyClock {
+    fn now_millis(&mut self) -> u64 {
+        self.boot_unix_millis
+            .saturating_add(Instant::now().as_millis())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{timestamp, v7::Generator, Version};
+    use embassy_time::{Duration, MockDriver};
+
+    #[test]
+    fn test_embassy_clock() {
+        let driver = MockDriver::get();
+        driver.reset();
+
+        driver.advance(Duration::from_millis(1_500));
+
+        let mut clock = EmbassyClock::from_unix_millis(1_645_557_742_000);
+
+        assert_eq!(1_645_557_740_500, clock.boot_unix_millis());
+        assert_eq!(1_645_557_742_000, clock.now_millis());
+
+        driver.advance(Duration::from_millis(250));
+
+        let mut generator = Generator::with_clock(clock);
+        let uuid = generator.generate();
+
+        assert_eq!(Some(Version::SortRand), uuid.get_version());
+        assert_eq!(
+            1_645_557_742_250,
+            timestamp::decode_unix_timestamp_millis(&uuid)
+        );
+
+        clock.set_unix_millis(1_645_557_800_000);
+        assert_eq!(1_645_557_798_250, clock.boot_unix_millis());
+
+        assert_eq!(
+            1_645_557_740_500,
+            EmbassyClock::from_boot_unix_millis(1_645_557_740_500).boot_unix_millis()
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{Builder, NonNilUuid, Timestamp, Uuid};
+use fake::{Dummy, Faker, Rng};
+
+/// A faker for version 7 UUIDs with a timestamp between two others.
+///
+/// Both timestamps are inclusive, and only their millisecond precision is
+/// used, since that's all a version 7 UUID stores. The order of the two
+/// timestamps doesn't matter.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fake::UuidV7Between, NoContext, Timestamp, Uuid};
+/// use fake::Fake;
+///
+/// let start = Timestamp::from_unix(NoContext, 1_645_557_742, 0);
+/// let end = Timestamp::from_unix(NoContext, 1_645_557_743, 0);
+///
+/// let uuid: Uuid = UuidV7Between(start, end).fake();
+///
+/// let (seconds, _) = uuid.get_timestamp().unwrap().to_unix();
+/// assert!(seconds == 1_645_557_742 || seconds == 1_645_557_743);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UuidV7Between(pub Timestamp, pub Timestamp);
+
+impl Dummy<Faker> for Uuid {
+    /// Generate a random version 4 UUID.
+    fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+        Builder::from_random_bytes(rng.gen()).into_uuid()
+    }
+}
+
+impl Dummy<Faker> for NonNilUuid {
+    /// Generate a random version 4 UUID, which is never nil.
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &Faker, rng: &mut R) -> Self {
+        NonNilUuid::new(Uuid::dummy_with_rng(config, rng)).expect("v4 UUIDs are never nil")
+    }
+}
+
+impl Dummy<UuidV7Between> for Uuid {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &UuidV7Between, rng: &mut R) -> Self {
+        let millis = |timestamp: &Timestamp| {
+            let (seconds, subsec_nanos) = timestamp.to_unix();
+
+            seconds * 1_000 + (subsec_nanos / 1_000_000) as u64
+        };
+
+        let (start, end) = (millis(&config.0), millis(&config.1));
+        let millis = rng.gen_range(start.min(end)..=start.max(end));
+
+        Builder::from_unix_timestamp_millis(millis, &rng.gen()).into_uuid()
+    }
+}
+
+impl Dummy<UuidV7Between> for NonNilUuid {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &UuidV7Between, rng: &mut R) -> Self {
+        NonNilUuid::new(Uuid::dummy_with_rng(config, rng)).expect("v7 UUIDs are never nil")
+    }
+}
+
+#[cfg(test)]
+mod fake_tests {
+    use super::*;
+
+    use crate::{NoContext, Variant, Version};
+    use fake::Fake;
+
+    #[test]
+    fn test_fake_v4() {
+        for _ in 0..100 {
+            let uuid: Uuid = Faker.fake();
+
+            assert_eq!(Some(Version::Random), uuid.get_version());
+            assert_eq!(Variant::RFC4122, uuid.get_variant());
+
+            let uuid: NonNilUuid = Faker.fake();
+            assert_eq!(Some(Version::Random), uuid.get().get_version());
+        }
+    }
+
+    #[test]
+    fn test_fake_v7_between() {
+        let start = Timestamp::from_unix(NoContext, 1_645_557_742, 500_000_000);
+        let end = Timestamp::from_unix(NoContext, 1_645_557_743, 0);
+
+        for config in [UuidV7Between(start, end), UuidV7Between(end, start)] {
+            for _ in 0..100 {
+                let uuid: Uuid = config.fake();
+
+                assert_eq!(Some(Version::SortRand), uuid.get_version());
+
+                let (seconds, subsec_nanos) = uuid.get_timestamp().unwrap().to_unix();
+                let millis = seconds * 1_000 + (subsec_nanos / 1_000_000) as u64;
+
+                assert!((1_645_557_742_500..=1_645_557_743_000).contains(&millis));
+            }
+        }
+
+        // A single millisecond
+        let uuid: Uuid = UuidV7Between(start, start).fake();
+        assert_eq!(
+            (1_645_557_742, 500_000_000),
+            uuid.get_timestamp().unwrap().to_unix()
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use crate::{
+    error::Error,
+    std::{convert::TryFrom, vec::Vec},
+    Uuid,
+};
+use parquet::{
+    basic::{LogicalType, Repetition, Type as PhysicalType},
+    data_type::FixedLenByteArray,
+    schema::types::Type,
+};
+
+impl Uuid {
+    /// Create a Parquet column type for UUIDs.
+    ///
+    /// The column is a `FIXED_LEN_BYTE_ARRAY(16)` annotated with the `UUID`
+    /// logical type, so other readers recognize its values as UUIDs. Values
+    /// are written as the UUID's 16 bytes in big-endian order, and Parquet
+    /// compares them as unsigned bytes, which matches the ordering of [`Uuid`].
+    ///
+    /// Values for the column can be converted with [`Uuid::to_parquet_values`]
+    /// and read back with [`Uuid::from_parquet_values`].
+    ///
+    /// Note that usage of this method requires the `parquet` feature of this
+    /// crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use uuid::Uuid;
+    /// use parquet::{
+    ///     basic::Repetition,
+    ///     file::{properties::WriterProperties, writer::SerializedFileWriter},
+    ///     schema::types::Type,
+    /// };
+    ///
+    /// let schema = Type::group_type_builder("schema")
+    ///     .with_fields(vec![Arc::new(Uuid::parquet_type("id", Repetition::REQUIRED))])
+    ///     .build()?;
+    ///
+    /// let uuids = [Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?];
+    ///
+    /// let mut writer = SerializedFileWriter::new(
+    ///     Vec::new(),
+    ///     Arc::new(schema),
+    ///     Arc::new(WriterProperties::builder().build()),
+    /// )?;
+    ///
+    /// let mut row_group = writer.next_row_group()?;
+    /// let mut column = row_group.next_column()?.unwrap();
+    ///
+    /// column
+    ///     .typed::<parquet::data_type::FixedLenByteArrayType>()
+    ///     .write_batch(&Uuid::to_parquet_values(&uuids), None, None)?;
+    ///
+    /// column.close()?;
+    /// row_group.close()?;
+    /// writer.close()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parquet_type(name: &str, repetition: Repetition) -> Type {
+        Type::primitive_type_builder(name, PhysicalType::FIXED_LEN_BYTE_ARRAY)
+            .with_repetition(repetition)
+            .with_length(16)
+            .with_logical_type(Some(LogicalType::Uuid))
+            .build()
+            .expect("a 16 byte `FIXED_LEN_BYTE_ARRAY` is a valid UUID column")
+    }
+
+    /// Convert a slice of UUIDs into the physical values of a Parquet UUID column.
+    ///
+    /// Each value holds the 16 bytes of a UUID, like [`FixedLenByteArray::from`].
+    ///
+    /// Note that usage of this method requires the `parquet` feature of this
+    /// crate to be enabled.
+    pub fn to_parquet_values(uuids: &[Uuid]) -> Vec<FixedLenByteArray> {
+        uuids
+            .iter()
+            .map(|uuid| FixedLenByteArray::from(*uuid))
+            .collect()
+    }
+
+    /// Convert the physical values of a Parquet UUID column into UUIDs.
+    ///
+    /// Note that usage of this method requires the `parquet` feature of this
+    /// crate to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if any of the values isn't exactly 16
+    /// bytes long.
+    pub fn from_parquet_values(values: &[FixedLenByteArray]) -> Result<Vec<Uuid>, Error> {
+        values.iter().map(Uuid::try_from).collect()
+    }
+}
+
+impl From<Uuid> for FixedLenByteArray {
+    fn from(uuid: Uuid) -> Self {
+        FixedLenByteArray::from(uuid.as_bytes().to_vec())
+    }
+}
+
+impl TryFrom<&FixedLenByteArray> for Uuid {
+    type Error = Error;
+
+    fn try_from(value: &FixedLenByteArray) -> Result<Self, Self::Error> {
+        Uuid::from_slice(value.data())
+    }
+}
+
+#[cfg(test)]
+mod parquet_tests {
+    use super::*;
+
+    use crate::{error::ErrorKind, tests::new};
+    use parquet::{
+        basic::ConvertedType,
+        column::reader::ColumnReader,
+        file::{
+            properties::WriterProperties,
+            reader::{FileReader, SerializedFileReader},
+            writer::SerializedFileWriter,
+        },
+    };
+    use std::sync::Arc;
+
+    #[test]
+    fn test_parquet_type() {
+        let column = Uuid::parquet_type("id", Repetition::OPTIONAL);
+        let info = column.get_basic_info();
+
+        assert_eq!("id", info.name());
+        assert_eq!(Repetition::OPTIONAL, info.repetition());
+        assert_eq!(Some(LogicalType::Uuid), info.logical_type());
+        assert_eq!(ConvertedType::NONE, info.converted_type());
+        assert_eq!(
+            PhysicalType::FIXED_LEN_BYTE_ARRAY,
+            column.get_physical_type()
+        );
+        assert!(matches!(
+            column,
+            Type::PrimitiveType {
+                type_length: 16,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_values_round_trip() {
+        let uuids = [new(), Uuid::nil(), Uuid::max()];
+
+        let values = Uuid::to_parquet_values(&uuids);
+
+        assert_eq!(uuids[0].as_bytes(), values[0].data());
+        assert_eq!(&uuids[..], &Uuid::from_parquet_values(&values).unwrap()[..]);
+    }
+
+    #[test]
+    fn test_values_invalid_length() {
+        let values = [
+            FixedLenByteArray::from(new()),
+            FixedLenByteArray::from(vec![0; 15]),
+        ];
+
+        assert_eq!(
+            ErrorKind::ByteLength { len: 15 },
+            Uuid::from_parquet_values(&values).unwrap_err().kind
+        );
+    }
+
+    #[test]
+    fn test_file_round_trip() {
+        let uuids = [new(), Uuid::max(), Uuid::nil()];
+
+        let schema = Type::group_type_builder("schema")
+            .with_fields(vec![Arc::new(Uuid::parquet_type(
+                "id",
+                Repetition::REQUIRED,
+            ))])
+            .build()
+            .unwrap();
+
+        let path =
+            std::env::temp_dir().join(format!("uuid-parquet-{}.parquet", std::process::id()));
+        let file = std::fs::File::create(&path).unwrap();
+
+        let mut writer = SerializedFileWriter::new(
+            file,
+            Arc::new(schema),
+            Arc::new(WriterProperties::builder().build()),
+        )
+        .unwrap();
+
+        let mut row_group = writer.next_row_group().unwrap();
+        let mut column = row_group.next_column().unwrap().unwrap();
+        column
+            .typed::<parquet::data_type::FixedLenByteArrayType>()
+            .write_batch(&Uuid::to_parquet_values(&uuids), None, None)
+            .unwrap();
+        column.close().unwrap();
+        row_group.close().unwrap();
+        writer.close().unwrap();
+
+        let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+
+        let column = reader.metadata().file_metadata().schema_descr().column(0);
+        assert_eq!(Some(LogicalType::Uuid), column.logical_type());
+        assert_eq!(16, column.type_length());
+
+        let mut values = Vec::new();
+        match reader
+            .get_row_group(0)
+            .unwrap()
+            .get_column_reader(0)
+            .unwrap()
+        {
+            ColumnReader::FixedLenByteArrayColumnReader(mut reader) => {
+                reader.read_records(16, None, None, &mut values).unwrap();
+            }
+            _ => panic!("expected a fixed length byte array column"),
+        }
+
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(&uuids[..], &Uuid::from_parquet_values(&values).unwrap()[..]);
+    }
+}
@@ -0,0 +1,138 @@
+//! Generate large batches of UUIDs in parallel.
+//!
+//! The functions in this module shard generation across `rayon`'s global
+//! thread pool, which is useful for data generation and load testing tools
+//! that need to mint many millions of UUIDs at once.
+//!
+//! Note that usage of this module requires the `rayon` feature of this crate
+//! to be enabled.
+//!
+//! ## Example
+//!
+//! ```rust
+//! # #[cfg(feature = "v7")]
+//! # {
+//! let uuids = uuid::parallel::now_v7_vec(100_000);
+//!
+//! assert_eq!(100_000, uuids.len());
+//! assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+//! # }
+//! ```
+
+use crate::{std::vec::Vec, Uuid};
+#[cfg(any(feature = "v7", feature = "rng-custom"))]
+use crate::{Builder, NoContext, Timestamp};
+use rayon::prelude::*;
+
+/// Generate `n` random (version 4) UUIDs in parallel.
+///
+/// The UUIDs are generated the same way as by [`Uuid::new_v4`].
+///
+/// Note that usage of this function requires the `v4` feature of this crate
+/// to be enabled.
+///
+/// [`Uuid::new_v4`]: ../struct.Uuid.html#method.new_v4
+#[cfg(any(feature = "v4", feature = "rng-custom"))]
+pub fn new_v4_vec(n: usize) -> Vec<Uuid> {
+    (0..n).into_par_iter().map(|_| Uuid::new_v4()).collect()
+}
+
+/// Generate `n` ordered version 7 UUIDs in parallel.
+///
+/// All of the UUIDs share the timestamp read when this function is called,
+/// and are followed by a counter that starts at a random value and increases
+/// by one for each UUID. That means the returned UUIDs are strictly ordered,
+/// regardless of which thread generated each one. The remaining bits are
+/// random.
+///
+/// The UUIDs are only guaranteed to be ordered within the returned `Vec`. A
+/// UUID generated by [`Uuid::now_v7`] in the same millisecond may sort before
+/// or after them.
+///
+/// Note that usage of this function requires the `v7` feature of this crate
+/// to be enabled.
+///
+/// # Panics
+///
+/// This function panics if `n` is larger than 2<sup>41</sup>, which would
+/// overflow the counter.
+///
+/// [`Uuid::now_v7`]: ../struct.Uuid.html#method.now_v7
+#[cfg(any(feature = "v7", feature = "rng-custom"))]
+pub fn now_v7_vec(n: usize) -> Vec<Uuid> {
+    // The counter is 42 bits, so starting it in the lower half always
+    // leaves room for 2^41 UUIDs
+    const MAX_LEN: u64 = 1 << 41;
+
+    assert!(
+        n as u64 <= MAX_LEN,
+        "can't generate more than 2^41 ordered UUIDs at once"
+    );
+
+    let (secs, nanos) = Timestamp::now(NoContext).to_unix();
+    let millis = secs * 1000 + (nanos / 1_000_000) as u64;
+
+    let start = crate::rng::u64() & (MAX_LEN - 1);
+
+    (0..n)
+        .into_par_iter()
+        .map(|i| {
+            let mut random_bytes = [0; 10];
+            random_bytes[..8].copy_from_slice(&crate::rng::u64().to_ne_bytes());
+
+            let uuid =
+                Builder::from_unix_timestamp_with_counter(millis, start + i as u64, &random_bytes)
+                    .into_uuid();
+
+            #[cfg(feature = "observe")]
+            let uuid = crate::observe::generated(uuid, crate::Version::SortRand);
+
+            uuid
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod rayon_tests {
+    use super::*;
+
+    use crate::Version;
+
+    #[test]
+    #[cfg(any(feature = "v4", feature = "rng-custom"))]
+    fn test_new_v4_vec() {
+        let uuids = new_v4_vec(1000);
+
+        assert_eq!(1000, uuids.len());
+        assert!(uuids
+            .iter()
+            .all(|uuid| uuid.get_version() == Some(Version::Random)));
+
+        let mut sorted = uuids.clone();
+        sorted.sort();
+        sorted.dedup();
+
+        assert_eq!(1000, sorted.len());
+    }
+
+    #[test]
+    #[cfg(any(feature = "v7", feature = "rng-custom"))]
+    fn test_now_v7_vec() {
+        let uuids = now_v7_vec(10_000);
+
+        assert_eq!(10_000, uuids.len());
+        assert!(uuids
+            .iter()
+            .all(|uuid| uuid.get_version() == Some(Version::SortRand)));
+        assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let timestamp = uuids[0].get_timestamp();
+        assert!(uuids.iter().all(|uuid| uuid.get_timestamp() == timestamp));
+    }
+
+    #[test]
+    #[cfg(any(feature = "v7", feature = "rng-custom"))]
+    fn test_now_v7_vec_empty() {
+        assert!(now_v7_vec(0).is_empty());
+    }
+}
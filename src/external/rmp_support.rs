@@ -0,0 +1,262 @@
+//! Serialize a [`Uuid`] as a MessagePack extension type.
+//!
+//! MessagePack libraries in other languages commonly write UUIDs as a
+//! `fixext 16` value with the extension type [`EXT_TYPE`], holding the 16
+//! bytes of the UUID. This adapter writes UUIDs the same way when used with
+//! `rmp-serde`, and reads them back from that extension type, from 16 raw
+//! bytes, or from any UUID string.
+//!
+//! Note that usage of this module requires the `rmp-serde` feature of this
+//! crate to be enabled.
+//!
+//! ## Example
+//!
+//! ```rust
+//! #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+//! struct Struct {
+//!     #[serde(with = "uuid::serde::msgpack")]
+//!     id: uuid::Uuid,
+//! }
+//!
+//! let value = Struct {
+//!     id: uuid::Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap(),
+//! };
+//!
+//! let encoded = rmp_serde::to_vec(&value).unwrap();
+//!
+//! // A `fixext 16` marker, followed by the extension type and the UUID
+//! assert_eq!(&[0xd8, 2, 0x67, 0xe5], &encoded[1..5]);
+//!
+//! let decoded: Struct = rmp_serde::from_slice(&encoded).unwrap();
+//!
+//! assert_eq!(value.id, decoded.id);
+//! ```
+//!
+//! [`Uuid`]: ../../struct.Uuid.html
+
+use rmp_serde::MSGPACK_EXT_STRUCT_NAME;
+use serde::{
+    de::{self, Error as _},
+    ser::SerializeTuple,
+};
+
+use crate::{error::Error, std::fmt, Uuid};
+
+/// The MessagePack extension type used for UUIDs.
+///
+/// This is the extension type used for UUIDs by Tarantool and a number of
+/// MessagePack libraries that follow it.
+pub const EXT_TYPE: i8 = 2;
+
+/// Serialize a [`Uuid`] as a MessagePack `fixext 16` value with the
+/// extension type [`EXT_TYPE`].
+///
+/// [`Uuid`]: ../../struct.Uuid.html
+pub fn serialize<S>(u: &Uuid, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_newtype_struct(MSGPACK_EXT_STRUCT_NAME, &Ext(u))
+}
+
+/// Deserialize a MessagePack extension value, 16 raw bytes, or a UUID string
+/// as a [`Uuid`].
+///
+/// [`Uuid`]: ../../struct.Uuid.html
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Uuid, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserializer.deserialize_any(MsgpackVisitor)
+}
+
+fn de_error<E: de::Error>(e: Error) -> E {
+    E::custom(format_args!("UUID parsing failed: {}", e))
+}
+
+// `rmp-serde` writes a newtype struct with a special name as an extension
+// value, with its contents as a tuple of the extension type and its data
+struct Ext<'a>(&'a Uuid);
+
+impl<'a> serde::Serialize for Ext<'a> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut ext = serializer.serialize_tuple(2)?;
+        ext.serialize_element(&EXT_TYPE)?;
+        ext.serialize_element(&ExtData(self.0))?;
+        ext.end()
+    }
+}
+
+struct ExtData<'a>(&'a Uuid);
+
+impl<'a> serde::Serialize for ExtData<'a> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0.as_bytes())
+    }
+}
+
+struct MsgpackVisitor;
+
+impl<'vi> de::Visitor<'vi> for MsgpackVisitor {
+    type Value = Uuid;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "a MessagePack extension value of type {}, 16 bytes, or a UUID string",
+            EXT_TYPE
+        )
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Uuid, E> {
+        value.parse::<Uuid>().map_err(de_error)
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Uuid, E> {
+        Uuid::from_slice(value).map_err(de_error)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Uuid, D::Error>
+    where
+        D: serde::Deserializer<'vi>,
+    {
+        deserializer.deserialize_tuple(2, ExtVisitor)
+    }
+}
+
+struct ExtVisitor;
+
+impl<'vi> de::Visitor<'vi> for ExtVisitor {
+    type Value = Uuid;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "a MessagePack extension value of type {}",
+            EXT_TYPE
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Uuid, A::Error>
+    where
+        A: de::SeqAccess<'vi>,
+    {
+        let ext_type: i8 = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+
+        if ext_type != EXT_TYPE {
+            return Err(A::Error::invalid_value(
+                de::Unexpected::Signed(ext_type as i64),
+                &self,
+            ));
+        }
+
+        seq.next_element_seed(ExtDataVisitor)?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))
+    }
+}
+
+struct ExtDataVisitor;
+
+impl<'vi> de::DeserializeSeed<'vi> for ExtDataVisitor {
+    type Value = Uuid;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Uuid, D::Error>
+    where
+        D: serde::Deserializer<'vi>,
+    {
+        deserializer.deserialize_bytes(self)
+    }
+}
+
+impl<'vi> de::Visitor<'vi> for ExtDataVisitor {
+    type Value = Uuid;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "16 bytes")
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Uuid, E> {
+        Uuid::from_slice(value).map_err(de_error)
+    }
+}
+
+#[cfg(test)]
+mod rmp_tests {
+    use super::*;
+
+    use crate::{
+        std::{string::ToString, vec::Vec},
+        tests::new,
+    };
+    use serde_derive::*;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct UuidContainer {
+        #[serde(with = "crate::serde::msgpack")]
+        u: Uuid,
+    }
+
+    fn fixext(ext_type: i8, uuid: &Uuid) -> Vec<u8> {
+        let mut encoded = vec![0xd8, ext_type as u8];
+        encoded.extend_from_slice(uuid.as_bytes());
+
+        encoded
+    }
+
+    #[test]
+    fn test_serialize_ext() {
+        let uuid = new();
+
+        let encoded = rmp_serde::to_vec(&UuidContainer { u: uuid }).unwrap();
+
+        // A fixarray of 1 element, followed by the extension value
+        assert_eq!(0x91, encoded[0]);
+        assert_eq!(fixext(EXT_TYPE, &uuid), &encoded[1..]);
+
+        let decoded: UuidContainer = rmp_serde::from_slice(&encoded).unwrap();
+
+        assert_eq!(uuid, decoded.u);
+    }
+
+    #[test]
+    fn test_deserialize_alternatives() {
+        let uuid = new();
+
+        // A fixarray of 1 string
+        let mut encoded = vec![0x91, 0xd9, 36];
+        encoded.extend_from_slice(uuid.hyphenated().to_string().as_bytes());
+
+        let decoded: UuidContainer = rmp_serde::from_slice(&encoded).unwrap();
+        assert_eq!(uuid, decoded.u);
+
+        // A fixarray of 16 raw bytes, as written by `Uuid`'s own `Serialize` impl
+        let mut encoded = vec![0x91, 0xc4, 16];
+        encoded.extend_from_slice(uuid.as_bytes());
+
+        let decoded: UuidContainer = rmp_serde::from_slice(&encoded).unwrap();
+        assert_eq!(uuid, decoded.u);
+
+        // Other formats can still use the adapter with strings
+        let json = serde_json::to_string(&uuid).unwrap();
+        let decoded: UuidContainer = serde_json::from_str(&format!("{{\"u\":{}}}", json)).unwrap();
+        assert_eq!(uuid, decoded.u);
+    }
+
+    #[test]
+    fn test_deserialize_invalid_ext() {
+        let uuid = new();
+
+        let mut encoded = vec![0x91];
+        encoded.extend_from_slice(&fixext(EXT_TYPE + 1, &uuid));
+
+        assert!(rmp_serde::from_slice::<UuidContainer>(&encoded).is_err());
+
+        // A `fixext 8` value of the right type is too short to be a UUID
+        let mut encoded = vec![0x91, 0xd7, EXT_TYPE as u8];
+        encoded.extend_from_slice(&uuid.as_bytes()[..8]);
+
+        assert!(rmp_serde::from_slice::<UuidContainer>(&encoded).is_err());
+    }
+}
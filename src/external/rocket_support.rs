@@ -0,0 +1,87 @@
+use crate::{std::convert::TryFrom, Error, NonNilUuid, Uuid};
+use rocket::{
+    form::{self, FromFormField, ValueField},
+    request::FromParam,
+};
+
+impl<'a> FromParam<'a> for Uuid {
+    type Error = Error;
+
+    #[inline]
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        Uuid::parse_str(param)
+    }
+}
+
+impl<'a> FromParam<'a> for NonNilUuid {
+    type Error = Error;
+
+    #[inline]
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        NonNilUuid::try_from(Uuid::parse_str(param)?)
+    }
+}
+
+impl<'v> FromFormField<'v> for Uuid {
+    #[inline]
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        Ok(Uuid::parse_str(field.value).map_err(form::Error::custom)?)
+    }
+}
+
+impl<'v> FromFormField<'v> for NonNilUuid {
+    #[inline]
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        let uuid = Uuid::from_value(field)?;
+
+        Ok(NonNilUuid::try_from(uuid).map_err(form::Error::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod rocket_tests {
+    use super::*;
+
+    #[test]
+    fn test_from_param() {
+        let uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+        assert_eq!(
+            uuid,
+            Uuid::from_param("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()
+        );
+        assert_eq!(
+            uuid,
+            NonNilUuid::from_param("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()
+        );
+
+        assert!(Uuid::from_param("not-a-uuid").is_err());
+        assert!(NonNilUuid::from_param("00000000-0000-0000-0000-000000000000").is_err());
+    }
+
+    #[test]
+    fn test_from_form_field() {
+        let uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+        assert_eq!(
+            uuid,
+            Uuid::from_value(ValueField::from_value(
+                "67e55044-10b1-426f-9247-bb680e5fe0c8"
+            ))
+            .unwrap()
+        );
+        assert_eq!(
+            uuid,
+            NonNilUuid::from_value(ValueField::from_value(
+                "67e55044-10b1-426f-9247-bb680e5fe0c8"
+            ))
+            .unwrap()
+        );
+
+        assert!(Uuid::from_value(ValueField::from_value("not-a-uuid")).is_err());
+        assert!(NonNilUuid::from_value(ValueField::from_value(
+            "00000000-0000-0000-0000-000000000000"
+        ))
+        .is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{non_nil::NonNilUuid, Uuid};
+use parity_scale_codec::{
+    ConstEncodedLen, Decode, DecodeWithMemTracking, Encode, EncodeLike, Error, Input,
+    MaxEncodedLen, Output,
+};
+use scale_info::{build::Fields, Path, Type, TypeInfo};
+
+// UUIDs are encoded as their 16 bytes, like a `[u8; 16]`, without a length prefix
+
+impl Encode for Uuid {
+    fn size_hint(&self) -> usize {
+        16
+    }
+
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(self.as_bytes());
+    }
+
+    fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+        f(self.as_bytes())
+    }
+
+    fn encoded_size(&self) -> usize {
+        16
+    }
+}
+
+impl EncodeLike for Uuid {}
+
+impl Decode for Uuid {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let mut bytes = [0; 16];
+        input.read(&mut bytes)?;
+
+        Ok(Uuid::from_bytes(bytes))
+    }
+
+    fn skip<I: Input>(input: &mut I) -> Result<(), Error> {
+        input.read(&mut [0; 16])
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(16)
+    }
+}
+
+impl DecodeWithMemTracking for Uuid {}
+
+impl MaxEncodedLen for Uuid {
+    fn max_encoded_len() -> usize {
+        16
+    }
+}
+
+impl ConstEncodedLen for Uuid {}
+
+impl TypeInfo for Uuid {
+    type Identity = Self;
+
+    fn type_info() -> Type {
+        Type::builder()
+            .path(Path::new("Uuid", "uuid"))
+            .composite(Fields::unnamed().field(|f| f.ty::<[u8; 16]>().type_name("[u8; 16]")))
+    }
+}
+
+impl Encode for NonNilUuid {
+    fn size_hint(&self) -> usize {
+        16
+    }
+
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        Uuid::from(*self).encode_to(dest)
+    }
+
+    fn encoded_size(&self) -> usize {
+        16
+    }
+}
+
+impl EncodeLike for NonNilUuid {}
+
+impl EncodeLike<NonNilUuid> for Uuid {}
+
+impl EncodeLike<Uuid> for NonNilUuid {}
+
+impl Decode for NonNilUuid {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        NonNilUuid::new(Uuid::decode(input)?).ok_or_else(|| "the UUID is nil".into())
+    }
+
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(16)
+    }
+}
+
+impl DecodeWithMemTracking for NonNilUuid {}
+
+impl MaxEncodedLen for NonNilUuid {
+    fn max_encoded_len() -> usize {
+        16
+    }
+}
+
+impl ConstEncodedLen for NonNilUuid {}
+
+impl TypeInfo for NonNilUuid {
+    type Identity = Self;
+
+    fn type_info() -> Type {
+        Type::builder()
+            .path(Path::new("NonNilUuid", "uuid"))
+            .composite(Fields::unnamed().field(|f| f.ty::<Uuid>().type_name("Uuid")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::new;
+
+    #[test]
+    fn test_scale_round_trip() {
+        let uuid = new();
+
+        let encoded = uuid.encode();
+
+        assert_eq!(uuid.as_bytes(), &*encoded);
+        assert_eq!(encoded.len(), Uuid::max_encoded_len());
+        assert_eq!(uuid, Uuid::decode(&mut &*encoded).unwrap());
+
+        let non_nil = NonNilUuid::new(uuid).unwrap();
+
+        assert_eq!(encoded, non_nil.encode());
+        assert_eq!(non_nil, NonNilUuid::decode(&mut &*encoded).unwrap());
+    }
+
+    #[test]
+    fn test_scale_decode_invalid() {
+        assert!(Uuid::decode(&mut &[0u8; 15][..]).is_err());
+        assert!(NonNilUuid::decode(&mut &[0u8; 16][..]).is_err());
+    }
+
+    #[test]
+    fn test_scale_type_info() {
+        let ty = Uuid::type_info();
+
+        assert_eq!(["uuid", "Uuid"], &*ty.path.segments);
+        assert_eq!(
+            ["uuid", "NonNilUuid"],
+            &*NonNilUuid::type_info().path.segments
+        );
+    }
+}
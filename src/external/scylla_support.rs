@@ -0,0 +1,185 @@
+use crate::{non_nil::NonNilUuid, std::convert::TryFrom, Uuid};
+use scylla_cql::{
+    deserialize::{
+        value::{mk_deser_err, mk_typck_err, BuiltinDeserializationErrorKind, DeserializeValue},
+        DeserializationError, FrameSlice, TypeCheckError,
+    },
+    frame::response::result::{ColumnType, NativeType},
+    serialize::{
+        value::{self as ser, SerializeValue},
+        writers::WrittenCellProof,
+        CellWriter, SerializationError,
+    },
+    value::CqlTimeuuid,
+};
+
+// A `timeuuid` is a `uuid` that Cassandra expects to be version 1, so UUIDs
+// can be bound to and read from columns of either type. The server rejects
+// UUIDs of other versions in `timeuuid` columns
+const COLUMN_TYPES: &[ColumnType<'static>] = &[
+    ColumnType::Native(NativeType::Uuid),
+    ColumnType::Native(NativeType::Timeuuid),
+];
+
+fn is_uuid_column(typ: &ColumnType) -> bool {
+    matches!(
+        typ,
+        ColumnType::Native(NativeType::Uuid) | ColumnType::Native(NativeType::Timeuuid)
+    )
+}
+
+impl SerializeValue for Uuid {
+    fn serialize<'b>(
+        &self,
+        typ: &ColumnType,
+        writer: CellWriter<'b>,
+    ) -> Result<WrittenCellProof<'b>, SerializationError> {
+        if !is_uuid_column(typ) {
+            return Err(ser::mk_typck_err::<Self>(
+                typ,
+                ser::BuiltinTypeCheckErrorKind::MismatchedType {
+                    expected: COLUMN_TYPES,
+                },
+            ));
+        }
+
+        // 16 bytes always fit in a cell
+        Ok(writer.set_value(self.as_bytes()).unwrap())
+    }
+}
+
+impl SerializeValue for NonNilUuid {
+    fn serialize<'b>(
+        &self,
+        typ: &ColumnType,
+        writer: CellWriter<'b>,
+    ) -> Result<WrittenCellProof<'b>, SerializationError> {
+        Uuid::from(*self).serialize(typ, writer)
+    }
+}
+
+impl<'frame, 'metadata> DeserializeValue<'frame, 'metadata> for Uuid {
+    fn type_check(typ: &ColumnType) -> Result<(), TypeCheckError> {
+        if is_uuid_column(typ) {
+            Ok(())
+        } else {
+            Err(mk_typck_err::<Self>(
+                typ,
+                scylla_cql::deserialize::value::BuiltinTypeCheckErrorKind::MismatchedType {
+                    expected: COLUMN_TYPES,
+                },
+            ))
+        }
+    }
+
+    fn deserialize(
+        typ: &'metadata ColumnType<'metadata>,
+        v: Option<FrameSlice<'frame>>,
+    ) -> Result<Self, DeserializationError> {
+        let bytes = v
+            .ok_or_else(|| {
+                mk_deser_err::<Self>(typ, BuiltinDeserializationErrorKind::ExpectedNonNull)
+            })?
+            .as_slice();
+
+        Uuid::from_slice(bytes).map_err(|_| {
+            mk_deser_err::<Self>(
+                typ,
+                BuiltinDeserializationErrorKind::ByteLengthMismatch {
+                    expected: 16,
+                    got: bytes.len(),
+                },
+            )
+        })
+    }
+}
+
+impl<'frame, 'metadata> DeserializeValue<'frame, 'metadata> for NonNilUuid {
+    fn type_check(typ: &ColumnType) -> Result<(), TypeCheckError> {
+        <Uuid as DeserializeValue>::type_check(typ)
+    }
+
+    fn deserialize(
+        typ: &'metadata ColumnType<'metadata>,
+        v: Option<FrameSlice<'frame>>,
+    ) -> Result<Self, DeserializationError> {
+        NonNilUuid::try_from(Uuid::deserialize(typ, v)?).map_err(DeserializationError::new)
+    }
+}
+
+impl From<CqlTimeuuid> for Uuid {
+    /// Convert a `timeuuid` read by the Scylla driver into a UUID.
+    fn from(timeuuid: CqlTimeuuid) -> Self {
+        Uuid::from_bytes(*timeuuid.as_bytes())
+    }
+}
+
+impl From<Uuid> for CqlTimeuuid {
+    /// Convert a UUID into a `timeuuid` for the Scylla driver.
+    ///
+    /// `CqlTimeuuid` compares UUIDs the way Cassandra sorts `timeuuid` columns,
+    /// by their version 1 timestamp first, rather than by their bytes.
+    fn from(uuid: Uuid) -> Self {
+        CqlTimeuuid::from_bytes(uuid.into_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{std::vec::Vec, tests::new};
+
+    fn serialize(
+        uuid: impl SerializeValue,
+        typ: &ColumnType,
+    ) -> Result<Vec<u8>, SerializationError> {
+        let mut buf = Vec::new();
+        uuid.serialize(typ, CellWriter::new(&mut buf))?;
+
+        // Skip the 4 byte length of the cell
+        Ok(buf.split_off(4))
+    }
+
+    #[test]
+    fn test_scylla_round_trip() {
+        let uuid = new();
+
+        for typ in COLUMN_TYPES {
+            let bytes = serialize(uuid, typ).unwrap();
+            assert_eq!(uuid.as_bytes(), &*bytes);
+
+            <Uuid as DeserializeValue>::type_check(typ).unwrap();
+            let de = Uuid::deserialize(typ, Some(FrameSlice::new_borrowed(&bytes))).unwrap();
+            assert_eq!(uuid, de);
+
+            let non_nil =
+                NonNilUuid::deserialize(typ, Some(FrameSlice::new_borrowed(&bytes))).unwrap();
+            assert_eq!(uuid, non_nil);
+            assert_eq!(bytes, serialize(non_nil, typ).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_scylla_invalid() {
+        let text = ColumnType::Native(NativeType::Text);
+        let typ = ColumnType::Native(NativeType::Uuid);
+
+        assert!(serialize(new(), &text).is_err());
+        assert!(<Uuid as DeserializeValue>::type_check(&text).is_err());
+
+        assert!(Uuid::deserialize(&typ, None).is_err());
+        assert!(Uuid::deserialize(&typ, Some(FrameSlice::new_borrowed(&[0; 15]))).is_err());
+        assert!(NonNilUuid::deserialize(&typ, Some(FrameSlice::new_borrowed(&[0; 16]))).is_err());
+    }
+
+    #[test]
+    fn test_scylla_timeuuid() {
+        let uuid = Uuid::parse_str("c232ab00-9414-11ec-b3c8-9f6bdeced846").unwrap();
+
+        let timeuuid = CqlTimeuuid::from(uuid);
+
+        assert_eq!(uuid.as_bytes(), timeuuid.as_bytes());
+        assert_eq!(uuid, Uuid::from(timeuuid));
+    }
+}
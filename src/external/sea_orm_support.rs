@@ -0,0 +1,103 @@
+use crate::{
+    std::{
+        borrow::ToOwned,
+        boxed::Box,
+        string::{String, ToString},
+        vec::Vec,
+    },
+    Uuid,
+};
+use sea_orm::{
+    sea_query::{ArrayType, ColumnType, Nullable, Value, ValueType, ValueTypeErr},
+    ColIdx, DbErr, QueryResult, TryFromU64, TryGetError, TryGetable,
+};
+
+// NOTE: These impls don't depend on `sea-orm`'s own `with-uuid` feature, which
+// would pull in a second copy of this crate. Values are stored as their 16 bytes
+// in a `BINARY(16)` column instead
+
+impl From<Uuid> for Value {
+    fn from(uuid: Uuid) -> Self {
+        Value::Bytes(Some(Box::new(uuid.as_bytes().to_vec())))
+    }
+}
+
+impl Nullable for Uuid {
+    fn null() -> Value {
+        Value::Bytes(None)
+    }
+}
+
+impl ValueType for Uuid {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::Bytes(Some(bytes)) => Uuid::from_slice(&bytes).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "Uuid".to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Bytes
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Binary(16)
+    }
+}
+
+impl TryGetable for Uuid {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let bytes = Vec::<u8>::try_get_by(res, index)?;
+
+        Uuid::from_slice(&bytes).map_err(|err| TryGetError::DbErr(DbErr::Type(err.to_string())))
+    }
+}
+
+impl TryFromU64 for Uuid {
+    fn try_from_u64(_: u64) -> Result<Self, DbErr> {
+        Err(DbErr::ConvertFromU64("Uuid"))
+    }
+}
+
+#[cfg(test)]
+mod sea_orm_tests {
+    use super::*;
+    use crate::std::vec;
+
+    #[test]
+    fn test_value_roundtrip() {
+        let uuid = Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4").unwrap();
+
+        let value = Value::from(uuid);
+
+        assert_eq!(
+            Value::Bytes(Some(Box::new(vec![
+                0xf9, 0x16, 0x8c, 0x5e, 0xce, 0xb2, 0x4f, 0xaa, 0xb6, 0xbf, 0x32, 0x9b, 0xf3, 0x9f,
+                0xa1, 0xe4,
+            ]))),
+            value
+        );
+        assert_eq!(uuid, <Uuid as ValueType>::try_from(value).unwrap());
+    }
+
+    #[test]
+    fn test_value_null() {
+        assert_eq!(Value::Bytes(None), <Uuid as Nullable>::null());
+        assert_eq!(Value::Bytes(None), Value::from(None::<Uuid>));
+
+        assert!(<Uuid as ValueType>::try_from(Value::Bytes(None)).is_err());
+        assert!(<Uuid as ValueType>::try_from(Value::Bytes(Some(Box::new(vec![1, 2])))).is_err());
+        assert!(<Uuid as ValueType>::try_from(Value::Int(Some(1))).is_err());
+    }
+
+    #[test]
+    fn test_value_type() {
+        assert_eq!(ColumnType::Binary(16), <Uuid as ValueType>::column_type());
+        assert_eq!(ArrayType::Bytes, <Uuid as ValueType>::array_type());
+        assert!(Uuid::try_from_u64(1).is_err());
+    }
+}
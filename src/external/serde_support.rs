@@ -14,8 +14,9 @@ use crate::{
     error::*,
     fmt::{Braced, Hyphenated, Simple, Urn},
     non_nil::NonNilUuid,
+    range::UuidRange,
     std::fmt,
-    Uuid,
+    Uuid, Variant, Version,
 };
 use serde::{
     de::{self, Error as _},
@@ -149,10 +150,203 @@ impl<'de> Deserialize<'de> for NonNilUuid {
     }
 }
 
+impl Serialize for UuidRange {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut range = serializer.serialize_struct("UuidRange", 2)?;
+        range.serialize_field("start", &self.start)?;
+        range.serialize_field("end", &self.end)?;
+        range.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for UuidRange {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const FIELDS: &[&str] = &["start", "end"];
+
+        enum Field {
+            Start,
+            End,
+        }
+
+        impl<'de> Deserialize<'de> for Field {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct FieldVisitor;
+
+                impl<'vi> de::Visitor<'vi> for FieldVisitor {
+                    type Value = Field;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        write!(formatter, "`start` or `end`")
+                    }
+
+                    fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                        match value {
+                            "start" => Ok(Field::Start),
+                            "end" => Ok(Field::End),
+                            _ => Err(E::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+
+                deserializer.deserialize_identifier(FieldVisitor)
+            }
+        }
+
+        struct UuidRangeVisitor;
+
+        impl<'vi> de::Visitor<'vi> for UuidRangeVisitor {
+            type Value = UuidRange;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a UuidRange")
+            }
+
+            fn visit_seq<A: de::SeqAccess<'vi>>(self, mut seq: A) -> Result<UuidRange, A::Error> {
+                let start = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+                let end = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+
+                Ok(UuidRange::new(start, end))
+            }
+
+            fn visit_map<A: de::MapAccess<'vi>>(self, mut map: A) -> Result<UuidRange, A::Error> {
+                let mut start = None;
+                let mut end = None;
+
+                while let Some(field) = map.next_key()? {
+                    match field {
+                        Field::Start if start.is_some() => {
+                            return Err(A::Error::duplicate_field("start"))
+                        }
+                        Field::Start => start = Some(map.next_value()?),
+                        Field::End if end.is_some() => {
+                            return Err(A::Error::duplicate_field("end"))
+                        }
+                        Field::End => end = Some(map.next_value()?),
+                    }
+                }
+
+                Ok(UuidRange::new(
+                    start.ok_or_else(|| A::Error::missing_field("start"))?,
+                    end.ok_or_else(|| A::Error::missing_field("end"))?,
+                ))
+            }
+        }
+
+        deserializer.deserialize_struct("UuidRange", FIELDS, UuidRangeVisitor)
+    }
+}
+
+impl Serialize for Version {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_u8(*self as u8)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct VersionVisitor;
+
+        impl<'vi> de::Visitor<'vi> for VersionVisitor {
+            type Value = Version;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a UUID version like `v7`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Version, E> {
+                value
+                    .parse()
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Version, E> {
+                match value {
+                    0 => Ok(Version::Nil),
+                    1 => Ok(Version::Mac),
+                    2 => Ok(Version::Dce),
+                    3 => Ok(Version::Md5),
+                    4 => Ok(Version::Random),
+                    5 => Ok(Version::Sha1),
+                    6 => Ok(Version::SortMac),
+                    7 => Ok(Version::SortRand),
+                    8 => Ok(Version::Custom),
+                    0xff => Ok(Version::Max),
+                    _ => Err(E::invalid_value(de::Unexpected::Unsigned(value), &self)),
+                }
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(VersionVisitor)
+        } else {
+            deserializer.deserialize_u8(VersionVisitor)
+        }
+    }
+}
+
+impl Serialize for Variant {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_u8(*self as u8)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Variant {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct VariantVisitor;
+
+        impl<'vi> de::Visitor<'vi> for VariantVisitor {
+            type Value = Variant;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a UUID variant like `RFC4122`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Variant, E> {
+                value
+                    .parse()
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Variant, E> {
+                match value {
+                    0 => Ok(Variant::NCS),
+                    1 => Ok(Variant::RFC4122),
+                    2 => Ok(Variant::Microsoft),
+                    3 => Ok(Variant::Future),
+                    _ => Err(E::invalid_value(de::Unexpected::Unsigned(value), &self)),
+                }
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(VariantVisitor)
+        } else {
+            deserializer.deserialize_u8(VariantVisitor)
+        }
+    }
+}
+
 enum ExpectedFormat {
     Simple,
     Braced,
     Urn,
+    Any,
+    Base64Url,
+    StrictHyphenated,
 }
 
 impl std::fmt::Display for ExpectedFormat {
@@ -165,6 +359,11 @@ impl std::fmt::Display for ExpectedFormat {
             ExpectedFormat::Urn => {
                 "a URN Uuid string like urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8"
             }
+            ExpectedFormat::Any => "a simple, hyphenated, braced, or URN Uuid string",
+            ExpectedFormat::Base64Url => "a base64url Uuid string like Z-VQRBCxQm-SR7toDl_gyA",
+            ExpectedFormat::StrictHyphenated => {
+                "a lowercase hyphenated Uuid string like 67e55044-10b1-426f-9247-bb680e5fe0c8"
+            }
         };
         f.write_str(s)
     }
@@ -303,7 +502,7 @@ pub mod simple {
     where
         S: serde::Serializer,
     {
-        serde::Serialize::serialize(u.as_simple(), serializer)
+        serde::Serialize::serialize(&u.simple(), serializer)
     }
 
     /// Deserialize a simple Uuid string as a [`Uuid`]
@@ -435,7 +634,7 @@ pub mod braced {
     where
         S: serde::Serializer,
     {
-        serde::Serialize::serialize(u.as_braced(), serializer)
+        serde::Serialize::serialize(&u.braced(), serializer)
     }
 
     /// Deserialize a braced Uuid string as a [`Uuid`]
@@ -567,7 +766,7 @@ pub mod urn {
     where
         S: serde::Serializer,
     {
-        serde::Serialize::serialize(u.as_urn(), serializer)
+        serde::Serialize::serialize(&u.urn(), serializer)
     }
 
     /// Deserialize a urn Uuid string as a [`Uuid`]
@@ -651,12 +850,646 @@ pub mod urn {
     }
 }
 
+/// Serialize a [`Uuid`] as a hyphenated string, and deserialize it from a
+/// string in any format
+///
+/// [`Uuid`]: ../../struct.Uuid.html
+///
+/// Payloads from .NET and XML-based systems commonly write UUIDs in the
+/// braced `{...}` or `urn:uuid:` formats. The default `Deserialize` impl for
+/// [`Uuid`] already accepts them from human-readable formats, but this module
+/// also accepts them from formats that aren't human-readable, where a UUID
+/// would otherwise be expected as 16 raw bytes.
+///
+/// ## Example
+///
+/// ```rust
+/// #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+/// struct Struct {
+///     #[serde(with = "uuid::serde::any_format")]
+///     id: uuid::Uuid,
+/// }
+/// ```
+pub mod any_format {
+    use serde::de;
+
+    use crate::{std::fmt, Uuid};
+
+    use super::ExpectedFormat;
+
+    /// Serialize from a [`Uuid`] as a `uuid::fmt::Hyphenated`
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn serialize<S>(u: &Uuid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&u.hyphenated(), serializer)
+    }
+
+    /// Deserialize a simple, hyphenated, braced, or URN Uuid string as a [`Uuid`]
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Uuid, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct AnyFormatVisitor;
+
+        impl<'vi> de::Visitor<'vi> for AnyFormatVisitor {
+            type Value = Uuid;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                de::Expected::fmt(&ExpectedFormat::Any, formatter)
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Uuid, E> {
+                Uuid::try_parse(value).map_err(|_| {
+                    de::Error::invalid_value(de::Unexpected::Str(value), &ExpectedFormat::Any)
+                })
+            }
+        }
+
+        deserializer.deserialize_str(AnyFormatVisitor)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use serde::de::{self, Error};
+        use serde_test::{Configure, Readable, Token};
+
+        use crate::{external::serde_support::ExpectedFormat, Uuid};
+
+        const HYPHENATED_UUID_STR: &str = "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4";
+
+        #[derive(PartialEq, Debug, serde_derive::Serialize, serde_derive::Deserialize)]
+        struct Struct(#[serde(with = "super")] crate::Uuid);
+
+        #[test]
+        fn test_serialize_as_hyphenated() {
+            let u = Struct(Uuid::parse_str(HYPHENATED_UUID_STR).unwrap());
+            serde_test::assert_ser_tokens(
+                &u.compact(),
+                &[
+                    Token::NewtypeStruct { name: "Struct" },
+                    Token::Str(HYPHENATED_UUID_STR),
+                ],
+            );
+        }
+
+        #[test]
+        fn test_de_from_any_format() {
+            let s = Struct(HYPHENATED_UUID_STR.parse().unwrap());
+
+            for uuid_str in [
+                HYPHENATED_UUID_STR,
+                "f9168c5eceb24faab6bf329bf39fa1e4",
+                "{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4}",
+                "urn:uuid:F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4",
+            ] {
+                serde_test::assert_de_tokens(
+                    &Struct(s.0).compact(),
+                    &[
+                        Token::NewtypeStruct { name: "Struct" },
+                        Token::Str(uuid_str),
+                    ],
+                );
+            }
+        }
+
+        #[test]
+        fn test_de_reject_invalid() {
+            serde_test::assert_de_tokens_error::<Readable<Struct>>(
+                &[
+                    Token::NewtypeStruct { name: "Struct" },
+                    Token::Str("{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4"),
+                ],
+                &format!(
+                    "{}",
+                    de::value::Error::invalid_value(
+                        de::Unexpected::Str("{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4"),
+                        &ExpectedFormat::Any,
+                    )
+                ),
+            );
+        }
+    }
+}
+
+/// Serialize a [`Uuid`] as a hyphenated string, and deserialize it from a
+/// string or an array of 16 bytes
+///
+/// [`Uuid`]: ../../struct.Uuid.html
+///
+/// Some serializers in other languages write UUIDs as arrays of their bytes,
+/// like `[249, 22, 140, ...]` in JSON. The default `Deserialize` impl for
+/// [`Uuid`] only asks human-readable formats for a string, so formats that
+/// guess the types of their values, like CSV, don't mistake a UUID for a
+/// number. This module asks the format for whatever value it has instead, so
+/// it only suits self-describing formats like JSON.
+///
+/// ## Example
+///
+/// ```rust
+/// #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+/// struct Struct {
+///     #[serde(with = "uuid::serde::byte_array")]
+///     id: uuid::Uuid,
+/// }
+///
+/// let value: Struct = serde_json::from_str(
+///     r#"{"id":[103,229,80,68,16,177,66,111,146,71,187,104,14,95,224,200]}"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!("67e55044-10b1-426f-9247-bb680e5fe0c8", value.id.to_string());
+/// ```
+pub mod byte_array {
+    use serde::de;
+
+    use crate::{std::fmt, Uuid};
+
+    /// Serialize from a [`Uuid`] as a `uuid::fmt::Hyphenated`
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn serialize<S>(u: &Uuid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&u.hyphenated(), serializer)
+    }
+
+    /// Deserialize a Uuid string or an array of 16 bytes as a [`Uuid`]
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Uuid, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ByteArrayVisitor;
+
+        impl<'vi> de::Visitor<'vi> for ByteArrayVisitor {
+            type Value = Uuid;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a UUID string or an array of 16 bytes")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Uuid, E> {
+                Uuid::try_parse(value)
+                    .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(value), &self))
+            }
+
+            fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Uuid, E> {
+                Uuid::from_slice(value).map_err(|_| de::Error::invalid_length(value.len(), &self))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Uuid, A::Error>
+            where
+                A: de::SeqAccess<'vi>,
+            {
+                let mut bytes = [0; 16];
+
+                for (i, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+
+                if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                    return Err(de::Error::invalid_length(17, &self));
+                }
+
+                Ok(Uuid::from_bytes(bytes))
+            }
+        }
+
+        deserializer.deserialize_any(ByteArrayVisitor)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::Uuid;
+
+        const HYPHENATED_UUID_STR: &str = "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4";
+
+        #[derive(PartialEq, Debug, serde_derive::Serialize, serde_derive::Deserialize)]
+        struct Struct(#[serde(with = "super")] crate::Uuid);
+
+        #[test]
+        fn test_de_from_json_array() {
+            let u = Struct(Uuid::parse_str(HYPHENATED_UUID_STR).unwrap());
+
+            assert_eq!(
+                u,
+                serde_json::from_str::<Struct>(
+                    "[249, 22, 140, 94, 206, 178, 79, 170, 182, 191, 50, 155, 243, 159, 161, 228]"
+                )
+                .unwrap()
+            );
+            assert_eq!(
+                u,
+                serde_json::from_str::<Struct>(&format!("\"{}\"", HYPHENATED_UUID_STR)).unwrap()
+            );
+            assert_eq!(
+                format!("\"{}\"", HYPHENATED_UUID_STR),
+                serde_json::to_string(&u).unwrap()
+            );
+
+            assert!(serde_json::from_str::<Struct>("[249, 22, 140]").is_err());
+            assert!(serde_json::from_str::<Struct>(
+                "[249, 22, 140, 94, 206, 178, 79, 170, 182, 191, 50, 155, 243, 159, 161, 228, 0]"
+            )
+            .is_err());
+            assert!(serde_json::from_str::<Struct>(
+                "[249, 22, 140, 94, 206, 178, 79, 170, 182, 191, 50, 155, 243, 159, 161, 256]"
+            )
+            .is_err());
+            assert!(serde_json::from_str::<Struct>("42").is_err());
+        }
+    }
+}
+
+/// Serialize a [`Uuid`] as an unpadded base64url string
+///
+/// [`Uuid`]: ../../struct.Uuid.html
+///
+/// The 16 bytes of the UUID are encoded with the URL and filename safe
+/// alphabet from RFC 4648, without padding, in 22 characters like
+/// `Z-VQRBCxQm-SR7toDl_gyA`. That's 14 characters shorter than a hyphenated
+/// UUID, which adds up in payloads with lots of IDs, and is the same text
+/// base64url libraries in other languages produce for the UUID's bytes.
+///
+/// ## Example
+///
+/// ```rust
+/// #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+/// struct Struct {
+///     #[serde(with = "uuid::serde::base64url")]
+///     id: uuid::Uuid,
+/// }
+///
+/// let value = Struct {
+///     id: uuid::Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap(),
+/// };
+///
+/// assert_eq!(
+///     r#"{"id":"Z-VQRBCxQm-SR7toDl_gyA"}"#,
+///     serde_json::to_string(&value).unwrap(),
+/// );
+/// ```
+pub mod base64url {
+    use serde::de;
+
+    use crate::{std::fmt, std::str, Uuid};
+
+    use super::ExpectedFormat;
+
+    const LENGTH: usize = 22;
+
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+    /// Serialize from a [`Uuid`] as an unpadded base64url string
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn serialize<S>(u: &Uuid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(encode(u, &mut [0; LENGTH]))
+    }
+
+    /// Deserialize an unpadded base64url string as a [`Uuid`]
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Uuid, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Base64UrlVisitor;
+
+        impl<'vi> de::Visitor<'vi> for Base64UrlVisitor {
+            type Value = Uuid;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                de::Expected::fmt(&ExpectedFormat::Base64Url, formatter)
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Uuid, E> {
+                decode(value.as_bytes()).ok_or_else(|| {
+                    de::Error::invalid_value(de::Unexpected::Str(value), &ExpectedFormat::Base64Url)
+                })
+            }
+        }
+
+        deserializer.deserialize_str(Base64UrlVisitor)
+    }
+
+    fn encode<'b>(u: &Uuid, buffer: &'b mut [u8; LENGTH]) -> &'b str {
+        let bits = u.as_u128();
+
+        // The last symbol only holds the lowest 2 bits, padded with zeros
+        for (i, symbol) in buffer.iter_mut().enumerate() {
+            let shift = 122 - 6 * i as i32;
+            let value = if shift >= 0 {
+                bits >> shift
+            } else {
+                bits << -shift
+            };
+
+            *symbol = ALPHABET[(value & 0x3f) as usize];
+        }
+
+        // The alphabet is ASCII
+        crate::fmt::ascii_str_mut(buffer)
+    }
+
+    fn decode(input: &[u8]) -> Option<Uuid> {
+        if input.len() != LENGTH {
+            return None;
+        }
+
+        let mut bits = 0u128;
+
+        for (i, symbol) in input.iter().enumerate() {
+            let value = ALPHABET.iter().position(|s| s == symbol)? as u128;
+
+            if i < LENGTH - 1 {
+                bits = (bits << 6) | value;
+            } else {
+                // Only accept the canonical encoding, where the padding bits
+                // of the last symbol are zero
+                if value & 0xf != 0 {
+                    return None;
+                }
+
+                bits = (bits << 2) | (value >> 4);
+            }
+        }
+
+        Some(Uuid::from_u128(bits))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use serde::de::{self, Error};
+        use serde_test::{Configure, Readable, Token};
+
+        use crate::{external::serde_support::ExpectedFormat, Uuid};
+
+        #[derive(PartialEq, Debug, serde_derive::Serialize, serde_derive::Deserialize)]
+        struct Struct(#[serde(with = "super")] crate::Uuid);
+
+        #[test]
+        fn test_serialize_as_base64url() {
+            let u = Struct(Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4").unwrap());
+
+            serde_test::assert_tokens(
+                &u.compact(),
+                &[
+                    Token::NewtypeStruct { name: "Struct" },
+                    Token::Str("-RaMXs6yT6q2vzKb85-h5A"),
+                ],
+            );
+        }
+
+        #[test]
+        fn test_round_trip() {
+            for uuid in [Uuid::nil(), Uuid::max(), crate::tests::new()] {
+                let mut buffer = [0; super::LENGTH];
+                let encoded = super::encode(&uuid, &mut buffer);
+
+                assert_eq!(Some(uuid), super::decode(encoded.as_bytes()));
+            }
+
+            assert_eq!(
+                "AAAAAAAAAAAAAAAAAAAAAA",
+                super::encode(&Uuid::nil(), &mut [0; 22])
+            );
+            assert_eq!(
+                "_____________________w",
+                super::encode(&Uuid::max(), &mut [0; 22])
+            );
+        }
+
+        #[test]
+        fn test_de_reject_invalid() {
+            for invalid in [
+                // Padded
+                "-RaMXs6yT6q2vzKb85-h5A==",
+                // Standard base64 alphabet
+                "+RaMXs6yT6q2vzKb85+h5A",
+                // Non-zero padding bits
+                "-RaMXs6yT6q2vzKb85-h5B",
+                "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+            ] {
+                serde_test::assert_de_tokens_error::<Readable<Struct>>(
+                    &[Token::NewtypeStruct { name: "Struct" }, Token::Str(invalid)],
+                    &format!(
+                        "{}",
+                        de::value::Error::invalid_value(
+                            de::Unexpected::Str(invalid),
+                            &ExpectedFormat::Base64Url,
+                        )
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Serialize a [`Uuid`] as a lowercase hyphenated string, and only accept
+/// that exact form when deserializing.
+///
+/// [`Uuid`]: ../../struct.Uuid.html
+///
+/// The default `Deserialize` impl for [`Uuid`] accepts any of the formats
+/// supported by [`Uuid::parse_str`], so the same UUID can arrive as several
+/// different strings. Protocols that sign or cache payloads by their text
+/// need a single canonical representation, so this module rejects braced,
+/// URN, simple, and uppercase UUIDs.
+///
+/// [`Uuid::parse_str`]: ../../struct.Uuid.html#method.parse_str
+///
+/// ## Example
+///
+/// ```rust
+/// #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+/// struct Struct {
+///     #[serde(with = "uuid::serde::strict_hyphenated")]
+///     id: uuid::Uuid,
+/// }
+///
+/// assert!(serde_json::from_str::<Struct>(
+///     r#"{"id":"67e55044-10b1-426f-9247-bb680e5fe0c8"}"#
+/// ).is_ok());
+///
+/// assert!(serde_json::from_str::<Struct>(
+///     r#"{"id":"67E55044-10B1-426F-9247-BB680E5FE0C8"}"#
+/// ).is_err());
+/// ```
+pub mod strict_hyphenated {
+    use serde::de;
+
+    use crate::{parser::ParseOptions, std::fmt, Uuid};
+
+    use super::ExpectedFormat;
+
+    const OPTIONS: ParseOptions = ParseOptions::strict().require_lowercase(true);
+
+    /// Serialize from a [`Uuid`] as a lowercase `uuid::fmt::Hyphenated`
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn serialize<S>(u: &Uuid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&u.hyphenated(), serializer)
+    }
+
+    /// Deserialize a lowercase hyphenated Uuid string as a [`Uuid`]
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Uuid, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct StrictHyphenatedVisitor;
+
+        impl<'vi> de::Visitor<'vi> for StrictHyphenatedVisitor {
+            type Value = Uuid;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                de::Expected::fmt(&ExpectedFormat::StrictHyphenated, formatter)
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Uuid, E> {
+                Uuid::parse_with(value, &OPTIONS).map_err(|_| {
+                    de::Error::invalid_value(
+                        de::Unexpected::Str(value),
+                        &ExpectedFormat::StrictHyphenated,
+                    )
+                })
+            }
+        }
+
+        deserializer.deserialize_str(StrictHyphenatedVisitor)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use serde::de::{self, Error};
+        use serde_test::{Configure, Readable, Token};
+
+        use crate::{external::serde_support::ExpectedFormat, Uuid};
+
+        const HYPHENATED_UUID_STR: &str = "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4";
+
+        #[derive(PartialEq, Debug, serde_derive::Serialize, serde_derive::Deserialize)]
+        struct Struct(#[serde(with = "super")] crate::Uuid);
+
+        #[test]
+        fn test_round_trip() {
+            let u = Struct(Uuid::parse_str(HYPHENATED_UUID_STR).unwrap());
+
+            serde_test::assert_tokens(
+                &u.readable(),
+                &[
+                    Token::NewtypeStruct { name: "Struct" },
+                    Token::Str(HYPHENATED_UUID_STR),
+                ],
+            );
+        }
+
+        #[test]
+        fn test_de_reject_non_canonical() {
+            for invalid in [
+                "F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4",
+                "f9168c5e-ceb2-4faa-b6bf-329bf39fa1E4",
+                "{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4}",
+                "urn:uuid:f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+                "f9168c5eceb24faab6bf329bf39fa1e4",
+                " f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+            ] {
+                serde_test::assert_de_tokens_error::<Readable<Struct>>(
+                    &[Token::NewtypeStruct { name: "Struct" }, Token::Str(invalid)],
+                    &format!(
+                        "{}",
+                        de::value::Error::invalid_value(
+                            de::Unexpected::Str(invalid),
+                            &ExpectedFormat::StrictHyphenated,
+                        )
+                    ),
+                );
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod serde_tests {
     use super::*;
 
     use serde_test::{Compact, Configure, Readable, Token};
 
+    #[test]
+    fn test_uuid_range_readable() {
+        let range = UuidRange::new(
+            Uuid::nil(),
+            Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4").unwrap(),
+        );
+
+        serde_test::assert_tokens(
+            &range.readable(),
+            &[
+                Token::Struct {
+                    name: "UuidRange",
+                    len: 2,
+                },
+                Token::Str("start"),
+                Token::Str("00000000-0000-0000-0000-000000000000"),
+                Token::Str("end"),
+                Token::Str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4"),
+                Token::StructEnd,
+            ],
+        );
+
+        serde_test::assert_de_tokens(
+            &range.readable(),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Str("00000000-0000-0000-0000-000000000000"),
+                Token::Str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4"),
+                Token::SeqEnd,
+            ],
+        );
+
+        serde_test::assert_de_tokens_error::<Readable<UuidRange>>(
+            &[
+                Token::Struct {
+                    name: "UuidRange",
+                    len: 1,
+                },
+                Token::Str("start"),
+                Token::Str("00000000-0000-0000-0000-000000000000"),
+                Token::StructEnd,
+            ],
+            "missing field `end`",
+        );
+    }
+
+    #[test]
+    fn test_uuid_range_json() {
+        let range = UuidRange::full();
+        let json = serde_json::to_string(&range).unwrap();
+
+        assert_eq!(
+            r#"{"start":"00000000-0000-0000-0000-000000000000","end":"ffffffff-ffff-ffff-ffff-ffffffffffff"}"#,
+            json
+        );
+        assert_eq!(range, serde_json::from_str::<UuidRange>(&json).unwrap());
+    }
+
     #[test]
     fn test_serialize_readable_string() {
         let uuid_str = "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4";
@@ -694,6 +1527,29 @@ mod serde_tests {
         );
     }
 
+    #[test]
+    fn test_deserialize_readable_braced_urn() {
+        let u = Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4").unwrap();
+
+        serde_test::assert_de_tokens(
+            &u.readable(),
+            &[Token::Str("{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4}")],
+        );
+        serde_test::assert_de_tokens(
+            &u.readable(),
+            &[Token::Str("urn:uuid:f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4")],
+        );
+    }
+
+    #[test]
+    fn test_deserialize_json_array_rejected() {
+        // Arrays of bytes are only accepted through `uuid::serde::byte_array`
+        assert!(serde_json::from_str::<Uuid>(
+            "[249, 22, 140, 94, 206, 178, 79, 170, 182, 191, 50, 155, 243, 159, 161, 228]"
+        )
+        .is_err());
+    }
+
     #[test]
     fn test_deserialize_readable_bytes() {
         let uuid_bytes = b"F9168C5E-CEB2-4F";
@@ -746,7 +1602,7 @@ mod serde_tests {
     fn test_de_failure() {
         serde_test::assert_de_tokens_error::<Readable<Uuid>>(
             &[Token::Str("hello_world")],
-            "UUID parsing failed: invalid character: expected an optional prefix of `urn:uuid:` followed by [0-9a-fA-F-], found `h` at 1",
+            "UUID parsing failed: invalid character: expected an optional prefix of `urn:uuid:` followed by [0-9a-fA-F-], found `h` at 1 (input: \"hello_world\")",
         );
 
         serde_test::assert_de_tokens_error::<Compact<Uuid>>(
@@ -763,5 +1619,42 @@ mod serde_tests {
 
         serde_test::assert_ser_tokens(&non_nil_uuid.readable(), &[Token::Str(uuid_str)]);
         serde_test::assert_de_tokens(&non_nil_uuid.readable(), &[Token::Str(uuid_str)]);
+
+        serde_test::assert_de_tokens_error::<Readable<NonNilUuid>>(
+            &[Token::Str("00000000-0000-0000-0000-000000000000")],
+            "invalid value: nil UUID, expected a non-nil UUID",
+        );
+    }
+
+    #[test]
+    fn test_serde_version() {
+        serde_test::assert_tokens(&Version::SortRand.readable(), &[Token::Str("v7")]);
+        serde_test::assert_tokens(&Version::Max.readable(), &[Token::Str("max")]);
+        serde_test::assert_tokens(&Version::SortRand.compact(), &[Token::U8(7)]);
+        serde_test::assert_tokens(&Version::Max.compact(), &[Token::U8(0xff)]);
+
+        serde_test::assert_de_tokens(&Version::Random.readable(), &[Token::Str("4")]);
+
+        serde_test::assert_de_tokens_error::<Readable<Version>>(
+            &[Token::Str("v9")],
+            "invalid value: string \"v9\", expected a UUID version like `v7`",
+        );
+        serde_test::assert_de_tokens_error::<Compact<Version>>(
+            &[Token::U8(9)],
+            "invalid value: integer `9`, expected a UUID version like `v7`",
+        );
+    }
+
+    #[test]
+    fn test_serde_variant() {
+        serde_test::assert_tokens(&Variant::RFC4122.readable(), &[Token::Str("RFC4122")]);
+        serde_test::assert_tokens(&Variant::Microsoft.compact(), &[Token::U8(2)]);
+
+        serde_test::assert_de_tokens(&Variant::RFC4122.readable(), &[Token::Str("rfc9562")]);
+
+        serde_test::assert_de_tokens_error::<Readable<Variant>>(
+            &[Token::Str("Apple")],
+            "invalid value: string \"Apple\", expected a UUID variant like `RFC4122`",
+        );
     }
 }
@@ -9,16 +9,20 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::{non_nil::NonNilUuid, Uuid};
+use crate::{
+    fmt::{Braced, Checksummed, Hyphenated, Separated, Simple, Urn},
+    non_nil::NonNilUuid,
+    Uuid,
+};
 
 impl slog::Value for Uuid {
     fn serialize(
         &self,
-        _: &slog::Record<'_>,
+        record: &slog::Record<'_>,
         key: slog::Key,
         serializer: &mut dyn slog::Serializer,
     ) -> Result<(), slog::Error> {
-        serializer.emit_arguments(key, &format_args!("{}", self))
+        self.hyphenated().serialize(record, key, serializer)
     }
 }
 
@@ -33,9 +37,36 @@ impl slog::Value for NonNilUuid {
     }
 }
 
+// The adapters are encoded into a buffer on the stack and emitted as
+// strings, so serializers don't need to go through `format_args!`
+macro_rules! impl_slog_value {
+    ($($T:ident),+) => {$(
+        impl slog::Value for $T {
+            fn serialize(
+                &self,
+                _: &slog::Record<'_>,
+                key: slog::Key,
+                serializer: &mut dyn slog::Serializer,
+            ) -> Result<(), slog::Error> {
+                serializer.emit_str(key, self.encode_lower(&mut [0; $T::LENGTH]))
+            }
+        }
+    )+};
+}
+
+impl_slog_value!(Hyphenated, Simple, Urn, Braced, Checksummed, Separated);
+
 #[cfg(test)]
 mod tests {
-    use crate::tests::new;
+    use crate::{
+        std::{
+            fmt,
+            string::{String, ToString},
+            sync::{Arc, Mutex},
+            vec::Vec,
+        },
+        tests::new,
+    };
 
     use slog::{crit, Drain};
 
@@ -45,4 +76,68 @@ mod tests {
         let u1 = new();
         crit!(root, "test"; "u1" => u1);
     }
+
+    // Collects the values of records, failing if any aren't emitted as strings
+    #[derive(Clone, Default)]
+    struct Collect(Arc<Mutex<Values>>);
+
+    #[derive(Default)]
+    struct Values(Vec<(String, String)>);
+
+    impl slog::Serializer for Values {
+        fn emit_arguments(&mut self, _: slog::Key, _: &fmt::Arguments<'_>) -> slog::Result {
+            Err(slog::Error::Other)
+        }
+
+        fn emit_str(&mut self, key: slog::Key, val: &str) -> slog::Result {
+            self.0.push((key.to_string(), val.to_string()));
+            Ok(())
+        }
+    }
+
+    impl Drain for Collect {
+        type Ok = ();
+        type Err = slog::Error;
+
+        fn log(&self, record: &slog::Record<'_>, _: &slog::OwnedKVList) -> slog::Result {
+            use slog::KV;
+
+            record.kv().serialize(record, &mut *self.0.lock().unwrap())
+        }
+    }
+
+    #[test]
+    fn test_slog_adapters() {
+        let collect = Collect::default();
+        let root = slog::Logger::root(collect.clone().fuse(), slog::o!());
+
+        let uuid = new();
+        crit!(
+            root,
+            "test";
+            "hyphenated" => uuid,
+            "simple" => uuid.simple(),
+            "urn" => uuid.urn(),
+            "braced" => uuid.braced(),
+            "checksummed" => uuid.checksummed(),
+            "separated" => uuid.separated('_'),
+        );
+
+        let mut values = collect.0.lock().unwrap().0.clone();
+
+        // Values are serialized in reverse order
+        values.reverse();
+
+        assert_eq!(
+            vec![
+                ("hyphenated".to_string(), uuid.hyphenated().to_string()),
+                ("simple".to_string(), uuid.simple().to_string()),
+                ("urn".to_string(), uuid.urn().to_string()),
+                ("braced".to_string(), uuid.braced().to_string()),
+                ("checksummed".to_string(), uuid.checksummed().to_string()),
+                ("separated".to_string(), uuid.separated('_').to_string()),
+            ],
+            values
+        );
+    }
 }
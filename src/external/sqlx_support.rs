@@ -0,0 +1,203 @@
+use crate::Uuid;
+use sqlx::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
+
+// NOTE: These impls don't depend on `sqlx`'s own `uuid` feature, which would pull
+// in a second copy of this crate. Values are read and written as their 16 bytes,
+// the same way `sqlx` itself does
+
+#[cfg(feature = "sqlx-postgres")]
+mod postgres_support {
+    use super::*;
+    use sqlx::{
+        postgres::{
+            types::Oid, PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef,
+        },
+        Postgres,
+    };
+
+    // The OIDs of builtin types are fixed, so these never need to be looked up
+    const UUID_OID: Oid = Oid(2950);
+    const UUID_ARRAY_OID: Oid = Oid(2951);
+
+    // Postgres has a native `uuid` type
+    impl Type<Postgres> for Uuid {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_oid(UUID_OID)
+        }
+    }
+
+    impl PgHasArrayType for Uuid {
+        fn array_type_info() -> PgTypeInfo {
+            PgTypeInfo::with_oid(UUID_ARRAY_OID)
+        }
+    }
+
+    impl Encode<'_, Postgres> for Uuid {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            buf.extend_from_slice(self.as_bytes());
+
+            Ok(IsNull::No)
+        }
+    }
+
+    impl Decode<'_, Postgres> for Uuid {
+        fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+            match value.format() {
+                PgValueFormat::Binary => Ok(Uuid::from_slice(value.as_bytes()?)?),
+                PgValueFormat::Text => Ok(Uuid::parse_str(value.as_str()?)?),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "sqlx-mysql")]
+mod mysql_support {
+    use super::*;
+    use sqlx::{
+        mysql::{MySqlTypeInfo, MySqlValueRef},
+        Database, MySql,
+    };
+
+    // MySQL stores UUIDs as `BINARY(16)`
+    impl Type<MySql> for Uuid {
+        fn type_info() -> MySqlTypeInfo {
+            <[u8] as Type<MySql>>::type_info()
+        }
+
+        fn compatible(ty: &MySqlTypeInfo) -> bool {
+            <[u8] as Type<MySql>>::compatible(ty)
+        }
+    }
+
+    impl Encode<'_, MySql> for Uuid {
+        fn encode_by_ref(
+            &self,
+            buf: &mut <MySql as Database>::ArgumentBuffer<'_>,
+        ) -> Result<IsNull, BoxDynError> {
+            <&[u8] as Encode<MySql>>::encode_by_ref(&&self.as_bytes()[..], buf)
+        }
+    }
+
+    impl Decode<'_, MySql> for Uuid {
+        fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+            let bytes = <&[u8] as Decode<MySql>>::decode(value)?;
+
+            Ok(Uuid::from_slice(bytes)?)
+        }
+    }
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+mod sqlite_support {
+    use super::*;
+    use crate::std::vec::Vec;
+    use sqlx::{
+        sqlite::{SqliteTypeInfo, SqliteValueRef},
+        Database, Sqlite, TypeInfo, ValueRef,
+    };
+
+    // SQLite stores UUIDs as a 16 byte `BLOB`, but can also decode them from `TEXT`
+    impl Type<Sqlite> for Uuid {
+        fn type_info() -> SqliteTypeInfo {
+            <[u8] as Type<Sqlite>>::type_info()
+        }
+
+        fn compatible(ty: &SqliteTypeInfo) -> bool {
+            <[u8] as Type<Sqlite>>::compatible(ty) || <str as Type<Sqlite>>::compatible(ty)
+        }
+    }
+
+    impl<'q> Encode<'q, Sqlite> for Uuid {
+        fn encode_by_ref(
+            &self,
+            buf: &mut <Sqlite as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            <Vec<u8> as Encode<'q, Sqlite>>::encode(self.as_bytes().to_vec(), buf)
+        }
+    }
+
+    impl Decode<'_, Sqlite> for Uuid {
+        fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+            if value.type_info().name() == "TEXT" {
+                let text = <&str as Decode<Sqlite>>::decode(value)?;
+
+                Ok(Uuid::parse_str(text)?)
+            } else {
+                let bytes = <&[u8] as Decode<Sqlite>>::decode(value)?;
+
+                Ok(Uuid::from_slice(bytes)?)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod sqlx_tests {
+    use super::*;
+
+    const UUID: Uuid = Uuid::from_u128(0xf9168c5e_ceb2_4faa_b6bf_329bf39fa1e4);
+
+    #[test]
+    #[cfg(feature = "sqlx-postgres")]
+    fn test_postgres() {
+        use sqlx::{
+            postgres::{types::Oid, PgArgumentBuffer, PgHasArrayType},
+            Postgres,
+        };
+
+        assert_eq!(Some(Oid(2950)), <Uuid as Type<Postgres>>::type_info().oid());
+        assert_eq!(
+            Some(Oid(2951)),
+            <Uuid as PgHasArrayType>::array_type_info().oid()
+        );
+
+        let mut buf = PgArgumentBuffer::default();
+        assert!(matches!(
+            Encode::<Postgres>::encode_by_ref(&UUID, &mut buf),
+            Ok(IsNull::No)
+        ));
+        assert_eq!(UUID.as_bytes(), &buf[..]);
+    }
+
+    #[test]
+    #[cfg(feature = "sqlx-mysql")]
+    fn test_mysql() {
+        use crate::std::vec::Vec;
+        use sqlx::MySql;
+
+        let mut buf = Vec::new();
+        assert!(matches!(
+            Encode::<MySql>::encode_by_ref(&UUID, &mut buf),
+            Ok(IsNull::No)
+        ));
+
+        // The bytes are prefixed by their length
+        assert_eq!(16, buf[0]);
+        assert_eq!(UUID.as_bytes(), &buf[1..]);
+    }
+
+    #[test]
+    #[cfg(feature = "sqlx-sqlite")]
+    fn test_sqlite() {
+        use crate::std::vec::Vec;
+        use sqlx::{sqlite::SqliteArgumentValue, Sqlite, TypeInfo};
+
+        assert_eq!("BLOB", <Uuid as Type<Sqlite>>::type_info().name());
+
+        let mut buf = Vec::new();
+        assert!(matches!(
+            Encode::<Sqlite>::encode_by_ref(&UUID, &mut buf),
+            Ok(IsNull::No)
+        ));
+
+        match &buf[..] {
+            [SqliteArgumentValue::Blob(bytes)] => assert_eq!(UUID.as_bytes(), &bytes[..]),
+            other => panic!("unexpected encoding: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{std::vec::Vec, Uuid};
+use tokio::{sync::mpsc, task::JoinHandle};
+
+/// The number of UUIDs an [`AsyncGenerator`] keeps ready by default.
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// An asynchronous generator for random (version 4) UUIDs.
+///
+/// Generating a random UUID needs entropy from the operating system, which can
+/// block while it's acquired. An `AsyncGenerator` keeps a pool of UUIDs ready,
+/// and refills it on a background task that runs the blocking work with
+/// [`tokio::task::spawn_blocking`]. This keeps async worker threads free even
+/// during spikes of load.
+///
+/// Note that usage of this type requires the `tokio` feature of this crate
+/// to be enabled.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{AsyncGenerator, Version};
+/// # let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// # runtime.block_on(async {
+/// let mut generator = AsyncGenerator::new();
+///
+/// let uuid = generator.next().await;
+///
+/// assert_eq!(Some(Version::Random), uuid.get_version());
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct AsyncGenerator {
+    pool: mpsc::Receiver<Uuid>,
+    refill: JoinHandle<()>,
+}
+
+impl AsyncGenerator {
+    /// Create a generator that keeps a default number of UUIDs ready.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if it's called outside of a Tokio runtime.
+    pub fn new() -> Self {
+        AsyncGenerator::with_capacity(DEFAULT_CAPACITY)
+    }
+
+    /// Create a generator that keeps up to `capacity` UUIDs ready.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `capacity` is zero, or if it's called outside of
+    /// a Tokio runtime.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let (sender, pool) = mpsc::channel(capacity);
+
+        let refill = tokio::spawn(async move {
+            loop {
+                let batch = tokio::task::spawn_blocking(move || {
+                    (0..capacity).map(|_| Uuid::new_v4()).collect::<Vec<_>>()
+                })
+                .await;
+
+                let batch = match batch {
+                    Ok(batch) => batch,
+                    // The runtime is shutting down
+                    Err(_) => return,
+                };
+
+                for uuid in batch {
+                    // The generator has been dropped
+                    if sender.send(uuid).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        AsyncGenerator { pool, refill }
+    }
+
+    /// Get the next UUID.
+    ///
+    /// If the pool is empty, this method waits for the background task to refill it.
+    pub async fn next(&mut self) -> Uuid {
+        match self.pool.recv().await {
+            Some(uuid) => uuid,
+            // The background task has stopped, so generate a UUID directly
+            None => Uuid::new_v4(),
+        }
+    }
+
+    /// Get the next UUID if one is ready, without waiting.
+    pub fn try_next(&mut self) -> Option<Uuid> {
+        self.pool.try_recv().ok()
+    }
+}
+
+impl Default for AsyncGenerator {
+    fn default() -> Self {
+        AsyncGenerator::new()
+    }
+}
+
+impl Drop for AsyncGenerator {
+    fn drop(&mut self) {
+        self.refill.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{std::collections::HashSet, Version};
+
+    fn block_on<F: std::future::Future>(f: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(f)
+    }
+
+    #[test]
+    fn test_next() {
+        block_on(async {
+            let mut generator = AsyncGenerator::with_capacity(8);
+
+            let mut seen = HashSet::new();
+
+            // Drain the pool several times over
+            for _ in 0..64 {
+                let uuid = generator.next().await;
+
+                assert_eq!(Some(Version::Random), uuid.get_version());
+                assert!(seen.insert(uuid));
+            }
+        });
+    }
+
+    #[test]
+    fn test_try_next() {
+        block_on(async {
+            let mut generator = AsyncGenerator::with_capacity(8);
+
+            // Nothing has been generated yet because the background task hasn't run
+            assert_eq!(None, generator.try_next());
+
+            let uuid = generator.next().await;
+
+            assert_eq!(Some(Version::Random), uuid.get_version());
+            assert!(generator.try_next().is_some());
+        });
+    }
+}
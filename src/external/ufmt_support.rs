@@ -0,0 +1,97 @@
+use crate::{
+    fmt::{Braced, Checksummed, Hyphenated, Separated, Simple, Urn},
+    non_nil::NonNilUuid,
+    Uuid,
+};
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+// The UUID is encoded into a buffer on the stack and written as a single
+// string, so none of `core::fmt` ends up in the binary
+
+impl uDisplay for Uuid {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        uDisplay::fmt(&self.hyphenated(), f)
+    }
+}
+
+impl uDebug for Uuid {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        uDisplay::fmt(self, f)
+    }
+}
+
+impl uDisplay for NonNilUuid {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        uDisplay::fmt(&Uuid::from(*self), f)
+    }
+}
+
+macro_rules! impl_ufmt {
+    ($($T:ident),+) => {$(
+        impl uDisplay for $T {
+            fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+                f.write_str(self.encode_lower(&mut [0; $T::LENGTH]))
+            }
+        }
+    )+};
+}
+
+impl_ufmt!(Hyphenated, Simple, Urn, Braced, Checksummed, Separated);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        non_nil::NonNilUuid,
+        std::{convert::Infallible, string::String, string::ToString},
+        tests::new,
+    };
+
+    use ufmt::{uWrite, uwrite};
+
+    #[derive(Default)]
+    struct Buf(String);
+
+    impl uWrite for Buf {
+        type Error = Infallible;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+            self.0.push_str(s);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ufmt() {
+        let uuid = new();
+        let non_nil = NonNilUuid::new(uuid).unwrap();
+
+        let mut buf = Buf::default();
+        uwrite!(
+            buf,
+            "{} {:?} {} {} {} {} {} {}",
+            uuid,
+            uuid,
+            uuid.simple(),
+            uuid.urn(),
+            uuid.braced(),
+            uuid.checksummed(),
+            uuid.separated('_'),
+            non_nil,
+        )
+        .unwrap();
+
+        let expected = [
+            uuid.to_string(),
+            format!("{:?}", uuid),
+            uuid.simple().to_string(),
+            uuid.urn().to_string(),
+            uuid.braced().to_string(),
+            uuid.checksummed().to_string(),
+            uuid.separated('_').to_string(),
+            non_nil.to_string(),
+        ]
+        .join(" ");
+
+        assert_eq!(expected, buf.0);
+    }
+}
@@ -0,0 +1,358 @@
+//! A C ABI for generating, parsing, and formatting UUIDs.
+//!
+//! This module is intended for building `uuid` into a shared or static library
+//! that's consumed from C, C++, Swift, or any other language that can call C functions.
+//! All types and functions here are `#[repr(C)]` or `extern "C"` so headers can be
+//! produced for them by tools like `cbindgen`.
+//!
+//! Every exported name is prefixed with `rs_uuid_`, or `RS_UUID_` for constants,
+//! so they don't collide with the `uuid_parse` and `uuid_t` of `libuuid`, which is
+//! often linked into the same program.
+//!
+//! Functions never panic or unwind across the FFI boundary. Instead, they
+//! return one of the `RS_UUID_*` status codes.
+//!
+//! Note that usage of this module requires the `ffi` feature of this crate
+//! to be enabled.
+//!
+//! # Examples
+//!
+//! From C:
+//!
+//! ```c
+//! rs_uuid_bytes_t id;
+//! char buf[RS_UUID_FORMAT_BUF_LEN];
+//!
+//! if (rs_uuid_parse((const uint8_t*)"67e55044-10b1-426f-9247-bb680e5fe0c8", 36, &id) != RS_UUID_OK) {
+//!     return -1;
+//! }
+//!
+//! rs_uuid_format(&id, RS_UUID_FORMAT_URN, (uint8_t*)buf, sizeof(buf));
+//! ```
+
+use crate::{std::slice, Uuid};
+
+/// The operation completed successfully.
+pub const RS_UUID_OK: i32 = 0;
+/// A required pointer argument was null.
+pub const RS_UUID_ERR_NULL: i32 = -1;
+/// The input couldn't be parsed as a UUID, or an argument was invalid.
+pub const RS_UUID_ERR_INVALID: i32 = -2;
+/// The output buffer is too small for the formatted UUID and its trailing nul.
+pub const RS_UUID_ERR_BUFFER_TOO_SMALL: i32 = -3;
+/// Random bytes couldn't be read from the system's random number generator.
+pub const RS_UUID_ERR_RANDOM: i32 = -4;
+
+/// Format a UUID as a hyphenated string, like `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+pub const RS_UUID_FORMAT_HYPHENATED: u32 = 0;
+/// Format a UUID as a simple string, like `67e5504410b1426f9247bb680e5fe0c8`.
+pub const RS_UUID_FORMAT_SIMPLE: u32 = 1;
+/// Format a UUID as a URN, like `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`.
+pub const RS_UUID_FORMAT_URN: u32 = 2;
+/// Format a UUID as a braced string, like `{67e55044-10b1-426f-9247-bb680e5fe0c8}`.
+pub const RS_UUID_FORMAT_BRACED: u32 = 3;
+/// A flag that can be combined with any format to use uppercase hex digits.
+pub const RS_UUID_FORMAT_UPPER: u32 = 0x100;
+
+/// The size of a buffer that can fit a UUID in any format, including its trailing nul.
+pub const RS_UUID_FORMAT_BUF_LEN: usize = crate::fmt::Urn::LENGTH + 1;
+
+/// The 16 bytes of a UUID, in big-endian order.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub struct rs_uuid_bytes_t {
+    /// The bytes of the UUID.
+    pub bytes: [u8; 16],
+}
+
+impl From<Uuid> for rs_uuid_bytes_t {
+    fn from(uuid: Uuid) -> Self {
+        rs_uuid_bytes_t {
+            bytes: uuid.into_bytes(),
+        }
+    }
+}
+
+impl From<rs_uuid_bytes_t> for Uuid {
+    fn from(uuid: rs_uuid_bytes_t) -> Self {
+        Uuid::from_bytes(uuid.bytes)
+    }
+}
+
+/// Generate a random (version 4) UUID into `out`.
+///
+/// Returns `RS_UUID_OK` on success, `RS_UUID_ERR_NULL` if `out` is null, or
+/// `RS_UUID_ERR_RANDOM` if random bytes couldn't be generated. `out` isn't
+/// written to when an error is returned.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes of a `rs_uuid_bytes_t`.
+#[cfg(any(feature = "v4", feature = "rng-custom"))]
+#[no_mangle]
+pub unsafe extern "C" fn rs_uuid_new_v4(out: *mut rs_uuid_bytes_t) -> i32 {
+    if out.is_null() {
+        return RS_UUID_ERR_NULL;
+    }
+
+    let uuid = match Uuid::try_new_v4() {
+        Ok(uuid) => uuid,
+        Err(_) => return RS_UUID_ERR_RANDOM,
+    };
+
+    // SAFETY: `out` is non-null and the caller guarantees it's valid for writes
+    unsafe { out.write(uuid.into()) };
+
+    RS_UUID_OK
+}
+
+/// Parse a UUID from `len` bytes of ASCII text starting at `input` into `out`.
+///
+/// Any format accepted by [`Uuid::try_parse_ascii`] is supported. The input
+/// doesn't need to be nul-terminated.
+///
+/// Returns `RS_UUID_OK` on success, `RS_UUID_ERR_NULL` if `input` or `out` are null,
+/// or `RS_UUID_ERR_INVALID` if the input isn't a valid UUID. `out` is only written
+/// to on success.
+///
+/// # Safety
+///
+/// `input` must be null or valid for reads of `len` bytes. `out` must be null
+/// or valid for writes of a `rs_uuid_bytes_t`.
+#[no_mangle]
+pub unsafe extern "C" fn rs_uuid_parse(
+    input: *const u8,
+    len: usize,
+    out: *mut rs_uuid_bytes_t,
+) -> i32 {
+    if input.is_null() || out.is_null() {
+        return RS_UUID_ERR_NULL;
+    }
+
+    // SAFETY: `input` is non-null and the caller guarantees it's valid for `len` bytes
+    let input = unsafe { slice::from_raw_parts(input, len) };
+
+    match Uuid::try_parse_ascii(input) {
+        Ok(uuid) => {
+            // SAFETY: `out` is non-null and the caller guarantees it's valid for writes
+            unsafe { out.write(uuid.into()) };
+
+            RS_UUID_OK
+        }
+        Err(_) => RS_UUID_ERR_INVALID,
+    }
+}
+
+/// Format the UUID at `uuid` into the `len` byte buffer at `buf` as a nul-terminated string.
+///
+/// `format` is one of the `RS_UUID_FORMAT_*` constants, optionally combined with
+/// `RS_UUID_FORMAT_UPPER`. A buffer of `RS_UUID_FORMAT_BUF_LEN` bytes is always large enough.
+///
+/// Returns `RS_UUID_OK` on success, `RS_UUID_ERR_NULL` if `uuid` or `buf` are null,
+/// `RS_UUID_ERR_INVALID` if `format` is unknown, or `RS_UUID_ERR_BUFFER_TOO_SMALL` if
+/// the formatted UUID and its trailing nul won't fit in `len` bytes. `buf` is
+/// only written to on success.
+///
+/// # Safety
+///
+/// `uuid` must be null or valid for reads of a `rs_uuid_bytes_t`. `buf` must be
+/// null or valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn rs_uuid_format(
+    uuid: *const rs_uuid_bytes_t,
+    format: u32,
+    buf: *mut u8,
+    len: usize,
+) -> i32 {
+    if uuid.is_null() || buf.is_null() {
+        return RS_UUID_ERR_NULL;
+    }
+
+    // SAFETY: `uuid` is non-null and the caller guarantees it's valid for reads
+    let uuid = Uuid::from(unsafe { uuid.read() });
+
+    let upper = format & RS_UUID_FORMAT_UPPER != 0;
+    let mut encoded = [0; RS_UUID_FORMAT_BUF_LEN];
+
+    let encoded = match (format & !RS_UUID_FORMAT_UPPER, upper) {
+        (RS_UUID_FORMAT_HYPHENATED, false) => uuid.hyphenated().encode_lower(&mut encoded),
+        (RS_UUID_FORMAT_HYPHENATED, true) => uuid.hyphenated().encode_upper(&mut encoded),
+        (RS_UUID_FORMAT_SIMPLE, false) => uuid.simple().encode_lower(&mut encoded),
+        (RS_UUID_FORMAT_SIMPLE, true) => uuid.simple().encode_upper(&mut encoded),
+        (RS_UUID_FORMAT_URN, false) => uuid.urn().encode_lower(&mut encoded),
+        (RS_UUID_FORMAT_URN, true) => uuid.urn().encode_upper(&mut encoded),
+        (RS_UUID_FORMAT_BRACED, false) => uuid.braced().encode_lower(&mut encoded),
+        (RS_UUID_FORMAT_BRACED, true) => uuid.braced().encode_upper(&mut encoded),
+        _ => return RS_UUID_ERR_INVALID,
+    };
+
+    if encoded.len() >= len {
+        return RS_UUID_ERR_BUFFER_TOO_SMALL;
+    }
+
+    // SAFETY: `buf` is non-null and the caller guarantees it's valid for `len` bytes,
+    // which we've just checked is enough for the encoded string and its nul
+    let buf = unsafe { slice::from_raw_parts_mut(buf, len) };
+
+    buf[..encoded.len()].copy_from_slice(encoded.as_bytes());
+    buf[encoded.len()] = 0;
+
+    RS_UUID_OK
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::ptr;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_parse_format_roundtrip() {
+        let input = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+        let mut uuid = rs_uuid_bytes_t::default();
+        let mut buf = [0xff; RS_UUID_FORMAT_BUF_LEN];
+
+        unsafe {
+            assert_eq!(
+                RS_UUID_OK,
+                rs_uuid_parse(input.as_ptr(), input.len(), &mut uuid)
+            );
+            assert_eq!(
+                RS_UUID_OK,
+                rs_uuid_format(
+                    &uuid,
+                    RS_UUID_FORMAT_HYPHENATED,
+                    buf.as_mut_ptr(),
+                    buf.len()
+                )
+            );
+        }
+
+        assert_eq!(input.as_bytes(), &buf[..36]);
+        assert_eq!(0, buf[36]);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_format_variants() {
+        let uuid = rs_uuid_bytes_t::from(crate::tests::new());
+        let expected = Uuid::from(uuid);
+
+        for (format, len) in [
+            (RS_UUID_FORMAT_HYPHENATED, 36),
+            (RS_UUID_FORMAT_SIMPLE, 32),
+            (RS_UUID_FORMAT_URN, 45),
+            (RS_UUID_FORMAT_BRACED, 38),
+        ] {
+            for upper in [0, RS_UUID_FORMAT_UPPER] {
+                let mut buf = [0; RS_UUID_FORMAT_BUF_LEN];
+
+                let status =
+                    unsafe { rs_uuid_format(&uuid, format | upper, buf.as_mut_ptr(), buf.len()) };
+
+                assert_eq!(RS_UUID_OK, status);
+                assert_eq!(0, buf[len]);
+
+                let formatted = crate::std::str::from_utf8(&buf[..len]).unwrap();
+                assert_eq!(expected, Uuid::parse_str(formatted).unwrap());
+                assert_eq!(
+                    upper != 0,
+                    formatted.bytes().any(|b| b.is_ascii_uppercase())
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_errors() {
+        let uuid = rs_uuid_bytes_t::default();
+        let mut out = rs_uuid_bytes_t::default();
+        let mut buf = [0; RS_UUID_FORMAT_BUF_LEN];
+
+        unsafe {
+            assert_eq!(RS_UUID_ERR_NULL, rs_uuid_parse(ptr::null(), 0, &mut out));
+            assert_eq!(
+                RS_UUID_ERR_NULL,
+                rs_uuid_parse(b"".as_ptr(), 0, ptr::null_mut())
+            );
+            assert_eq!(
+                RS_UUID_ERR_INVALID,
+                rs_uuid_parse(b"not a uuid".as_ptr(), 10, &mut out)
+            );
+
+            assert_eq!(
+                RS_UUID_ERR_NULL,
+                rs_uuid_format(
+                    ptr::null(),
+                    RS_UUID_FORMAT_SIMPLE,
+                    buf.as_mut_ptr(),
+                    buf.len()
+                )
+            );
+            assert_eq!(
+                RS_UUID_ERR_INVALID,
+                rs_uuid_format(&uuid, 42, buf.as_mut_ptr(), buf.len())
+            );
+
+            // There's no room for the trailing nul
+            assert_eq!(
+                RS_UUID_ERR_BUFFER_TOO_SMALL,
+                rs_uuid_format(&uuid, RS_UUID_FORMAT_SIMPLE, buf.as_mut_ptr(), 32)
+            );
+        }
+
+        assert_eq!([0; RS_UUID_FORMAT_BUF_LEN], buf);
+    }
+
+    #[test]
+    #[cfg(any(feature = "v4", feature = "rng-custom"))]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_v4() {
+        let mut uuid = rs_uuid_bytes_t::default();
+
+        unsafe {
+            assert_eq!(RS_UUID_OK, rs_uuid_new_v4(&mut uuid));
+            assert_eq!(RS_UUID_ERR_NULL, rs_uuid_new_v4(ptr::null_mut()));
+        }
+
+        assert_eq!(Some(crate::Version::Random), Uuid::from(uuid).get_version());
+    }
+}
@@ -12,17 +12,74 @@
 //! Adapters for alternative string formats.
 
 use crate::{
-    std::{borrow::Borrow, fmt, ptr, str},
-    Uuid, Variant,
+    error::*,
+    std::{borrow::Borrow, fmt, str},
+    AnyVersion, Uuid, Variant, Version,
 };
 
 #[cfg(feature = "std")]
-use crate::std::string::{String, ToString};
+use crate::std::string::String;
+
+mod base32;
+mod base58;
+mod formatted;
+mod radix;
+mod redacted;
+
+pub use self::{
+    base32::Base32,
+    base58::Base58,
+    formatted::{FormatOptions, Formatted},
+    radix::{Alphabet, Radix},
+    redacted::Redacted,
+};
 
 impl std::fmt::Debug for Uuid {
-    #[inline]
+    /// Formats the UUID as a hyphenated string.
+    ///
+    /// The alternate flag (`{:#?}`) instead formats the UUID decomposed into
+    /// its version and variant, along with the timestamp, counter, and node ID
+    /// for the versions that have them. The timestamp is formatted as Unix
+    /// seconds with a fractional part.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::LowerHex::fmt(self, f)
+        if !f.alternate() {
+            return fmt::LowerHex::fmt(self, f);
+        }
+
+        let mut debug = f.debug_struct("Uuid");
+
+        debug.field("value", &format_args!("{}", self.hyphenated()));
+
+        match self.get_version() {
+            Some(version) => debug.field("version", &version),
+            None => debug.field("version", &self.get_version_num()),
+        };
+
+        debug.field("variant", &self.get_variant());
+
+        if let Some(ts) = self.get_timestamp() {
+            let (seconds, nanos) = ts.to_unix();
+
+            debug.field("timestamp", &format_args!("{}.{:09}", seconds, nanos));
+        }
+
+        if let Some(node_id) = self.get_node_id() {
+            // Versions 1 and 6 carry a clock sequence alongside the node ID
+            let (_, counter) = self
+                .get_timestamp()
+                .map(|ts| ts.to_gregorian())
+                .unwrap_or_default();
+
+            debug.field("counter", &counter).field(
+                "node_id",
+                &format_args!(
+                    "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+                    node_id[0], node_id[1], node_id[2], node_id[3], node_id[4], node_id[5]
+                ),
+            );
+        }
+
+        debug.finish()
     }
 }
 
@@ -32,6 +89,38 @@ impl fmt::Display for Uuid {
     }
 }
 
+#[cfg(feature = "std")]
+impl Uuid {
+    /// Converts the UUID to a lower-case hyphenated `String`.
+    ///
+    /// This is the same string produced by the [`Display`] implementation, but
+    /// the UUID is encoded directly into a `String` of the right size instead
+    /// of going through [`ToString`] and the formatting machinery. It takes
+    /// priority over [`ToString::to_string`] when called as `uuid.to_string()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!("67e55044-10b1-426f-9247-bb680e5fe0c8", uuid.to_string());
+    /// assert_eq!(format!("{}", uuid), uuid.to_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+    /// [`ToString`]: https://doc.rust-lang.org/std/string/trait.ToString.html
+    /// [`ToString::to_string`]: https://doc.rust-lang.org/std/string/trait.ToString.html#tymethod.to_string
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    #[inline]
+    pub fn to_string(&self) -> String {
+        String::from(self.hyphenated().encode_lower(&mut [0; Hyphenated::LENGTH]) as &str)
+    }
+}
+
 #[cfg(feature = "std")]
 impl From<Uuid> for String {
     fn from(uuid: Uuid) -> Self {
@@ -39,6 +128,25 @@ impl From<Uuid> for String {
     }
 }
 
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Version::Nil => write!(f, "nil"),
+            Version::Max => write!(f, "max"),
+            version => write!(f, "v{}", version as u8),
+        }
+    }
+}
+
+impl fmt::Display for AnyVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            AnyVersion::Known(version) => fmt::Display::fmt(&version, f),
+            AnyVersion::NonStandard(num) => write!(f, "v{}", num),
+        }
+    }
+}
+
 impl fmt::Display for Variant {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
@@ -52,14 +160,14 @@ impl fmt::Display for Variant {
 
 impl fmt::LowerHex for Uuid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::LowerHex::fmt(self.as_hyphenated(), f)
+        fmt::LowerHex::fmt(&self.hyphenated(), f)
     }
 }
 
 impl fmt::UpperHex for Uuid {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::UpperHex::fmt(self.as_hyphenated(), f)
+        fmt::UpperHex::fmt(&self.hyphenated(), f)
     }
 }
 
@@ -67,26 +175,56 @@ impl fmt::UpperHex for Uuid {
 /// `67e55044-10b1-426f-9247-bb680e5fe0c8`.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[repr(transparent)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::TransparentWrapper))]
 pub struct Hyphenated(Uuid);
 
 /// Format a [`Uuid`] as a simple string, like
 /// `67e5504410b1426f9247bb680e5fe0c8`.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[repr(transparent)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::TransparentWrapper))]
 pub struct Simple(Uuid);
 
 /// Format a [`Uuid`] as a URN string, like
 /// `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[repr(transparent)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::TransparentWrapper))]
 pub struct Urn(Uuid);
 
 /// Format a [`Uuid`] as a braced hyphenated string, like
 /// `{67e55044-10b1-426f-9247-bb680e5fe0c8}`.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[repr(transparent)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::TransparentWrapper))]
 pub struct Braced(Uuid);
 
+/// Format a [`Uuid`] as a hyphenated string followed by two check symbols, like
+/// `67e55044-10b1-426f-9247-bb680e5fe0c8-sh`.
+///
+/// The check symbols are a checksum of the UUID encoded in Crockford's base32.
+/// They catch any single mistyped character and any swapped pair of adjacent
+/// characters when a UUID is transcribed by hand, such as when it's read aloud
+/// over the phone. Use [`Uuid::parse_checksummed`] to parse and verify these
+/// strings.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::TransparentWrapper))]
+pub struct Checksummed(Uuid);
+
+/// Format a [`Uuid`] as five groups of hex digits with a custom separator
+/// between them, like `67e55044_10b1_426f_9247_bb680e5fe0c8`.
+///
+/// This is the hyphenated format with each hyphen replaced by another ASCII
+/// character. Use [`ParseOptions::allow_separator`] to parse these strings.
+///
+/// [`ParseOptions::allow_separator`]: ../struct.ParseOptions.html#method.allow_separator
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Separated {
+    uuid: Uuid,
+    separator: u8,
+}
+
 impl Uuid {
     /// Get a [`Hyphenated`] formatter.
     #[inline]
@@ -97,8 +235,16 @@ impl Uuid {
     /// Get a borrowed [`Hyphenated`] formatter.
     #[inline]
     pub fn as_hyphenated(&self) -> &Hyphenated {
-        // SAFETY: `Uuid` and `Hyphenated` have the same ABI
-        unsafe { &*(self as *const Uuid as *const Hyphenated) }
+        #[cfg(not(feature = "forbid-unsafe"))]
+        {
+            // SAFETY: `Uuid` and `Hyphenated` have the same ABI
+            unsafe { &*(self as *const Uuid as *const Hyphenated) }
+        }
+
+        #[cfg(feature = "forbid-unsafe")]
+        {
+            bytemuck::TransparentWrapper::wrap_ref(self)
+        }
     }
 
     /// Get a [`Simple`] formatter.
@@ -110,8 +256,16 @@ impl Uuid {
     /// Get a borrowed [`Simple`] formatter.
     #[inline]
     pub fn as_simple(&self) -> &Simple {
-        // SAFETY: `Uuid` and `Simple` have the same ABI
-        unsafe { &*(self as *const Uuid as *const Simple) }
+        #[cfg(not(feature = "forbid-unsafe"))]
+        {
+            // SAFETY: `Uuid` and `Simple` have the same ABI
+            unsafe { &*(self as *const Uuid as *const Simple) }
+        }
+
+        #[cfg(feature = "forbid-unsafe")]
+        {
+            bytemuck::TransparentWrapper::wrap_ref(self)
+        }
     }
 
     /// Get a [`Urn`] formatter.
@@ -123,8 +277,16 @@ impl Uuid {
     /// Get a borrowed [`Urn`] formatter.
     #[inline]
     pub fn as_urn(&self) -> &Urn {
-        // SAFETY: `Uuid` and `Urn` have the same ABI
-        unsafe { &*(self as *const Uuid as *const Urn) }
+        #[cfg(not(feature = "forbid-unsafe"))]
+        {
+            // SAFETY: `Uuid` and `Urn` have the same ABI
+            unsafe { &*(self as *const Uuid as *const Urn) }
+        }
+
+        #[cfg(feature = "forbid-unsafe")]
+        {
+            bytemuck::TransparentWrapper::wrap_ref(self)
+        }
     }
 
     /// Get a [`Braced`] formatter.
@@ -136,8 +298,86 @@ impl Uuid {
     /// Get a borrowed [`Braced`] formatter.
     #[inline]
     pub fn as_braced(&self) -> &Braced {
-        // SAFETY: `Uuid` and `Braced` have the same ABI
-        unsafe { &*(self as *const Uuid as *const Braced) }
+        #[cfg(not(feature = "forbid-unsafe"))]
+        {
+            // SAFETY: `Uuid` and `Braced` have the same ABI
+            unsafe { &*(self as *const Uuid as *const Braced) }
+        }
+
+        #[cfg(feature = "forbid-unsafe")]
+        {
+            bytemuck::TransparentWrapper::wrap_ref(self)
+        }
+    }
+
+    /// Get a [`Checksummed`] formatter.
+    #[inline]
+    pub const fn checksummed(self) -> Checksummed {
+        Checksummed(self)
+    }
+
+    /// Get a borrowed [`Checksummed`] formatter.
+    #[inline]
+    pub fn as_checksummed(&self) -> &Checksummed {
+        #[cfg(not(feature = "forbid-unsafe"))]
+        {
+            // SAFETY: `Uuid` and `Checksummed` have the same ABI
+            unsafe { &*(self as *const Uuid as *const Checksummed) }
+        }
+
+        #[cfg(feature = "forbid-unsafe")]
+        {
+            bytemuck::TransparentWrapper::wrap_ref(self)
+        }
+    }
+
+    /// Get a [`Separated`] formatter that separates groups with `separator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `separator` isn't an ASCII character.
+    #[inline]
+    pub const fn separated(self, separator: char) -> Separated {
+        Separated::from_uuid(self, separator)
+    }
+
+    /// Get the lowercase hyphenated text of this UUID as an array of ASCII bytes.
+    ///
+    /// This is the same text as [`Hyphenated::encode_lower`] produces, but
+    /// doesn't need a buffer and can be used in `const` contexts. Use
+    /// [`<[u8]>::make_ascii_uppercase`] on the result for uppercase text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::uuid;
+    /// const ID: [u8; 36] = uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8").to_ascii_array();
+    ///
+    /// assert_eq!(b"67e55044-10b1-426f-9247-bb680e5fe0c8", &ID);
+    /// ```
+    ///
+    /// [`<[u8]>::make_ascii_uppercase`]: https://doc.rust-lang.org/std/primitive.slice.html#method.make_ascii_uppercase
+    #[inline]
+    pub const fn to_ascii_array(&self) -> [u8; 36] {
+        format_hyphenated(self.as_bytes(), false)
+    }
+
+    /// Get the lowercase simple text of this UUID as an array of ASCII bytes.
+    ///
+    /// This is the same text as [`Simple::encode_lower`] produces, but
+    /// doesn't need a buffer and can be used in `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::uuid;
+    /// const ID: [u8; 32] = uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8").to_simple_ascii_array();
+    ///
+    /// assert_eq!(b"67e5504410b1426f9247bb680e5fe0c8", &ID);
+    /// ```
+    #[inline]
+    pub const fn to_simple_ascii_array(&self) -> [u8; 32] {
+        format_simple(self.as_bytes(), false)
     }
 }
 
@@ -148,15 +388,37 @@ const LOWER: [u8; 16] = [
     b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'a', b'b', b'c', b'd', b'e', b'f',
 ];
 
+// Each byte maps directly to its pair of hex digits, so formatting only
+// needs a single lookup per byte
+const UPPER_PAIRS: &[[u8; 2]; 256] = &hex_pairs(&UPPER);
+const LOWER_PAIRS: &[[u8; 2]; 256] = &hex_pairs(&LOWER);
+
+const fn hex_pairs(digits: &[u8; 16]) -> [[u8; 2]; 256] {
+    let mut pairs = [[0; 2]; 256];
+    let mut i = 0;
+    while i < 256 {
+        pairs[i] = [digits[i >> 4], digits[i & 0x0f]];
+        i += 1;
+    }
+    pairs
+}
+
+/// The index of the first hex digit of each byte in a hyphenated UUID.
+///
+/// Walking this table instead of the groups keeps the loops over a
+/// hyphenated UUID flat, so they can be fully unrolled.
+pub(crate) const HYPHENATED_POSITIONS: [u8; 16] =
+    [0, 2, 4, 6, 9, 11, 14, 16, 19, 21, 24, 26, 28, 30, 32, 34];
+
 #[inline]
 const fn format_simple(src: &[u8; 16], upper: bool) -> [u8; 32] {
-    let lut = if upper { &UPPER } else { &LOWER };
+    let lut = if upper { UPPER_PAIRS } else { LOWER_PAIRS };
     let mut dst = [0; 32];
     let mut i = 0;
     while i < 16 {
-        let x = src[i];
-        dst[i * 2] = lut[(x >> 4) as usize];
-        dst[i * 2 + 1] = lut[(x & 0x0f) as usize];
+        let [hi, lo] = lut[src[i] as usize];
+        dst[i * 2] = hi;
+        dst[i * 2 + 1] = lo;
         i += 1;
     }
     dst
@@ -164,55 +426,71 @@ const fn format_simple(src: &[u8; 16], upper: bool) -> [u8; 32] {
 
 #[inline]
 const fn format_hyphenated(src: &[u8; 16], upper: bool) -> [u8; 36] {
-    let lut = if upper { &UPPER } else { &LOWER };
-    let groups = [(0, 8), (9, 13), (14, 18), (19, 23), (24, 36)];
+    let lut = if upper { UPPER_PAIRS } else { LOWER_PAIRS };
     let mut dst = [0; 36];
+    dst[8] = b'-';
+    dst[13] = b'-';
+    dst[18] = b'-';
+    dst[23] = b'-';
 
-    let mut group_idx = 0;
     let mut i = 0;
-    while group_idx < 5 {
-        let (start, end) = groups[group_idx];
-        let mut j = start;
-        while j < end {
-            let x = src[i];
-            i += 1;
-
-            dst[j] = lut[(x >> 4) as usize];
-            dst[j + 1] = lut[(x & 0x0f) as usize];
-            j += 2;
-        }
-        if group_idx < 4 {
-            dst[end] = b'-';
-        }
-        group_idx += 1;
+    while i < 16 {
+        let [hi, lo] = lut[src[i] as usize];
+        let j = HYPHENATED_POSITIONS[i] as usize;
+        dst[j] = hi;
+        dst[j + 1] = lo;
+        i += 1;
     }
     dst
 }
 
+/// Crockford's base32 alphabet, used for the check symbols of a [`Checksummed`] UUID.
+pub(crate) const CHECK_SYMBOLS: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Compute a 10 bit checksum over the hex digits of a UUID.
+///
+/// Each digit is weighted by its position and the sum is taken modulo a prime
+/// larger than any weighted difference a single substitution or adjacent
+/// transposition could introduce, so those errors always change the checksum.
+pub(crate) const fn checksum(src: &[u8; 16]) -> u16 {
+    let mut sum = 0u32;
+    let mut i = 0;
+
+    while i < 16 {
+        sum += (2 * i as u32 + 1) * (src[i] >> 4) as u32;
+        sum += (2 * i as u32 + 2) * (src[i] & 0x0f) as u32;
+        i += 1;
+    }
+
+    (sum % 1021) as u16
+}
+
+#[inline]
+fn check_buffer(buffer: &[u8], required: usize) -> Result<(), Error> {
+    if buffer.len() < required {
+        Err(Error::new(ErrorKind::BufferTooSmall {
+            len: buffer.len(),
+            required,
+        }))
+    } else {
+        Ok(())
+    }
+}
+
 #[inline]
 fn encode_simple<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b mut str {
     let buf = &mut buffer[..Simple::LENGTH];
-    let dst = buf.as_mut_ptr();
+    buf.copy_from_slice(&format_simple(src, upper));
 
-    // SAFETY: `buf` is guaranteed to be at least `LEN` bytes
-    // SAFETY: The encoded buffer is ASCII encoded
-    unsafe {
-        ptr::write(dst.cast(), format_simple(src, upper));
-        str::from_utf8_unchecked_mut(buf)
-    }
+    ascii_str_mut(buf)
 }
 
 #[inline]
 fn encode_hyphenated<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b mut str {
     let buf = &mut buffer[..Hyphenated::LENGTH];
-    let dst = buf.as_mut_ptr();
+    buf.copy_from_slice(&format_hyphenated(src, upper));
 
-    // SAFETY: `buf` is guaranteed to be at least `LEN` bytes
-    // SAFETY: The encoded buffer is ASCII encoded
-    unsafe {
-        ptr::write(dst.cast(), format_hyphenated(src, upper));
-        str::from_utf8_unchecked_mut(buf)
-    }
+    ascii_str_mut(buf)
 }
 
 #[inline]
@@ -220,29 +498,103 @@ fn encode_braced<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b m
     let buf = &mut buffer[..Braced::LENGTH];
     buf[0] = b'{';
     buf[Braced::LENGTH - 1] = b'}';
+    buf[1..Braced::LENGTH - 1].copy_from_slice(&format_hyphenated(src, upper));
 
-    // SAFETY: `buf` is guaranteed to be at least `LEN` bytes
-    // SAFETY: The encoded buffer is ASCII encoded
-    unsafe {
-        let dst = buf.as_mut_ptr().add(1);
-
-        ptr::write(dst.cast(), format_hyphenated(src, upper));
-        str::from_utf8_unchecked_mut(buf)
-    }
+    ascii_str_mut(buf)
 }
 
 #[inline]
 fn encode_urn<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b mut str {
     let buf = &mut buffer[..Urn::LENGTH];
     buf[..9].copy_from_slice(b"urn:uuid:");
+    buf[9..].copy_from_slice(&format_hyphenated(src, upper));
+
+    ascii_str_mut(buf)
+}
+
+#[inline]
+fn encode_checksummed<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b mut str {
+    let buf = &mut buffer[..Checksummed::LENGTH];
+    let checksum = checksum(src) as usize;
+    let case = if upper { 0 } else { 0x20 };
+
+    buf[Hyphenated::LENGTH] = b'-';
+    // Digits aren't affected by setting the lower-case bit
+    buf[Hyphenated::LENGTH + 1] = CHECK_SYMBOLS[checksum >> 5] | case;
+    buf[Hyphenated::LENGTH + 2] = CHECK_SYMBOLS[checksum & 0x1f] | case;
+    buf[..Hyphenated::LENGTH].copy_from_slice(&format_hyphenated(src, upper));
+
+    ascii_str_mut(buf)
+}
+
+#[inline]
+fn encode_separated<'b>(
+    src: &[u8; 16],
+    separator: u8,
+    buffer: &'b mut [u8],
+    upper: bool,
+) -> &'b mut str {
+    let buf = &mut buffer[..Separated::LENGTH];
+
+    let mut dst = format_hyphenated(src, upper);
+    for i in [8, 13, 18, 23] {
+        dst[i] = separator;
+    }
 
-    // SAFETY: `buf` is guaranteed to be at least `LEN` bytes
-    // SAFETY: The encoded buffer is ASCII encoded
-    unsafe {
-        let dst = buf.as_mut_ptr().add(9);
+    buf.copy_from_slice(&dst);
 
-        ptr::write(dst.cast(), format_hyphenated(src, upper));
-        str::from_utf8_unchecked_mut(buf)
+    ascii_str_mut(buf)
+}
+
+#[inline]
+fn encode_many<'b>(
+    uuids: &[Uuid],
+    delimiter: char,
+    buffer: &'b mut [u8],
+    length: usize,
+    encode: for<'a> fn(&[u8; 16], &'a mut [u8], bool) -> &'a mut str,
+    upper: bool,
+) -> Result<&'b mut str, Error> {
+    assert!(delimiter.is_ascii(), "UUID list delimiters must be ASCII");
+
+    let required = match uuids.len() {
+        0 => 0,
+        n => n * (length + 1) - 1,
+    };
+
+    check_buffer(buffer, required)?;
+
+    let buf = &mut buffer[..required];
+    for (i, uuid) in uuids.iter().enumerate() {
+        let start = i * (length + 1);
+        if i > 0 {
+            buf[start - 1] = delimiter as u8;
+        }
+
+        encode(uuid.as_bytes(), &mut buf[start..], upper);
+    }
+
+    Ok(ascii_str_mut(buf))
+}
+
+/// View an encoded buffer as a string.
+///
+/// All of the encoders in this crate only write ASCII, so this can skip
+/// validating the buffer unless the `forbid-unsafe` feature is enabled.
+#[inline]
+pub(crate) fn ascii_str_mut(buf: &mut [u8]) -> &mut str {
+    #[cfg(not(feature = "forbid-unsafe"))]
+    {
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { str::from_utf8_unchecked_mut(buf) }
+    }
+
+    #[cfg(feature = "forbid-unsafe")]
+    {
+        match str::from_utf8_mut(buf) {
+            Ok(s) => s,
+            Err(_) => unreachable!("the encoded buffer is ASCII encoded"),
+        }
     }
 }
 
@@ -362,6 +714,132 @@ impl Hyphenated {
         encode_hyphenated(self.0.as_bytes(), buffer, true)
     }
 
+    /// Writes the [`Uuid`] as a lower-case hyphenated string to `buffer`,
+    /// returning an error instead of panicking if the buffer is too small.
+    ///
+    /// This method is like [`Hyphenated::encode_lower`], but never panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer has a length less than [`Hyphenated::LENGTH`].
+    /// The buffer isn't written to in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{fmt::Hyphenated, Uuid};
+    ///
+    /// let uuid = Uuid::nil();
+    ///
+    /// assert!(uuid.hyphenated().encode_lower_checked(&mut [0; Hyphenated::LENGTH]).is_ok());
+    /// assert!(uuid.hyphenated().encode_lower_checked(&mut [0; 8]).is_err());
+    /// ```
+    #[inline]
+    pub fn encode_lower_checked<'buf>(
+        &self,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        check_buffer(buffer, Self::LENGTH)?;
+
+        Ok(encode_hyphenated(self.0.as_bytes(), buffer, false))
+    }
+
+    /// Writes the [`Uuid`] as an upper-case hyphenated string to `buffer`,
+    /// returning an error instead of panicking if the buffer is too small.
+    ///
+    /// This method is like [`Hyphenated::encode_upper`], but never panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer has a length less than [`Hyphenated::LENGTH`].
+    /// The buffer isn't written to in that case.
+    #[inline]
+    pub fn encode_upper_checked<'buf>(
+        &self,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        check_buffer(buffer, Self::LENGTH)?;
+
+        Ok(encode_hyphenated(self.0.as_bytes(), buffer, true))
+    }
+
+    /// Writes each [`Uuid`] in `uuids` as a lower-case hyphenated string to
+    /// `buffer`, separated by `delimiter`, and returns the subslice of the
+    /// buffer that contains the encoded list.
+    ///
+    /// This is a zero-allocation way to format a batch of UUIDs, such as a
+    /// CSV column. The delimiter is only written between UUIDs, so `n` UUIDs
+    /// need a buffer of at least `n * (LENGTH + 1) - 1` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer is too small to hold the encoded list.
+    /// The buffer isn't written to in that case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delimiter` is not ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{fmt::Hyphenated, Uuid};
+    ///
+    /// let uuids = [Uuid::nil(), Uuid::max()];
+    ///
+    /// let mut buf = [0; 2 * (Hyphenated::LENGTH + 1)];
+    /// assert_eq!(
+    ///     Hyphenated::encode_many_lower(&uuids, ',', &mut buf).unwrap(),
+    ///     "00000000-0000-0000-0000-000000000000,ffffffff-ffff-ffff-ffff-ffffffffffff"
+    /// );
+    /// ```
+    #[inline]
+    pub fn encode_many_lower<'buf>(
+        uuids: &[Uuid],
+        delimiter: char,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        encode_many(
+            uuids,
+            delimiter,
+            buffer,
+            Self::LENGTH,
+            encode_hyphenated,
+            false,
+        )
+    }
+
+    /// Writes each [`Uuid`] in `uuids` as an upper-case hyphenated string to
+    /// `buffer`, separated by `delimiter`, and returns the subslice of the
+    /// buffer that contains the encoded list.
+    ///
+    /// This method is like [`Hyphenated::encode_many_lower`], but writes
+    /// upper-case hex digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer is too small to hold the encoded list.
+    /// The buffer isn't written to in that case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delimiter` is not ASCII.
+    #[inline]
+    pub fn encode_many_upper<'buf>(
+        uuids: &[Uuid],
+        delimiter: char,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        encode_many(
+            uuids,
+            delimiter,
+            buffer,
+            Self::LENGTH,
+            encode_hyphenated,
+            true,
+        )
+    }
+
     /// Get a reference to the underlying [`Uuid`].
     ///
     /// # Examples
@@ -507,6 +985,55 @@ impl Braced {
         encode_braced(self.0.as_bytes(), buffer, true)
     }
 
+    /// Writes the [`Uuid`] as a lower-case braced string to `buffer`,
+    /// returning an error instead of panicking if the buffer is too small.
+    ///
+    /// This method is like [`Braced::encode_lower`], but never panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer has a length less than [`Braced::LENGTH`].
+    /// The buffer isn't written to in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{fmt::Braced, Uuid};
+    ///
+    /// let uuid = Uuid::nil();
+    ///
+    /// assert!(uuid.braced().encode_lower_checked(&mut [0; Braced::LENGTH]).is_ok());
+    /// assert!(uuid.braced().encode_lower_checked(&mut [0; 8]).is_err());
+    /// ```
+    #[inline]
+    pub fn encode_lower_checked<'buf>(
+        &self,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        check_buffer(buffer, Self::LENGTH)?;
+
+        Ok(encode_braced(self.0.as_bytes(), buffer, false))
+    }
+
+    /// Writes the [`Uuid`] as an upper-case braced string to `buffer`,
+    /// returning an error instead of panicking if the buffer is too small.
+    ///
+    /// This method is like [`Braced::encode_upper`], but never panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer has a length less than [`Braced::LENGTH`].
+    /// The buffer isn't written to in that case.
+    #[inline]
+    pub fn encode_upper_checked<'buf>(
+        &self,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        check_buffer(buffer, Self::LENGTH)?;
+
+        Ok(encode_braced(self.0.as_bytes(), buffer, true))
+    }
+
     /// Get a reference to the underlying [`Uuid`].
     ///
     /// # Examples
@@ -650,62 +1177,174 @@ impl Simple {
         encode_simple(self.0.as_bytes(), buffer, true)
     }
 
-    /// Get a reference to the underlying [`Uuid`].
+    /// Writes the [`Uuid`] as a lower-case simple string to `buffer`,
+    /// returning an error instead of panicking if the buffer is too small.
     ///
-    /// # Examples
+    /// This method is like [`Simple::encode_lower`], but never panics.
     ///
-    /// ```rust
-    /// use uuid::Uuid;
+    /// # Errors
     ///
-    /// let simple = Uuid::nil().simple();
-    /// assert_eq!(*simple.as_uuid(), Uuid::nil());
-    /// ```
-    pub const fn as_uuid(&self) -> &Uuid {
-        &self.0
-    }
-
-    /// Consumes the [`Simple`], returning the underlying [`Uuid`].
+    /// Returns an error if the buffer has a length less than [`Simple::LENGTH`].
+    /// The buffer isn't written to in that case.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use uuid::Uuid;
+    /// use uuid::{fmt::Simple, Uuid};
     ///
-    /// let simple = Uuid::nil().simple();
-    /// assert_eq!(simple.into_uuid(), Uuid::nil());
+    /// let uuid = Uuid::nil();
+    ///
+    /// assert!(uuid.simple().encode_lower_checked(&mut [0; Simple::LENGTH]).is_ok());
+    /// assert!(uuid.simple().encode_lower_checked(&mut [0; 8]).is_err());
     /// ```
-    pub const fn into_uuid(self) -> Uuid {
-        self.0
+    #[inline]
+    pub fn encode_lower_checked<'buf>(
+        &self,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        check_buffer(buffer, Self::LENGTH)?;
+
+        Ok(encode_simple(self.0.as_bytes(), buffer, false))
     }
-}
 
-impl Urn {
-    /// The length of a URN [`Uuid`] string.
+    /// Writes the [`Uuid`] as an upper-case simple string to `buffer`,
+    /// returning an error instead of panicking if the buffer is too small.
     ///
-    /// [`Uuid`]: ../struct.Uuid.html
-    pub const LENGTH: usize = 45;
-
-    /// Creates a [`Urn`] from a [`Uuid`].
+    /// This method is like [`Simple::encode_upper`], but never panics.
     ///
-    /// [`Uuid`]: ../struct.Uuid.html
-    /// [`Urn`]: struct.Urn.html
-    pub const fn from_uuid(uuid: Uuid) -> Self {
-        Urn(uuid)
+    /// # Errors
+    ///
+    /// Returns an error if the buffer has a length less than [`Simple::LENGTH`].
+    /// The buffer isn't written to in that case.
+    #[inline]
+    pub fn encode_upper_checked<'buf>(
+        &self,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        check_buffer(buffer, Self::LENGTH)?;
+
+        Ok(encode_simple(self.0.as_bytes(), buffer, true))
     }
 
-    /// Writes the [`Uuid`] as a lower-case URN string to
-    /// `buffer`, and returns the subslice of the buffer that contains the
-    /// encoded UUID.
+    /// Writes each [`Uuid`] in `uuids` as a lower-case simple string to
+    /// `buffer`, separated by `delimiter`, and returns the subslice of the
+    /// buffer that contains the encoded list.
     ///
-    /// This is slightly more efficient than using the formatting
-    /// infrastructure as it avoids virtual calls, and may avoid
-    /// double buffering.
+    /// This is a zero-allocation way to format a batch of UUIDs, such as a
+    /// CSV column. The delimiter is only written between UUIDs, so `n` UUIDs
+    /// need a buffer of at least `n * (LENGTH + 1) - 1` bytes.
     ///
-    /// [`Uuid`]: ../struct.Uuid.html
+    /// # Errors
+    ///
+    /// Returns an error if the buffer is too small to hold the encoded list.
+    /// The buffer isn't written to in that case.
     ///
     /// # Panics
     ///
-    /// Panics if the buffer is not large enough: it must have length at least
+    /// Panics if `delimiter` is not ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{fmt::Simple, Uuid};
+    ///
+    /// let uuids = [Uuid::nil(), Uuid::max()];
+    ///
+    /// let mut buf = [0; 2 * (Simple::LENGTH + 1)];
+    /// assert_eq!(
+    ///     Simple::encode_many_lower(&uuids, ',', &mut buf).unwrap(),
+    ///     "00000000000000000000000000000000,ffffffffffffffffffffffffffffffff"
+    /// );
+    /// ```
+    #[inline]
+    pub fn encode_many_lower<'buf>(
+        uuids: &[Uuid],
+        delimiter: char,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        encode_many(uuids, delimiter, buffer, Self::LENGTH, encode_simple, false)
+    }
+
+    /// Writes each [`Uuid`] in `uuids` as an upper-case simple string to
+    /// `buffer`, separated by `delimiter`, and returns the subslice of the
+    /// buffer that contains the encoded list.
+    ///
+    /// This method is like [`Simple::encode_many_lower`], but writes
+    /// upper-case hex digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer is too small to hold the encoded list.
+    /// The buffer isn't written to in that case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delimiter` is not ASCII.
+    #[inline]
+    pub fn encode_many_upper<'buf>(
+        uuids: &[Uuid],
+        delimiter: char,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        encode_many(uuids, delimiter, buffer, Self::LENGTH, encode_simple, true)
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// let simple = Uuid::nil().simple();
+    /// assert_eq!(*simple.as_uuid(), Uuid::nil());
+    /// ```
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`Simple`], returning the underlying [`Uuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// let simple = Uuid::nil().simple();
+    /// assert_eq!(simple.into_uuid(), Uuid::nil());
+    /// ```
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Urn {
+    /// The length of a URN [`Uuid`] string.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub const LENGTH: usize = 45;
+
+    /// Creates a [`Urn`] from a [`Uuid`].
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`Urn`]: struct.Urn.html
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Urn(uuid)
+    }
+
+    /// Writes the [`Uuid`] as a lower-case URN string to
+    /// `buffer`, and returns the subslice of the buffer that contains the
+    /// encoded UUID.
+    ///
+    /// This is slightly more efficient than using the formatting
+    /// infrastructure as it avoids virtual calls, and may avoid
+    /// double buffering.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
     /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
     /// sufficiently-large temporary buffer.
     ///
@@ -800,6 +1439,55 @@ impl Urn {
         encode_urn(self.0.as_bytes(), buffer, true)
     }
 
+    /// Writes the [`Uuid`] as a lower-case URN string to `buffer`,
+    /// returning an error instead of panicking if the buffer is too small.
+    ///
+    /// This method is like [`Urn::encode_lower`], but never panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer has a length less than [`Urn::LENGTH`].
+    /// The buffer isn't written to in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{fmt::Urn, Uuid};
+    ///
+    /// let uuid = Uuid::nil();
+    ///
+    /// assert!(uuid.urn().encode_lower_checked(&mut [0; Urn::LENGTH]).is_ok());
+    /// assert!(uuid.urn().encode_lower_checked(&mut [0; 8]).is_err());
+    /// ```
+    #[inline]
+    pub fn encode_lower_checked<'buf>(
+        &self,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        check_buffer(buffer, Self::LENGTH)?;
+
+        Ok(encode_urn(self.0.as_bytes(), buffer, false))
+    }
+
+    /// Writes the [`Uuid`] as an upper-case URN string to `buffer`,
+    /// returning an error instead of panicking if the buffer is too small.
+    ///
+    /// This method is like [`Urn::encode_upper`], but never panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer has a length less than [`Urn::LENGTH`].
+    /// The buffer isn't written to in that case.
+    #[inline]
+    pub fn encode_upper_checked<'buf>(
+        &self,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        check_buffer(buffer, Self::LENGTH)?;
+
+        Ok(encode_urn(self.0.as_bytes(), buffer, true))
+    }
+
     /// Get a reference to the underlying [`Uuid`].
     ///
     /// # Examples
@@ -829,6 +1517,325 @@ impl Urn {
     }
 }
 
+impl Checksummed {
+    /// The length of a checksummed [`Uuid`] string.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub const LENGTH: usize = 39;
+
+    /// Creates a [`Checksummed`] from a [`Uuid`].
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`Checksummed`]: struct.Checksummed.html
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Checksummed(uuid)
+    }
+
+    /// Writes the [`Uuid`] as a lower-case hyphenated string followed by
+    /// its check symbols to `buffer`, and returns the subslice of the buffer
+    /// that contains the encoded UUID.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    ///     assert_eq!(
+    ///         uuid.checksummed()
+    ///             .encode_lower(&mut Uuid::encode_buffer()),
+    ///         "67e55044-10b1-426f-9247-bb680e5fe0c8-sh"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_checksummed(self.0.as_bytes(), buffer, false)
+    }
+
+    /// Writes the [`Uuid`] as an upper-case hyphenated string followed by
+    /// its check symbols to `buffer`, and returns the subslice of the buffer
+    /// that contains the encoded UUID.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    ///     assert_eq!(
+    ///         uuid.checksummed()
+    ///             .encode_upper(&mut Uuid::encode_buffer()),
+    ///         "67E55044-10B1-426F-9247-BB680E5FE0C8-SH"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_checksummed(self.0.as_bytes(), buffer, true)
+    }
+
+    /// Writes the [`Uuid`] as a lower-case checksummed string to `buffer`,
+    /// returning an error instead of panicking if the buffer is too small.
+    ///
+    /// This method is like [`Checksummed::encode_lower`], but never panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer has a length less than [`Checksummed::LENGTH`].
+    /// The buffer isn't written to in that case.
+    #[inline]
+    pub fn encode_lower_checked<'buf>(
+        &self,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        check_buffer(buffer, Self::LENGTH)?;
+
+        Ok(encode_checksummed(self.0.as_bytes(), buffer, false))
+    }
+
+    /// Writes the [`Uuid`] as an upper-case checksummed string to `buffer`,
+    /// returning an error instead of panicking if the buffer is too small.
+    ///
+    /// This method is like [`Checksummed::encode_upper`], but never panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer has a length less than [`Checksummed::LENGTH`].
+    /// The buffer isn't written to in that case.
+    #[inline]
+    pub fn encode_upper_checked<'buf>(
+        &self,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        check_buffer(buffer, Self::LENGTH)?;
+
+        Ok(encode_checksummed(self.0.as_bytes(), buffer, true))
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`Checksummed`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Separated {
+    /// The length of a separated [`Uuid`] string.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub const LENGTH: usize = 36;
+
+    /// Creates a [`Separated`] from a [`Uuid`] and the separator to put
+    /// between its groups.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `separator` isn't an ASCII character.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`Separated`]: struct.Separated.html
+    pub const fn from_uuid(uuid: Uuid, separator: char) -> Self {
+        assert!(separator.is_ascii(), "UUID group separators must be ASCII");
+
+        Separated {
+            uuid,
+            separator: separator as u8,
+        }
+    }
+
+    /// Writes the [`Uuid`] as a lower-case separated string to `buffer`, and
+    /// returns the subslice of the buffer that contains the encoded UUID.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    ///     assert_eq!(
+    ///         uuid.separated('_')
+    ///             .encode_lower(&mut Uuid::encode_buffer()),
+    ///         "67e55044_10b1_426f_9247_bb680e5fe0c8"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_separated(self.uuid.as_bytes(), self.separator, buffer, false)
+    }
+
+    /// Writes the [`Uuid`] as an upper-case separated string to `buffer`, and
+    /// returns the subslice of the buffer that contains the encoded UUID.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    #[inline]
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_separated(self.uuid.as_bytes(), self.separator, buffer, true)
+    }
+
+    /// Writes the [`Uuid`] as a lower-case separated string to `buffer`,
+    /// returning an error instead of panicking if the buffer is too small.
+    ///
+    /// This method is like [`Separated::encode_lower`], but never panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer has a length less than [`Separated::LENGTH`].
+    /// The buffer isn't written to in that case.
+    #[inline]
+    pub fn encode_lower_checked<'buf>(
+        &self,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        check_buffer(buffer, Self::LENGTH)?;
+
+        Ok(encode_separated(
+            self.uuid.as_bytes(),
+            self.separator,
+            buffer,
+            false,
+        ))
+    }
+
+    /// Writes the [`Uuid`] as an upper-case separated string to `buffer`,
+    /// returning an error instead of panicking if the buffer is too small.
+    ///
+    /// This method is like [`Separated::encode_upper`], but never panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer has a length less than [`Separated::LENGTH`].
+    /// The buffer isn't written to in that case.
+    #[inline]
+    pub fn encode_upper_checked<'buf>(
+        &self,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        check_buffer(buffer, Self::LENGTH)?;
+
+        Ok(encode_separated(
+            self.uuid.as_bytes(),
+            self.separator,
+            buffer,
+            true,
+        ))
+    }
+
+    /// The separator put between groups.
+    pub const fn separator(&self) -> char {
+        self.separator as char
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    /// Consumes the [`Separated`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.uuid
+    }
+}
+
+impl fmt::Display for Separated {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for Separated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.encode_lower(&mut [0; Self::LENGTH]))
+    }
+}
+
+impl fmt::UpperHex for Separated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.encode_upper(&mut [0; Self::LENGTH]))
+    }
+}
+
+impl From<Separated> for Uuid {
+    #[inline]
+    fn from(f: Separated) -> Self {
+        f.into_uuid()
+    }
+}
+
+impl AsRef<Uuid> for Separated {
+    #[inline]
+    fn as_ref(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
+impl Borrow<Uuid> for Separated {
+    #[inline]
+    fn borrow(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
 macro_rules! impl_fmt_traits {
     ($($T:ident<$($a:lifetime),*>),+) => {$(
         impl<$($a),*> fmt::Display for $T<$($a),*> {
@@ -840,13 +1847,13 @@ macro_rules! impl_fmt_traits {
 
         impl<$($a),*> fmt::LowerHex for $T<$($a),*> {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.write_str(self.encode_lower(&mut [0; Self::LENGTH]))
+                f.pad(self.encode_lower(&mut [0; Self::LENGTH]))
             }
         }
 
         impl<$($a),*> fmt::UpperHex for $T<$($a),*> {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.write_str(self.encode_upper(&mut [0; Self::LENGTH]))
+                f.pad(self.encode_upper(&mut [0; Self::LENGTH]))
             }
         }
 
@@ -919,7 +1926,8 @@ impl_fmt_traits! {
     Hyphenated<>,
     Simple<>,
     Urn<>,
-    Braced<>
+    Braced<>,
+    Checksummed<>
 }
 
 #[cfg(test)]
@@ -990,6 +1998,22 @@ mod tests {
         assert!(buf[len..].iter().all(|x| *x == b'x'));
     }
 
+    #[test]
+    fn checksummed_trailing() {
+        let mut buf = [b'x'; 100];
+        let len = Uuid::nil().checksummed().encode_lower(&mut buf).len();
+        assert_eq!(len, super::Checksummed::LENGTH);
+        assert!(buf[len..].iter().all(|x| *x == b'x'));
+    }
+
+    #[test]
+    fn checksummed_ref_trailing() {
+        let mut buf = [b'x'; 100];
+        let len = Uuid::nil().as_checksummed().encode_lower(&mut buf).len();
+        assert_eq!(len, super::Checksummed::LENGTH);
+        assert!(buf[len..].iter().all(|x| *x == b'x'));
+    }
+
     #[test]
     #[should_panic]
     fn hyphenated_too_small() {
@@ -1014,6 +2038,12 @@ mod tests {
         Uuid::nil().braced().encode_lower(&mut [0; 37]);
     }
 
+    #[test]
+    #[should_panic]
+    fn checksummed_too_small() {
+        Uuid::nil().checksummed().encode_lower(&mut [0; 38]);
+    }
+
     #[test]
     fn hyphenated_to_inner() {
         let hyphenated = Uuid::nil().hyphenated();
@@ -1037,4 +2067,255 @@ mod tests {
         let braced = Uuid::nil().braced();
         assert_eq!(Uuid::from(braced), Uuid::nil());
     }
+
+    #[test]
+    fn checked_never_panics() {
+        let uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+        for len in 0..=Urn::LENGTH + 1 {
+            let mut buf = [b'x'; Urn::LENGTH + 1];
+            let buf = &mut buf[..len];
+
+            let encoded = [
+                (
+                    Hyphenated::LENGTH,
+                    uuid.hyphenated().encode_lower_checked(buf).map(|s| s.len()),
+                ),
+                (
+                    Hyphenated::LENGTH,
+                    uuid.hyphenated().encode_upper_checked(buf).map(|s| s.len()),
+                ),
+                (
+                    Simple::LENGTH,
+                    uuid.simple().encode_lower_checked(buf).map(|s| s.len()),
+                ),
+                (
+                    Simple::LENGTH,
+                    uuid.simple().encode_upper_checked(buf).map(|s| s.len()),
+                ),
+                (
+                    Urn::LENGTH,
+                    uuid.urn().encode_lower_checked(buf).map(|s| s.len()),
+                ),
+                (
+                    Urn::LENGTH,
+                    uuid.urn().encode_upper_checked(buf).map(|s| s.len()),
+                ),
+                (
+                    Braced::LENGTH,
+                    uuid.braced().encode_lower_checked(buf).map(|s| s.len()),
+                ),
+                (
+                    Braced::LENGTH,
+                    uuid.braced().encode_upper_checked(buf).map(|s| s.len()),
+                ),
+                (
+                    Checksummed::LENGTH,
+                    uuid.checksummed()
+                        .encode_lower_checked(buf)
+                        .map(|s| s.len()),
+                ),
+                (
+                    Checksummed::LENGTH,
+                    uuid.checksummed()
+                        .encode_upper_checked(buf)
+                        .map(|s| s.len()),
+                ),
+                (
+                    Separated::LENGTH,
+                    uuid.separated('_')
+                        .encode_lower_checked(buf)
+                        .map(|s| s.len()),
+                ),
+                (
+                    Separated::LENGTH,
+                    uuid.separated('_')
+                        .encode_upper_checked(buf)
+                        .map(|s| s.len()),
+                ),
+            ];
+
+            for (required, encoded) in encoded {
+                if len < required {
+                    assert!(encoded.is_err());
+                } else {
+                    assert_eq!(Ok(required), encoded);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn checked_too_small_leaves_buffer() {
+        let mut buf = [b'x'; 35];
+
+        assert!(Uuid::nil()
+            .hyphenated()
+            .encode_lower_checked(&mut buf)
+            .is_err());
+        assert_eq!([b'x'; 35], buf);
+    }
+
+    #[test]
+    fn checked_matches_unchecked() {
+        let uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+        assert_eq!(
+            uuid.braced().encode_upper(&mut Uuid::encode_buffer()),
+            uuid.braced()
+                .encode_upper_checked(&mut Uuid::encode_buffer())
+                .unwrap()
+        );
+        assert_eq!(
+            uuid.urn().encode_lower(&mut Uuid::encode_buffer()),
+            uuid.urn()
+                .encode_lower_checked(&mut Uuid::encode_buffer())
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn separated() {
+        use crate::std::string::ToString;
+
+        let uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+        assert_eq!(
+            "67e55044_10b1_426f_9247_bb680e5fe0c8",
+            uuid.separated('_').to_string()
+        );
+        assert_eq!(
+            "67E55044.10B1.426F.9247.BB680E5FE0C8",
+            format!("{:X}", uuid.separated('.'))
+        );
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            uuid.separated('-').to_string()
+        );
+        assert_eq!('_', uuid.separated('_').separator());
+        assert_eq!(uuid, Uuid::from(uuid.separated('_')));
+    }
+
+    #[test]
+    #[should_panic]
+    fn separated_non_ascii() {
+        let _ = Uuid::nil().separated('·');
+    }
+
+    #[test]
+    fn encode_many() {
+        let a = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+        let b = Uuid::from_u128(0x936da01f_9abd_4d9d_80c7_02af85c822a8);
+
+        let mut buf = [b'x'; 80];
+        assert_eq!(
+            "67e55044-10b1-426f-9247-bb680e5fe0c8\n936da01f-9abd-4d9d-80c7-02af85c822a8",
+            Hyphenated::encode_many_lower(&[a, b], '\n', &mut buf).unwrap()
+        );
+        assert!(buf[73..].iter().all(|x| *x == b'x'));
+
+        let mut buf = [b'x'; 80];
+        assert_eq!(
+            "67E55044-10B1-426F-9247-BB680E5FE0C8",
+            Hyphenated::encode_many_upper(&[a], ',', &mut buf).unwrap()
+        );
+
+        let mut buf = [0; 65];
+        assert_eq!(
+            "67E5504410B1426F9247BB680E5FE0C8;936DA01F9ABD4D9D80C702AF85C822A8",
+            Simple::encode_many_upper(&[a, b], ';', &mut buf).unwrap()
+        );
+
+        assert_eq!("", Simple::encode_many_lower(&[], ',', &mut []).unwrap());
+
+        let mut buf = [b'x'; 64];
+        assert!(Simple::encode_many_lower(&[a, b], ';', &mut buf).is_err());
+        assert!(buf.iter().all(|x| *x == b'x'));
+    }
+
+    #[test]
+    #[should_panic]
+    fn encode_many_non_ascii() {
+        let _ = Hyphenated::encode_many_lower(&[Uuid::nil()], '·', &mut [0; 36]);
+    }
+
+    #[test]
+    fn ascii_array_matches_encode() {
+        let uuid = crate::tests::new();
+
+        assert_eq!(
+            uuid.hyphenated()
+                .encode_lower(&mut Uuid::encode_buffer())
+                .as_bytes(),
+            &uuid.to_ascii_array()
+        );
+        assert_eq!(
+            uuid.simple()
+                .encode_lower(&mut Uuid::encode_buffer())
+                .as_bytes(),
+            &uuid.to_simple_ascii_array()
+        );
+
+        const MAX: [u8; 32] = Uuid::max().to_simple_ascii_array();
+        assert_eq!(b"ffffffffffffffffffffffffffffffff", &MAX);
+    }
+
+    #[test]
+    fn debug_alternate() {
+        use crate::std::{
+            fmt::Write,
+            string::{String, ToString},
+        };
+        use crate::timestamp::{self, UUID_TICKS_BETWEEN_EPOCHS};
+
+        let debug = |uuid: Uuid| {
+            let mut buf = String::new();
+            write!(buf, "{:#?}", uuid).unwrap();
+            buf
+        };
+
+        let ticks = UUID_TICKS_BETWEEN_EPOCHS + 1_645_557_742 * 10_000_000 + 1234;
+        let v1 = timestamp::encode_gregorian_timestamp(ticks, 42, &[1, 2, 3, 4, 5, 0xab]);
+
+        assert_eq!(
+            format_args!(
+                "Uuid {{\n    value: {},\n    version: Mac,\n    variant: RFC4122,\n    timestamp: 1645557742.000123400,\n    counter: 42,\n    node_id: 01:02:03:04:05:ab,\n}}",
+                v1
+            )
+            .to_string(),
+            debug(v1)
+        );
+
+        let v7 = timestamp::encode_unix_timestamp_millis(1_645_557_742_010, &[0; 10]);
+
+        assert_eq!(
+            "Uuid {\n    value: 017f22e2-79ba-7000-8000-000000000000,\n    version: SortRand,\n    variant: RFC4122,\n    timestamp: 1645557742.010000000,\n}",
+            debug(v7)
+        );
+
+        assert_eq!(
+            "Uuid {\n    value: 67e55044-10b1-b26f-9247-bb680e5fe0c8,\n    version: 11,\n    variant: RFC4122,\n}",
+            debug(Uuid::from_u128(0x67e55044_10b1_b26f_9247_bb680e5fe0c8))
+        );
+
+        // The default format is unchanged
+        let mut buf = String::new();
+        write!(buf, "{:?}", v7).unwrap();
+
+        assert_eq!("017f22e2-79ba-7000-8000-000000000000", buf);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn to_string_matches_display() {
+        use crate::std::string::ToString;
+
+        let uuid = crate::tests::new();
+        let borrowed = &uuid;
+
+        assert_eq!("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4", uuid.to_string());
+        assert_eq!(ToString::to_string(&uuid), uuid.to_string());
+        assert_eq!(ToString::to_string(&uuid), borrowed.to_string());
+        assert_eq!(ToString::to_string(&uuid), String::from(uuid));
+    }
 }
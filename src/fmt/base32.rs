@@ -0,0 +1,306 @@
+//! Encoding UUIDs in Crockford's base 32.
+
+use crate::{
+    error::*,
+    fmt::{check_buffer, Alphabet, Radix},
+    std::{borrow::Borrow, fmt, str},
+    Uuid,
+};
+
+/// Format a [`Uuid`] in Crockford's base 32, like `37WN84845H89QS4HXVD075ZR68`.
+///
+/// Base 32 strings are 26 characters long, use only digits and letters, and
+/// sort in the same order as the UUIDs themselves. The [`Display`]
+/// implementation writes upper-case letters, which is the canonical form.
+///
+/// Parsing ignores case, and reads `I` and `L` as `1` and `O` as `0`. See
+/// [`Alphabet::BASE32_CROCKFORD`] for the symbols used.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fmt::Base32, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+///
+/// assert_eq!("37WN84845H89QS4HXVD075ZR68", uuid.base32().to_string());
+///
+/// let parsed: Base32 = "37wn84845h89qs4hxvd075zr68".parse()?;
+/// assert_eq!(uuid, parsed.into_uuid());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Base32(Uuid);
+
+impl Uuid {
+    /// Get a [`Base32`] formatter.
+    #[inline]
+    pub const fn base32(self) -> Base32 {
+        Base32(self)
+    }
+
+    /// Parses a UUID encoded in Crockford's base 32.
+    ///
+    /// Case is ignored, and `I`, `L`, and `O` are read as `1`, `1`, and `0`.
+    /// The input may be shorter than [`Base32::LENGTH`] if leading zeros have
+    /// been trimmed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input is empty or longer than
+    /// [`Base32::LENGTH`], if it contains a character that isn't a base 32
+    /// symbol, or if its value doesn't fit in 128 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_base32("37WN84845H89QS4HXVD075ZR68")?;
+    ///
+    /// assert_eq!(
+    ///     Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?,
+    ///     uuid,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_base32(input: &str) -> Result<Uuid, Error> {
+        Uuid::parse_radix(input, &Alphabet::BASE32_CROCKFORD)
+    }
+}
+
+impl Base32 {
+    /// The length of a base 32 [`Uuid`] string.
+    pub const LENGTH: usize = 26;
+
+    /// Creates a [`Base32`] from a [`Uuid`].
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Base32(uuid)
+    }
+
+    /// Writes the [`Uuid`] as a lower-case base 32 string to `buffer`,
+    /// and returns the subslice of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`](#associatedconstant.LENGTH).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Base32, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(
+    ///     "37wn84845h89qs4hxvd075zr68",
+    ///     uuid.base32().encode_lower(&mut [0; Base32::LENGTH]),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        let encoded = self.encode_upper(buffer);
+        encoded.make_ascii_lowercase();
+
+        encoded
+    }
+
+    /// Writes the [`Uuid`] as an upper-case base 32 string to `buffer`,
+    /// and returns the subslice of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`](#associatedconstant.LENGTH).
+    #[inline]
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        Radix::from_uuid(self.0, &Alphabet::BASE32_CROCKFORD).encode(buffer)
+    }
+
+    /// Writes the [`Uuid`] as a lower-case base 32 string to `buffer`,
+    /// returning an error instead of panicking if the buffer is too small.
+    ///
+    /// This method is like [`Base32::encode_lower`], but never panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer has a length less than [`Base32::LENGTH`].
+    /// The buffer isn't written to in that case.
+    #[inline]
+    pub fn encode_lower_checked<'buf>(
+        &self,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        check_buffer(buffer, Self::LENGTH)?;
+
+        Ok(self.encode_lower(buffer))
+    }
+
+    /// Writes the [`Uuid`] as an upper-case base 32 string to `buffer`,
+    /// returning an error instead of panicking if the buffer is too small.
+    ///
+    /// This method is like [`Base32::encode_upper`], but never panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer has a length less than [`Base32::LENGTH`].
+    /// The buffer isn't written to in that case.
+    #[inline]
+    pub fn encode_upper_checked<'buf>(
+        &self,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf mut str, Error> {
+        check_buffer(buffer, Self::LENGTH)?;
+
+        Ok(self.encode_upper(buffer))
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`Base32`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl fmt::Display for Base32 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for Base32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.encode_lower(&mut [0; Self::LENGTH]))
+    }
+}
+
+impl fmt::UpperHex for Base32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.encode_upper(&mut [0; Self::LENGTH]))
+    }
+}
+
+impl str::FromStr for Base32 {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_base32(input).map(Base32)
+    }
+}
+
+impl From<Uuid> for Base32 {
+    #[inline]
+    fn from(f: Uuid) -> Self {
+        Base32(f)
+    }
+}
+
+impl From<Base32> for Uuid {
+    #[inline]
+    fn from(f: Base32) -> Self {
+        f.into_uuid()
+    }
+}
+
+impl AsRef<Uuid> for Base32 {
+    #[inline]
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Borrow<Uuid> for Base32 {
+    #[inline]
+    fn borrow(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::string::ToString;
+
+    const UUID: Uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+    #[test]
+    fn test_encode() {
+        assert_eq!("37WN84845H89QS4HXVD075ZR68", UUID.base32().to_string());
+        assert_eq!("37wn84845h89qs4hxvd075zr68", format!("{:x}", UUID.base32()));
+        assert_eq!("0".repeat(26), Uuid::nil().base32().to_string());
+        assert_eq!(
+            "7ZZZZZZZZZZZZZZZZZZZZZZZZZ",
+            Uuid::max().base32().to_string()
+        );
+    }
+
+    #[test]
+    fn test_encode_checked() {
+        let mut buf = [b'!'; 25];
+
+        assert_eq!(
+            Error::new(ErrorKind::BufferTooSmall {
+                len: 25,
+                required: 26
+            }),
+            UUID.base32().encode_upper_checked(&mut buf).unwrap_err()
+        );
+        assert_eq!([b'!'; 25], buf);
+
+        assert_eq!(
+            "37wn84845h89qs4hxvd075zr68",
+            UUID.base32().encode_lower_checked(&mut [0; 27]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for uuid in [UUID, Uuid::nil(), Uuid::max()] {
+            let upper = uuid.base32().to_string();
+            let lower = format!("{:x}", uuid.base32());
+
+            assert_eq!(Base32::LENGTH, upper.len());
+            assert_eq!(uuid, upper.parse::<Base32>().unwrap().into_uuid());
+            assert_eq!(uuid, lower.parse::<Base32>().unwrap().into_uuid());
+        }
+    }
+
+    #[test]
+    fn test_parse_aliases() {
+        assert_eq!(
+            Uuid::parse_base32("1100").unwrap(),
+            Uuid::parse_base32("iLoO").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            Error::new(ErrorKind::RadixChar {
+                character: 'U',
+                index: 2
+            }),
+            Uuid::parse_base32("0U").unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::RadixOverflow),
+            Uuid::parse_base32(&"Z".repeat(26)).unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::RadixLength { len: 27, max: 26 }),
+            "0".repeat(27).parse::<Base32>().unwrap_err()
+        );
+    }
+}
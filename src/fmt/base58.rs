@@ -0,0 +1,232 @@
+//! Encoding UUIDs in base 58.
+
+use crate::{
+    error::*,
+    fmt::{check_buffer, Alphabet, Radix},
+    std::{borrow::Borrow, fmt, str},
+    Uuid,
+};
+
+/// Format a [`Uuid`] in base 58, like `Dq7QdGPZBdz9vwjm3jLQSB`.
+///
+/// Base 58 strings are 22 characters long, padded with leading `1`s, and
+/// sort in the same order as the UUIDs themselves. They leave out
+/// characters that are easily confused, like `0` and `O`, and don't contain
+/// any punctuation, so they fit in URLs and can be selected with a double
+/// click. See [`Alphabet::BASE58`] for the symbols used.
+///
+/// Unlike base 32, base 58 is case sensitive, so there's only one way to
+/// encode a UUID.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fmt::Base58, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+///
+/// assert_eq!("Dq7QdGPZBdz9vwjm3jLQSB", uuid.base58().to_string());
+///
+/// let parsed: Base58 = "Dq7QdGPZBdz9vwjm3jLQSB".parse()?;
+/// assert_eq!(uuid, parsed.into_uuid());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Base58(Uuid);
+
+impl Uuid {
+    /// Get a [`Base58`] formatter.
+    #[inline]
+    pub const fn base58(self) -> Base58 {
+        Base58(self)
+    }
+
+    /// Parses a UUID encoded in base 58.
+    ///
+    /// The input may be shorter than [`Base58::LENGTH`] if leading `1`s have
+    /// been trimmed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input is empty or longer than
+    /// [`Base58::LENGTH`], if it contains a character that isn't a base 58
+    /// symbol, or if its value doesn't fit in 128 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_base58("Dq7QdGPZBdz9vwjm3jLQSB")?;
+    ///
+    /// assert_eq!(
+    ///     Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?,
+    ///     uuid,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_base58(input: &str) -> Result<Uuid, Error> {
+        Uuid::parse_radix(input, &Alphabet::BASE58)
+    }
+}
+
+impl Base58 {
+    /// The length of a base 58 [`Uuid`] string.
+    pub const LENGTH: usize = 22;
+
+    /// Creates a [`Base58`] from a [`Uuid`].
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Base58(uuid)
+    }
+
+    /// Writes the [`Uuid`] as a base 58 string to `buffer`, and returns the
+    /// subslice of the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`](#associatedconstant.LENGTH).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Base58, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(
+    ///     "Dq7QdGPZBdz9vwjm3jLQSB",
+    ///     uuid.base58().encode(&mut [0; Base58::LENGTH]),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        Radix::from_uuid(self.0, &Alphabet::BASE58).encode(buffer)
+    }
+
+    /// Writes the [`Uuid`] as a base 58 string to `buffer`, returning an
+    /// error instead of panicking if the buffer is too small.
+    ///
+    /// This method is like [`Base58::encode`], but never panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer has a length less than [`Base58::LENGTH`].
+    /// The buffer isn't written to in that case.
+    #[inline]
+    pub fn encode_checked<'buf>(&self, buffer: &'buf mut [u8]) -> Result<&'buf mut str, Error> {
+        check_buffer(buffer, Self::LENGTH)?;
+
+        Ok(self.encode(buffer))
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`Base58`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl fmt::Display for Base58 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.encode(&mut [0; Self::LENGTH]))
+    }
+}
+
+impl str::FromStr for Base58 {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_base58(input).map(Base58)
+    }
+}
+
+impl From<Uuid> for Base58 {
+    #[inline]
+    fn from(f: Uuid) -> Self {
+        Base58(f)
+    }
+}
+
+impl From<Base58> for Uuid {
+    #[inline]
+    fn from(f: Base58) -> Self {
+        f.into_uuid()
+    }
+}
+
+impl AsRef<Uuid> for Base58 {
+    #[inline]
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Borrow<Uuid> for Base58 {
+    #[inline]
+    fn borrow(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::string::ToString;
+
+    const UUID: Uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+    #[test]
+    fn test_encode() {
+        assert_eq!("Dq7QdGPZBdz9vwjm3jLQSB", UUID.base58().to_string());
+        assert_eq!("1".repeat(22), Uuid::nil().base58().to_string());
+        assert_eq!("YcVfxkQb6JRzqk5kF2tNLv", Uuid::max().base58().to_string());
+    }
+
+    #[test]
+    fn test_encode_checked() {
+        let mut buf = [b'!'; 21];
+
+        assert_eq!(
+            Error::new(ErrorKind::BufferTooSmall {
+                len: 21,
+                required: 22
+            }),
+            UUID.base58().encode_checked(&mut buf).unwrap_err()
+        );
+        assert_eq!([b'!'; 21], buf);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for uuid in [UUID, Uuid::nil(), Uuid::max()] {
+            let encoded = uuid.base58().to_string();
+
+            assert_eq!(Base58::LENGTH, encoded.len());
+            assert_eq!(uuid, encoded.parse::<Base58>().unwrap().into_uuid());
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            Error::new(ErrorKind::RadixChar {
+                character: '0',
+                index: 1
+            }),
+            Uuid::parse_base58("0").unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::RadixOverflow),
+            Uuid::parse_base58(&"z".repeat(22)).unwrap_err()
+        );
+    }
+}
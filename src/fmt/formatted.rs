@@ -0,0 +1,773 @@
+//! Formatting UUIDs with a configurable layout.
+
+use crate::{
+    error::*,
+    fmt::{ascii_str_mut, check_buffer, format_hyphenated, format_simple, Hyphenated, Simple},
+    std::{borrow::Borrow, fmt, str},
+    Uuid,
+};
+
+/// The layout used by a [`Formatted`] UUID, and accepted by
+/// [`Uuid::parse_formatted`].
+///
+/// [`FormatOptions::new`] writes lower-case hyphenated UUIDs, like
+/// [`Hyphenated`]. Each method adjusts one part of the layout, so unusual
+/// combinations, like upper-case braced UUIDs for the Windows registry, don't
+/// need their own adapter type.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fmt::FormatOptions, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+///
+/// let registry = FormatOptions::new().uppercase(true).braces(true);
+///
+/// assert_eq!(
+///     "{67E55044-10B1-426F-9247-BB680E5FE0C8}",
+///     uuid.formatted(registry).to_string(),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FormatOptions {
+    uppercase: bool,
+    braces: bool,
+    hyphens: bool,
+    urn: bool,
+    c_struct: bool,
+}
+
+impl FormatOptions {
+    /// Options for lower-case hyphenated UUIDs, like
+    /// `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    pub const fn new() -> Self {
+        FormatOptions {
+            uppercase: false,
+            braces: false,
+            hyphens: true,
+            urn: false,
+            c_struct: false,
+        }
+    }
+
+    /// Whether to write upper-case hex digits.
+    pub const fn uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        self
+    }
+
+    /// Whether to wrap the UUID in braces, like
+    /// `{67e55044-10b1-426f-9247-bb680e5fe0c8}`.
+    pub const fn braces(mut self, braces: bool) -> Self {
+        self.braces = braces;
+        self
+    }
+
+    /// Whether to put hyphens between the groups of the UUID. Without them,
+    /// UUIDs are written like `67e5504410b1426f9247bb680e5fe0c8`.
+    pub const fn hyphens(mut self, hyphens: bool) -> Self {
+        self.hyphens = hyphens;
+        self
+    }
+
+    /// Whether to prefix the UUID with `urn:uuid:`, like
+    /// `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    pub const fn urn(mut self, urn: bool) -> Self {
+        self.urn = urn;
+        self
+    }
+
+    /// Whether to write the UUID as a C struct initializer, like
+    /// `{0x67e55044,0x10b1,0x426f,{0x92,0x47,0xbb,0x68,0x0e,0x5f,0xe0,0xc8}}`.
+    ///
+    /// This is the layout of a `GUID` in C and C++ sources, and of .NET's
+    /// `Guid.ToString("X")`. It replaces the braces, hyphens, and URN options,
+    /// which are ignored while it's set.
+    pub const fn c_struct(mut self, c_struct: bool) -> Self {
+        self.c_struct = c_struct;
+        self
+    }
+
+    /// The length of a UUID written with these options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::fmt::{FormatOptions, Hyphenated, Urn};
+    /// assert_eq!(Hyphenated::LENGTH, FormatOptions::new().length());
+    /// assert_eq!(Urn::LENGTH, FormatOptions::new().urn(true).length());
+    /// ```
+    pub const fn length(&self) -> usize {
+        if self.c_struct {
+            return Formatted::MAX_LENGTH;
+        }
+
+        let mut len = if self.hyphens {
+            Hyphenated::LENGTH
+        } else {
+            Simple::LENGTH
+        };
+
+        if self.braces {
+            len += 2;
+        }
+
+        if self.urn {
+            len += URN_PREFIX.len();
+        }
+
+        len
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions::new()
+    }
+}
+
+/// Format a [`Uuid`] using the layout described by [`FormatOptions`].
+///
+/// This covers the formats of [`Hyphenated`], [`Simple`], [`Braced`], and
+/// [`Urn`], in either case, as well as combinations of them and the C struct
+/// initializer form used for `GUID`s. Use [`Uuid::parse_formatted`] to parse
+/// the same layouts.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fmt::FormatOptions, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+///
+/// let options = FormatOptions::new().c_struct(true);
+///
+/// assert_eq!(
+///     "{0x67e55044,0x10b1,0x426f,{0x92,0x47,0xbb,0x68,0x0e,0x5f,0xe0,0xc8}}",
+///     uuid.formatted(options).to_string(),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Braced`]: struct.Braced.html
+/// [`Urn`]: struct.Urn.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Formatted {
+    uuid: Uuid,
+    options: FormatOptions,
+}
+
+const URN_PREFIX: &[u8] = b"urn:uuid:";
+
+impl Uuid {
+    /// Get a [`Formatted`] formatter that uses the given [`FormatOptions`].
+    #[inline]
+    pub const fn formatted(self, options: FormatOptions) -> Formatted {
+        Formatted::from_uuid(self, options)
+    }
+
+    /// Parses a `Uuid` written in the layout described by the given
+    /// [`FormatOptions`].
+    ///
+    /// Parsing is relaxed in the ways that don't change the layout: hex
+    /// digits and prefixes may be in any case, and UUIDs may be written with
+    /// or without hyphens. The URN prefix, braces, and C struct form are
+    /// required when the options include them. The C struct form also allows
+    /// whitespace between its parts and fields without leading zeros, as
+    /// they're often written in source code.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input isn't a UUID in the
+    /// layout described by `options`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::FormatOptions, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let options = FormatOptions::new().c_struct(true);
+    ///
+    /// let uuid = Uuid::parse_formatted(
+    ///     "{ 0x67e55044, 0x10b1, 0x426f, { 0x92, 0x47, 0xbb, 0x68, 0xe, 0x5f, 0xe0, 0xc8 } }",
+    ///     &options,
+    /// )?;
+    ///
+    /// assert_eq!(Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?, uuid);
+    ///
+    /// let options = FormatOptions::new().braces(true);
+    ///
+    /// assert_eq!(uuid, Uuid::parse_formatted("{67E5504410B1426F9247BB680E5FE0C8}", &options)?);
+    /// assert!(Uuid::parse_formatted("67e55044-10b1-426f-9247-bb680e5fe0c8", &options).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_formatted(input: &str, options: &FormatOptions) -> Result<Uuid, Error> {
+        let result = if options.c_struct {
+            parse_c_struct(input.as_bytes())
+        } else {
+            parse_formatted(input.as_bytes(), options)
+        };
+
+        #[cfg(feature = "std")]
+        let result = result.map_err(|err| err.with_input(input));
+
+        result
+    }
+}
+
+impl Formatted {
+    /// The length of the longest string a [`Formatted`] UUID can be written
+    /// as, which is the C struct initializer form.
+    pub const MAX_LENGTH: usize = 68;
+
+    /// Creates a [`Formatted`] from a [`Uuid`] and the options to write it with.
+    pub const fn from_uuid(uuid: Uuid, options: FormatOptions) -> Self {
+        Formatted { uuid, options }
+    }
+
+    /// Writes the [`Uuid`] to `buffer`, and returns the subslice of the buffer
+    /// that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`FormatOptions::length`]. A buffer of [`MAX_LENGTH`] is always large
+    /// enough. [`Uuid::encode_buffer`] isn't, because it's only long enough for
+    /// a URN, and the C struct initializer form is longer.
+    ///
+    /// [`MAX_LENGTH`]: #associatedconstant.MAX_LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::{FormatOptions, Formatted}, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// let options = FormatOptions::new().hyphens(false).urn(true);
+    ///
+    /// assert_eq!(
+    ///     "urn:uuid:67e5504410b1426f9247bb680e5fe0c8",
+    ///     uuid.formatted(options).encode(&mut [0; Formatted::MAX_LENGTH]),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        let src = self.uuid.as_bytes();
+        let upper = self.options.uppercase;
+        let buf = &mut buffer[..self.options.length()];
+
+        if self.options.c_struct {
+            encode_c_struct(src, buf, upper);
+        } else {
+            let mut start = 0;
+            let mut end = buf.len();
+
+            if self.options.urn {
+                buf[..URN_PREFIX.len()].copy_from_slice(URN_PREFIX);
+                start += URN_PREFIX.len();
+            }
+
+            if self.options.braces {
+                buf[start] = b'{';
+                buf[end - 1] = b'}';
+                start += 1;
+                end -= 1;
+            }
+
+            if self.options.hyphens {
+                buf[start..end].copy_from_slice(&format_hyphenated(src, upper));
+            } else {
+                buf[start..end].copy_from_slice(&format_simple(src, upper));
+            }
+        }
+
+        ascii_str_mut(buf)
+    }
+
+    /// Writes the [`Uuid`] to `buffer`, returning an error instead of
+    /// panicking if the buffer is too small.
+    ///
+    /// This method is like [`Formatted::encode`], but never panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer has a length less than
+    /// [`FormatOptions::length`]. The buffer isn't written to in that case.
+    #[inline]
+    pub fn encode_checked<'buf>(&self, buffer: &'buf mut [u8]) -> Result<&'buf mut str, Error> {
+        check_buffer(buffer, self.options.length())?;
+
+        Ok(self.encode(buffer))
+    }
+
+    /// The options the [`Uuid`] is written with.
+    pub const fn options(&self) -> FormatOptions {
+        self.options
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    /// Consumes the [`Formatted`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.uuid
+    }
+}
+
+impl fmt::Display for Formatted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.encode(&mut [0; Self::MAX_LENGTH]))
+    }
+}
+
+impl From<Formatted> for Uuid {
+    #[inline]
+    fn from(f: Formatted) -> Self {
+        f.into_uuid()
+    }
+}
+
+impl AsRef<Uuid> for Formatted {
+    #[inline]
+    fn as_ref(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
+impl Borrow<Uuid> for Formatted {
+    #[inline]
+    fn borrow(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
+fn encode_c_struct(src: &[u8; 16], buf: &mut [u8], upper: bool) {
+    let hex = format_simple(src, upper);
+    let mut i = 0;
+
+    let mut push = |bytes: &[u8]| {
+        buf[i..i + bytes.len()].copy_from_slice(bytes);
+        i += bytes.len();
+    };
+
+    // The first three fields are written as whole integers, and the last
+    // 8 bytes one at a time
+    push(b"{");
+    for (start, end) in [(0, 8), (8, 12), (12, 16)] {
+        push(b"0x");
+        push(&hex[start..end]);
+        push(b",");
+    }
+
+    push(b"{");
+    for byte in 0..8 {
+        if byte > 0 {
+            push(b",");
+        }
+
+        push(b"0x");
+        push(&hex[16 + byte * 2..18 + byte * 2]);
+    }
+    push(b"}}");
+}
+
+fn parse_formatted(input: &[u8], options: &FormatOptions) -> Result<Uuid, Error> {
+    let mut uuid = input;
+    let mut offset = 0;
+
+    if options.urn {
+        if uuid.len() < URN_PREFIX.len()
+            || !uuid[..URN_PREFIX.len()].eq_ignore_ascii_case(URN_PREFIX)
+        {
+            return Err(invalid_char(input, mismatch(input, URN_PREFIX)));
+        }
+
+        uuid = &uuid[URN_PREFIX.len()..];
+        offset += URN_PREFIX.len();
+    }
+
+    if options.braces {
+        match uuid {
+            [b'{', s @ .., b'}'] => {
+                uuid = s;
+                offset += 1;
+            }
+            [b'{', ..] => return Err(invalid_char(input, input.len() - 1)),
+            _ => return Err(invalid_char(input, offset)),
+        }
+    }
+
+    let parsed = match uuid.len() {
+        Hyphenated::LENGTH => crate::parser::parse_hyphenated(uuid),
+        Simple::LENGTH => crate::parser::parse_simple(uuid),
+        _ => Err(InvalidUuid(uuid)),
+    };
+
+    parsed.map(Uuid::from_bytes).map_err(|_| {
+        let mut err = InvalidUuid(uuid).into_err();
+
+        // Errors are reported relative to the UUID itself, so shift them
+        // past any prefix
+        match err.kind {
+            ErrorKind::Char { ref mut index, .. }
+            | ErrorKind::GroupLength { ref mut index, .. } => *index += offset,
+            _ => (),
+        }
+
+        err
+    })
+}
+
+fn parse_c_struct(input: &[u8]) -> Result<Uuid, Error> {
+    let mut parser = CStructParser { input, index: 0 };
+
+    parser.expect(b'{')?;
+
+    let a = parser.field(8)? as u32;
+    parser.expect(b',')?;
+    let b = parser.field(4)? as u16;
+    parser.expect(b',')?;
+    let c = parser.field(4)? as u16;
+    parser.expect(b',')?;
+
+    parser.expect(b'{')?;
+
+    let mut d = [0; 8];
+    for (i, byte) in d.iter_mut().enumerate() {
+        if i > 0 {
+            parser.expect(b',')?;
+        }
+
+        *byte = parser.field(2)? as u8;
+    }
+
+    parser.expect(b'}')?;
+    parser.expect(b'}')?;
+
+    parser.skip_whitespace();
+    if parser.index < input.len() {
+        return Err(invalid_char(input, parser.index));
+    }
+
+    Ok(Uuid::from_fields(a, b, c, &d))
+}
+
+struct CStructParser<'a> {
+    input: &'a [u8],
+    index: usize,
+}
+
+impl<'a> CStructParser<'a> {
+    fn skip_whitespace(&mut self) {
+        while self
+            .input
+            .get(self.index)
+            .map_or(false, |b| b.is_ascii_whitespace())
+        {
+            self.index += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), Error> {
+        self.skip_whitespace();
+
+        match self.input.get(self.index) {
+            Some(&b) if b == expected => {
+                self.index += 1;
+                Ok(())
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    // Parses a `0x` prefixed hex integer with at most `max_digits` digits
+    fn field(&mut self, max_digits: usize) -> Result<u64, Error> {
+        self.skip_whitespace();
+
+        match self.input.get(self.index..self.index + 2) {
+            Some([b'0', b'x' | b'X']) => self.index += 2,
+            _ => return Err(self.error()),
+        }
+
+        let mut value = 0;
+        let mut digits = 0;
+
+        while let Some(digit) = self
+            .input
+            .get(self.index)
+            .and_then(|&b| (b as char).to_digit(16))
+        {
+            if digits == max_digits {
+                return Err(self.error());
+            }
+
+            value = (value << 4) | digit as u64;
+            digits += 1;
+            self.index += 1;
+        }
+
+        if digits == 0 {
+            return Err(self.error());
+        }
+
+        Ok(value)
+    }
+
+    fn error(&self) -> Error {
+        if self.index < self.input.len() {
+            invalid_char(self.input, self.index)
+        } else {
+            Error::new(ErrorKind::Other)
+        }
+    }
+}
+
+// The index of the first byte of `input` that doesn't match `expected`,
+// ignoring case
+fn mismatch(input: &[u8], expected: &[u8]) -> usize {
+    input
+        .iter()
+        .zip(expected)
+        .position(|(a, b)| !a.eq_ignore_ascii_case(b))
+        .unwrap_or_else(|| input.len().min(expected.len()))
+}
+
+fn invalid_char(input: &[u8], index: usize) -> Error {
+    if index >= input.len() {
+        return Error::new(ErrorKind::Other);
+    }
+
+    // The input is a `str`, so there's a char at any ASCII boundary
+    let character = str::from_utf8(&input[index..])
+        .ok()
+        .and_then(|s| s.chars().next())
+        .unwrap_or(char::REPLACEMENT_CHARACTER);
+
+    Error::new(ErrorKind::Char {
+        character,
+        index: index + 1,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::string::ToString;
+
+    const UUID: Uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+    #[test]
+    fn test_encode_matches_adapters() {
+        let new = FormatOptions::new();
+
+        assert_eq!(
+            UUID.hyphenated().to_string(),
+            UUID.formatted(new).to_string()
+        );
+        assert_eq!(
+            UUID.simple().to_string(),
+            UUID.formatted(new.hyphens(false)).to_string()
+        );
+        assert_eq!(
+            UUID.braced().to_string(),
+            UUID.formatted(new.braces(true)).to_string()
+        );
+        assert_eq!(
+            UUID.urn().to_string(),
+            UUID.formatted(new.urn(true)).to_string()
+        );
+        assert_eq!(
+            format!("{:X}", UUID.braced()),
+            UUID.formatted(new.braces(true).uppercase(true)).to_string()
+        );
+    }
+
+    #[test]
+    fn test_encode_c_struct() {
+        let options = FormatOptions::new().c_struct(true);
+
+        assert_eq!(
+            "{0x67e55044,0x10b1,0x426f,{0x92,0x47,0xbb,0x68,0x0e,0x5f,0xe0,0xc8}}",
+            UUID.formatted(options).to_string()
+        );
+        assert_eq!(
+            "{0x67E55044,0x10B1,0x426F,{0x92,0x47,0xBB,0x68,0x0E,0x5F,0xE0,0xC8}}",
+            UUID.formatted(options.uppercase(true).urn(true))
+                .to_string()
+        );
+        assert_eq!(Formatted::MAX_LENGTH, options.length());
+    }
+
+    #[test]
+    fn test_encode_checked() {
+        let options = FormatOptions::new().braces(true).urn(true);
+        let mut buf = [b'!'; 80];
+
+        assert_eq!(47, options.length());
+        assert_eq!(
+            "urn:uuid:{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            UUID.formatted(options).encode_checked(&mut buf).unwrap()
+        );
+        assert_eq!(b'!', buf[47]);
+
+        let mut buf = [b'!'; 46];
+        assert_eq!(
+            Error::new(ErrorKind::BufferTooSmall {
+                len: 46,
+                required: 47
+            }),
+            UUID.formatted(options)
+                .encode_checked(&mut buf)
+                .unwrap_err()
+        );
+        assert_eq!([b'!'; 46], buf);
+    }
+
+    #[test]
+    fn test_parse_roundtrip() {
+        for uppercase in [false, true] {
+            for braces in [false, true] {
+                for hyphens in [false, true] {
+                    for urn in [false, true] {
+                        for c_struct in [false, true] {
+                            let options = FormatOptions::new()
+                                .uppercase(uppercase)
+                                .braces(braces)
+                                .hyphens(hyphens)
+                                .urn(urn)
+                                .c_struct(c_struct);
+
+                            for uuid in [UUID, Uuid::nil(), Uuid::max()] {
+                                let encoded = uuid.formatted(options).to_string();
+
+                                assert_eq!(options.length(), encoded.len());
+                                assert_eq!(
+                                    uuid,
+                                    Uuid::parse_formatted(&encoded, &options).unwrap(),
+                                    "{}",
+                                    encoded
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_relaxed() {
+        let options = FormatOptions::new().urn(true);
+
+        assert_eq!(
+            UUID,
+            Uuid::parse_formatted("URN:UUID:67E5504410B1426F9247BB680E5FE0C8", &options).unwrap()
+        );
+
+        let options = FormatOptions::new().c_struct(true);
+
+        assert_eq!(
+            UUID,
+            Uuid::parse_formatted(
+                "\t{0X67E55044,0x10b1 ,0x426f,\n  {0x92,0x47,0xbb,0x68,0xe,0x5f,0xe0,0xc8}}\n",
+                &options
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            Uuid::from_u128(1),
+            Uuid::parse_formatted("{0x0,0x0,0x0,{0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1}}", &options)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let options = FormatOptions::new().braces(true);
+
+        assert_eq!(
+            Error::new(ErrorKind::Char {
+                character: '6',
+                index: 1
+            }),
+            Uuid::parse_formatted("67e55044-10b1-426f-9247-bb680e5fe0c8", &options).unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::Char {
+                character: 'x',
+                index: 10
+            }),
+            Uuid::parse_formatted("{67e55044x10b1-426f-9247-bb680e5fe0c8}", &options).unwrap_err()
+        );
+
+        let options = FormatOptions::new().urn(true);
+
+        assert_eq!(
+            Error::new(ErrorKind::Char {
+                character: 'x',
+                index: 4
+            }),
+            Uuid::parse_formatted("urnxuuid:67e55044-10b1-426f-9247-bb680e5fe0c8", &options)
+                .unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::GroupLength {
+                group: 1,
+                len: 3,
+                index: 19,
+            }),
+            Uuid::parse_formatted("urn:uuid:67e55044-10b-426f-9247-bb680e5fe0c8", &options)
+                .unwrap_err()
+        );
+
+        let options = FormatOptions::new().c_struct(true);
+
+        for (input, character, index) in [
+            // Too many digits in a field
+            (
+                "{0x67e550441,0x10b1,0x426f,{0x92,0x47,0xbb,0x68,0x0e,0x5f,0xe0,0xc8}}",
+                '1',
+                12,
+            ),
+            // A missing `0x` prefix
+            (
+                "{0x67e55044,10b1,0x426f,{0x92,0x47,0xbb,0x68,0x0e,0x5f,0xe0,0xc8}}",
+                '1',
+                13,
+            ),
+            // Too many bytes
+            (
+                "{0x67e55044,0x10b1,0x426f,{0x92,0x47,0xbb,0x68,0x0e,0x5f,0xe0,0xc8,0x00}}",
+                ',',
+                67,
+            ),
+            // Trailing characters
+            (
+                "{0x67e55044,0x10b1,0x426f,{0x92,0x47,0xbb,0x68,0x0e,0x5f,0xe0,0xc8}};",
+                ';',
+                69,
+            ),
+        ] {
+            assert_eq!(
+                Error::new(ErrorKind::Char { character, index }),
+                Uuid::parse_formatted(input, &options).unwrap_err(),
+                "{}",
+                input
+            );
+        }
+
+        assert!(Uuid::parse_formatted("{0x67e55044,0x10b1,0x426f,{0x92", &options).is_err());
+        assert!(Uuid::parse_formatted("", &options).is_err());
+    }
+}
@@ -0,0 +1,547 @@
+//! Encoding UUIDs in arbitrary bases.
+
+use crate::{
+    error::*,
+    std::{fmt, str},
+    Uuid,
+};
+
+/// A set of symbols for encoding a [`Uuid`] in a base between 2 and 62.
+///
+/// Each symbol is a printable ASCII character, and its position in the
+/// alphabet is its digit value. Encoded UUIDs always use the same number
+/// of symbols for a given base, padded with the zero symbol, so they sort
+/// in the same order as the UUIDs themselves.
+///
+/// # Examples
+///
+/// Using a custom alphabet:
+///
+/// ```
+/// # use uuid::{fmt::Alphabet, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// // Base 32 without easily confused characters
+/// let alphabet = Alphabet::new("0123456789abcdefghjkmnpqrstvwxyz")?;
+///
+/// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+/// let encoded = uuid.radix(&alphabet).to_string();
+///
+/// assert_eq!("37wn84845h89qs4hxvd075zr68", encoded);
+/// assert_eq!(uuid, Uuid::parse_radix(&encoded, &alphabet)?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct Alphabet {
+    encode: [u8; Alphabet::MAX_RADIX],
+    decode: [u8; 128],
+    radix: u8,
+    len: u8,
+}
+
+/// Format a [`Uuid`] using an [`Alphabet`].
+///
+/// This type is returned by [`Uuid::radix`].
+#[derive(Clone, Copy)]
+pub struct Radix<'a> {
+    uuid: Uuid,
+    alphabet: &'a Alphabet,
+}
+
+const INVALID: u8 = u8::MAX;
+
+impl Alphabet {
+    /// The smallest supported base.
+    pub const MIN_RADIX: usize = 2;
+
+    /// The largest supported base.
+    pub const MAX_RADIX: usize = 62;
+
+    /// The digits `0-9` followed by the lower-case letters `a-z`.
+    pub const BASE36: Alphabet = match Alphabet::try_new("0123456789abcdefghijklmnopqrstuvwxyz") {
+        Some(alphabet) => alphabet,
+        None => panic!("invalid alphabet"),
+    };
+
+    /// The digits `0-9` followed by the upper-case letters `A-Z` and then the
+    /// lower-case letters `a-z`.
+    pub const BASE62: Alphabet =
+        match Alphabet::try_new("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz") {
+            Some(alphabet) => alphabet,
+            None => panic!("invalid alphabet"),
+        };
+
+    /// Crockford's base 32: the digits `0-9` followed by the upper-case letters
+    /// `A-Z` without `I`, `L`, `O`, and `U`.
+    ///
+    /// Parsing with this alphabet ignores case, and reads `I` and `L` as `1`
+    /// and `O` as `0`, since they're easily mistaken for each other.
+    pub const BASE32_CROCKFORD: Alphabet =
+        match Alphabet::try_new("0123456789ABCDEFGHJKMNPQRSTVWXYZ") {
+            Some(alphabet) => alphabet
+                .ignore_case()
+                .alias(b'I', b'1')
+                .alias(b'L', b'1')
+                .alias(b'O', b'0'),
+            None => panic!("invalid alphabet"),
+        };
+
+    /// The base 58 alphabet used by Bitcoin, which is base 62 without `0`,
+    /// `I`, `O`, and `l`.
+    pub const BASE58: Alphabet =
+        match Alphabet::try_new("123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz") {
+            Some(alphabet) => alphabet,
+            None => panic!("invalid alphabet"),
+        };
+
+    /// Creates an alphabet from a string of symbols.
+    ///
+    /// The base of the alphabet is the number of symbols in it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `symbols` doesn't contain between
+    /// [`MIN_RADIX`](#associatedconstant.MIN_RADIX) and
+    /// [`MAX_RADIX`](#associatedconstant.MAX_RADIX) symbols, if any symbol isn't
+    /// a printable ASCII character, or if any symbol is repeated.
+    pub const fn new(symbols: &str) -> Result<Self, Error> {
+        match Self::try_new(symbols) {
+            Some(alphabet) => Ok(alphabet),
+            None => Err(Error::new(ErrorKind::Alphabet)),
+        }
+    }
+
+    // This returns an `Option` so the built-in alphabets can be checked in
+    // `const` items: dropping an `Error` can't happen at compile time
+    const fn try_new(symbols: &str) -> Option<Self> {
+        let symbols = symbols.as_bytes();
+
+        if symbols.len() < Self::MIN_RADIX || symbols.len() > Self::MAX_RADIX {
+            return None;
+        }
+
+        let mut encode = [0; Self::MAX_RADIX];
+        let mut decode = [INVALID; 128];
+
+        let mut i = 0;
+        while i < symbols.len() {
+            let symbol = symbols[i];
+
+            if !symbol.is_ascii_graphic() || decode[symbol as usize] != INVALID {
+                return None;
+            }
+
+            encode[i] = symbol;
+            decode[symbol as usize] = i as u8;
+
+            i += 1;
+        }
+
+        let radix = symbols.len() as u128;
+
+        // Count the digits needed for the largest UUID
+        let mut len = 1;
+        let mut rest = u128::MAX / radix;
+        while rest > 0 {
+            rest /= radix;
+            len += 1;
+        }
+
+        Some(Alphabet {
+            encode,
+            decode,
+            radix: radix as u8,
+            len,
+        })
+    }
+
+    // Decode each lower-case letter the same as its upper-case symbol
+    const fn ignore_case(mut self) -> Self {
+        let mut symbol = b'A';
+        while symbol <= b'Z' {
+            self.decode[symbol.to_ascii_lowercase() as usize] = self.decode[symbol as usize];
+            symbol += 1;
+        }
+
+        self
+    }
+
+    // Decode an upper-case letter, and its lower-case form, as another symbol
+    const fn alias(mut self, alias: u8, symbol: u8) -> Self {
+        self.decode[alias as usize] = self.decode[symbol as usize];
+        self.decode[alias.to_ascii_lowercase() as usize] = self.decode[symbol as usize];
+
+        self
+    }
+
+    /// The base of the alphabet.
+    pub const fn radix(&self) -> usize {
+        self.radix as usize
+    }
+
+    /// The length of a [`Uuid`] encoded with this alphabet.
+    pub const fn encoded_len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// The symbols in the alphabet, in order of their digit value.
+    pub fn symbols(&self) -> &str {
+        match str::from_utf8(&self.encode[..self.radix()]) {
+            Ok(symbols) => symbols,
+            Err(_) => unreachable!("the alphabet only contains ASCII symbols"),
+        }
+    }
+}
+
+impl fmt::Debug for Alphabet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Alphabet").field(&self.symbols()).finish()
+    }
+}
+
+impl PartialEq for Alphabet {
+    fn eq(&self, other: &Self) -> bool {
+        self.symbols() == other.symbols()
+    }
+}
+
+impl Eq for Alphabet {}
+
+impl Uuid {
+    /// Get a [`Radix`] formatter that encodes this UUID using `alphabet`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Alphabet, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(
+    ///     "3A30O3qtpTRBe7GtXy50u0",
+    ///     uuid.radix(&Alphabet::BASE62).to_string(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn radix(self, alphabet: &Alphabet) -> Radix<'_> {
+        Radix {
+            uuid: self,
+            alphabet,
+        }
+    }
+
+    /// Parses a UUID encoded using `alphabet`.
+    ///
+    /// The input may be shorter than [`Alphabet::encoded_len`] if leading
+    /// zero symbols have been trimmed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input is empty or longer than
+    /// [`Alphabet::encoded_len`], if it contains a symbol that isn't in
+    /// `alphabet`, or if its value doesn't fit in 128 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Alphabet, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_radix("3A30O3qtpTRBe7GtXy50u0", &Alphabet::BASE62)?;
+    ///
+    /// assert_eq!(
+    ///     Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?,
+    ///     uuid,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_radix(input: &str, alphabet: &Alphabet) -> Result<Uuid, Error> {
+        if input.is_empty() || input.len() > alphabet.encoded_len() {
+            return Err(Error::new(ErrorKind::RadixLength {
+                len: input.len(),
+                max: alphabet.encoded_len(),
+            }));
+        }
+
+        let radix = alphabet.radix as u128;
+        let mut value = 0u128;
+
+        for (index, character) in input.char_indices() {
+            let digit = if character.is_ascii() {
+                alphabet.decode[character as usize]
+            } else {
+                INVALID
+            };
+
+            if digit == INVALID {
+                return Err(Error::new(ErrorKind::RadixChar {
+                    character,
+                    index: index + 1,
+                }));
+            }
+
+            value = match value
+                .checked_mul(radix)
+                .and_then(|value| value.checked_add(digit as u128))
+            {
+                Some(value) => value,
+                None => return Err(Error::new(ErrorKind::RadixOverflow)),
+            };
+        }
+
+        Ok(Uuid::from_u128(value))
+    }
+}
+
+impl<'a> Radix<'a> {
+    /// The length of the longest string a [`Radix`] UUID can be written as,
+    /// which is in base 2.
+    pub const MAX_LENGTH: usize = 128;
+
+    /// Creates a [`Radix`] from a [`Uuid`] and an [`Alphabet`].
+    pub const fn from_uuid(uuid: Uuid, alphabet: &'a Alphabet) -> Self {
+        Radix { uuid, alphabet }
+    }
+
+    /// The alphabet used to encode the [`Uuid`].
+    pub const fn alphabet(&self) -> &'a Alphabet {
+        self.alphabet
+    }
+
+    /// Writes the encoded [`Uuid`] to `buffer`, and returns the subslice of
+    /// the buffer that contains the encoded UUID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`Alphabet::encoded_len`]. A buffer of [`MAX_LENGTH`] is always large
+    /// enough. [`Uuid::encode_buffer`] isn't, because it's only long enough for
+    /// a URN, and a UUID in a small base is longer.
+    ///
+    /// [`MAX_LENGTH`]: #associatedconstant.MAX_LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Alphabet, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(
+    ///     "65fjgt56no7e0j1xr28lgpsjs",
+    ///     uuid.radix(&Alphabet::BASE36).encode(&mut [0; 25]),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        let buf = &mut buffer[..self.alphabet.encoded_len()];
+        let radix = self.alphabet.radix as u128;
+
+        let mut value = self.uuid.as_u128();
+        for dst in buf.iter_mut().rev() {
+            *dst = self.alphabet.encode[(value % radix) as usize];
+            value /= radix;
+        }
+
+        // The alphabet only contains ASCII symbols
+        crate::fmt::ascii_str_mut(buf)
+    }
+
+    /// Writes the encoded [`Uuid`] to `buffer`, and returns the subslice of
+    /// the buffer that contains the encoded UUID.
+    ///
+    /// This is like [`Radix::encode`], but returns an error instead of
+    /// panicking if the buffer is too small. In that case, the buffer is
+    /// left unchanged.
+    #[inline]
+    pub fn encode_checked<'buf>(&self, buffer: &'buf mut [u8]) -> Result<&'buf mut str, Error> {
+        if buffer.len() < self.alphabet.encoded_len() {
+            return Err(Error::new(ErrorKind::BufferTooSmall {
+                len: buffer.len(),
+                required: self.alphabet.encoded_len(),
+            }));
+        }
+
+        Ok(self.encode(buffer))
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    /// Consumes the [`Radix`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.uuid
+    }
+}
+
+impl<'a> fmt::Display for Radix<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.encode(&mut [0; Radix::MAX_LENGTH]))
+    }
+}
+
+impl<'a> fmt::Debug for Radix<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<'a> AsRef<Uuid> for Radix<'a> {
+    #[inline]
+    fn as_ref(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::string::ToString;
+
+    const UUID: Uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+    #[test]
+    fn test_encoded_len() {
+        assert_eq!(
+            Radix::MAX_LENGTH,
+            Alphabet::new("01").unwrap().encoded_len()
+        );
+        assert_eq!(32, Alphabet::new("0123456789abcdef").unwrap().encoded_len());
+        assert_eq!(25, Alphabet::BASE36.encoded_len());
+        assert_eq!(22, Alphabet::BASE62.encoded_len());
+    }
+
+    #[test]
+    fn test_invalid_alphabet() {
+        for symbols in ["", "0", "001", "0 1", "0é", &"0".repeat(63)] {
+            assert_eq!(
+                Error::new(ErrorKind::Alphabet),
+                Alphabet::new(symbols).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(
+            "67e5504410b1426f9247bb680e5fe0c8",
+            UUID.radix(&Alphabet::new("0123456789abcdef").unwrap())
+                .to_string()
+        );
+        assert_eq!(
+            "3A30O3qtpTRBe7GtXy50u0",
+            UUID.radix(&Alphabet::BASE62).to_string()
+        );
+        assert_eq!(
+            "7n42DGM5Tflk9n8mt7Fhc7",
+            Uuid::max().radix(&Alphabet::BASE62).to_string()
+        );
+        assert_eq!(
+            "0000000000000000000000",
+            Uuid::nil().radix(&Alphabet::BASE62).to_string()
+        );
+        assert_eq!(
+            "f5lxx1zz5pnorynqglhzmsp33",
+            Uuid::max().radix(&Alphabet::BASE36).to_string()
+        );
+        assert_eq!(
+            "1".repeat(128),
+            Uuid::max().radix(&Alphabet::new("01").unwrap()).to_string()
+        );
+    }
+
+    #[test]
+    fn test_encode_checked() {
+        let mut buf = [b'!'; 21];
+
+        assert_eq!(
+            Error::new(ErrorKind::BufferTooSmall {
+                len: 21,
+                required: 22
+            }),
+            UUID.radix(&Alphabet::BASE62)
+                .encode_checked(&mut buf)
+                .unwrap_err()
+        );
+        assert_eq!([b'!'; 21], buf);
+
+        assert_eq!(
+            "3A30O3qtpTRBe7GtXy50u0",
+            UUID.radix(&Alphabet::BASE62)
+                .encode_checked(&mut [0; 23])
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let alphabets = [
+            Alphabet::new("01").unwrap(),
+            Alphabet::new("abc").unwrap(),
+            Alphabet::new("!#$%&()*+,-./:;<=>?@[]^_`{|}~").unwrap(),
+            Alphabet::BASE36,
+            Alphabet::BASE62,
+        ];
+
+        for alphabet in &alphabets {
+            for uuid in [UUID, Uuid::nil(), Uuid::max()] {
+                let encoded = uuid.radix(alphabet).to_string();
+
+                assert_eq!(alphabet.encoded_len(), encoded.len());
+                assert_eq!(uuid, Uuid::parse_radix(&encoded, alphabet).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_trimmed() {
+        assert_eq!(
+            Uuid::from_u128(61),
+            Uuid::parse_radix("z", &Alphabet::BASE62).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            Error::new(ErrorKind::RadixLength { len: 0, max: 22 }),
+            Uuid::parse_radix("", &Alphabet::BASE62).unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::RadixLength { len: 23, max: 22 }),
+            Uuid::parse_radix(&"0".repeat(23), &Alphabet::BASE62).unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::RadixChar {
+                character: '-',
+                index: 3
+            }),
+            Uuid::parse_radix("3A-0", &Alphabet::BASE62).unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::RadixChar {
+                character: 'é',
+                index: 2
+            }),
+            Uuid::parse_radix("3é", &Alphabet::BASE62).unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::RadixChar {
+                character: 'A',
+                index: 1
+            }),
+            Uuid::parse_radix("A", &Alphabet::BASE36).unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::RadixOverflow),
+            Uuid::parse_radix("7n42DGM5Tflk9n8mt7Fhc8", &Alphabet::BASE62).unwrap_err()
+        );
+    }
+}
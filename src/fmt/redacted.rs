@@ -0,0 +1,195 @@
+//! Formatting UUIDs without revealing them.
+
+use crate::{
+    fmt::{ascii_str_mut, LOWER_PAIRS},
+    hash::mix64,
+    std::fmt,
+    Uuid,
+};
+
+/// Format a [`Uuid`] as a short digest that doesn't reveal it, like `3b9f07c2`.
+///
+/// The digest is the first 32 bits of a salted hash of the UUID, written as
+/// 8 hex digits. The same UUID always produces the same digest for the same
+/// salt, so a redacted UUID can still be followed through logs and crash
+/// reports without the identifier itself appearing in them. Both the
+/// [`Display`] and [`Debug`] implementations write the digest.
+///
+/// [`Uuid::redacted`] uses a salt that's chosen randomly once per process,
+/// so digests can be correlated within a process but not between processes.
+/// Use [`Uuid::redacted_with_salt`] to pick the salt yourself.
+///
+/// The digest isn't a cryptographic hash. Anyone who knows the salt can
+/// check a guess of the UUID against its digest, and different UUIDs may
+/// share a digest.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::Uuid;
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+///
+/// let redacted = uuid.redacted_with_salt(42).to_string();
+///
+/// assert_eq!(8, redacted.len());
+/// assert_eq!(redacted, uuid.redacted_with_salt(42).to_string());
+/// assert_ne!(redacted, uuid.redacted_with_salt(43).to_string());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+/// [`Debug`]: https://doc.rust-lang.org/std/fmt/trait.Debug.html
+#[derive(Clone, Copy)]
+pub struct Redacted {
+    digest: u32,
+}
+
+impl Redacted {
+    /// The length of a redacted [`Uuid`] string.
+    pub const LENGTH: usize = 8;
+
+    /// Creates a [`Redacted`] from a [`Uuid`] and the salt to hash it with.
+    pub const fn from_uuid(uuid: Uuid, salt: u64) -> Self {
+        let (high_bits, low_bits) = uuid.as_u64_pair();
+
+        Redacted {
+            digest: (mix64(mix64(high_bits ^ salt) ^ low_bits) >> 32) as u32,
+        }
+    }
+
+    /// The digest that's written in place of the UUID.
+    pub const fn digest(&self) -> u32 {
+        self.digest
+    }
+
+    fn encode<'buf>(&self, buffer: &'buf mut [u8; Self::LENGTH]) -> &'buf mut str {
+        for (dst, byte) in buffer
+            .chunks_exact_mut(2)
+            .zip(self.digest.to_be_bytes().iter())
+        {
+            dst.copy_from_slice(&LOWER_PAIRS[*byte as usize]);
+        }
+
+        ascii_str_mut(buffer)
+    }
+}
+
+impl Uuid {
+    /// Get a [`Redacted`] formatter using this process' salt.
+    ///
+    /// The salt is chosen randomly the first time it's needed, and stays the
+    /// same for the rest of the process.
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// let logged = format!("request {:?} failed", uuid.redacted());
+    ///
+    /// assert!(!logged.contains("67e55044"));
+    /// assert_eq!(logged, format!("request {:?} failed", uuid.redacted()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn redacted(self) -> Redacted {
+        Redacted::from_uuid(self, process_salt())
+    }
+
+    /// Get a [`Redacted`] formatter using the given salt.
+    #[inline]
+    pub const fn redacted_with_salt(self, salt: u64) -> Redacted {
+        Redacted::from_uuid(self, salt)
+    }
+}
+
+impl fmt::Display for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.encode(&mut [0; Self::LENGTH]))
+    }
+}
+
+// The derived implementation would write the digest as a decimal number
+impl fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+fn process_salt() -> u64 {
+    use crate::std::{
+        collections::hash_map::RandomState,
+        hash::{BuildHasher, Hasher},
+        sync::Mutex,
+    };
+
+    static SALT: Mutex<Option<u64>> = Mutex::new(None);
+
+    // A poisoned lock still holds a valid salt
+    let mut salt = SALT.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    *salt.get_or_insert_with(|| RandomState::new().build_hasher().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::{format, string::ToString};
+
+    const UUID: Uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+    #[test]
+    fn test_redacted_with_salt() {
+        let redacted = UUID.redacted_with_salt(1);
+
+        assert_eq!(Redacted::LENGTH, redacted.to_string().len());
+        assert_eq!(format!("{:08x}", redacted.digest()), redacted.to_string());
+        assert_eq!(redacted.to_string(), format!("{:?}", redacted));
+
+        assert_eq!(redacted.digest(), UUID.redacted_with_salt(1).digest());
+        assert_ne!(redacted.digest(), UUID.redacted_with_salt(2).digest());
+        assert_ne!(
+            redacted.digest(),
+            Uuid::max().redacted_with_salt(1).digest()
+        );
+    }
+
+    #[test]
+    fn test_redacted_pad() {
+        let redacted = UUID.redacted_with_salt(1);
+
+        assert_eq!(
+            format!("  {:08x}", redacted.digest()),
+            format!("{:>10}", redacted)
+        );
+        assert_eq!(
+            format!("{:08x}--", redacted.digest()),
+            format!("{:-<10?}", redacted)
+        );
+        assert_eq!(&redacted.to_string()[..4], format!("{:.4}", redacted));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_redacted_is_stable() {
+        let redacted = UUID.redacted();
+
+        assert_eq!(redacted.digest(), UUID.redacted().digest());
+        assert_eq!(
+            redacted.digest(),
+            crate::std::thread::spawn(|| UUID.redacted().digest())
+                .join()
+                .unwrap()
+        );
+        assert!(!format!("{:?}", redacted).contains("67e55044"));
+    }
+}
@@ -0,0 +1,398 @@
+//! Hashing helpers keyed by UUIDs.
+//!
+//! These use fixed hash functions rather than [`std::collections::hash_map::DefaultHasher`],
+//! whose output may change between Rust releases, so every process in a
+//! distributed system computes the same result for the same UUID.
+//!
+//! [`Uuid::stable_hash64`] is available without the `std` feature, but
+//! [`rendezvous_pick`] needs it.
+
+#[cfg(feature = "std")]
+use crate::std::hash::Hasher;
+use crate::Uuid;
+
+impl Uuid {
+    /// Get a 64-bit hash of this UUID that never changes.
+    ///
+    /// The [`Hash`] implementation on `Uuid` feeds whichever hasher it's given,
+    /// and the hashers in the standard library are free to change between Rust
+    /// releases and are randomly keyed. This hash is a fixed function of the
+    /// UUID's bits instead, so it's the same on every machine, architecture,
+    /// and version of this library. It can be used to make routing or sharding
+    /// decisions that need to agree between processes.
+    ///
+    /// The hash is computed from the UUID's [`as_u64_pair`] as:
+    ///
+    /// ```text
+    /// fmix64(fmix64(high_bits ^ 0x9e3779b97f4a7c15) ^ low_bits)
+    /// ```
+    ///
+    /// where `fmix64` is the 64-bit finalizer from MurmurHash3:
+    ///
+    /// ```text
+    /// fn fmix64(mut h: u64) -> u64 {
+    ///     h ^= h >> 33;
+    ///     h = h.wrapping_mul(0xff51afd7ed558ccd);
+    ///     h ^= h >> 33;
+    ///     h = h.wrapping_mul(0xc4ceb9fe1a85ec53);
+    ///     h ^= h >> 33;
+    ///     h
+    /// }
+    /// ```
+    ///
+    /// This hash isn't keyed, so it shouldn't be used where an attacker can
+    /// choose UUIDs to cause collisions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+    ///
+    /// let shard = uuid.stable_hash64() % 16;
+    ///
+    /// assert_eq!(shard, uuid.stable_hash64() % 16);
+    /// ```
+    ///
+    /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
+    /// [`as_u64_pair`]: #method.as_u64_pair
+    pub const fn stable_hash64(&self) -> u64 {
+        let (high_bits, low_bits) = self.as_u64_pair();
+
+        mix64(mix64(high_bits ^ 0x9e37_79b9_7f4a_7c15) ^ low_bits)
+    }
+}
+
+/// Pick the node that owns a UUID using rendezvous hashing.
+///
+/// Rendezvous, or highest-random-weight, hashing scores every node against
+/// the UUID and picks the node with the highest score. Every process that
+/// picks from the same set of nodes picks the same owner, without needing to
+/// coordinate. When a node is added or removed, only the UUIDs owned by that
+/// node move, unlike picking with `hash % nodes.len()`.
+///
+/// Nodes are identified by their [`Hash`] implementation, so two nodes that
+/// hash the same, like two equal node names, always receive the same score.
+/// `weight_fn` returns the relative capacity of each node: a node with a weight
+/// of `2.0` will own about twice as many UUIDs as a node with a weight of
+/// `1.0`. Nodes with a weight that isn't positive are never picked.
+///
+/// Returns `None` if there are no nodes that can be picked.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, hash::rendezvous_pick};
+/// let nodes = ["cache-a", "cache-b", "cache-c"];
+///
+/// let id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+///
+/// let owner = rendezvous_pick(id, &nodes, |_| 1.0).unwrap();
+///
+/// // Picking again always gives the same owner
+/// assert_eq!(owner, rendezvous_pick(id, &nodes, |_| 1.0).unwrap());
+///
+/// // Removing a node that doesn't own the UUID doesn't change its owner
+/// let other = *nodes.iter().find(|node| *node != owner).unwrap();
+/// let remaining: Vec<&str> = nodes.iter().copied().filter(|node| *node != other).collect();
+///
+/// assert_eq!(owner, rendezvous_pick(id, &remaining, |_| 1.0).unwrap());
+/// ```
+#[cfg(feature = "std")]
+pub fn rendezvous_pick<N, F>(id: Uuid, nodes: &[N], mut weight_fn: F) -> Option<&N>
+where
+    N: crate::std::hash::Hash,
+    F: FnMut(&N) -> f64,
+{
+    let (high_bits, low_bits) = id.as_u64_pair();
+
+    let mut picked = None;
+    let mut best_score = 0.0;
+
+    for node in nodes {
+        let weight = weight_fn(node);
+
+        // Nodes without capacity are never picked
+        if weight.is_nan() || weight <= 0.0 {
+            continue;
+        }
+
+        let mut hasher = Fnv1a::default();
+        node.hash(&mut hasher);
+
+        let hash = mix64(mix64(high_bits ^ hasher.finish()) ^ low_bits);
+
+        // Map the top 53 bits of the hash into (0, 1), and scale them by the
+        // weight so that the node with the highest score is picked in
+        // proportion to its weight
+        let unit = ((hash >> 11) as f64 + 0.5) / (1u64 << 53) as f64;
+        let score = weight / -unit.ln();
+
+        if picked.is_none() || score > best_score {
+            picked = Some(node);
+            best_score = score;
+        }
+    }
+
+    picked
+}
+
+// The finalizer from MurmurHash3, which mixes every input bit into every
+// output bit
+pub(crate) const fn mix64(mut hash: u64) -> u64 {
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    hash ^= hash >> 33;
+
+    hash
+}
+
+// A small, fast generator that's good enough for simulations and tests
+//
+// See: https://prng.di.unimi.it/splitmix64.c
+#[cfg(any(feature = "simulation", test))]
+#[allow(dead_code)]
+pub(crate) struct SplitMix64(pub(crate) u64);
+
+#[cfg(any(feature = "simulation", test))]
+#[allow(dead_code)]
+impl SplitMix64 {
+    pub(crate) const GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    pub(crate) fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(Self::GAMMA);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    pub(crate) fn bytes<const N: usize>(&mut self) -> [u8; N] {
+        let mut bytes = [0; N];
+
+        for chunk in bytes.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next().to_le_bytes()[..chunk.len()]);
+        }
+
+        bytes
+    }
+}
+
+// The 64-bit FNV-1a hash, used to turn nodes into stable integers.
+// Integers are always hashed as little-endian, and `usize` and `isize` as
+// 64 bits, so nodes hash the same on every platform
+#[cfg(feature = "std")]
+struct Fnv1a(u64);
+
+#[cfg(feature = "std")]
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Hasher for Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ *byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16);
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32);
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128);
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_u64(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    use crate::std::vec::Vec;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_stable_hash64() {
+        // Changing these would break routing decisions made by older versions
+        assert_eq!(0x6393_d51c_06c6_18dc, Uuid::nil().stable_hash64());
+        assert_eq!(0xa055_e88e_d5c9_0f86, Uuid::max().stable_hash64());
+        assert_eq!(
+            0xd009_6811_91e9_8d89,
+            Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8).stable_hash64()
+        );
+
+        const HASH: u64 = Uuid::nil().stable_hash64();
+        assert_eq!(HASH, Uuid::nil().stable_hash64());
+    }
+
+    #[cfg(feature = "std")]
+    fn ids(len: u64) -> impl Iterator<Item = Uuid> {
+        (0..len).map(|i| {
+            crate::Builder::from_random_bytes(
+                ((mix64(i) as u128) << 64 | mix64(!i) as u128).to_be_bytes(),
+            )
+            .into_uuid()
+        })
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_rendezvous_pick_empty() {
+        let id = crate::tests::new();
+
+        assert_eq!(None, rendezvous_pick::<u32, _>(id, &[], |_| 1.0));
+        assert_eq!(None, rendezvous_pick(id, &[1, 2], |_| 0.0));
+        assert_eq!(None, rendezvous_pick(id, &[1, 2], |_| f64::NAN));
+        assert_eq!(Some(&2), rendezvous_pick(id, &[1, 2], |n| (*n - 1) as f64));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_rendezvous_pick_stable() {
+        // Changing these would move keys between nodes in deployed systems
+        let nodes = ["a", "b", "c", "d"];
+        let picked: Vec<&str> = ids(8)
+            .map(|id| *rendezvous_pick(id, &nodes, |_| 1.0).unwrap())
+            .collect();
+
+        assert_eq!(&["a", "d", "b", "a", "a", "d", "b", "d"], &picked[..]);
+
+        // Integers hash the same on every platform
+        let nodes = [1u64, 2, 3, 4];
+        let picked: Vec<u64> = ids(8)
+            .map(|id| *rendezvous_pick(id, &nodes, |_| 1.0).unwrap())
+            .collect();
+
+        assert_eq!(&[3, 1, 2, 3, 2, 1, 1, 1], &picked[..]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_rendezvous_pick_distribution() {
+        let nodes = [0usize, 1, 2, 3];
+        let weights = [1.0, 1.0, 2.0, 4.0];
+
+        let mut counts = [0; 4];
+        for id in ids(8_000) {
+            counts[*rendezvous_pick(id, &nodes, |n| weights[*n]).unwrap()] += 1;
+        }
+
+        // Expect 1000, 1000, 2000, and 4000
+        for (count, weight) in counts.iter().zip(weights.iter()) {
+            let expected = 1_000.0 * weight;
+
+            assert!(
+                (*count as f64 - expected).abs() < expected * 0.1,
+                "{:?}",
+                counts
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_rendezvous_pick_minimal_disruption() {
+        let nodes = [0u32, 1, 2, 3, 4];
+        let fewer = [0u32, 1, 3, 4];
+
+        for id in ids(1_000) {
+            let before = *rendezvous_pick(id, &nodes, |_| 1.0).unwrap();
+            let after = *rendezvous_pick(id, &fewer, |_| 1.0).unwrap();
+
+            // Only UUIDs owned by the removed node move
+            if before != 2 {
+                assert_eq!(before, after);
+            }
+        }
+    }
+}
@@ -0,0 +1,365 @@
+//! Hybrid logical clock UUIDs.
+//!
+//! A hybrid logical clock (HLC) combines a physical timestamp with a logical
+//! counter, so that timestamps stay close to wall-clock time but still respect
+//! causality: if one event happened before another, even on a different
+//! machine whose clock is behind, its timestamp is smaller. This module
+//! encodes HLC timestamps into version 8 UUIDs, so an identifier and its
+//! causal ordering travel together.
+//!
+//! Each UUID contains a [`Timestamp`] made of:
+//!
+//! * The number of milliseconds since the Unix epoch, in the first 48 bits,
+//!   like a version 7 UUID.
+//! * A 16 bit logical counter.
+//! * A 58 bit node ID identifying the [`Generator`] that produced it.
+//!
+//! UUIDs sort by their physical time, then counter, then node.
+//!
+//! Note that you need to enable the `v8` Cargo feature
+//! in order to use this module.
+//!
+//! # References
+//!
+//! * [Logical Physical Clocks and Consistent Snapshots in Globally Distributed Databases](https://cse.buffalo.edu/tech-reports/2014-04.pdf)
+
+use crate::{v8::V8Payload, Error, Uuid};
+
+/// The largest node ID that fits in an HLC UUID.
+pub const MAX_NODE: u64 = (1 << 58) - 1;
+
+/// A hybrid logical clock timestamp, as encoded in a version 8 UUID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp {
+    millis: u64,
+    counter: u16,
+    node: u64,
+}
+
+impl Timestamp {
+    /// Create a timestamp from its parts.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `millis` doesn't fit in 48 bits, or if `node`
+    /// is greater than [`MAX_NODE`].
+    pub const fn new(millis: u64, counter: u16, node: u64) -> Self {
+        assert!(
+            millis >> 48 == 0,
+            "an HLC timestamp can't fit more than 48 bits of milliseconds"
+        );
+        assert!(node <= MAX_NODE, "an HLC node can't fit more than 58 bits");
+
+        Timestamp {
+            millis,
+            counter,
+            node,
+        }
+    }
+
+    /// The physical part of the timestamp, in milliseconds since the Unix epoch.
+    pub const fn millis(&self) -> u64 {
+        self.millis
+    }
+
+    /// The logical part of the timestamp, which orders events within the
+    /// same millisecond.
+    pub const fn counter(&self) -> u16 {
+        self.counter
+    }
+
+    /// The ID of the node that produced the timestamp.
+    pub const fn node(&self) -> u64 {
+        self.node
+    }
+}
+
+impl V8Payload for Timestamp {
+    fn to_payload_bits(&self) -> u128 {
+        ((self.millis as u128) << 74) | ((self.counter as u128) << 58) | self.node as u128
+    }
+
+    fn from_payload_bits(bits: u128) -> Option<Self> {
+        Some(Timestamp {
+            millis: (bits >> 74) as u64 & 0xffff_ffff_ffff,
+            counter: (bits >> 58) as u16,
+            node: bits as u64 & MAX_NODE,
+        })
+    }
+}
+
+/// A generator for hybrid logical clock UUIDs.
+///
+/// Each UUID the generator produces has a larger timestamp than the ones it
+/// produced before, and than any remote UUID passed to [`Generator::observe`].
+/// Send UUIDs from [`Generator::generate`] along with messages, and observe
+/// them when they're received, so UUIDs for later events always sort after
+/// UUIDs for the events that caused them.
+///
+/// If the counter overflows within a millisecond, the physical time is moved
+/// forward by one millisecond to keep timestamps increasing.
+///
+/// A `Generator` isn't synchronized. Wrap it in a `Mutex` to share it between threads.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::hlc::Generator;
+/// # fn main() -> Result<(), uuid::Error> {
+/// // Node 2's clock is behind node 1's
+/// let mut node1 = Generator::with_clock(1, || 1_645_557_742_000);
+/// let mut node2 = Generator::with_clock(2, || 1_645_557_741_000);
+///
+/// let sent = node1.generate();
+///
+/// node2.observe(sent)?;
+/// let reply = node2.generate();
+///
+/// assert!(sent < reply);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Generator<C = fn() -> u64> {
+    clock: C,
+    last: Timestamp,
+}
+
+#[cfg(feature = "std")]
+impl Generator {
+    /// Create a generator for the given node that uses the system clock.
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `node` is greater than [`MAX_NODE`].
+    pub fn new(node: u64) -> Self {
+        fn system_millis() -> u64 {
+            let (seconds, subsec_nanos) = crate::timestamp::now();
+
+            (seconds * 1_000).saturating_add(subsec_nanos as u64 / 1_000_000)
+        }
+
+        Generator::with_clock(node, system_millis)
+    }
+}
+
+impl<C: FnMut() -> u64> Generator<C> {
+    /// Create a generator for the given node that reads the number of
+    /// milliseconds since the Unix epoch from `clock`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `node` is greater than [`MAX_NODE`].
+    pub fn with_clock(node: u64, clock: C) -> Self {
+        Generator {
+            clock,
+            last: Timestamp::new(0, 0, node),
+        }
+    }
+
+    /// The timestamp of the last UUID generated or observed.
+    pub fn last(&self) -> Timestamp {
+        self.last
+    }
+
+    /// Generate a UUID for a local event, or for sending a message.
+    pub fn generate(&mut self) -> Uuid {
+        let now = self.now();
+
+        if now > self.last.millis {
+            self.set(now, 0);
+        } else {
+            self.set(self.last.millis, self.last.counter as u32 + 1);
+        }
+
+        let uuid = self.last.to_uuid();
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::Custom);
+
+        uuid
+    }
+
+    /// Merge the timestamp of a UUID received from another node.
+    ///
+    /// UUIDs generated after this call sort after `remote`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if `remote` isn't a version 8 UUID.
+    pub fn observe(&mut self, remote: Uuid) -> Result<(), Error> {
+        let remote = Timestamp::decode(remote)?;
+
+        let now = self.now();
+        let millis = now.max(self.last.millis).max(remote.millis);
+
+        let counter = if millis == self.last.millis && millis == remote.millis {
+            self.last.counter.max(remote.counter) as u32 + 1
+        } else if millis == self.last.millis {
+            self.last.counter as u32 + 1
+        } else if millis == remote.millis {
+            remote.counter as u32 + 1
+        } else {
+            0
+        };
+
+        self.set(millis, counter);
+
+        Ok(())
+    }
+
+    fn now(&mut self) -> u64 {
+        (self.clock)() & 0xffff_ffff_ffff
+    }
+
+    fn set(&mut self, millis: u64, counter: u32) {
+        self.last = if counter > u16::MAX as u32 {
+            Timestamp::new((millis + 1) & 0xffff_ffff_ffff, 0, self.last.node)
+        } else {
+            Timestamp::new(millis, counter as u16, self.last.node)
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{Variant, Version};
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_generate() {
+        let mut generator = Generator::with_clock(7, || 1_645_557_742_000);
+
+        let uuid1 = generator.generate();
+        let uuid2 = generator.generate();
+
+        assert_eq!(Some(Version::Custom), uuid1.get_version());
+        assert_eq!(Variant::RFC4122, uuid1.get_variant());
+        assert!(format!("{}", uuid1).starts_with("017f22e2-79b0-8"));
+
+        let ts = Timestamp::decode(uuid2).unwrap();
+        assert_eq!(1_645_557_742_000, ts.millis());
+        assert_eq!(1, ts.counter());
+        assert_eq!(7, ts.node());
+
+        assert!(uuid1 < uuid2);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_clock_backwards() {
+        // The clock goes back a second every time it's read
+        let mut millis = 1_645_557_743_000;
+        let mut generator = Generator::with_clock(1, move || {
+            millis -= 1_000;
+            millis
+        });
+
+        let uuid1 = generator.generate();
+        let uuid2 = generator.generate();
+
+        assert!(uuid1 < uuid2);
+        assert_eq!(
+            Timestamp::new(1_645_557_742_000, 1, 1),
+            Timestamp::decode(uuid2).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_observe() {
+        let mut local = Generator::with_clock(1, || 1_645_557_741_000);
+
+        let remote = Timestamp::new(1_645_557_742_000, 5, 2).to_uuid();
+        local.observe(remote).unwrap();
+
+        assert_eq!(Timestamp::new(1_645_557_742_000, 6, 1), local.last());
+
+        let uuid = local.generate();
+
+        assert!(remote < uuid);
+        assert_eq!(
+            Timestamp::new(1_645_557_742_000, 7, 1),
+            Timestamp::decode(uuid).unwrap()
+        );
+
+        // An older remote timestamp only bumps the counter
+        local
+            .observe(Timestamp::new(1_645_557_740_000, 100, 3).to_uuid())
+            .unwrap();
+        assert_eq!(Timestamp::new(1_645_557_742_000, 8, 1), local.last());
+
+        assert!(local.observe(Uuid::nil()).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_counter_overflow() {
+        let mut generator = Generator::with_clock(1, || 1_645_557_742_000);
+
+        generator
+            .observe(Timestamp::new(1_645_557_742_000, u16::MAX, 2).to_uuid())
+            .unwrap();
+
+        assert_eq!(Timestamp::new(1_645_557_742_001, 0, 1), generator.last());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_timestamp_round_trip() {
+        let ts = Timestamp::new(0xffff_ffff_ffff, u16::MAX, MAX_NODE);
+
+        assert_eq!(ts, Timestamp::decode(ts.to_uuid()).unwrap());
+        assert_eq!(
+            Timestamp::new(0, 0, 0),
+            Timestamp::decode(Timestamp::new(0, 0, 0).to_uuid()).unwrap()
+        );
+    }
+}
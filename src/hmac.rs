@@ -0,0 +1,15 @@
+#[cfg(feature = "hmac")]
+pub(crate) fn hash(key: &[u8], data: &[u8]) -> [u8; 16] {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    // HMAC accepts keys of any length, so this can't fail
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC can take a key of any size");
+
+    mac.update(data);
+
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(&mac.finalize().into_bytes()[..16]);
+
+    bytes
+}
@@ -103,14 +103,88 @@
 //! * `macro-diagnostics` - enhances the diagnostics of `uuid!` macro.
 //! * `serde` - adds the ability to serialize and deserialize a UUID using
 //!   `serde`.
+//! * `rmp-serde` - adds a `serde::msgpack` adapter that writes a UUID as a
+//!   MessagePack extension type, like MessagePack libraries in other languages.
 //! * `borsh` - adds the ability to serialize and deserialize a UUID using
 //!   `borsh`.
+//! * `sea-orm` - adds trait implementations so `Uuid` can be used directly in
+//!   SeaORM entities. UUIDs are stored as their 16 bytes in a binary column.
+//! * `sqlx-postgres`, `sqlx-mysql`, and `sqlx-sqlite` - add `sqlx` trait implementations
+//!   so `Uuid` can be bound and decoded directly with each database.
+//! * `rocket` - adds `FromParam` and `FromFormField` implementations so `Uuid` and
+//!   `NonNilUuid` can be used directly in Rocket routes and forms.
 //! * `arbitrary` - adds an `Arbitrary` trait implementation to `Uuid` for
 //!   fuzzing.
+//! * `fake` - adds `Dummy` implementations so `Uuid` and `NonNilUuid` fields
+//!   can be populated by the `fake` crate, including version 7 UUIDs within a
+//!   time range using `fake::UuidV7Between`.
+//! * `bytes` - adds `Uuid::get_from` and `Uuid::put_to` for reading and writing
+//!   UUIDs directly with the `bytes` crate's `Buf` and `BufMut`.
+//! * `embassy` - adds a `v7::EmbassyClock` that reads `embassy_time::Instant`, so
+//!   embedded firmware can generate version 7 UUIDs with a `v7::Generator`
+//!   without the standard library.
+//! * `clap` - adds a `UuidValueParser` for `clap` arguments, with readable errors
+//!   and optional version restrictions.
+//! * `parquet` - adds `Uuid::parquet_type` for declaring `FIXED_LEN_BYTE_ARRAY(16)`
+//!   columns with the `UUID` logical type, and conversions between UUIDs and the
+//!   values of those columns.
+//! * `rayon` - adds a `parallel` module for generating large batches of version 4
+//!   and ordered version 7 UUIDs across `rayon`'s thread pool. This needs the `v4`
+//!   or `v7` feature too.
+//! * `bip39` - adds `Uuid::to_mnemonic` and `Uuid::from_mnemonic` for encoding
+//!   UUIDs as 12 BIP39 words, for recovery codes and reading UUIDs out loud.
+//! * `observe` - adds an `observe` module for setting a callback that's invoked
+//!   with every UUID this library generates, for auditing and metrics.
+//! * `simulation` - adds a `simulation` module that routes version 4 and 7
+//!   generation through a seedable, recordable source of randomness and a
+//!   simulated clock, for deterministic simulation testing.
+//! * `ulid` - adds a `ulid` module with a `Ulid` type that converts losslessly
+//!   to and from `Uuid`, and parses and formats ULID strings.
+//! * `bincode` - adds `bincode` 2's `Encode` and `Decode` trait implementations
+//!   to `Uuid` and `NonNilUuid`, which write UUIDs as their 16 raw bytes without
+//!   going through `serde`.
+//! * `scale` - adds `parity-scale-codec`'s `Encode`, `Decode`, and `MaxEncodedLen`
+//!   trait implementations, and `scale-info`'s `TypeInfo`, to `Uuid` and
+//!   `NonNilUuid`, so Substrate runtimes can store UUIDs as 16 fixed bytes.
+//! * `scylla` - adds the Scylla driver's `SerializeValue` and `DeserializeValue`
+//!   trait implementations to `Uuid` and `NonNilUuid` for `uuid` and `timeuuid`
+//!   columns, and conversions between `Uuid` and `CqlTimeuuid`.
+//! * `ufmt` - adds `ufmt`'s `uDisplay` and `uDebug` trait implementations to
+//!   `Uuid`, and `uDisplay` to `NonNilUuid` and the formatting adapters, so
+//!   firmware using `ufmt` can print UUIDs without pulling in `core::fmt`.
 //! * `fast-rng` - uses a faster algorithm for generating random UUIDs.
 //!   This feature requires more dependencies to compile, but is just as suitable for
 //!   UUIDs as the default algorithm.
+//! * `rng-getrandom03` - enables generating version 4 and 7 UUIDs like the `v4` and
+//!   `v7` features, but uses `getrandom` 0.3 instead of 0.2 as the source of
+//!   randomness, so it picks up the backend configured for that version. Enable it
+//!   in place of `v4` and `v7`, which always compile `getrandom` 0.2.
+//! * `fast-rng-rand09` - like `fast-rng`, but uses `rand` 0.9 instead of 0.8. This
+//!   also enables `rng-getrandom03`, so enable it in place of `v4` and `v7` too.
+//! * `rng-custom` - enables generating version 4 and 7 UUIDs like the `v4` and `v7`
+//!   features, but without depending on `getrandom`. Random bytes are only read from
+//!   the source set with `entropy::set_random_source`, so this can be used on
+//!   bare-metal targets that `getrandom` doesn't support.
 //! * `bytemuck` - adds a `Pod` trait implementation to `Uuid` for byte manipulation
+//! * `hmac` - adds `Uuid::new_v8_hmac` for generating keyed version 8 UUIDs
+//!   using HMAC-SHA-256.
+//! * `siphash` - adds `Uuid::new_v8_siphash` for quickly generating non-cryptographic
+//!   version 8 UUIDs using SipHash.
+//! * `digest` - adds `Uuid::new_name_based` for generating name-based version 8 UUIDs
+//!   using any hash that implements `digest::Digest`.
+//! * `tokio` - adds an `AsyncGenerator` that generates random UUIDs on a
+//!   background task, so async code never blocks waiting for entropy.
+//! * `ffi` - adds a C ABI in the `ffi` module for generating, parsing, and
+//!   formatting UUIDs from other languages.
+//! * `forbid-unsafe` - compiles this crate with `#![forbid(unsafe_code)]`. Methods
+//!   that borrow a `Uuid` as something else without copying it, like
+//!   `Uuid::from_bytes_ref` and `Uuid::as_hyphenated`, are implemented with
+//!   `bytemuck` instead. `NonNilUuid::new_unchecked` isn't available, and this
+//!   feature can't be enabled together with `ffi`.
+//! * `core-error` - implements `core::error::Error` for [`Error`] without `std`.
+//!   This requires Rust 1.81 or newer, above this crate's minimum supported version.
+//! * `cli` - builds a `uuid` binary for generating, inspecting, and converting
+//!   UUIDs from the command line.
 //!
 //! # Unstable features
 //!
@@ -146,6 +220,21 @@
 //! ]
 //! ```
 //!
+//! The `js` feature is only needed in the browser or Node.js. On WASI targets,
+//! like `wasm32-wasip1` and `wasm32-wasip2`, `Uuid::new_v4` and `Uuid::now_v7`
+//! work without any extra features, using WASI's `random_get` and the clock
+//! from the standard library. To use WASI 0.2's own random interface on
+//! `wasm32-wasip2` instead of the WASI 0.1 one, enable the `rng-getrandom03` feature
+//! in place of `v4` and `v7`:
+//!
+//! ```toml
+//! [dependencies.uuid]
+//! version = "1.12.0"
+//! features = [
+//!     "rng-getrandom03",
+//! ]
+//! ```
+//!
 //! ## Embedded
 //!
 //! For embedded targets without the standard library, you'll need to
@@ -166,7 +255,50 @@
 //! follow [`getrandom`'s docs] on configuring a source of randomness
 //! on currently unsupported targets. Alternatively, you can produce
 //! random bytes yourself and then pass them to [`Builder::from_random_bytes`]
-//! without enabling the `v4` or `v7` features.
+//! without enabling the `v4` or `v7` features. If your firmware has its own
+//! hardware RNG, you can also pass it as an [`EntropySource`] to
+//! [`Builder::random_with`] or `v7::Generator::with_clock_and_entropy`.
+//! To use it for every UUID generated with `v4` or `v7` instead, install it
+//! once at startup with `entropy::set_random_source`, and enable the `rng-custom`
+//! feature in place of `v4` and `v7` so `getrandom` isn't compiled at all.
+//!
+//! On Rust 1.81 and newer, enabling the `core-error` feature also implements
+//! `core::error::Error` for [`Error`] when the `std` feature is disabled.
+//!
+//! ### Without `alloc`
+//!
+//! With default features disabled, `uuid` doesn't depend on `alloc` at all, so it can
+//! be used in environments like kernels that don't have a global allocator:
+//!
+//! * Parsing works on borrowed input with [`Uuid::try_parse`] and [`Uuid::parse_str`].
+//! * Formatting writes into caller-provided buffers with methods like
+//!   [`fmt::Hyphenated::encode_lower`], or through [`core::fmt::Write`].
+//! * [`Error`] doesn't own any heap-allocated data.
+//! * UUIDs can be generated from entropy you supply yourself using the
+//!   [`Builder`], or by the `v1`, `v3`, `v5`, `v6`, and `v8` features.
+//!
+//! The `serde` feature is also `alloc`-free. Other features, like `borsh`, may
+//! depend on `alloc` through their own dependencies.
+//!
+//! ## Without panics
+//!
+//! Some environments can't tolerate panics at all. Parsing with [`Uuid::try_parse`]
+//! and formatting with [`core::fmt`] never panic. For the rest of the API,
+//! there are fallible alternatives to methods that could panic:
+//!
+//! * Formatting into a buffer with methods like [`fmt::Hyphenated::encode_lower_checked`]
+//!   returns an error if the buffer is too small.
+//! * Generating a version 4 UUID with `Uuid::try_new_v4` returns an error if the source
+//!   of randomness fails.
+//! * Setting individual bytes, nibbles, or bits with [`Builder::with_byte_checked`],
+//!   [`Builder::with_nibble_checked`], and [`Builder::with_bits_checked`] returns
+//!   `None` if the position is out of range. The rest of the [`Builder`] can't
+//!   panic, so UUIDs can be generated from a clock and entropy you supply yourself.
+//!
+//! These functions are checked with the `no-panic` crate in `tests/no-panic`.
+//! `Uuid::try_now_v7` also returns an error instead of panicking if the system clock
+//! or source of randomness fails, but it goes through the standard library's clock
+//! and locks, so it isn't covered by that check.
 //!
 //! # Examples
 //!
@@ -206,7 +338,9 @@
 //! [`getrandom`'s docs]: https://docs.rs/getrandom
 
 #![no_std]
+#![cfg_attr(uuid_step_trait, feature(step_trait))]
 #![deny(missing_debug_implementations, missing_docs)]
+#![cfg_attr(feature = "forbid-unsafe", forbid(unsafe_code))]
 #![allow(clippy::mixed_attributes_style)]
 #![doc(
     html_logo_url = "https://www.rust-lang.org/logos/rust-logo-128x128-blk-v2.png",
@@ -222,20 +356,46 @@ extern crate std;
 #[macro_use]
 extern crate core as std;
 
+#[cfg(all(feature = "forbid-unsafe", feature = "ffi"))]
+compile_error!(
+    "the `ffi` feature exports unsafe functions, so it can't be enabled with `forbid-unsafe`"
+);
+
 mod builder;
 mod error;
+mod namespace;
 mod non_nil;
 mod parser;
+mod scan;
 
+pub mod codec;
+#[cfg(feature = "std")]
+pub mod collections;
+pub mod describe;
+pub mod entropy;
 pub mod fmt;
+pub mod hash;
+#[cfg(feature = "v8")]
+pub mod hlc;
+#[cfg(feature = "observe")]
+pub mod observe;
+pub mod range;
+#[cfg(feature = "simulation")]
+pub mod simulation;
+#[cfg(feature = "std")]
+pub mod sort;
 pub mod timestamp;
+pub mod trace;
+#[cfg(feature = "ulid")]
+pub mod ulid;
+pub mod validate;
 
 pub use timestamp::{context::NoContext, ClockSequence, Timestamp};
 
 #[cfg(any(feature = "v1", feature = "v6"))]
 pub use timestamp::context::Context;
 
-#[cfg(feature = "v7")]
+#[cfg(any(feature = "v7", feature = "rng-custom"))]
 pub use timestamp::context::ContextV7;
 
 #[cfg(feature = "v1")]
@@ -245,26 +405,33 @@ pub use timestamp::context::ContextV7;
 pub mod v1;
 #[cfg(feature = "v3")]
 mod v3;
-#[cfg(feature = "v4")]
+#[cfg(any(feature = "v4", feature = "rng-custom"))]
 mod v4;
 #[cfg(feature = "v5")]
 mod v5;
 #[cfg(feature = "v6")]
 mod v6;
-#[cfg(feature = "v7")]
-mod v7;
+#[cfg(any(feature = "v7", feature = "rng-custom"))]
+pub mod v7;
 #[cfg(feature = "v8")]
-mod v8;
+pub mod v8;
 
+#[cfg(feature = "hmac")]
+mod hmac;
 #[cfg(feature = "md5")]
 mod md5;
-#[cfg(feature = "rng")]
+#[cfg(any(feature = "rng", feature = "rng-custom"))]
 mod rng;
 #[cfg(feature = "sha1")]
 mod sha1;
+#[cfg(feature = "siphash")]
+mod siphash;
 
 mod external;
 
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
 #[macro_use]
 mod macros;
 
@@ -279,7 +446,27 @@ pub mod __macro_support {
 
 use crate::std::convert;
 
-pub use crate::{builder::Builder, error::Error, non_nil::NonNilUuid};
+pub use crate::{
+    builder::Builder,
+    entropy::EntropySource,
+    error::Error,
+    namespace::Namespace,
+    non_nil::NonNilUuid,
+    parser::{ParseMany, ParseOptions},
+    scan::{scan, Scan},
+};
+
+#[cfg(feature = "clap")]
+pub use crate::external::clap_support::UuidValueParser;
+
+#[cfg(all(
+    feature = "rayon",
+    any(feature = "v4", feature = "v7", feature = "rng-custom")
+))]
+pub use crate::external::rayon_support as parallel;
+
+#[cfg(feature = "tokio")]
+pub use crate::external::tokio_support::AsyncGenerator;
 
 /// A 128-bit (16 byte) buffer containing the UUID.
 ///
@@ -293,7 +480,7 @@ pub type Bytes = [u8; 16];
 /// # References
 ///
 /// * [Version Field in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-4.2)
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum Version {
@@ -319,6 +506,56 @@ pub enum Version {
     Max = 0xff,
 }
 
+/// The version of a UUID, including version numbers that aren't recognized.
+///
+/// [`Version`] only covers the versions defined by RFC 9562, so
+/// [`Uuid::get_version`] returns `None` for a UUID minted by a newer
+/// specification. This type keeps the raw version number for those UUIDs
+/// instead, so tooling can still classify and pass them through.
+///
+/// This type is returned by [`Uuid::get_any_version`].
+///
+/// # References
+///
+/// * [Version Field in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-4.2)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AnyVersion {
+    /// A version recognized by this library.
+    Known(Version),
+    /// A version number that isn't recognized by this library.
+    ///
+    /// This includes a zero version number on a UUID that isn't nil.
+    NonStandard(u8),
+}
+
+impl AnyVersion {
+    /// The recognized version, if there is one.
+    pub const fn known(self) -> Option<Version> {
+        match self {
+            AnyVersion::Known(version) => Some(version),
+            AnyVersion::NonStandard(_) => None,
+        }
+    }
+
+    /// The value of the version field.
+    ///
+    /// This is the same as [`Uuid::get_version_num`], so it's `0xf` for
+    /// [`Version::Max`].
+    pub const fn num(self) -> u8 {
+        match self {
+            AnyVersion::Known(Version::Max) => 0xf,
+            AnyVersion::Known(version) => version as u8,
+            AnyVersion::NonStandard(num) => num,
+        }
+    }
+}
+
+impl From<Version> for AnyVersion {
+    fn from(version: Version) -> Self {
+        AnyVersion::Known(version)
+    }
+}
+
 /// The reserved variants of UUIDs.
 ///
 /// # References
@@ -433,6 +670,8 @@ pub enum Variant {
 /// # ABI
 ///
 /// The `Uuid` type is always guaranteed to be have the same ABI as [`Bytes`].
+/// That means slices of UUIDs and their bytes can be converted between each other
+/// without copying using [`Uuid::slice_from_bytes`] and [`Uuid::slice_as_bytes`].
 #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[repr(transparent)]
 // NOTE: Also check `NonNilUuid` when ading new derives here
@@ -450,6 +689,13 @@ pub enum Variant {
 )]
 pub struct Uuid(Bytes);
 
+// Unsafe code relies on `Uuid` being layout-compatible with `Bytes`,
+// including when reinterpreting slices of them
+const _: () = {
+    assert!(std::mem::size_of::<Uuid>() == std::mem::size_of::<Bytes>());
+    assert!(std::mem::align_of::<Uuid>() == std::mem::align_of::<Bytes>());
+};
+
 impl Uuid {
     /// UUID namespace for Domain Name System (DNS).
     pub const NAMESPACE_DNS: Self = Uuid([
@@ -579,6 +825,83 @@ impl Uuid {
         }
     }
 
+    /// Returns the version of the UUID, even if it isn't recognized.
+    ///
+    /// This is like [`Uuid::get_version`], but returns
+    /// [`AnyVersion::NonStandard`] with the raw version number instead of
+    /// `None` when the version field doesn't contain a recognized version.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{AnyVersion, Uuid, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let my_uuid = Uuid::parse_str("02f09a3f-1624-3b1d-8409-44eff7708208")?;
+    ///
+    /// assert_eq!(AnyVersion::Known(Version::Md5), my_uuid.get_any_version());
+    ///
+    /// let future_uuid = Uuid::parse_str("02f09a3f-1624-9b1d-8409-44eff7708208")?;
+    ///
+    /// assert_eq!(None, future_uuid.get_version());
+    /// assert_eq!(AnyVersion::NonStandard(9), future_uuid.get_any_version());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Version Field in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-4.2)
+    pub const fn get_any_version(&self) -> AnyVersion {
+        match self.get_version() {
+            Some(version) => AnyVersion::Known(version),
+            None => AnyVersion::NonStandard(self.get_version_num() as u8),
+        }
+    }
+
+    /// Returns the version of the UUID, taking its variant into account.
+    ///
+    /// The version field is only defined for the RFC 9562 variant.
+    /// [`Uuid::get_version`] reads the version bits regardless of the variant,
+    /// so a legacy NCS or Microsoft GUID can appear to have a version that it
+    /// was never generated with. This method returns `None` for those variants
+    /// instead, except for the nil and max UUIDs, which have their own special
+    /// versions.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Variant, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// // A legacy Microsoft GUID whose version bits happen to be `1`
+    /// let guid = Uuid::parse_str("00000000-0000-1000-c000-000000000046")?;
+    ///
+    /// assert_eq!(Variant::Microsoft, guid.get_variant());
+    /// assert_eq!(Some(Version::Mac), guid.get_version());
+    /// assert_eq!(None, guid.get_version_for_variant());
+    ///
+    /// let uuid = Uuid::parse_str("02f09a3f-1624-3b1d-8409-44eff7708208")?;
+    ///
+    /// assert_eq!(Some(Version::Md5), uuid.get_version_for_variant());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Variant Field in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-4.1)
+    pub const fn get_version_for_variant(&self) -> Option<Version> {
+        match self.get_variant() {
+            Variant::RFC4122 => self.get_version(),
+            _ if self.is_nil() => Some(Version::Nil),
+            _ if self.is_max() => Some(Version::Max),
+            _ => None,
+        }
+    }
+
     /// Returns the four field values of the UUID.
     ///
     /// These values can be passed to the [`Uuid::from_fields`] method to get
@@ -730,6 +1053,108 @@ impl Uuid {
         u128::from_le_bytes(*self.as_bytes())
     }
 
+    /// Returns a signed 128bit value containing the value.
+    ///
+    /// The bytes in the UUID are packed into a two's-complement big-endian
+    /// `i128`, so the most significant bit of the UUID is the sign bit. This
+    /// is the same as [`Uuid::as_u128`] converted with `as`, and the inverse
+    /// of [`Uuid::from_i128`]. UUIDs whose first hex digit is `8` to `f`
+    /// produce negative values.
+    ///
+    /// Note that ordering UUIDs by this value doesn't match their ordering as
+    /// [`Uuid`]s, because negative values sort before positive ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(
+    ///     uuid.to_i128(),
+    ///     -0x5e5d5c5b4e4d3e3d2e2d2c2b2a292828,
+    /// );
+    ///
+    /// assert_eq!(Uuid::max().to_i128(), -1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn to_i128(&self) -> i128 {
+        i128::from_be_bytes(*self.as_bytes())
+    }
+
+    /// Returns the 122 bits of the UUID that aren't used by its version or variant.
+    ///
+    /// The 4 version bits and 2 variant bits of the RFC 9562 layout are removed and
+    /// the remaining bits are packed into the least significant 122 bits of the
+    /// returned value, in their original order. The 6 most significant bits are
+    /// always zero.
+    ///
+    /// This is the inverse of [`Builder::from_payload_bits`], and is useful for
+    /// custom version 8 layouts that need to treat the rest of the UUID as a single
+    /// integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Uuid, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("ffffffff-ffff-8fff-bfff-ffffffffffff")?;
+    ///
+    /// assert_eq!((1 << 122) - 1, uuid.payload_bits());
+    ///
+    /// assert_eq!(
+    ///     uuid,
+    ///     Builder::from_payload_bits(Version::Custom, uuid.payload_bits()).into_uuid(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn payload_bits(&self) -> u128 {
+        let bits = self.as_u128();
+
+        ((bits >> 80) << 74) | (((bits >> 64) & 0xfff) << 62) | (bits & ((1 << 62) - 1))
+    }
+
+    /// Returns the three custom fields of a version 8 UUID defined by RFC 9562.
+    ///
+    /// The fields are `custom_a`, the 48 bits before the version field,
+    /// `custom_b`, the 12 bits between the version and variant fields, and
+    /// `custom_c`, the 62 bits after the variant field. The version and variant
+    /// bits themselves aren't included, so any unused high bits of the returned
+    /// values are always zero.
+    ///
+    /// This is the inverse of [`Builder::from_custom_fields`]. The version of
+    /// the UUID isn't checked, so this can also be used to read the same bits
+    /// out of other UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Builder;
+    /// let shard = 0x2a;
+    /// let tenant = 0x7f1;
+    /// let id = 0x0123_4567_89ab_cdef;
+    ///
+    /// let uuid = Builder::from_custom_fields(shard, tenant, id).into_uuid();
+    ///
+    /// assert_eq!((shard, tenant, id), uuid.as_custom_fields());
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [UUID Version 8 in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-5.8)
+    pub const fn as_custom_fields(&self) -> (u64, u16, u64) {
+        let bits = self.as_u128();
+
+        (
+            (bits >> 80) as u64,
+            ((bits >> 64) & 0xfff) as u16,
+            (bits & ((1 << 62) - 1)) as u64,
+        )
+    }
+
     /// Returns two 64bit values containing the value.
     ///
     /// The bytes in the UUID will be split into two `u64`.
@@ -754,6 +1179,106 @@ impl Uuid {
         ((value >> 64) as u64, value as u64)
     }
 
+    /// Returns two 64bit little-endian values containing the value.
+    ///
+    /// The bytes in each `u64` will be flipped to convert into little-endian
+    /// order. This is based on the endianness of the UUID, rather than the
+    /// target environment so bytes will be flipped on both big and little
+    /// endian machines.
+    ///
+    /// Note that this will produce a different result than
+    /// [`Uuid::to_u128_le`], because each half of the UUID is reversed
+    /// separately, rather than reversing the entire UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    /// assert_eq!(
+    ///     uuid.to_u64_pair_le(),
+    ///     (0xc2c1b2b1a4a3a2a1, 0xd8d7d6d5d4d3d2d1),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn to_u64_pair_le(&self) -> (u64, u64) {
+        let (high_bits, low_bits) = self.as_u64_pair();
+        (high_bits.swap_bytes(), low_bits.swap_bytes())
+    }
+
+    /// Returns a 64bit value derived by XOR-ing the two halves of the UUID.
+    ///
+    /// This is a cheap way to fit a UUID into a 64bit key, such as for bloom
+    /// filters, sampling, or storage that only has 64bit key slots. The value
+    /// is the XOR of the two values returned by [`Uuid::as_u64_pair`], and is
+    /// guaranteed not to change between releases or platforms.
+    ///
+    /// Different UUIDs can produce the same value, so it isn't a substitute
+    /// for the full UUID where uniqueness matters. For random UUIDs, the
+    /// version and variant bits are the same in every UUID, so the value has
+    /// 122 bits of the UUID's randomness folded into it, but only 64 bits of
+    /// output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(0x707070706464161a, uuid.xor_fold64());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn xor_fold64(&self) -> u64 {
+        let (high_bits, low_bits) = self.as_u64_pair();
+        high_bits ^ low_bits
+    }
+
+    /// Make a stable sampling decision for this UUID.
+    ///
+    /// Returns `true` if the UUID falls within the given `rate`, which is a
+    /// fraction between `0.0` (keep nothing) and `1.0` (keep everything). The
+    /// decision is based only on the UUID itself, so independent services sampling
+    /// the same ID, such as a trace or request ID, will agree on whether to keep it
+    /// without needing to share any state.
+    ///
+    /// The low 64 bits of the UUID are hashed before they're compared against the
+    /// `rate`. Those bits are random in version 4 UUIDs, but version 7 UUIDs generated
+    /// by this crate start them with a counter that increments within each
+    /// millisecond. Hashing means UUIDs generated in the same millisecond are still
+    /// kept or dropped independently of each other.
+    ///
+    /// A `rate` that's `NaN` or below `0.0` keeps nothing, and a `rate` above
+    /// `1.0` keeps everything. A UUID kept at some `rate` is also kept at any
+    /// higher `rate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-41c2-9000-00000000000a")?;
+    ///
+    /// assert!(uuid.sample(0.5));
+    /// assert!(!uuid.sample(0.25));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sample(&self, rate: f64) -> bool {
+        const RANGE: u64 = 1 << 62;
+
+        let (_, low_bits) = self.as_u64_pair();
+
+        // Float to int casts saturate, so out-of-range rates are clamped
+        // and `NaN` becomes `0`
+        let threshold = (rate * RANGE as f64) as u64;
+
+        (hash::mix64(low_bits) >> 2) < threshold
+    }
+
     /// Returns a slice of 16 octets containing the value.
     ///
     /// This method borrows the underlying byte value of the UUID.
@@ -785,6 +1310,56 @@ impl Uuid {
         &self.0
     }
 
+    /// Borrows a slice of UUIDs as a slice of their bytes without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuids = [Uuid::nil(), Uuid::max()];
+    ///
+    /// assert_eq!(&[[0; 16], [0xff; 16]], Uuid::slice_as_bytes(&uuids));
+    /// ```
+    #[inline]
+    pub fn slice_as_bytes(uuids: &[Uuid]) -> &[Bytes] {
+        #[cfg(not(feature = "forbid-unsafe"))]
+        {
+            // SAFETY: `Bytes` and `Uuid` have the same ABI
+            unsafe { std::slice::from_raw_parts(uuids.as_ptr() as *const Bytes, uuids.len()) }
+        }
+
+        #[cfg(feature = "forbid-unsafe")]
+        {
+            bytemuck::TransparentWrapper::peel_slice(uuids)
+        }
+    }
+
+    /// Borrows a mutable slice of UUIDs as a mutable slice of their bytes without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let mut uuids = [Uuid::nil(); 2];
+    ///
+    /// Uuid::slice_as_bytes_mut(&mut uuids)[0][15] = 1;
+    ///
+    /// assert_eq!(1, uuids[0].as_u128());
+    /// ```
+    #[inline]
+    pub fn slice_as_bytes_mut(uuids: &mut [Uuid]) -> &mut [Bytes] {
+        #[cfg(not(feature = "forbid-unsafe"))]
+        {
+            // SAFETY: `Bytes` and `Uuid` have the same ABI
+            unsafe { std::slice::from_raw_parts_mut(uuids.as_mut_ptr() as *mut Bytes, uuids.len()) }
+        }
+
+        #[cfg(feature = "forbid-unsafe")]
+        {
+            bytemuck::TransparentWrapper::peel_slice_mut(uuids)
+        }
+    }
+
     /// Consumes self and returns the underlying byte value of the UUID.
     ///
     /// # Examples
@@ -837,39 +1412,245 @@ impl Uuid {
         ]
     }
 
-    /// Tests if the UUID is nil (all zeros).
-    pub const fn is_nil(&self) -> bool {
-        self.as_u128() == u128::MIN
-    }
-
-    /// Tests if the UUID is max (all ones).
-    pub const fn is_max(&self) -> bool {
-        self.as_u128() == u128::MAX
-    }
-
-    /// A buffer that can be used for `encode_...` calls, that is
-    /// guaranteed to be long enough for any of the format adapters.
+    /// Returns the bytes of the UUID in the layout of a Microsoft GUID.
+    ///
+    /// This is the layout .NET's `new Guid(byte[])` expects and that ADO.NET
+    /// and ODBC use when a `uniqueidentifier` is written as a binary blob:
+    /// the first three fields are little-endian and the last 8 bytes are
+    /// unchanged. It's the same layout as [`Uuid::to_bytes_le`], named for
+    /// the interop it's used for. This is the inverse of
+    /// [`Uuid::from_guid_bytes`].
     ///
     /// # Examples
     ///
     /// ```
-    /// # use uuid::Uuid;
-    /// let uuid = Uuid::nil();
-    ///
-    /// assert_eq!(
-    ///     uuid.simple().encode_lower(&mut Uuid::encode_buffer()),
-    ///     "00000000000000000000000000000000"
-    /// );
+    /// use uuid::Uuid;
     ///
-    /// assert_eq!(
-    ///     uuid.hyphenated()
-    ///         .encode_lower(&mut Uuid::encode_buffer()),
-    ///     "00000000-0000-0000-0000-000000000000"
-    /// );
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
     ///
     /// assert_eq!(
-    ///     uuid.urn().encode_lower(&mut Uuid::encode_buffer()),
-    ///     "urn:uuid:00000000-0000-0000-0000-000000000000"
+    ///     uuid.to_guid_bytes(),
+    ///     ([
+    ///         0xa4, 0xa3, 0xa2, 0xa1, 0xb2, 0xb1, 0xc2, 0xc1, 0xd1, 0xd2,
+    ///         0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8
+    ///     ])
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn to_guid_bytes(&self) -> Bytes {
+        self.to_bytes_le()
+    }
+
+    /// Returns the bytes of the UUID in MySQL's time-swapped order.
+    ///
+    /// This is the layout produced by MySQL's `UUID_TO_BIN(uuid, 1)`. The
+    /// `time_hi_and_version` and `time_mid` fields are moved in front of
+    /// `time_low` so that version 1 UUIDs stored in a `BINARY(16)` column
+    /// are indexed in roughly time order. The remaining bytes are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("6ccd780c-baba-1026-9564-5b8c656024db")?;
+    ///
+    /// assert_eq!(
+    ///     uuid.to_mysql_bin_swapped(),
+    ///     ([
+    ///         0x10, 0x26, 0xba, 0xba, 0x6c, 0xcd, 0x78, 0x0c, 0x95, 0x64,
+    ///         0x5b, 0x8c, 0x65, 0x60, 0x24, 0xdb
+    ///     ])
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn to_mysql_bin_swapped(&self) -> Bytes {
+        [
+            self.0[6], self.0[7], self.0[4], self.0[5], self.0[0], self.0[1], self.0[2], self.0[3],
+            self.0[8], self.0[9], self.0[10], self.0[11], self.0[12], self.0[13], self.0[14],
+            self.0[15],
+        ]
+    }
+
+    /// Returns the two `UInt64` halves ClickHouse uses to store the UUID.
+    ///
+    /// ClickHouse's native and `RowBinary` formats write a UUID as two
+    /// little-endian `UInt64` values, the high 64 bits first. The returned
+    /// values should be written with the client's `UInt64` encoding. Writing
+    /// the UUID's bytes directly instead produces IDs with each half
+    /// byte-reversed. This is the inverse of [`Uuid::from_clickhouse_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(
+    ///     (0xa1a2a3a4b1b2c1c2, 0xd1d2d3d4d5d6d7d8),
+    ///     uuid.to_clickhouse_parts(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn to_clickhouse_parts(&self) -> (u64, u64) {
+        self.as_u64_pair()
+    }
+
+    /// Returns the bytes of the UUID in ClickHouse's wire order.
+    ///
+    /// These are the 16 bytes ClickHouse's native and `RowBinary` formats
+    /// use for a UUID: the high and low halves of the UUID, each as a
+    /// little-endian `UInt64`. This is the inverse of
+    /// [`Uuid::from_clickhouse_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(
+    ///     [
+    ///         0xc2, 0xc1, 0xb2, 0xb1, 0xa4, 0xa3, 0xa2, 0xa1, 0xd8, 0xd7,
+    ///         0xd6, 0xd5, 0xd4, 0xd3, 0xd2, 0xd1,
+    ///     ],
+    ///     uuid.to_clickhouse_bytes(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn to_clickhouse_bytes(&self) -> Bytes {
+        [
+            self.0[7], self.0[6], self.0[5], self.0[4], self.0[3], self.0[2], self.0[1], self.0[0],
+            self.0[15], self.0[14], self.0[13], self.0[12], self.0[11], self.0[10], self.0[9],
+            self.0[8],
+        ]
+    }
+
+    /// Compares two UUIDs the way SQL Server orders `uniqueidentifier` values.
+    ///
+    /// SQL Server doesn't sort GUIDs by their bytes from first to last. It
+    /// compares the last 6 bytes first, then the 2 bytes before them, and
+    /// then the remaining groups from right to left, with the first three
+    /// groups compared as little-endian integers. Using this comparison for
+    /// merge joins or keyset pagination in Rust gives the same order as an
+    /// `ORDER BY` over the same values in the database.
+    ///
+    /// The UUIDs are expected in their string representation, as returned by
+    /// [`Uuid::parse_str`] for the text SQL Server formats.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cmp::Ordering;
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let a = Uuid::parse_str("ffffffff-ffff-ffff-ffff-000000000000")?;
+    /// let b = Uuid::parse_str("00000000-0000-0000-0000-000000000001")?;
+    ///
+    /// assert_eq!(Ordering::Greater, a.cmp(&b));
+    /// assert_eq!(Ordering::Less, a.cmp_sqlserver(&b));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Comparing GUID and uniqueidentifier Values](https://learn.microsoft.com/en-us/sql/connect/ado-net/sql/compare-guid-uniqueidentifier-values)
+    pub fn cmp_sqlserver(&self, other: &Uuid) -> crate::std::cmp::Ordering {
+        self.sqlserver_order().cmp(&other.sqlserver_order())
+    }
+
+    /// Returns a key whose byte order matches SQL Server's `uniqueidentifier`
+    /// ordering.
+    ///
+    /// Comparing these keys with `memcmp`, or storing them in a byte-ordered
+    /// index like a B-tree or an LSM store, gives the same order as
+    /// [`Uuid::cmp_sqlserver`] and an `ORDER BY` in SQL Server. The key is
+    /// only meant for ordering; use [`Uuid::to_guid_bytes`] for the bytes
+    /// SQL Server clients actually exchange. This is the inverse of
+    /// [`Uuid::from_sqlserver_sort_key`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let a = Uuid::parse_str("ffffffff-ffff-ffff-ffff-000000000000")?;
+    /// let b = Uuid::parse_str("00000000-0000-0000-0000-000000000001")?;
+    ///
+    /// assert!(a.to_sqlserver_sort_key() < b.to_sqlserver_sort_key());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn to_sqlserver_sort_key(&self) -> Bytes {
+        self.sqlserver_order().to_be_bytes()
+    }
+
+    // The bytes of the UUID reordered from most to least significant in
+    // SQL Server's ordering
+    const fn sqlserver_order(&self) -> u128 {
+        u128::from_be_bytes([
+            self.0[10], self.0[11], self.0[12], self.0[13], self.0[14], self.0[15], self.0[8],
+            self.0[9], self.0[7], self.0[6], self.0[5], self.0[4], self.0[3], self.0[2], self.0[1],
+            self.0[0],
+        ])
+    }
+
+    /// Tests if the UUID is nil (all zeros).
+    pub const fn is_nil(&self) -> bool {
+        self.as_u128() == u128::MIN
+    }
+
+    /// Tests if the UUID is max (all ones).
+    pub const fn is_max(&self) -> bool {
+        self.as_u128() == u128::MAX
+    }
+
+    /// A buffer that can be used for `encode_...` calls, that is
+    /// guaranteed to be long enough for the [`Simple`], [`Hyphenated`],
+    /// [`Urn`], and [`Braced`] format adapters.
+    ///
+    /// It isn't long enough for every [`Formatted`] or [`Radix`] UUID. Use a
+    /// buffer of [`Formatted::MAX_LENGTH`] or [`Radix::MAX_LENGTH`] bytes for
+    /// those instead.
+    ///
+    /// [`Simple`]: fmt/struct.Simple.html
+    /// [`Hyphenated`]: fmt/struct.Hyphenated.html
+    /// [`Urn`]: fmt/struct.Urn.html
+    /// [`Braced`]: fmt/struct.Braced.html
+    /// [`Formatted`]: fmt/struct.Formatted.html
+    /// [`Formatted::MAX_LENGTH`]: fmt/struct.Formatted.html#associatedconstant.MAX_LENGTH
+    /// [`Radix`]: fmt/struct.Radix.html
+    /// [`Radix::MAX_LENGTH`]: fmt/struct.Radix.html#associatedconstant.MAX_LENGTH
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::nil();
+    ///
+    /// assert_eq!(
+    ///     uuid.simple().encode_lower(&mut Uuid::encode_buffer()),
+    ///     "00000000000000000000000000000000"
+    /// );
+    ///
+    /// assert_eq!(
+    ///     uuid.hyphenated()
+    ///         .encode_lower(&mut Uuid::encode_buffer()),
+    ///     "00000000-0000-0000-0000-000000000000"
+    /// );
+    ///
+    /// assert_eq!(
+    ///     uuid.urn().encode_lower(&mut Uuid::encode_buffer()),
+    ///     "urn:uuid:00000000-0000-0000-0000-000000000000"
     /// );
     /// ```
     pub const fn encode_buffer() -> [u8; fmt::Urn::LENGTH] {
@@ -909,6 +1690,106 @@ impl Uuid {
         }
     }
 
+    /// If the UUID is a version 7 UUID this will return its timestamp,
+    /// including a fraction of a millisecond stored in the 12 bits after the
+    /// millisecond timestamp. For other versions this will return `None`.
+    ///
+    /// This is the inverse of [`Builder::from_unix_timestamp_sub_millis`]. The
+    /// sub-millisecond fraction has a resolution of about 244 nanoseconds, so the
+    /// returned timestamp may be up to that much earlier than the one the UUID
+    /// was built from. UUIDs that don't store a fraction in those bits, like
+    /// those from [`Uuid::now_v7`], will return a timestamp with a meaningless
+    /// sub-millisecond part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Builder;
+    /// let uuid = Builder::from_unix_timestamp_sub_millis(1_645_557_742_000, 500_000, &[0; 10])
+    ///     .into_uuid();
+    ///
+    /// assert_eq!(
+    ///     (1_645_557_742, 500_000),
+    ///     uuid.get_timestamp_sub_millis().unwrap().to_unix(),
+    /// );
+    /// ```
+    ///
+    /// [`Uuid::now_v7`]: #method.now_v7
+    pub const fn get_timestamp_sub_millis(&self) -> Option<Timestamp> {
+        match self.get_version() {
+            Some(Version::SortRand) => {
+                let millis = timestamp::decode_unix_timestamp_millis(self);
+
+                let seconds = millis / 1000;
+                let nanos = ((millis % 1000) * 1_000_000
+                    + timestamp::decode_sub_millis(self, 1_000_000))
+                    as u32;
+
+                Some(Timestamp::from_unix_time(seconds, nanos, 0, 0))
+            }
+            _ => None,
+        }
+    }
+
+    /// If the UUID is a version 7 UUID this will return its timestamp as a
+    /// number of microseconds since the Unix epoch, reading a fraction of a
+    /// millisecond stored in the 12 bits after the millisecond timestamp. For
+    /// other versions this will return `None`.
+    ///
+    /// This is the inverse of [`Builder::from_unix_timestamp_micros`], and
+    /// returns the same number of microseconds the UUID was built from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Builder;
+    /// let uuid = Builder::from_unix_timestamp_micros(1_645_557_742_000_999, &[0; 10]).into_uuid();
+    ///
+    /// assert_eq!(Some(1_645_557_742_000_999), uuid.get_unix_timestamp_micros());
+    /// ```
+    pub const fn get_unix_timestamp_micros(&self) -> Option<u64> {
+        match self.get_version() {
+            Some(Version::SortRand) => Some(
+                timestamp::decode_unix_timestamp_millis(self) * 1_000
+                    + timestamp::decode_sub_millis(self, 1_000),
+            ),
+            _ => None,
+        }
+    }
+
+    /// If the UUID is the correct version (v1, v6, or v7) this will return
+    /// the time it was created as a [`SystemTime`]. For other versions, or if
+    /// the timestamp is before the Unix epoch or can't be represented by a
+    /// `SystemTime` on the current platform, this will return `None`.
+    ///
+    /// This is a shortcut for calling [`Timestamp::to_system_time`] on the
+    /// result of [`Uuid::get_timestamp`], and has the same precision.
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::{Duration, SystemTime};
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")?;
+    ///
+    /// assert_eq!(
+    ///     Some(SystemTime::UNIX_EPOCH + Duration::from_millis(1_645_557_742_000)),
+    ///     uuid.get_datetime(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`SystemTime`]: https://doc.rust-lang.org/std/time/struct.SystemTime.html
+    #[cfg(feature = "std")]
+    pub fn get_datetime(&self) -> Option<std::time::SystemTime> {
+        self.get_timestamp()?.to_system_time()
+    }
+
     /// If the UUID is the correct version (v1, or v6) this will return the
     /// node value as a 6-byte array. For other versions this will return `None`.
     pub const fn get_node_id(&self) -> Option<[u8; 6]> {
@@ -951,6 +1832,59 @@ impl AsRef<[u8]> for Uuid {
     }
 }
 
+// Any 16 bytes are a valid UUID, so the bytes can be handed out mutably
+impl AsMut<[u8]> for Uuid {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+// `Uuid` derives `Eq`, `Ord`, and `Hash` from its bytes, so they're consistent
+// with the implementations on `Bytes` as `Borrow` requires
+impl std::borrow::Borrow<Bytes> for Uuid {
+    #[inline]
+    fn borrow(&self) -> &Bytes {
+        &self.0
+    }
+}
+
+impl From<Bytes> for Uuid {
+    #[inline]
+    fn from(value: Bytes) -> Self {
+        Uuid::from_bytes(value)
+    }
+}
+
+impl From<Uuid> for Bytes {
+    #[inline]
+    fn from(value: Uuid) -> Self {
+        value.into_bytes()
+    }
+}
+
+impl From<u128> for Uuid {
+    #[inline]
+    fn from(value: u128) -> Self {
+        Uuid::from_u128(value)
+    }
+}
+
+impl From<Uuid> for u128 {
+    #[inline]
+    fn from(value: Uuid) -> Self {
+        value.as_u128()
+    }
+}
+
+impl convert::TryFrom<&'_ [u8]> for Uuid {
+    type Error = Error;
+
+    fn try_from(value: &'_ [u8]) -> Result<Self, Self::Error> {
+        Uuid::from_slice(value)
+    }
+}
+
 #[cfg(feature = "std")]
 impl From<Uuid> for std::vec::Vec<u8> {
     fn from(value: Uuid) -> Self {
@@ -967,6 +1901,20 @@ impl std::convert::TryFrom<std::vec::Vec<u8>> for Uuid {
     }
 }
 
+#[cfg(feature = "fake")]
+pub mod fake {
+    //! Fakers for generating UUIDs with the `fake` crate.
+    //!
+    //! With the `fake` feature enabled, `Uuid` and `NonNilUuid` implement
+    //! `Dummy<Faker>`, which generates random version 4 UUIDs. The fakers in
+    //! this module generate UUIDs with other constraints.
+    //!
+    //! Don't also enable the `uuid` feature of the `fake` crate, which makes
+    //! it depend on this one.
+
+    pub use crate::external::fake_support::UuidV7Between;
+}
+
 #[cfg(feature = "serde")]
 pub mod serde {
     //! Adapters for alternative `serde` formats.
@@ -975,7 +1923,12 @@ pub mod serde {
     //! to change the way a [`Uuid`](../struct.Uuid.html) is serialized
     //! and deserialized.
 
-    pub use crate::external::serde_support::{braced, compact, simple, urn};
+    pub use crate::external::serde_support::{
+        any_format, base64url, braced, byte_array, compact, simple, strict_hyphenated, urn,
+    };
+
+    #[cfg(feature = "rmp-serde")]
+    pub use crate::external::rmp_support as msgpack;
 }
 
 #[cfg(test)]
@@ -1014,6 +1967,17 @@ mod tests {
         ])
     }
 
+    // Random version 4 UUIDs for tests that need a lot of them. The same
+    // seed always produces the same UUIDs
+    #[cfg(feature = "std")]
+    pub fn random_uuids(len: usize, seed: u64) -> crate::std::vec::Vec<Uuid> {
+        let mut rng = crate::hash::SplitMix64(seed);
+
+        (0..len)
+            .map(|_| Builder::from_random_bytes(rng.bytes()).into_uuid())
+            .collect()
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -1093,6 +2057,48 @@ mod tests {
             || c == '-');
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_uuid_display_flags() {
+        use crate::std::fmt::Write;
+
+        macro_rules! assert_fmt {
+            ($expected:expr, $($args:tt)*) => {{
+                let mut buffer = String::new();
+                write!(buffer, $($args)*).unwrap();
+
+                assert_eq!($expected, buffer);
+            }};
+        }
+
+        let uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+        assert_fmt!("    67e55044-10b1-426f-9247-bb680e5fe0c8", "{:>40}", uuid);
+        assert_fmt!("**67e55044-10b1-426f-9247-bb680e5fe0c8**", "{:*^40}", uuid);
+        assert_fmt!("67e55044-10b1-426f-9247-bb680e5fe0c8    |", "{:40}|", uuid);
+        assert_fmt!("67e55044-10b1-426f-9247-bb680e5fe0c8", "{:10}", uuid);
+        assert_fmt!("67e55044", "{:.8}", uuid);
+        assert_fmt!("  67e55044", "{:>10.8}", uuid);
+
+        assert_fmt!(
+            "  67E5504410B1426F9247BB680E5FE0C8",
+            "{:>34X}",
+            uuid.simple()
+        );
+        assert_fmt!(
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8--",
+            "{:-<47}",
+            uuid.urn()
+        );
+    }
+
     // noinspection RsAssertEqual
     #[test]
     #[cfg_attr(
@@ -1193,22 +2199,50 @@ mod tests {
         ),
         wasm_bindgen_test
     )]
-    fn test_max() {
-        let max = Uuid::max();
-        let not_max = new();
+    fn test_min_max_for_version() {
+        assert_eq!(Uuid::nil(), Uuid::min_for_version(Version::Nil));
+        assert_eq!(Uuid::nil(), Uuid::max_for_version(Version::Nil));
+        assert_eq!(Uuid::max(), Uuid::min_for_version(Version::Max));
+        assert_eq!(Uuid::max(), Uuid::max_for_version(Version::Max));
 
-        assert!(max.is_max());
-        assert!(!not_max.is_max());
+        let uuid = new();
 
-        assert_eq!(max.get_version(), Some(Version::Max));
-        assert_eq!(not_max.get_version(), Some(Version::Random));
+        let min = Uuid::min_for_version(Version::Random);
+        let max = Uuid::max_for_version(Version::Random);
 
-        assert_eq!(
-            max,
-            Builder::from_bytes([0xff; 16])
-                .with_version(Version::Max)
-                .into_uuid()
-        );
+        assert_eq!(Some(Version::Random), min.get_version());
+        assert_eq!(Some(Version::Random), max.get_version());
+        assert_eq!(Variant::RFC4122, min.get_variant());
+        assert_eq!(Variant::RFC4122, max.get_variant());
+
+        assert!(min < uuid && uuid < max);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_max() {
+        let max = Uuid::max();
+        let not_max = new();
+
+        assert!(max.is_max());
+        assert!(!not_max.is_max());
+
+        assert_eq!(max.get_version(), Some(Version::Max));
+        assert_eq!(not_max.get_version(), Some(Version::Random));
+
+        assert_eq!(
+            max,
+            Builder::from_bytes([0xff; 16])
+                .with_version(Version::Max)
+                .into_uuid()
+        );
     }
 
     #[test]
@@ -1275,6 +2309,70 @@ mod tests {
         assert!(uuid.get_timestamp().is_none());
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_get_datetime() {
+        use crate::std::time::{Duration, SystemTime};
+
+        let expected = SystemTime::UNIX_EPOCH + Duration::new(1_496_854_535, 812_946_000);
+
+        // 2017-06-07 16:55:35.812946 UTC in 100ns ticks since 1582-10-15
+        let ticks = 0x01B2_1DD2_1381_4000 + 14_968_545_358_129_460;
+
+        let v1 = Builder::from_gregorian_timestamp(ticks, 0, &[0; 6]).into_uuid();
+        let v6 = Builder::from_sorted_gregorian_timestamp(ticks, 0, &[0; 6]).into_uuid();
+        let v7 = Builder::from_unix_timestamp_millis(1_496_854_535_812, &[0; 10]).into_uuid();
+
+        assert_eq!(Some(expected), v1.get_datetime());
+        assert_eq!(Some(expected), v6.get_datetime());
+        assert_eq!(
+            Some(SystemTime::UNIX_EPOCH + Duration::from_millis(1_496_854_535_812)),
+            v7.get_datetime()
+        );
+        assert_eq!(None, new().get_datetime());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_get_datetime_before_unix_epoch() {
+        // 00:00:00.00, 15 October 1582
+        let v1 = Uuid::parse_str("00000000-0000-1000-8000-000000000000").unwrap();
+
+        assert_eq!(Some(0), v1.get_timestamp().map(|ts| ts.to_gregorian().0));
+        assert_eq!(None, v1.get_timestamp().unwrap().to_unix_duration());
+        assert_eq!(None, v1.get_datetime());
+
+        // One tick before the Unix epoch
+        let ticks = 0x01B2_1DD2_1381_4000 - 1;
+
+        let v6 = Builder::from_sorted_gregorian_timestamp(ticks, 0, &[0; 6]).into_uuid();
+
+        assert_eq!(None, v6.get_datetime());
+
+        let v6 = Builder::from_sorted_gregorian_timestamp(ticks + 1, 0, &[0; 6]).into_uuid();
+
+        assert_eq!(
+            Some(crate::std::time::SystemTime::UNIX_EPOCH),
+            v6.get_datetime()
+        );
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -1318,6 +2416,71 @@ mod tests {
         assert_eq!(uuid6.get_variant(), Variant::NCS);
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_get_any_version() {
+        let uuid = new();
+
+        assert_eq!(AnyVersion::Known(Version::Random), uuid.get_any_version());
+        assert_eq!(Some(Version::Random), uuid.get_any_version().known());
+        assert_eq!(4, uuid.get_any_version().num());
+
+        assert_eq!(
+            AnyVersion::Known(Version::Nil),
+            Uuid::nil().get_any_version()
+        );
+        assert_eq!(
+            AnyVersion::Known(Version::Max),
+            Uuid::max().get_any_version()
+        );
+        assert_eq!(0xf, Uuid::max().get_any_version().num());
+
+        for num in [0, 9, 10, 11, 12, 13, 14] {
+            let uuid = Builder::from_u128(1).with_nibble(12, num).into_uuid();
+
+            assert_eq!(AnyVersion::NonStandard(num), uuid.get_any_version());
+            assert_eq!(None, uuid.get_any_version().known());
+            assert_eq!(num, uuid.get_any_version().num());
+        }
+
+        assert_eq!("v9", AnyVersion::NonStandard(9).to_string());
+        assert_eq!("max", Uuid::max().get_any_version().to_string());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_get_version_for_variant() {
+        let microsoft = Uuid::parse_str("936DA01F9ABD4d9dC0C702AF85C822A8").unwrap();
+        let ncs = Uuid::parse_str("f81d4fae-7dec-11d0-7765-00a0c91e6bf6").unwrap();
+        let future = Uuid::parse_str("f81d4fae-7dec-11d0-e765-00a0c91e6bf6").unwrap();
+
+        assert_eq!(Some(Version::Random), microsoft.get_version());
+        assert_eq!(Some(Version::Mac), ncs.get_version());
+        assert_eq!(Some(Version::Mac), future.get_version());
+
+        assert_eq!(None, microsoft.get_version_for_variant());
+        assert_eq!(None, ncs.get_version_for_variant());
+        assert_eq!(None, future.get_version_for_variant());
+
+        assert_eq!(Some(Version::Random), new().get_version_for_variant());
+        assert_eq!(Some(Version::Nil), Uuid::nil().get_version_for_variant());
+        assert_eq!(Some(Version::Max), Uuid::max().get_version_for_variant());
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -1666,6 +2829,44 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_from_u64_pair_le() {
+        let high_in: u64 = 0xc2c1b2b1a4a3a2a1;
+        let low_in: u64 = 0xd8d7d6d5d4d3d2d1;
+
+        let u = Uuid::from_u64_pair_le(high_in, low_in);
+
+        let expected = "a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8";
+        let result = u.simple().to_string();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_u64_pair_le_roundtrip() {
+        let u = new();
+
+        let (high, low) = u.to_u64_pair_le();
+
+        assert_eq!(u, Uuid::from_u64_pair_le(high, low));
+        assert_eq!(u.as_u64_pair(), (high.swap_bytes(), low.swap_bytes()));
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -1702,6 +2903,37 @@ mod tests {
         assert_eq!(v_in, v_out);
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_i128_roundtrip() {
+        for v_in in [
+            0,
+            1,
+            -1,
+            i128::MIN,
+            i128::MAX,
+            0x1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8,
+        ] {
+            let u = Uuid::from_i128(v_in);
+
+            assert_eq!(v_in, u.to_i128());
+            assert_eq!(v_in as u128, u.as_u128());
+        }
+
+        // The sign bit is the top bit of the first byte
+        assert_eq!(0x80, Uuid::from_i128(i128::MIN).as_bytes()[0]);
+        assert_eq!(Uuid::nil(), Uuid::from_i128(0));
+        assert_eq!(Uuid::max(), Uuid::from_i128(-1));
+        assert!(new().to_i128() < 0);
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -1761,6 +2993,35 @@ mod tests {
         assert_eq!(u.simple().to_string(), expected);
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_from_slice_const() {
+        const BYTES: &[u8] = &[
+            0xa1, 0xa2, 0xa3, 0xa4, 0xb1, 0xb2, 0xc1, 0xc2, 0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6,
+            0xd7, 0xd8,
+        ];
+
+        const UUID: Result<Uuid, Error> = Uuid::from_slice(BYTES);
+        const UUID_LE: Result<Uuid, Error> = Uuid::from_slice_le(BYTES);
+        const BUILDER: Result<Builder, Error> = Builder::from_slice(BYTES);
+        const TOO_SHORT: Result<Uuid, Error> = Uuid::from_slice(&[0; 15]);
+
+        assert_eq!(Uuid::from_slice(BYTES), UUID);
+        assert_eq!(Uuid::from_slice_le(BYTES), UUID_LE);
+        assert_eq!(UUID.unwrap(), BUILDER.unwrap().into_uuid());
+        assert_eq!(
+            Err(Error::new(crate::error::ErrorKind::ByteLength { len: 15 })),
+            TOO_SHORT
+        );
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -1782,6 +3043,40 @@ mod tests {
         assert_eq!(u.simple().to_string(), expected);
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_conversion_traits() {
+        use crate::std::{borrow::Borrow, collections::HashSet};
+
+        let uuid = new();
+
+        assert_eq!(uuid, Uuid::from(uuid.into_bytes()));
+        assert_eq!(uuid.into_bytes(), <[u8; 16]>::from(uuid));
+        assert_eq!(uuid, Uuid::from(uuid.as_u128()));
+        assert_eq!(uuid.as_u128(), u128::from(uuid));
+
+        let bytes: &[u8; 16] = uuid.borrow();
+        assert_eq!(uuid.as_bytes(), bytes);
+
+        let mut set = HashSet::new();
+        set.insert(uuid);
+
+        assert!(set.contains(uuid.as_bytes()));
+
+        let mut uuid = uuid;
+        let bytes: &mut [u8] = uuid.as_mut();
+        bytes[0] = 0;
+
+        assert_eq!(0, uuid.as_bytes()[0]);
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -1802,6 +3097,69 @@ mod tests {
         assert!(!ur.iter().all(|&b| b == 0));
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_slice_bytes_roundtrip() {
+        let mut bytes = [*new().as_bytes(), *new2().as_bytes()];
+
+        let uuids = Uuid::slice_from_bytes(&bytes);
+
+        assert_eq!([new(), new2()], uuids);
+        assert!(std::ptr::eq(
+            uuids.as_ptr() as *const u8,
+            bytes.as_ptr() as *const u8
+        ));
+        assert_eq!(&bytes, Uuid::slice_as_bytes(uuids));
+
+        Uuid::slice_from_bytes_mut(&mut bytes)[0] = Uuid::nil();
+        assert_eq!([0; 16], bytes[0]);
+
+        let mut uuids = [new(), new2()];
+        Uuid::slice_as_bytes_mut(&mut uuids)[1] = [0xff; 16];
+        assert_eq!([new(), Uuid::max()], uuids);
+
+        assert!(Uuid::slice_from_bytes(&[]).is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_try_slice_from_bytes() {
+        let mut buf = [0u8; 49];
+        buf[1..17].copy_from_slice(new().as_bytes());
+        buf[17..33].copy_from_slice(new2().as_bytes());
+
+        // The view doesn't need to be aligned
+        let uuids = Uuid::try_slice_from_bytes(&buf[1..]).unwrap();
+
+        assert_eq!([new(), new2(), Uuid::nil()], uuids);
+        assert!(std::ptr::eq(uuids.as_ptr() as *const u8, buf[1..].as_ptr()));
+
+        Uuid::try_slice_from_bytes_mut(&mut buf[1..]).unwrap()[2] = Uuid::max();
+        assert_eq!([0xff; 16], buf[33..]);
+
+        assert!(Uuid::try_slice_from_bytes(&[]).unwrap().is_empty());
+
+        assert_eq!(
+            Err(Error::new(crate::error::ErrorKind::SliceLength { len: 49 })),
+            Uuid::try_slice_from_bytes(&buf).map(|_| ())
+        );
+        assert!(Uuid::try_slice_from_bytes_mut(&mut buf[..15]).is_err());
+    }
+
     #[test]
     #[cfg(feature = "std")]
     #[cfg_attr(
@@ -1825,6 +3183,37 @@ mod tests {
         let uv: Uuid = v.try_into().unwrap();
 
         assert_eq!(uv, u);
+
+        let short: Result<Uuid, _> = vec![0; 15].try_into();
+
+        assert!(short.is_err());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_convert_slice() {
+        use crate::std::convert::TryInto;
+
+        let u = new();
+        let ub: &[u8] = u.as_ref();
+
+        let us: Uuid = ub.try_into().unwrap();
+
+        assert_eq!(us, u);
+
+        let short: Result<Uuid, _> = ub[..15].try_into();
+
+        assert_eq!(
+            Err(Error::new(crate::error::ErrorKind::ByteLength { len: 15 })),
+            short
+        );
     }
 
     #[test]
@@ -1873,6 +3262,498 @@ mod tests {
         assert_eq!(u1, u2);
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_mysql_bin_swapped_roundtrip() {
+        let u1 = new();
+
+        let swapped = u1.to_mysql_bin_swapped();
+
+        assert_eq!(&u1.as_bytes()[6..8], &swapped[0..2]);
+        assert_eq!(&u1.as_bytes()[4..6], &swapped[2..4]);
+        assert_eq!(&u1.as_bytes()[0..4], &swapped[4..8]);
+        assert_eq!(&u1.as_bytes()[8..], &swapped[8..]);
+
+        let u2 = Uuid::from_mysql_bin_swapped(swapped);
+
+        assert_eq!(u1, u2);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_clickhouse_roundtrip() {
+        let u1 = new();
+
+        let (high_bits, low_bits) = u1.to_clickhouse_parts();
+        let bytes = u1.to_clickhouse_bytes();
+
+        assert_eq!(high_bits.to_le_bytes(), bytes[..8]);
+        assert_eq!(low_bits.to_le_bytes(), bytes[8..]);
+
+        assert_eq!(u1, Uuid::from_clickhouse_parts(high_bits, low_bits));
+        assert_eq!(u1, Uuid::from_clickhouse_bytes(bytes));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_cmp_sqlserver() {
+        use crate::std::cmp::Ordering;
+
+        // Each UUID has a single byte set, listed from least to most
+        // significant in SQL Server's ordering
+        let ascending = [
+            "01000000-0000-0000-0000-000000000000",
+            "00010000-0000-0000-0000-000000000000",
+            "00000100-0000-0000-0000-000000000000",
+            "00000001-0000-0000-0000-000000000000",
+            "00000000-0100-0000-0000-000000000000",
+            "00000000-0001-0000-0000-000000000000",
+            "00000000-0000-0100-0000-000000000000",
+            "00000000-0000-0001-0000-000000000000",
+            "00000000-0000-0000-0001-000000000000",
+            "00000000-0000-0000-0100-000000000000",
+            "00000000-0000-0000-0000-000000000001",
+            "00000000-0000-0000-0000-000000000100",
+            "00000000-0000-0000-0000-000000010000",
+            "00000000-0000-0000-0000-000001000000",
+            "00000000-0000-0000-0000-000100000000",
+            "00000000-0000-0000-0000-010000000000",
+        ]
+        .map(|uuid| Uuid::parse_str(uuid).unwrap());
+
+        for pair in ascending.windows(2) {
+            assert_eq!(Ordering::Less, pair[0].cmp_sqlserver(&pair[1]));
+            assert_eq!(Ordering::Greater, pair[1].cmp_sqlserver(&pair[0]));
+        }
+
+        let uuid = new();
+        assert_eq!(Ordering::Equal, uuid.cmp_sqlserver(&uuid));
+        assert_eq!(Ordering::Less, Uuid::nil().cmp_sqlserver(&uuid));
+        assert_eq!(Ordering::Greater, Uuid::max().cmp_sqlserver(&uuid));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_guid_bytes_roundtrip() {
+        let u1 = new();
+
+        assert_eq!(u1.to_bytes_le(), u1.to_guid_bytes());
+        assert_eq!(u1, Uuid::from_guid_bytes(u1.to_guid_bytes()));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_sqlserver_sort_key() {
+        let uuids = [
+            new(),
+            new2(),
+            Uuid::nil(),
+            Uuid::max(),
+            Uuid::parse_str("ffffffff-ffff-ffff-ffff-000000000000").unwrap(),
+            Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap(),
+            Uuid::parse_str("00000000-0000-0000-0100-000000000000").unwrap(),
+        ];
+
+        for a in uuids {
+            assert_eq!(a, Uuid::from_sqlserver_sort_key(a.to_sqlserver_sort_key()));
+
+            for b in uuids {
+                assert_eq!(
+                    a.cmp_sqlserver(&b),
+                    a.to_sqlserver_sort_key().cmp(&b.to_sqlserver_sort_key())
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_payload_bits() {
+        let uuid = new();
+
+        let payload = uuid.payload_bits();
+
+        assert_eq!(0, payload >> 122);
+        assert_eq!(
+            uuid,
+            Builder::from_payload_bits(Version::Random, payload).into_uuid()
+        );
+
+        // The version and variant bits are skipped over
+        let uuid = Builder::from_payload_bits(Version::Custom, u128::MAX).into_uuid();
+
+        assert_eq!(Some(Version::Custom), uuid.get_version());
+        assert_eq!(Variant::RFC4122, uuid.get_variant());
+        assert_eq!((1 << 122) - 1, uuid.payload_bits());
+
+        let uuid = Builder::from_payload_bits(Version::Custom, 1 << 62).into_uuid();
+
+        assert_eq!(1 << 64, uuid.as_u128() & !(0xc0 << 56) & !(0xf << 76));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_from_custom_fields() {
+        let uuid =
+            Builder::from_custom_fields(0xa1a2_a3a4_b1b2, 0x1c2, 0x11d2_d3d4_d5d6_d7d8).into_uuid();
+
+        assert_eq!(Some(Version::Custom), uuid.get_version());
+        assert_eq!(Variant::RFC4122, uuid.get_variant());
+        assert_eq!(0xa1a2a3a4_b1b2_81c2_91d2_d3d4d5d6d7d8, uuid.as_u128());
+
+        // Bits that don't fit in the fields are masked off
+        let uuid = Builder::from_custom_fields(u64::MAX, u16::MAX, u64::MAX).into_uuid();
+
+        assert_eq!(Uuid::max().payload_bits(), uuid.payload_bits());
+        assert_eq!(0xffffffff_ffff_8fff_bfff_ffffffffffff, uuid.as_u128());
+        assert_eq!(
+            (0xffff_ffff_ffff, 0xfff, (1 << 62) - 1),
+            uuid.as_custom_fields()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_as_custom_fields() {
+        let fields = (0xa1a2_a3a4_b1b2, 0x1c2, 0x11d2_d3d4_d5d6_d7d8);
+        let uuid = Builder::from_custom_fields(fields.0, fields.1, fields.2).into_uuid();
+
+        assert_eq!(fields, uuid.as_custom_fields());
+
+        let (a, b, c) = new().as_custom_fields();
+        assert_eq!(
+            new().payload_bits(),
+            Builder::from_custom_fields(a, b, c)
+                .into_uuid()
+                .payload_bits()
+        );
+
+        assert_eq!((0, 0, 0), Uuid::nil().as_custom_fields());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_try_build() {
+        use crate::error::ErrorKind;
+
+        for uuid in [new(), Uuid::nil(), Uuid::max()] {
+            assert_eq!(Ok(uuid), Builder::from_u128(uuid.as_u128()).try_build());
+        }
+
+        let err = Builder::from_u128(new().as_u128())
+            .with_variant(Variant::Microsoft)
+            .try_build()
+            .unwrap_err();
+        assert_eq!(ErrorKind::BuildVariant, err.kind);
+
+        let err = Builder::from_u128(new().as_u128() & !(0xf << 76))
+            .try_build()
+            .unwrap_err();
+        assert_eq!(ErrorKind::BuildVersion { version: 0 }, err.kind);
+
+        let err = Builder::from_u128(new().as_u128() | (0xf << 76))
+            .try_build()
+            .unwrap_err();
+        assert_eq!(ErrorKind::BuildVersion { version: 15 }, err.kind);
+
+        for version in [Version::Mac, Version::SortMac, Version::SortRand] {
+            let err = Builder::from_random_bytes([0; 16])
+                .with_version(version)
+                .try_build()
+                .unwrap_err();
+            assert_eq!(ErrorKind::BuildTimestamp, err.kind);
+
+            assert!(Builder::from_random_bytes([1; 16])
+                .with_version(version)
+                .try_build()
+                .is_ok());
+        }
+
+        // Only the timestamp bits of a version 1 UUID need to be set
+        assert!(Builder::from_gregorian_timestamp(1, 0, &[0; 6])
+            .try_build()
+            .is_ok());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_set_bytes_and_bits() {
+        let uuid = new();
+
+        let mut builder = Builder::nil();
+        for (index, byte) in uuid.as_bytes().iter().enumerate() {
+            builder.set_byte(index, *byte);
+        }
+        assert_eq!(uuid, builder.into_uuid());
+
+        let mut builder = Builder::nil();
+        for (index, digit) in uuid.simple().to_string().chars().enumerate() {
+            builder.set_nibble(index, digit.to_digit(16).unwrap() as u8);
+        }
+        assert_eq!(uuid, builder.into_uuid());
+
+        // Only the low 4 bits of a nibble are used
+        assert_eq!(
+            Builder::nil().with_nibble(1, 0xa).into_uuid(),
+            Builder::nil().with_nibble(1, 0xfa).into_uuid()
+        );
+
+        // Setting the version and variant bits directly
+        assert_eq!(
+            Builder::from_u128(uuid.as_u128())
+                .with_version(Version::Custom)
+                .with_variant(Variant::RFC4122)
+                .into_uuid(),
+            Builder::from_u128(uuid.as_u128())
+                .with_bits(48..52, 8)
+                .with_bits(64..66, 0b10)
+                .into_uuid()
+        );
+
+        // Values are masked to the width of the range
+        assert_eq!(
+            0x0000000f_0000_0000_0000_000000000000,
+            Builder::nil()
+                .with_bits(28..32, u128::MAX)
+                .into_uuid()
+                .as_u128()
+        );
+
+        assert_eq!(
+            uuid,
+            Builder::nil().with_bits(0..128, uuid.as_u128()).into_uuid()
+        );
+        assert_eq!(
+            uuid,
+            Builder::from_u128(uuid.as_u128())
+                .with_bits(5..5, u128::MAX)
+                .into_uuid()
+        );
+
+        let mut builder = Builder::nil();
+        builder.set_bits(120..128, 0xd8);
+        assert_eq!(0xd8, builder.as_uuid().as_u128());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_set_bytes_and_bits_checked() {
+        let uuid = new();
+        let builder = || Builder::from_u128(uuid.as_u128());
+
+        assert_eq!(
+            Some(builder().with_byte(15, 0xd8).into_uuid()),
+            builder()
+                .with_byte_checked(15, 0xd8)
+                .map(Builder::into_uuid)
+        );
+        assert_eq!(
+            Some(builder().with_nibble(31, 0xa).into_uuid()),
+            builder()
+                .with_nibble_checked(31, 0xa)
+                .map(Builder::into_uuid)
+        );
+        assert_eq!(
+            Some(builder().with_bits(0..128, 0).into_uuid()),
+            builder()
+                .with_bits_checked(0..128, 0)
+                .map(Builder::into_uuid)
+        );
+
+        assert!(builder().with_byte_checked(16, 0).is_none());
+        assert!(builder().with_nibble_checked(32, 0).is_none());
+        assert!(builder().with_bits_checked(120..129, 0).is_none());
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 9..8;
+        assert!(builder().with_bits_checked(reversed, 0).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_with_bits_out_of_range() {
+        let _ = Builder::nil().with_bits(120..129, 0);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_with_variant_microsoft() {
+        for uuid in [new(), Uuid::nil(), Uuid::max()] {
+            let microsoft = Builder::from_u128(uuid.as_u128())
+                .with_variant(Variant::Microsoft)
+                .into_uuid();
+
+            assert_eq!(Variant::Microsoft, microsoft.get_variant());
+
+            // Only the top 3 bits of the 9th byte are changed
+            let mask = !(0xe0 << 56);
+            assert_eq!(uuid.as_u128() & mask, microsoft.as_u128() & mask);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_xor_fold64() {
+        assert_eq!(0, Uuid::nil().xor_fold64());
+        assert_eq!(0, Uuid::max().xor_fold64());
+        assert_eq!(1, Uuid::from_u128(1).xor_fold64());
+        assert_eq!(1, Uuid::from_u128(1 << 64).xor_fold64());
+
+        let uuid = new();
+        let (high_bits, low_bits) = uuid.as_u64_pair();
+
+        assert_eq!(high_bits ^ low_bits, uuid.xor_fold64());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_sample() {
+        let low = Uuid::from_u128(0xa1a2a3a4_b1b2_41c2_8000_000000000000);
+        let high = Uuid::from_u128(0xa1a2a3a4_b1b2_41c2_bfff_ffffffffffff);
+
+        assert!(low.sample(1.0));
+        assert!(high.sample(1.0));
+        assert!(high.sample(2.0));
+
+        assert!(!low.sample(0.0));
+        assert!(!low.sample(-1.0));
+        assert!(!low.sample(f64::NAN));
+
+        let uuid = new();
+        let kept = uuid.sample(0.5);
+
+        assert_eq!(kept, uuid.sample(0.5));
+        if kept {
+            assert!(uuid.sample(0.75));
+        } else {
+            assert!(!uuid.sample(0.25));
+        }
+
+        // Only a few bits in the middle change, like the counter in a version 7 UUID
+        let kept = (0..4096)
+            .filter(|i| Uuid::from_u64_pair(0, i << 20).sample(0.25))
+            .count();
+        assert!((824..1224).contains(&kept), "{}", kept);
+    }
+
+    #[test]
+    #[cfg(feature = "v7")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_sample_v7_burst() {
+        // These are all generated within the same millisecond, so they
+        // only differ in their counter and random bits
+        let context = ContextV7::new();
+
+        let kept = (0..4096)
+            .filter(|_| Uuid::new_v7(Timestamp::from_unix(&context, 1_645_557_742, 0)).sample(0.25))
+            .count();
+        assert!((824..1224).contains(&kept), "{}", kept);
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -5,10 +5,7 @@ macro_rules! define_uuid_macro {
         #[macro_export]
         macro_rules! uuid {
             ($uuid:expr) => {{
-                const OUTPUT: $crate::Uuid = match $crate::Uuid::try_parse($uuid) {
-                    $crate::__macro_support::Ok(u) => u,
-                    $crate::__macro_support::Err(_) => panic!("invalid UUID"),
-                };
+                const OUTPUT: $crate::Uuid = $crate::Uuid::from_str_const($uuid);
                 OUTPUT
             }};
             ($uuid:literal) => {{
@@ -21,10 +18,7 @@ macro_rules! define_uuid_macro {
         #[macro_export]
         macro_rules! uuid {
             ($uuid:expr) => {{
-                const OUTPUT: $crate::Uuid = match $crate::Uuid::try_parse($uuid) {
-                    $crate::__macro_support::Ok(u) => u,
-                    $crate::__macro_support::Err(_) => panic!("invalid UUID"),
-                };
+                const OUTPUT: $crate::Uuid = $crate::Uuid::from_str_const($uuid);
                 OUTPUT
             }};
         }
@@ -12,3 +12,69 @@ pub(crate) fn hash(ns: &[u8], src: &[u8]) -> [u8; 16] {
 
     bytes
 }
+
+#[cfg(feature = "v3")]
+pub(crate) fn hash_parts(ns: &[u8], parts: &[&[u8]]) -> [u8; 16] {
+    use md5::{Digest, Md5};
+
+    let mut hasher = Md5::new();
+
+    hasher.update(ns);
+
+    for part in parts {
+        hasher.update((part.len() as u64).to_be_bytes());
+        hasher.update(part);
+    }
+
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(&hasher.finalize()[..16]);
+
+    bytes
+}
+
+#[cfg(feature = "v3")]
+pub(crate) fn hash_chunks<I>(ns: &[u8], chunks: I) -> [u8; 16]
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    use md5::{Digest, Md5};
+
+    let mut hasher = Md5::new();
+
+    hasher.update(ns);
+
+    for chunk in chunks {
+        hasher.update(chunk);
+    }
+
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(&hasher.finalize()[..16]);
+
+    bytes
+}
+
+#[cfg(all(feature = "v3", feature = "std"))]
+pub(crate) fn hash_reader<R: std::io::Read>(ns: &[u8], mut reader: R) -> std::io::Result<[u8; 16]> {
+    use md5::{Digest, Md5};
+    use std::io::ErrorKind;
+
+    let mut hasher = Md5::new();
+
+    hasher.update(ns);
+
+    let mut buf = [0; 8192];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(len) => hasher.update(&buf[..len]),
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(&hasher.finalize()[..16]);
+
+    Ok(bytes)
+}
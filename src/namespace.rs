@@ -0,0 +1,279 @@
+use crate::{std::fmt, Uuid};
+
+/// A namespace for generating name-based UUIDs.
+///
+/// Name-based UUIDs (versions 3 and 5) are generated from a namespace and a name.
+/// Since both are just values, it's easy to accidentally pass them in the wrong order,
+/// or to pass an arbitrary UUID where a namespace is expected. Wrapping namespaces in
+/// this type makes them distinct from other UUIDs, so they can be passed around APIs
+/// as first-class values.
+///
+/// # Examples
+///
+/// Generating a UUID in a well-known namespace:
+///
+/// ```
+/// # fn main() {
+/// # #[cfg(feature = "v5")]
+/// # {
+/// # use uuid::{Namespace, Uuid};
+/// let uuid = Namespace::DNS.uuid_v5(b"rust-lang.org");
+///
+/// assert_eq!(Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"rust-lang.org"), uuid);
+/// # }
+/// # }
+/// ```
+///
+/// Defining a custom namespace:
+///
+/// ```
+/// # use uuid::{uuid, Namespace};
+/// const ORDERS: Namespace = Namespace::from_uuid(uuid!("2b6f9b34-5b7a-4a0e-8d4c-9a3c2f4e1d10"));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Namespace(Uuid);
+
+impl Namespace {
+    /// The namespace for Domain Name System (DNS) names.
+    pub const DNS: Self = Namespace(Uuid::NAMESPACE_DNS);
+
+    /// The namespace for ISO Object Identifiers (OIDs).
+    pub const OID: Self = Namespace(Uuid::NAMESPACE_OID);
+
+    /// The namespace for Uniform Resource Locators (URLs).
+    pub const URL: Self = Namespace(Uuid::NAMESPACE_URL);
+
+    /// The namespace for X.500 Distinguished Names (DNs).
+    pub const X500: Self = Namespace(Uuid::NAMESPACE_X500);
+
+    /// The namespaces registered with IANA, in the order they're listed in
+    /// RFC 9562.
+    ///
+    /// # References
+    ///
+    /// * [Namespace ID Usage and Allocation in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-6.6)
+    pub const WELL_KNOWN: [Self; 4] = [Self::DNS, Self::URL, Self::OID, Self::X500];
+
+    /// Use a UUID as a namespace.
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Namespace(uuid)
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`Namespace`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+
+    /// Get the registered name of a well-known namespace, like `"DNS"`.
+    ///
+    /// Returns `None` for namespaces that aren't in [`Namespace::WELL_KNOWN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Namespace, Uuid};
+    /// assert_eq!(Some("URL"), Namespace::URL.name());
+    /// assert_eq!(None, Namespace::from_uuid(Uuid::max()).name());
+    /// ```
+    pub const fn name(&self) -> Option<&'static str> {
+        match self.0.as_u128() {
+            n if n == Uuid::NAMESPACE_DNS.as_u128() => Some("DNS"),
+            n if n == Uuid::NAMESPACE_URL.as_u128() => Some("URL"),
+            n if n == Uuid::NAMESPACE_OID.as_u128() => Some("OID"),
+            n if n == Uuid::NAMESPACE_X500.as_u128() => Some("X500"),
+            _ => None,
+        }
+    }
+
+    /// Creates a namespace nested in this one, using the version 5 UUID for a
+    /// name in this namespace.
+    ///
+    /// This is a `const fn`, so trees of namespaces, such as one for an
+    /// organization with a namespace per service, can be declared as constants
+    /// instead of being recomputed at runtime. The result is the same as
+    /// [`Namespace::uuid_v5`], but this method is slower, so prefer that
+    /// method for names that are only known at runtime.
+    ///
+    /// Note that usage of this method requires the `v5` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Namespace, Uuid};
+    /// const ORG: Namespace = Namespace::DNS.child(b"example.com");
+    /// const BILLING: Namespace = ORG.child(b"billing");
+    ///
+    /// assert_eq!(Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"example.com"), ORG.into_uuid());
+    /// assert_eq!(ORG.uuid_v5(b"billing"), BILLING.into_uuid());
+    ///
+    /// let invoice = BILLING.uuid_v5(b"invoice-42");
+    /// ```
+    #[cfg(feature = "v5")]
+    pub const fn child(&self, name: &[u8]) -> Namespace {
+        Namespace(
+            crate::Builder::from_sha1_bytes(crate::sha1::hash_const(self.0.as_bytes(), name))
+                .into_uuid(),
+        )
+    }
+
+    /// Creates a UUID for a name in this namespace, based on the MD5 hash.
+    ///
+    /// This method is equivalent to [`Uuid::new_v3`].
+    ///
+    /// Note that usage of this method requires the `v3` feature of this crate
+    /// to be enabled.
+    #[cfg(feature = "v3")]
+    pub fn uuid_v3(&self, name: &[u8]) -> Uuid {
+        Uuid::new_v3(&self.0, name)
+    }
+
+    /// Creates a UUID for a name in this namespace, based on the SHA-1 hash.
+    ///
+    /// This method is equivalent to [`Uuid::new_v5`].
+    ///
+    /// Note that usage of this method requires the `v5` feature of this crate
+    /// to be enabled.
+    #[cfg(feature = "v5")]
+    pub fn uuid_v5(&self, name: &[u8]) -> Uuid {
+        Uuid::new_v5(&self.0, name)
+    }
+}
+
+impl fmt::Display for Namespace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl From<Uuid> for Namespace {
+    fn from(uuid: Uuid) -> Self {
+        Namespace(uuid)
+    }
+}
+
+impl From<Namespace> for Uuid {
+    fn from(namespace: Namespace) -> Self {
+        namespace.0
+    }
+}
+
+impl AsRef<Uuid> for Namespace {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_well_known() {
+        assert_eq!(Uuid::NAMESPACE_DNS, Namespace::DNS.into_uuid());
+        assert_eq!(Uuid::NAMESPACE_OID, *Namespace::OID.as_uuid());
+        assert_eq!(Uuid::NAMESPACE_URL, Uuid::from(Namespace::URL));
+        assert_eq!(Namespace::X500, Namespace::from(Uuid::NAMESPACE_X500));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_name() {
+        let names = Namespace::WELL_KNOWN.map(|namespace| namespace.name().unwrap());
+
+        assert_eq!(["DNS", "URL", "OID", "X500"], names);
+        assert_eq!(None, Namespace::from_uuid(Uuid::nil()).name());
+    }
+
+    #[test]
+    #[cfg(feature = "v5")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_child() {
+        const ORG: Namespace = Namespace::URL.child(b"https://example.com");
+        const SERVICE: Namespace = ORG.child(b"orders");
+
+        assert_eq!(
+            Namespace::URL.uuid_v5(b"https://example.com"),
+            ORG.into_uuid()
+        );
+        assert_eq!(ORG.as_uuid().child(b"orders"), SERVICE.into_uuid());
+        assert_eq!(Some(crate::Version::Sha1), SERVICE.as_uuid().get_version());
+
+        for namespace in Namespace::WELL_KNOWN {
+            assert_eq!(namespace.uuid_v5(b""), namespace.child(b"").into_uuid());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "v3")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_uuid_v3() {
+        let namespace = Namespace::from_uuid(crate::tests::new());
+
+        assert_eq!(
+            Uuid::new_v3(&crate::tests::new(), b"example"),
+            namespace.uuid_v3(b"example")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "v5")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_uuid_v5() {
+        let namespace = Namespace::from_uuid(crate::tests::new());
+
+        assert_eq!(
+            Uuid::new_v5(&crate::tests::new(), b"example"),
+            namespace.uuid_v5(b"example")
+        );
+    }
+}
@@ -2,7 +2,7 @@
 //! `Option<NonNilUuid>` representation.
 
 use core::convert::TryFrom;
-use std::{fmt, num::NonZeroU128};
+use std::{fmt, num::NonZeroU128, str};
 
 use crate::{
     error::{Error, ErrorKind},
@@ -33,7 +33,7 @@ use crate::{
 /// may change. It is currently only guaranteed that `NonNilUuid` and `Option<NonNilUuid>`
 /// are the same size as `Uuid`.
 #[repr(transparent)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct NonNilUuid(NonZeroU128);
 
 impl fmt::Display for NonNilUuid {
@@ -68,15 +68,155 @@ impl NonNilUuid {
     /// # Safety
     ///
     /// The value must not be nil.
+    ///
+    /// Note that this method isn't available with the `forbid-unsafe` feature
+    /// of this crate.
+    #[cfg(not(feature = "forbid-unsafe"))]
     pub const unsafe fn new_unchecked(uuid: Uuid) -> Self {
         NonNilUuid(unsafe { NonZeroU128::new_unchecked(uuid.as_u128()) })
     }
 
+    /// Parses a non-nil UUID from a string in any of the formats accepted by
+    /// [`Uuid::parse_str`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the string isn't a valid UUID,
+    /// or if it's the nil UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::NonNilUuid;
+    /// let uuid = NonNilUuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8");
+    /// assert!(uuid.is_ok());
+    ///
+    /// let nil = NonNilUuid::parse_str("00000000-0000-0000-0000-000000000000");
+    /// assert!(nil.is_err());
+    /// ```
+    pub fn parse_str(input: &str) -> Result<Self, Error> {
+        let result = Uuid::parse_str(input).and_then(NonNilUuid::try_from);
+
+        #[cfg(feature = "std")]
+        let result = result.map_err(|err| err.with_input(input));
+
+        result
+    }
+
+    /// Parses a non-nil UUID from a string in the formats accepted by
+    /// [`Uuid::try_parse`].
+    ///
+    /// This is a `const` alternative to [`NonNilUuid::parse_str`], so it can
+    /// be used to build non-nil UUIDs in `const` and `static` items. An
+    /// [`Error`] can't be discarded at compile time, so keep the whole
+    /// `Result` rather than matching on it in a `const` item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Error, NonNilUuid};
+    /// # fn main() -> Result<(), Error> {
+    /// const ID: Result<NonNilUuid, Error> = NonNilUuid::try_parse("67e55044-10b1-426f-9247-bb680e5fe0c8");
+    ///
+    /// assert_eq!("67e55044-10b1-426f-9247-bb680e5fe0c8", ID?.to_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error`]: struct.Error.html
+    pub const fn try_parse(input: &str) -> Result<Self, Error> {
+        match crate::parser::try_parse(input.as_bytes()) {
+            Ok(bytes) => match NonNilUuid::new(Uuid::from_bytes(bytes)) {
+                Some(non_nil) => Ok(non_nil),
+                None => Err(Error::new(ErrorKind::Nil)),
+            },
+            // Match `Uuid::try_parse`, which doesn't say what went wrong
+            Err(_) => Err(Error::new(ErrorKind::Other)),
+        }
+    }
+
     /// Get the underlying [`Uuid`] value.
     #[inline]
     pub const fn get(self) -> Uuid {
         Uuid::from_u128(self.0.get())
     }
+
+    /// Creates a random non-nil UUID.
+    ///
+    /// This is the same as [`Uuid::new_v4`], but doesn't need to be checked
+    /// for nil, because a version 4 UUID never is.
+    ///
+    /// Note that usage of this method requires the `v4` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{NonNilUuid, Version};
+    /// let uuid = NonNilUuid::new_v4();
+    ///
+    /// assert_eq!(Some(Version::Random), uuid.get().get_version());
+    /// ```
+    #[cfg(any(feature = "v4", feature = "rng-custom"))]
+    pub fn new_v4() -> Self {
+        Self::from_versioned(Uuid::new_v4())
+    }
+
+    /// Creates a non-nil version 7 UUID using the current time value.
+    ///
+    /// This is the same as [`Uuid::now_v7`], but doesn't need to be checked
+    /// for nil, because a version 7 UUID never is. UUIDs generated by this
+    /// method are ordered with those generated by [`Uuid::now_v7`].
+    ///
+    /// Note that usage of this method requires the `v7` and `std` features of
+    /// this crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{NonNilUuid, Version};
+    /// let uuid = NonNilUuid::now_v7();
+    ///
+    /// assert_eq!(Some(Version::SortRand), uuid.get().get_version());
+    /// ```
+    #[cfg(all(any(feature = "v7", feature = "rng-custom"), feature = "std"))]
+    pub fn now_v7() -> Self {
+        Self::from_versioned(Uuid::now_v7())
+    }
+
+    // Every version number is non-zero, so a UUID with one set is never nil
+    #[cfg(any(
+        feature = "v4",
+        feature = "rng-custom",
+        all(feature = "std", feature = "v7")
+    ))]
+    fn from_versioned(uuid: Uuid) -> Self {
+        match NonNilUuid::new(uuid) {
+            Some(non_nil) => non_nil,
+            None => unreachable!("a UUID with a version is never nil"),
+        }
+    }
+}
+
+impl Uuid {
+    /// Convert this UUID into a [`NonNilUuid`], if it's not the nil UUID.
+    ///
+    /// Use [`NonNilUuid::try_from`] instead to get an [`Error`] describing
+    /// why the conversion failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::from_u128(0x0123456789abcdef0123456789abcdef);
+    ///
+    /// assert_eq!(uuid, uuid.into_non_nil().unwrap());
+    /// assert_eq!(None, Uuid::nil().into_non_nil());
+    /// ```
+    #[inline]
+    pub const fn into_non_nil(self) -> Option<NonNilUuid> {
+        NonNilUuid::new(self)
+    }
 }
 
 impl From<NonNilUuid> for Uuid {
@@ -112,7 +252,23 @@ impl TryFrom<Uuid> for NonNilUuid {
     fn try_from(uuid: Uuid) -> Result<Self, Self::Error> {
         NonZeroU128::new(uuid.as_u128())
             .map(Self)
-            .ok_or(Error(ErrorKind::Nil))
+            .ok_or(Error::new(ErrorKind::Nil))
+    }
+}
+
+impl str::FromStr for NonNilUuid {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        NonNilUuid::parse_str(input)
+    }
+}
+
+impl TryFrom<&'_ str> for NonNilUuid {
+    type Error = Error;
+
+    fn try_from(input: &'_ str) -> Result<Self, Self::Error> {
+        NonNilUuid::parse_str(input)
     }
 }
 
@@ -134,9 +290,80 @@ mod tests {
 
         assert_eq!(Uuid::from(NonNilUuid::try_from(uuid).unwrap()), uuid);
         assert_eq!(NonNilUuid::new(uuid).unwrap(), uuid);
+        #[cfg(not(feature = "forbid-unsafe"))]
         assert_eq!(unsafe { NonNilUuid::new_unchecked(uuid) }, uuid);
 
         assert!(NonNilUuid::try_from(Uuid::nil()).is_err());
         assert!(NonNilUuid::new(Uuid::nil()).is_none());
     }
+
+    #[test]
+    fn test_into_non_nil() {
+        let uuid = Uuid::from_u128(0x0123456789abcdef0123456789abcdef);
+
+        assert_eq!(Some(uuid), uuid.into_non_nil().map(NonNilUuid::get));
+        assert_eq!(None, Uuid::nil().into_non_nil());
+
+        assert_eq!(
+            ErrorKind::Nil,
+            NonNilUuid::try_from(Uuid::nil()).unwrap_err().kind
+        );
+    }
+
+    #[test]
+    fn test_non_nil_parse() {
+        let uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+        for input in [
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "67e5504410b1426f9247bb680e5fe0c8",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+        ] {
+            assert_eq!(uuid, NonNilUuid::parse_str(input).unwrap());
+            assert_eq!(uuid, input.parse::<NonNilUuid>().unwrap());
+            assert_eq!(uuid, NonNilUuid::try_from(input).unwrap());
+        }
+
+        assert_eq!(
+            uuid,
+            NonNilUuid::try_parse("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()
+        );
+
+        let nil = "00000000-0000-0000-0000-000000000000";
+        assert_eq!(ErrorKind::Nil, NonNilUuid::parse_str(nil).unwrap_err().kind);
+        assert_eq!(ErrorKind::Nil, NonNilUuid::try_parse(nil).unwrap_err().kind);
+
+        assert!(NonNilUuid::parse_str("not a uuid").is_err());
+        assert!(NonNilUuid::try_parse("not a uuid").is_err());
+    }
+
+    #[test]
+    fn test_non_nil_ord() {
+        let a = Uuid::from_u128(0x00ff_0000_0000_0000_0000_0000_0000_0001);
+        let b = Uuid::from_u128(0x0100_0000_0000_0000_0000_0000_0000_0000);
+
+        // Ordering matches the ordering of the UUIDs
+        assert!(a < b);
+        assert!(a.into_non_nil() < b.into_non_nil());
+    }
+
+    #[test]
+    #[cfg(any(feature = "v4", feature = "rng-custom"))]
+    fn test_non_nil_new_v4() {
+        let uuid = NonNilUuid::new_v4();
+
+        assert_eq!(Some(crate::Version::Random), uuid.get().get_version());
+        assert_ne!(NonNilUuid::new_v4(), uuid);
+    }
+
+    #[test]
+    #[cfg(all(any(feature = "v7", feature = "rng-custom"), feature = "std"))]
+    fn test_non_nil_now_v7() {
+        let a = NonNilUuid::now_v7();
+        let b = Uuid::now_v7();
+        let c = NonNilUuid::now_v7();
+
+        assert_eq!(Some(crate::Version::SortRand), a.get().get_version());
+        assert!(a.get() < b && b < c.get());
+    }
 }
@@ -0,0 +1,201 @@
+//! Observe the UUIDs generated by this library.
+//!
+//! An observer is a callback that's invoked with every UUID generated by
+//! this library, along with its version. It can be used to implement
+//! auditing, metrics, or duplicate detection across a whole program without
+//! wrapping every call site that generates UUIDs.
+//!
+//! Observed UUIDs are those produced by the `new_*` and `now_*` methods on
+//! [`Uuid`], like [`Uuid::new_v4`] and [`Uuid::now_v7`], and by generators
+//! like [`v7::Generator`]. UUIDs built from caller-supplied bytes, like with
+//! [`Builder`] or [`Uuid::new_v8`], aren't observed.
+//!
+//! Note that usage of this module requires the `observe` feature of this
+//! crate to be enabled.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! static GENERATED: AtomicUsize = AtomicUsize::new(0);
+//!
+//! uuid::observe::set_observer(|_, _| {
+//!     GENERATED.fetch_add(1, Ordering::Relaxed);
+//! });
+//! # #[cfg(feature = "v4")]
+//! # {
+//! let _ = uuid::Uuid::new_v4();
+//!
+//! assert!(GENERATED.load(Ordering::Relaxed) > 0);
+//! # }
+//!
+//! uuid::observe::clear_observer();
+//! ```
+//!
+//! [`Uuid`]: ../struct.Uuid.html
+//! [`Uuid::new_v4`]: ../struct.Uuid.html#method.new_v4
+//! [`Uuid::now_v7`]: ../struct.Uuid.html#method.now_v7
+//! [`Uuid::new_v8`]: ../struct.Uuid.html#method.new_v8
+//! [`v7::Generator`]: ../v7/struct.Generator.html
+//! [`Builder`]: ../struct.Builder.html
+
+use crate::{
+    std::{
+        boxed::Box,
+        cell::Cell,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            RwLock,
+        },
+    },
+    Uuid, Version,
+};
+
+type Observer = Box<dyn Fn(Uuid, Version) + Send + Sync>;
+
+static OBSERVER: RwLock<Option<Observer>> = RwLock::new(None);
+
+// Checked before taking the lock so generation stays cheap without an observer
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+std::thread_local! {
+    static OBSERVING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Set the observer that's invoked with every generated UUID.
+///
+/// This replaces any observer that was previously set. The observer is
+/// called on the thread that generated the UUID, before the UUID is returned
+/// to the caller, so it should be quick.
+///
+/// UUIDs generated by the observer itself aren't observed.
+pub fn set_observer(observer: impl Fn(Uuid, Version) + Send + Sync + 'static) {
+    let mut guard = OBSERVER.write().unwrap_or_else(|e| e.into_inner());
+
+    *guard = Some(Box::new(observer));
+    ENABLED.store(true, Ordering::Release);
+}
+
+/// Remove the observer, if one is set.
+pub fn clear_observer() {
+    let mut guard = OBSERVER.write().unwrap_or_else(|e| e.into_inner());
+
+    ENABLED.store(false, Ordering::Release);
+    *guard = None;
+}
+
+/// Pass a freshly generated UUID to the observer.
+// This is unused when none of the features for generating UUIDs are enabled
+#[inline]
+#[allow(dead_code)]
+pub(crate) fn generated(uuid: Uuid, version: Version) -> Uuid {
+    if ENABLED.load(Ordering::Acquire) {
+        notify(uuid, version);
+    }
+
+    uuid
+}
+
+#[cold]
+fn notify(uuid: Uuid, version: Version) {
+    // Don't re-enter the observer if it generates UUIDs of its own
+    if OBSERVING.with(|observing| observing.replace(true)) {
+        return;
+    }
+
+    struct Reset;
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            OBSERVING.with(|observing| observing.set(false));
+        }
+    }
+
+    let _reset = Reset;
+
+    let guard = OBSERVER.read().unwrap_or_else(|e| e.into_inner());
+
+    if let Some(ref observer) = *guard {
+        observer(uuid, version);
+    }
+}
+
+#[cfg(test)]
+mod observe_tests {
+    use super::*;
+
+    use crate::std::{sync::Mutex, vec::Vec};
+
+    // The observer is global, so tests that set it can't run concurrently
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_observer() {
+        let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+        static SEEN: Mutex<Vec<(Uuid, u8)>> = Mutex::new(Vec::new());
+
+        set_observer(|uuid, version| {
+            SEEN.lock().unwrap().push((uuid, version as u8));
+        });
+
+        let uuid = generated(crate::tests::new(), Version::Random);
+
+        clear_observer();
+
+        let unobserved = generated(Uuid::from_u128(1), Version::Random);
+
+        let seen = SEEN.lock().unwrap();
+
+        assert!(seen.contains(&(uuid, Version::Random as u8)));
+        assert!(seen.iter().all(|(seen, _)| *seen != unobserved));
+    }
+
+    #[test]
+    fn test_observer_reentrant() {
+        let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+        static CALLS: Mutex<usize> = Mutex::new(0);
+
+        // Other tests may generate UUIDs while the observer is set
+        let uuid = Uuid::from_u128(2);
+
+        set_observer(move |seen, version| {
+            if seen == uuid {
+                *CALLS.lock().unwrap() += 1;
+
+                // Would recurse forever if observed
+                let _ = generated(uuid, version);
+            }
+        });
+
+        let _ = generated(uuid, Version::Custom);
+
+        clear_observer();
+
+        assert_eq!(1, *CALLS.lock().unwrap());
+    }
+
+    #[test]
+    #[cfg(any(feature = "v7", feature = "rng-custom"))]
+    fn test_observer_generates_during_batch() {
+        let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+        static GENERATED: Mutex<Vec<Uuid>> = Mutex::new(Vec::new());
+
+        // Would deadlock if the observer were called while the shared context is locked
+        set_observer(|_, version| {
+            if version == Version::SortRand {
+                GENERATED.lock().unwrap().push(Uuid::now_v7());
+            }
+        });
+
+        let mut uuids = [Uuid::nil(); 4];
+        Uuid::now_v7_batch(&mut uuids);
+
+        clear_observer();
+
+        assert!(GENERATED.lock().unwrap().len() >= uuids.len());
+    }
+}
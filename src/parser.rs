@@ -15,8 +15,9 @@
 
 use crate::{
     error::*,
-    std::{convert::TryFrom, str},
-    Uuid,
+    fmt::{self, Checksummed, Hyphenated, Simple, HYPHENATED_POSITIONS},
+    std::{convert::TryFrom, iter::FusedIterator, ops::Range, str},
+    Uuid, Variant, Version,
 };
 
 impl str::FromStr for Uuid {
@@ -35,6 +36,80 @@ impl TryFrom<&'_ str> for Uuid {
     }
 }
 
+impl str::FromStr for Version {
+    type Err = Error;
+
+    /// Parses a version from its name, like `v7`.
+    ///
+    /// This accepts the same names written by the `Display` implementation
+    /// for [`Version`], `nil`, `max`, and `v1` to `v8`, in any case. The
+    /// leading `v` of a numbered version is optional.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let number = match input.as_bytes() {
+            [b'v' | b'V', number @ ..] => number,
+            number => number,
+        };
+
+        let version = match number {
+            b"1" => Some(Version::Mac),
+            b"2" => Some(Version::Dce),
+            b"3" => Some(Version::Md5),
+            b"4" => Some(Version::Random),
+            b"5" => Some(Version::Sha1),
+            b"6" => Some(Version::SortMac),
+            b"7" => Some(Version::SortRand),
+            b"8" => Some(Version::Custom),
+            _ if input.eq_ignore_ascii_case("nil") => Some(Version::Nil),
+            _ if input.eq_ignore_ascii_case("max") => Some(Version::Max),
+            _ => None,
+        };
+
+        match version {
+            Some(version) => Ok(version),
+            None => {
+                let err = Error::new(ErrorKind::VersionName);
+
+                #[cfg(feature = "std")]
+                let err = err.with_input(input);
+
+                Err(err)
+            }
+        }
+    }
+}
+
+impl str::FromStr for Variant {
+    type Err = Error;
+
+    /// Parses a variant from its name, like `RFC4122`.
+    ///
+    /// This accepts the same names written by the `Display` implementation
+    /// for [`Variant`], in any case. `RFC9562` is also accepted as the name
+    /// of [`Variant::RFC4122`].
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let names = [
+            ("NCS", Variant::NCS),
+            ("RFC4122", Variant::RFC4122),
+            ("RFC9562", Variant::RFC4122),
+            ("Microsoft", Variant::Microsoft),
+            ("Future", Variant::Future),
+        ];
+
+        for (name, variant) in names {
+            if input.eq_ignore_ascii_case(name) {
+                return Ok(variant);
+            }
+        }
+
+        let err = Error::new(ErrorKind::VariantName);
+
+        #[cfg(feature = "std")]
+        let err = err.with_input(input);
+
+        Err(err)
+    }
+}
+
 impl Uuid {
     /// Parses a `Uuid` from a string of hexadecimal digits with optional
     /// hyphens.
@@ -64,7 +139,14 @@ impl Uuid {
     pub fn parse_str(input: &str) -> Result<Uuid, Error> {
         try_parse(input.as_bytes())
             .map(Uuid::from_bytes)
-            .map_err(InvalidUuid::into_err)
+            .map_err(|err| {
+                let err = err.into_err();
+
+                #[cfg(feature = "std")]
+                let err = err.with_input(input);
+
+                err
+            })
     }
 
     /// Parses a `Uuid` from a string of hexadecimal digits with optional
@@ -127,12 +209,399 @@ impl Uuid {
             Ok(bytes) => Ok(Uuid::from_bytes(bytes)),
             // If parsing fails then we don't know exactly what went wrong
             // In this case, we just return a generic error
-            Err(_) => Err(Error(ErrorKind::Other)),
+            Err(_) => Err(Error::new(ErrorKind::Other)),
+        }
+    }
+
+    /// Parses a `Uuid` from a string of hexadecimal digits with optional
+    /// hyphens, panicking if the input isn't a valid UUID.
+    ///
+    /// This accepts the same formats as [`try_parse`]. It's intended for
+    /// constants: when used to initialize a `const` or `static`, an invalid
+    /// UUID is reported as a compile error, without needing the proc-macro
+    /// dependency of the [`uuid!`] macro's `macro-diagnostics` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` isn't a valid UUID. In a const context this is a
+    /// compile error instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// const NAMESPACE: Uuid = Uuid::from_str_const("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    ///
+    /// assert_eq!(Uuid::NAMESPACE_DNS, NAMESPACE);
+    /// ```
+    ///
+    /// Invalid UUIDs fail to compile:
+    ///
+    /// ```compile_fail
+    /// # use uuid::Uuid;
+    /// const NAMESPACE: Uuid = Uuid::from_str_const("6ba7b810-9dad-11d1-80b4-00c04fd430cx");
+    /// # let _ = NAMESPACE;
+    /// ```
+    ///
+    /// [`try_parse`]: #method.try_parse
+    /// [`uuid!`]: macro.uuid.html
+    pub const fn from_str_const(input: &str) -> Uuid {
+        match try_parse(input.as_bytes()) {
+            Ok(bytes) => Uuid::from_bytes(bytes),
+            Err(_) => panic!("invalid UUID"),
         }
     }
+
+    /// Parses a `Uuid` from a hyphenated string followed by its check symbols,
+    /// like `67e55044-10b1-426f-9247-bb680e5fe0c8-sh`.
+    ///
+    /// This is the format produced by [`Uuid::checksummed`]. The check symbols
+    /// are case-insensitive, and the commonly confused characters `o`, `i`, and `l`
+    /// are read as `0`, `1`, and `1`, as in Crockford's base32.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input isn't a hyphenated UUID
+    /// followed by a `-` and 2 check symbols, or if the check symbols don't
+    /// match the UUID. A mismatch means the input was most likely mistyped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_checksummed("67e55044-10b1-426f-9247-bb680e5fe0c8-sh")?;
+    ///
+    /// assert_eq!(Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?, uuid);
+    ///
+    /// // The last two digits have been swapped
+    /// assert!(Uuid::parse_checksummed("67e55044-10b1-426f-9247-bb680e5fe08c-sh").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_checksummed(input: &str) -> Result<Uuid, Error> {
+        let result = parse_checksummed(input.as_bytes());
+
+        #[cfg(feature = "std")]
+        let result = result.map_err(|err| err.with_input(input));
+
+        result
+    }
+
+    /// Parses a `Uuid` from a string, accepting only the formats allowed by
+    /// the given [`ParseOptions`].
+    ///
+    /// This is useful for services that need to codify exactly which forms of
+    /// UUID they accept, like only lowercase hyphenated UUIDs in URLs, but
+    /// also braced UUIDs with surrounding whitespace in CSV imports.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{ParseOptions, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let options = ParseOptions::strict().require_lowercase(true);
+    ///
+    /// let uuid = Uuid::parse_with("67e55044-10b1-426f-9247-bb680e5fe0c8", &options)?;
+    ///
+    /// assert!(Uuid::parse_with("67e5504410b1426f9247bb680e5fe0c8", &options).is_err());
+    /// assert!(Uuid::parse_with("67E55044-10B1-426F-9247-BB680E5FE0C8", &options).is_err());
+    ///
+    /// let options = ParseOptions::new().allow_whitespace(true);
+    ///
+    /// assert_eq!(uuid, Uuid::parse_with(" {67E55044-10B1-426F-9247-BB680E5FE0C8}\n", &options)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_with(input: &str, options: &ParseOptions) -> Result<Uuid, Error> {
+        let result = parse_with(input.as_bytes(), options);
+
+        #[cfg(feature = "std")]
+        let result = result.map_err(|err| err.with_input(input));
+
+        result
+    }
 }
 
-const fn try_parse(input: &[u8]) -> Result<[u8; 16], InvalidUuid> {
+/// The formats accepted by [`Uuid::parse_with`].
+///
+/// [`ParseOptions::new`] accepts the same formats as [`Uuid::parse_str`]:
+/// hyphenated UUIDs, optionally wrapped in braces or prefixed with
+/// `urn:uuid:`, and simple UUIDs without hyphens, in any case.
+/// [`ParseOptions::strict`] only accepts bare hyphenated UUIDs. Either can
+/// then be adjusted with the other methods on this type.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::ParseOptions;
+/// // Accept hyphenated UUIDs, with or without braces, but nothing else
+/// let options = ParseOptions::strict().allow_braces(true);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    allow_braces: bool,
+    allow_urn: bool,
+    allow_missing_hyphens: bool,
+    allow_whitespace: bool,
+    require_lowercase: bool,
+    separator: u8,
+}
+
+impl ParseOptions {
+    /// Options that accept the same formats as [`Uuid::parse_str`].
+    pub const fn new() -> Self {
+        ParseOptions {
+            allow_braces: true,
+            allow_urn: true,
+            allow_missing_hyphens: true,
+            allow_whitespace: false,
+            require_lowercase: false,
+            separator: b'-',
+        }
+    }
+
+    /// Options that only accept hyphenated UUIDs, like
+    /// `67e55044-10b1-426f-9247-bb680e5fe0c8`, in any case.
+    pub const fn strict() -> Self {
+        ParseOptions {
+            allow_braces: false,
+            allow_urn: false,
+            allow_missing_hyphens: false,
+            allow_whitespace: false,
+            require_lowercase: false,
+            separator: b'-',
+        }
+    }
+
+    /// Whether to accept hyphenated UUIDs wrapped in braces, like
+    /// `{67e55044-10b1-426f-9247-bb680e5fe0c8}`.
+    pub const fn allow_braces(mut self, allow: bool) -> Self {
+        self.allow_braces = allow;
+        self
+    }
+
+    /// Whether to accept hyphenated UUIDs prefixed with `urn:uuid:`, like
+    /// `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    pub const fn allow_urn(mut self, allow: bool) -> Self {
+        self.allow_urn = allow;
+        self
+    }
+
+    /// Whether to accept UUIDs without hyphens, like
+    /// `67e5504410b1426f9247bb680e5fe0c8`.
+    pub const fn allow_missing_hyphens(mut self, allow: bool) -> Self {
+        self.allow_missing_hyphens = allow;
+        self
+    }
+
+    /// Whether to ignore ASCII whitespace before and after the UUID.
+    pub const fn allow_whitespace(mut self, allow: bool) -> Self {
+        self.allow_whitespace = allow;
+        self
+    }
+
+    /// Whether to reject UUIDs with uppercase hex digits.
+    pub const fn require_lowercase(mut self, require: bool) -> Self {
+        self.require_lowercase = require;
+        self
+    }
+
+    /// Also accept UUIDs with `separator` between their groups instead of
+    /// hyphens, like `67e55044_10b1_426f_9247_bb680e5fe0c8`.
+    ///
+    /// This is the format written by [`Uuid::separated`]. Hyphenated UUIDs are
+    /// still accepted, but the separator can't be mixed with hyphens in the
+    /// same UUID, or used in braced UUIDs or URNs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `separator` isn't an ASCII character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{ParseOptions, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let options = ParseOptions::new().allow_separator('_');
+    ///
+    /// let uuid = Uuid::parse_with("67e55044_10b1_426f_9247_bb680e5fe0c8", &options)?;
+    ///
+    /// assert_eq!(uuid, Uuid::parse_with("67e55044-10b1-426f-9247-bb680e5fe0c8", &options)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Uuid::separated`]: struct.Uuid.html#method.separated
+    pub const fn allow_separator(mut self, separator: char) -> Self {
+        assert!(separator.is_ascii(), "UUID group separators must be ASCII");
+
+        self.separator = separator as u8;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::new()
+    }
+}
+
+fn parse_with(input: &[u8], options: &ParseOptions) -> Result<Uuid, Error> {
+    let mut offset = 0;
+    let mut trimmed = input;
+
+    if options.allow_whitespace {
+        while let [first, rest @ ..] = trimmed {
+            if !first.is_ascii_whitespace() {
+                break;
+            }
+
+            trimmed = rest;
+            offset += 1;
+        }
+
+        while let [rest @ .., last] = trimmed {
+            if !last.is_ascii_whitespace() {
+                break;
+            }
+
+            trimmed = rest;
+        }
+    }
+
+    let invalid_char = |index: usize| {
+        // The input is a `str`, so there's a char at any ASCII boundary
+        let character = str::from_utf8(&input[index..])
+            .ok()
+            .and_then(|s| s.chars().next())
+            .unwrap_or(char::REPLACEMENT_CHARACTER);
+
+        Error::new(ErrorKind::Char {
+            character,
+            index: index + 1,
+        })
+    };
+
+    // Swap a custom separator for hyphens so the UUID can be parsed as
+    // a hyphenated one. The separator is ASCII, so the normalized input is
+    // still valid UTF-8 with its characters at the same indexes
+    let mut normalized = [0; Hyphenated::LENGTH];
+    let trimmed = match trimmed {
+        s if options.separator != b'-'
+            && s.len() == Hyphenated::LENGTH
+            && [8, 13, 18, 23].iter().all(|&i| s[i] == options.separator) =>
+        {
+            normalized.copy_from_slice(s);
+            for i in [8, 13, 18, 23] {
+                normalized[i] = b'-';
+            }
+
+            &normalized[..]
+        }
+        s => s,
+    };
+
+    let parsed = match trimmed {
+        [b'{', ..] if !options.allow_braces => return Err(invalid_char(offset)),
+        [b'u', b'r', b'n', b':', b'u', b'u', b'i', b'd', b':', ..] if !options.allow_urn => {
+            return Err(invalid_char(offset))
+        }
+        s if s.len() == Simple::LENGTH && !options.allow_missing_hyphens => {
+            // Report simple UUIDs as hyphenated UUIDs missing their groups
+            match parse_simple(s) {
+                Ok(_) => return Err(Error::new(ErrorKind::GroupCount { count: 1 })),
+                Err(err) => Err(err),
+            }
+        }
+        s => try_parse(s),
+    };
+
+    let uuid = match parsed {
+        Ok(bytes) => Uuid::from_bytes(bytes),
+        Err(_) => {
+            let mut err = InvalidUuid(trimmed).into_err();
+
+            // Errors are reported relative to the trimmed input, so shift
+            // them past any leading whitespace
+            if let ErrorKind::Char { ref mut index, .. } = err.kind {
+                *index += offset;
+            }
+
+            return Err(err);
+        }
+    };
+
+    if options.require_lowercase {
+        if let Some(index) = trimmed.iter().position(|b| b.is_ascii_uppercase()) {
+            return Err(invalid_char(offset + index));
+        }
+    }
+
+    Ok(uuid)
+}
+
+fn parse_checksummed(input: &[u8]) -> Result<Uuid, Error> {
+    if input.len() != Checksummed::LENGTH {
+        return Err(Error::new(ErrorKind::ChecksumLength { len: input.len() }));
+    }
+
+    let (uuid, check) = input.split_at(Hyphenated::LENGTH);
+
+    // Only the hyphenated format is 36 characters long
+    let uuid = Uuid::from_bytes(try_parse(uuid).map_err(InvalidUuid::into_err)?);
+
+    let invalid_char = |index: usize| {
+        // The input is a `str`, so there's a char at any ASCII boundary
+        let character = str::from_utf8(&input[index..])
+            .ok()
+            .and_then(|s| s.chars().next())
+            .unwrap_or(char::REPLACEMENT_CHARACTER);
+
+        Error::new(ErrorKind::ChecksumChar {
+            character,
+            index: index + 1,
+        })
+    };
+
+    if check[0] != b'-' {
+        return Err(invalid_char(Hyphenated::LENGTH));
+    }
+
+    let mut checksum = 0;
+    for (i, symbol) in check[1..].iter().enumerate() {
+        match decode_check_symbol(*symbol) {
+            Some(value) => checksum = (checksum << 5) | value as u16,
+            None => return Err(invalid_char(Hyphenated::LENGTH + 1 + i)),
+        }
+    }
+
+    if checksum != fmt::checksum(uuid.as_bytes()) {
+        return Err(Error::new(ErrorKind::Checksum));
+    }
+
+    Ok(uuid)
+}
+
+const fn decode_check_symbol(symbol: u8) -> Option<u8> {
+    let symbol = match symbol.to_ascii_uppercase() {
+        b'O' => b'0',
+        b'I' | b'L' => b'1',
+        symbol => symbol,
+    };
+
+    let mut i = 0;
+    while i < fmt::CHECK_SYMBOLS.len() {
+        if fmt::CHECK_SYMBOLS[i] == symbol {
+            return Some(i as u8);
+        }
+        i += 1;
+    }
+
+    None
+}
+
+pub(crate) const fn try_parse(input: &[u8]) -> Result<[u8; 16], InvalidUuid<'_>> {
     match (input.len(), input) {
         // Inputs of 32 bytes must be a non-hyphenated UUID
         (32, s) => parse_simple(s),
@@ -152,7 +621,7 @@ const fn try_parse(input: &[u8]) -> Result<[u8; 16], InvalidUuid> {
 
 #[inline]
 #[allow(dead_code)]
-pub(crate) const fn parse_braced(input: &[u8]) -> Result<[u8; 16], InvalidUuid> {
+pub(crate) const fn parse_braced(input: &[u8]) -> Result<[u8; 16], InvalidUuid<'_>> {
     if let (38, [b'{', s @ .., b'}']) = (input.len(), input) {
         parse_hyphenated(s)
     } else {
@@ -162,7 +631,7 @@ pub(crate) const fn parse_braced(input: &[u8]) -> Result<[u8; 16], InvalidUuid>
 
 #[inline]
 #[allow(dead_code)]
-pub(crate) const fn parse_urn(input: &[u8]) -> Result<[u8; 16], InvalidUuid> {
+pub(crate) const fn parse_urn(input: &[u8]) -> Result<[u8; 16], InvalidUuid<'_>> {
     if let (45, [b'u', b'r', b'n', b':', b'u', b'u', b'i', b'd', b':', s @ ..]) =
         (input.len(), input)
     {
@@ -173,7 +642,7 @@ pub(crate) const fn parse_urn(input: &[u8]) -> Result<[u8; 16], InvalidUuid> {
 }
 
 #[inline]
-pub(crate) const fn parse_simple(s: &[u8]) -> Result<[u8; 16], InvalidUuid> {
+pub(crate) const fn parse_simple(s: &[u8]) -> Result<[u8; 16], InvalidUuid<'_>> {
     // This length check here removes all other bounds
     // checks in this function
     if s.len() != 32 {
@@ -181,6 +650,7 @@ pub(crate) const fn parse_simple(s: &[u8]) -> Result<[u8; 16], InvalidUuid> {
     }
 
     let mut buf: [u8; 16] = [0; 16];
+    let mut invalid = 0;
     let mut i = 0;
 
     while i < 16 {
@@ -190,10 +660,10 @@ pub(crate) const fn parse_simple(s: &[u8]) -> Result<[u8; 16], InvalidUuid> {
         let h2 = HEX_TABLE[s[i * 2 + 1] as usize];
 
         // We use `0xff` as a sentinel value to indicate
-        // an invalid hex character sequence (like the letter `G`)
-        if h1 | h2 == 0xff {
-            return Err(InvalidUuid(s));
-        }
+        // an invalid hex character sequence (like the letter `G`).
+        // Valid digits never set the high bits, so the sentinel is
+        // collected across the whole input and checked once at the end
+        invalid |= h1 | h2;
 
         // The upper nibble needs to be shifted into position
         // to produce the final byte value
@@ -201,19 +671,21 @@ pub(crate) const fn parse_simple(s: &[u8]) -> Result<[u8; 16], InvalidUuid> {
         i += 1;
     }
 
+    if invalid == 0xff {
+        return Err(InvalidUuid(s));
+    }
+
     Ok(buf)
 }
 
 #[inline]
-const fn parse_hyphenated(s: &[u8]) -> Result<[u8; 16], InvalidUuid> {
+pub(crate) const fn parse_hyphenated(s: &[u8]) -> Result<[u8; 16], InvalidUuid<'_>> {
     // This length check here removes all other bounds
     // checks in this function
     if s.len() != 36 {
         return Err(InvalidUuid(s));
     }
 
-    // We look at two hex-encoded values (4 chars) at a time because
-    // that's the size of the smallest group in a hyphenated UUID.
     // The indexes we're interested in are:
     //
     // uuid     : 936da01f-9abd-4d9d-80c7-02af85c822a8
@@ -227,27 +699,25 @@ const fn parse_hyphenated(s: &[u8]) -> Result<[u8; 16], InvalidUuid> {
         _ => return Err(InvalidUuid(s)),
     }
 
-    let positions: [u8; 8] = [0, 4, 9, 14, 19, 24, 28, 32];
     let mut buf: [u8; 16] = [0; 16];
-    let mut j = 0;
+    let mut invalid = 0;
+    let mut i = 0;
 
-    while j < 8 {
-        let i = positions[j];
+    while i < 16 {
+        // The decoding here is the same as the simple case,
+        // we just look up where each byte's digits are
+        let j = HYPHENATED_POSITIONS[i] as usize;
+        let h1 = HEX_TABLE[s[j] as usize];
+        let h2 = HEX_TABLE[s[j + 1] as usize];
 
-        // The decoding here is the same as the simple case
-        // We're just dealing with two values instead of one
-        let h1 = HEX_TABLE[s[i as usize] as usize];
-        let h2 = HEX_TABLE[s[(i + 1) as usize] as usize];
-        let h3 = HEX_TABLE[s[(i + 2) as usize] as usize];
-        let h4 = HEX_TABLE[s[(i + 3) as usize] as usize];
+        invalid |= h1 | h2;
 
-        if h1 | h2 | h3 | h4 == 0xff {
-            return Err(InvalidUuid(s));
-        }
+        buf[i] = SHL4_TABLE[h1 as usize] | h2;
+        i += 1;
+    }
 
-        buf[j * 2] = SHL4_TABLE[h1 as usize] | h2;
-        buf[j * 2 + 1] = SHL4_TABLE[h3 as usize] | h4;
-        j += 1;
+    if invalid == 0xff {
+        return Err(InvalidUuid(s));
     }
 
     Ok(buf)
@@ -288,6 +758,109 @@ const SHL4_TABLE: &[u8; 256] = &{
     }
 };
 
+impl Uuid {
+    /// Parses many UUIDs from a string, separated by `delimiter`.
+    ///
+    /// Each field between delimiters is trimmed of ASCII whitespace and parsed
+    /// with [`Uuid::parse_str`]. The returned iterator yields the range of bytes
+    /// each field was found at in `input` along with the result of parsing it, so
+    /// a bad field can be reported without stopping at it. Nothing is allocated,
+    /// so this can be used to read large files of UUIDs a chunk at a time.
+    ///
+    /// A trailing delimiter, like the final newline of a file, doesn't yield
+    /// an empty field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let input = "67e55044-10b1-426f-9247-bb680e5fe0c8\nnot a uuid\n";
+    ///
+    /// let mut parsed = Uuid::parse_many(input, '\n');
+    ///
+    /// let (range, first) = parsed.next().unwrap();
+    /// assert_eq!(0..36, range);
+    /// assert!(first.is_ok());
+    ///
+    /// let (range, second) = parsed.next().unwrap();
+    /// assert_eq!(37..47, range);
+    /// assert!(second.is_err());
+    ///
+    /// assert!(parsed.next().is_none());
+    /// ```
+    ///
+    /// Collecting the UUIDs, stopping at the first error:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let input = "67e55044-10b1-426f-9247-bb680e5fe0c8, a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8";
+    ///
+    /// let uuids = Uuid::parse_many(input, ',')
+    ///     .map(|(_, uuid)| uuid)
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert_eq!(2, uuids.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_many(input: &str, delimiter: char) -> ParseMany<'_> {
+        ParseMany {
+            input,
+            delimiter,
+            position: Some(0),
+        }
+    }
+}
+
+/// An iterator over UUIDs separated by a delimiter.
+///
+/// This type is returned by [`Uuid::parse_many`].
+#[derive(Clone, Debug)]
+pub struct ParseMany<'a> {
+    input: &'a str,
+    delimiter: char,
+    // The start of the next field, or `None` once the last field is parsed
+    position: Option<usize>,
+}
+
+impl<'a> Iterator for ParseMany<'a> {
+    type Item = (Range<usize>, Result<Uuid, Error>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.position?;
+        let rest = &self.input[position..];
+
+        let field = match rest.find(self.delimiter) {
+            Some(end) => {
+                self.position = Some(position + end + self.delimiter.len_utf8());
+                &rest[..end]
+            }
+            None => {
+                self.position = None;
+                rest
+            }
+        };
+
+        let trimmed = field.trim_matches(|c: char| c.is_ascii_whitespace());
+
+        // Don't yield an empty field after a trailing delimiter
+        if self.position.is_none() && trimmed.is_empty() {
+            return None;
+        }
+
+        let start = position
+            + (field.len()
+                - field
+                    .trim_start_matches(|c: char| c.is_ascii_whitespace())
+                    .len());
+
+        Some((start..start + trimmed.len(), Uuid::parse_str(trimmed)))
+    }
+}
+
+impl<'a> FusedIterator for ParseMany<'a> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -329,12 +902,12 @@ mod tests {
         // Invalid
         assert_eq!(
             Uuid::parse_str(""),
-            Err(Error(ErrorKind::SimpleLength { len: 0 }))
+            Err(Error::new(ErrorKind::SimpleLength { len: 0 }))
         );
 
         assert_eq!(
             Uuid::parse_str("!"),
-            Err(Error(ErrorKind::Char {
+            Err(Error::new(ErrorKind::Char {
                 character: '!',
                 index: 1,
             }))
@@ -342,7 +915,7 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E45"),
-            Err(Error(ErrorKind::GroupLength {
+            Err(Error::new(ErrorKind::GroupLength {
                 group: 4,
                 len: 13,
                 index: 25,
@@ -351,7 +924,7 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa-BBF-329BF39FA1E4"),
-            Err(Error(ErrorKind::GroupLength {
+            Err(Error::new(ErrorKind::GroupLength {
                 group: 3,
                 len: 3,
                 index: 20,
@@ -360,7 +933,7 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa-BGBF-329BF39FA1E4"),
-            Err(Error(ErrorKind::Char {
+            Err(Error::new(ErrorKind::Char {
                 character: 'G',
                 index: 21,
             }))
@@ -368,27 +941,27 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2F4faaFB6BFF329BF39FA1E4"),
-            Err(Error(ErrorKind::GroupCount { count: 2 }))
+            Err(Error::new(ErrorKind::GroupCount { count: 2 }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faaFB6BFF329BF39FA1E4"),
-            Err(Error(ErrorKind::GroupCount { count: 3 }))
+            Err(Error::new(ErrorKind::GroupCount { count: 3 }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa-B6BFF329BF39FA1E4"),
-            Err(Error(ErrorKind::GroupCount { count: 4 }))
+            Err(Error::new(ErrorKind::GroupCount { count: 4 }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa"),
-            Err(Error(ErrorKind::GroupCount { count: 3 }))
+            Err(Error::new(ErrorKind::GroupCount { count: 3 }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faaXB6BFF329BF39FA1E4"),
-            Err(Error(ErrorKind::Char {
+            Err(Error::new(ErrorKind::Char {
                 character: 'X',
                 index: 19,
             }))
@@ -396,7 +969,7 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("{F9168C5E-CEB2-4faa9B6BFF329BF39FA1E41"),
-            Err(Error(ErrorKind::Char {
+            Err(Error::new(ErrorKind::Char {
                 character: '{',
                 index: 1,
             }))
@@ -404,12 +977,12 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("{F9168C5E-CEB2-4faa9B6BFF329BF39FA1E41}"),
-            Err(Error(ErrorKind::GroupCount { count: 3 }))
+            Err(Error::new(ErrorKind::GroupCount { count: 3 }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB-24fa-eB6BFF32-BF39FA1E4"),
-            Err(Error(ErrorKind::GroupLength {
+            Err(Error::new(ErrorKind::GroupLength {
                 group: 1,
                 len: 3,
                 index: 10,
@@ -420,7 +993,7 @@ mod tests {
         // //
         assert_eq!(
             Uuid::parse_str("01020304-1112-2122-3132-41424344"),
-            Err(Error(ErrorKind::GroupLength {
+            Err(Error::new(ErrorKind::GroupLength {
                 group: 4,
                 len: 8,
                 index: 25,
@@ -429,17 +1002,17 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c"),
-            Err(Error(ErrorKind::SimpleLength { len: 31 }))
+            Err(Error::new(ErrorKind::SimpleLength { len: 31 }))
         );
 
         assert_eq!(
             Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c88"),
-            Err(Error(ErrorKind::SimpleLength { len: 33 }))
+            Err(Error::new(ErrorKind::SimpleLength { len: 33 }))
         );
 
         assert_eq!(
             Uuid::parse_str("67e5504410b1426f9247bb680e5fe0cg8"),
-            Err(Error(ErrorKind::Char {
+            Err(Error::new(ErrorKind::Char {
                 character: 'g',
                 index: 32,
             }))
@@ -447,7 +1020,7 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("67e5504410b1426%9247bb680e5fe0c8"),
-            Err(Error(ErrorKind::Char {
+            Err(Error::new(ErrorKind::Char {
                 character: '%',
                 index: 16,
             }))
@@ -455,22 +1028,22 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("231231212212423424324323477343246663"),
-            Err(Error(ErrorKind::SimpleLength { len: 36 }))
+            Err(Error::new(ErrorKind::SimpleLength { len: 36 }))
         );
 
         assert_eq!(
             Uuid::parse_str("{00000000000000000000000000000000}"),
-            Err(Error(ErrorKind::GroupCount { count: 1 }))
+            Err(Error::new(ErrorKind::GroupCount { count: 1 }))
         );
 
         assert_eq!(
             Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c"),
-            Err(Error(ErrorKind::SimpleLength { len: 31 }))
+            Err(Error::new(ErrorKind::SimpleLength { len: 31 }))
         );
 
         assert_eq!(
             Uuid::parse_str("67e550X410b1426f9247bb680e5fe0cd"),
-            Err(Error(ErrorKind::Char {
+            Err(Error::new(ErrorKind::Char {
                 character: 'X',
                 index: 7,
             }))
@@ -478,12 +1051,12 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("67e550-4105b1426f9247bb680e5fe0c"),
-            Err(Error(ErrorKind::GroupCount { count: 2 }))
+            Err(Error::new(ErrorKind::GroupCount { count: 2 }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF1-02BF39FA1E4"),
-            Err(Error(ErrorKind::GroupLength {
+            Err(Error::new(ErrorKind::GroupLength {
                 group: 3,
                 len: 5,
                 index: 20,
@@ -492,7 +1065,7 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("\u{bcf3c}"),
-            Err(Error(ErrorKind::Char {
+            Err(Error::new(ErrorKind::Char {
                 character: '\u{bcf3c}',
                 index: 1
             }))
@@ -559,4 +1132,380 @@ mod tests {
     fn test_try_parse_ascii_non_utf8() {
         assert!(Uuid::try_parse_ascii(b"67e55044-10b1-426f-9247-bb680e5\0e0c8").is_err());
     }
+
+    #[test]
+    fn test_from_str_const() {
+        const SIMPLE: Uuid = Uuid::from_str_const("67e5504410b1426f9247bb680e5fe0c8");
+        const URN: Uuid = Uuid::from_str_const("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8");
+
+        let expected = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        assert_eq!(expected, SIMPLE);
+        assert_eq!(expected, URN);
+        assert_eq!(
+            expected,
+            Uuid::from_str_const("{67e55044-10b1-426f-9247-bb680e5fe0c8}")
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_str_const_invalid() {
+        let _ = Uuid::from_str_const("67e55044-10b1-426f-9247-bb680e5fe0c");
+    }
+
+    #[test]
+    fn test_parse_never_panics() {
+        let valid = "urn:uuid:{67e55044-10b1-426f-9247-bb680e5fe0c8}-";
+
+        // Every prefix and suffix of a jumble of valid pieces, with some
+        // multi-byte characters thrown in, should produce an error rather than a panic
+        for input in [valid, "{-é-67e5-}", "urn:uuid:ü"] {
+            for (i, _) in input.char_indices() {
+                for s in [&input[..i], &input[i..]] {
+                    let _ = Uuid::try_parse(s);
+                    let _ = Uuid::try_parse_ascii(s.as_bytes());
+
+                    if let Err(err) = Uuid::parse_str(s) {
+                        let _ = err.to_string();
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_checksummed() {
+        let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        for input in [
+            "67e55044-10b1-426f-9247-bb680e5fe0c8-sh",
+            "67E55044-10B1-426F-9247-BB680E5FE0C8-SH",
+            "67e55044-10b1-426f-9247-bb680e5fe0c8-sH",
+        ] {
+            assert_eq!(uuid, Uuid::parse_checksummed(input).unwrap());
+        }
+
+        // Confusable symbols are accepted
+        assert_eq!(
+            Uuid::nil(),
+            Uuid::parse_checksummed("00000000-0000-0000-0000-000000000000-oO").unwrap()
+        );
+
+        for uuid in [new(), Uuid::nil(), Uuid::max()] {
+            let encoded = uuid.checksummed().to_string();
+
+            assert_eq!(uuid, Uuid::parse_checksummed(&encoded).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_parse_checksummed_detects_typos() {
+        let encoded = "67e55044-10b1-426f-9247-bb680e5fe0c8-sh";
+
+        for i in (0..Hyphenated::LENGTH).filter(|i| ![8, 13, 18, 23].contains(i)) {
+            // Every single character substitution
+            for digit in b"0123456789abcdef" {
+                if *digit == encoded.as_bytes()[i] {
+                    continue;
+                }
+
+                let mut typo = encoded.as_bytes().to_vec();
+                typo[i] = *digit;
+
+                assert_eq!(
+                    Error::new(ErrorKind::Checksum),
+                    Uuid::parse_checksummed(str::from_utf8(&typo).unwrap()).unwrap_err()
+                );
+            }
+
+            // Every transposition of adjacent digits
+            let j = if [7, 12, 17, 22].contains(&i) {
+                i + 2
+            } else {
+                i + 1
+            };
+            if j < Hyphenated::LENGTH && encoded.as_bytes()[i] != encoded.as_bytes()[j] {
+                let mut typo = encoded.as_bytes().to_vec();
+                typo.swap(i, j);
+
+                assert_eq!(
+                    Error::new(ErrorKind::Checksum),
+                    Uuid::parse_checksummed(str::from_utf8(&typo).unwrap()).unwrap_err()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_checksummed_invalid() {
+        assert_eq!(
+            Error::new(ErrorKind::ChecksumLength { len: 36 }),
+            Uuid::parse_checksummed("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::ChecksumChar {
+                character: '_',
+                index: 37
+            }),
+            Uuid::parse_checksummed("67e55044-10b1-426f-9247-bb680e5fe0c8_sh").unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::ChecksumChar {
+                character: 'u',
+                index: 39
+            }),
+            Uuid::parse_checksummed("67e55044-10b1-426f-9247-bb680e5fe0c8-su").unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::Char {
+                character: 'g',
+                index: 1
+            }),
+            Uuid::parse_checksummed("g7e55044-10b1-426f-9247-bb680e5fe0c8-sh").unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::Checksum),
+            Uuid::parse_checksummed("67e55044-10b1-426f-9247-bb680e5fe0c8-th").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_with_default() {
+        let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let options = ParseOptions::default();
+
+        for input in [
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "67E55044-10B1-426F-9247-BB680E5FE0C8",
+            "67e5504410b1426f9247bb680e5fe0c8",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+        ] {
+            assert_eq!(uuid, Uuid::parse_with(input, &options).unwrap());
+        }
+
+        for input in [
+            "",
+            " 67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "{67e5504410b1426f9247bb680e5fe0c8}",
+            "67e55044-10b1-426f-9247-bb680e5fe0cg",
+        ] {
+            assert_eq!(
+                Uuid::parse_str(input).unwrap_err(),
+                Uuid::parse_with(input, &options).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_with_strict() {
+        let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let options = ParseOptions::strict();
+
+        assert_eq!(
+            uuid,
+            Uuid::parse_with("67e55044-10b1-426f-9247-bb680e5fe0c8", &options).unwrap()
+        );
+
+        assert_eq!(
+            Error::new(ErrorKind::Char {
+                character: '{',
+                index: 1
+            }),
+            Uuid::parse_with("{67e55044-10b1-426f-9247-bb680e5fe0c8}", &options).unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::Char {
+                character: 'u',
+                index: 1
+            }),
+            Uuid::parse_with("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8", &options)
+                .unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::GroupCount { count: 1 }),
+            Uuid::parse_with("67e5504410b1426f9247bb680e5fe0c8", &options).unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::Char {
+                character: 'g',
+                index: 31
+            }),
+            Uuid::parse_with("67e5504410b1426f9247bb680e5fe0g8", &options).unwrap_err()
+        );
+
+        let options = options.allow_braces(true).allow_urn(true);
+
+        assert_eq!(
+            uuid,
+            Uuid::parse_with("{67e55044-10b1-426f-9247-bb680e5fe0c8}", &options).unwrap()
+        );
+        assert_eq!(
+            uuid,
+            Uuid::parse_with("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8", &options).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_with_separator() {
+        let uuid = new();
+        let options = ParseOptions::strict().allow_separator('_');
+
+        assert_eq!(
+            Ok(uuid),
+            Uuid::parse_with(&uuid.separated('_').to_string(), &options)
+        );
+        assert_eq!(
+            Ok(uuid),
+            Uuid::parse_with(&uuid.hyphenated().to_string(), &options)
+        );
+        assert_eq!(
+            Ok(uuid),
+            Uuid::parse_with(
+                &format!(" {:X}\t", uuid.separated('_')),
+                &options.allow_whitespace(true)
+            )
+        );
+
+        // Separators are only accepted when allowed, and can't be mixed with hyphens
+        assert!(
+            Uuid::parse_with(&uuid.separated('_').to_string(), &ParseOptions::strict()).is_err()
+        );
+        assert!(Uuid::parse_with("f9168c5e_ceb2-4faa_b6bf_329bf39fa1e4", &options).is_err());
+
+        // Errors point at the original input
+        assert_eq!(
+            Error::new(ErrorKind::Char {
+                character: 'g',
+                index: 10
+            }),
+            Uuid::parse_with("f9168c5e_geb2_4faa_b6bf_329bf39fa1e4", &options).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_with_whitespace_and_case() {
+        let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let options = ParseOptions::new()
+            .allow_whitespace(true)
+            .require_lowercase(true);
+
+        assert_eq!(
+            uuid,
+            Uuid::parse_with("\t {67e55044-10b1-426f-9247-bb680e5fe0c8}\r\n", &options).unwrap()
+        );
+        assert_eq!(
+            uuid,
+            Uuid::parse_with("67e5504410b1426f9247bb680e5fe0c8 ", &options).unwrap()
+        );
+
+        // Errors are reported at their position in the original input
+        assert_eq!(
+            Error::new(ErrorKind::Char {
+                character: 'E',
+                index: 5
+            }),
+            Uuid::parse_with("  67E55044-10b1-426f-9247-bb680e5fe0c8", &options).unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::Char {
+                character: 'x',
+                index: 3
+            }),
+            Uuid::parse_with("  x7e55044-10b1-426f-9247-bb680e5fe0c8", &options).unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::SimpleLength { len: 0 }),
+            Uuid::parse_with("   ", &options).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_many() {
+        let a = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let b = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8").unwrap();
+
+        let input =
+            "67e55044-10b1-426f-9247-bb680e5fe0c8\r\n  a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8\n\nxyz\n";
+        let parsed: crate::std::vec::Vec<_> = Uuid::parse_many(input, '\n').collect();
+
+        assert_eq!(4, parsed.len());
+        assert_eq!((0..36, Ok(a)), parsed[0]);
+        assert_eq!((40..72, Ok(b)), parsed[1]);
+        assert_eq!(73..73, parsed[2].0);
+        assert_eq!(
+            ErrorKind::SimpleLength { len: 0 },
+            parsed[2].1.as_ref().unwrap_err().kind
+        );
+        assert_eq!(74..77, parsed[3].0);
+        assert!(parsed[3].1.is_err());
+
+        // A multi-byte delimiter, without a trailing one
+        let mut parsed = Uuid::parse_many(
+            "67e55044-10b1-426f-9247-bb680e5fe0c8→a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+            '→',
+        );
+        assert_eq!(Some((0..36, Ok(a))), parsed.next());
+        assert_eq!(Some((39..75, Ok(b))), parsed.next());
+        assert_eq!(None, parsed.next());
+        assert_eq!(None, parsed.next());
+
+        assert_eq!(0, Uuid::parse_many("", ',').count());
+        assert_eq!(1, Uuid::parse_many(",", ',').count());
+    }
+
+    #[test]
+    fn test_version_from_str() {
+        let versions = [
+            Version::Nil,
+            Version::Mac,
+            Version::Dce,
+            Version::Md5,
+            Version::Random,
+            Version::Sha1,
+            Version::SortMac,
+            Version::SortRand,
+            Version::Custom,
+            Version::Max,
+        ];
+
+        for version in versions {
+            assert_eq!(version, version.to_string().parse::<Version>().unwrap());
+        }
+
+        assert_eq!("v7", Version::SortRand.to_string());
+        assert_eq!(Version::SortRand, "V7".parse::<Version>().unwrap());
+        assert_eq!(Version::SortRand, "7".parse::<Version>().unwrap());
+        assert_eq!(Version::Max, "MAX".parse::<Version>().unwrap());
+
+        for invalid in ["", "v", "v0", "v9", "v10", "vnil", "random"] {
+            assert_eq!(
+                ErrorKind::VersionName,
+                invalid.parse::<Version>().unwrap_err().kind
+            );
+        }
+    }
+
+    #[test]
+    fn test_variant_from_str() {
+        let variants = [
+            Variant::NCS,
+            Variant::RFC4122,
+            Variant::Microsoft,
+            Variant::Future,
+        ];
+
+        for variant in variants {
+            assert_eq!(variant, variant.to_string().parse::<Variant>().unwrap());
+        }
+
+        assert_eq!(Variant::RFC4122, "rfc4122".parse::<Variant>().unwrap());
+        assert_eq!(Variant::RFC4122, "RFC9562".parse::<Variant>().unwrap());
+
+        assert_eq!(
+            ErrorKind::VariantName,
+            "Apple".parse::<Variant>().unwrap_err().kind
+        );
+    }
 }
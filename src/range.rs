@@ -0,0 +1,599 @@
+//! Ranges of UUIDs.
+//!
+//! UUIDs are ordered as 128-bit unsigned integers, so a range of UUIDs
+//! can be stepped through one at a time like a range of integers, or split
+//! into smaller ranges with a [`UuidRange`].
+
+use crate::{
+    std::{
+        iter::FusedIterator,
+        ops::{Bound, RangeBounds, RangeInclusive},
+    },
+    Uuid,
+};
+
+/// An iterator over every UUID in a range, in ascending order.
+///
+/// Each UUID is one more than the last when treated as a `u128`. This makes
+/// it possible to exhaustively scan a small keyspace, like a reserved block
+/// of IDs, with a plain loop. Iterating over a large range isn't practical,
+/// but the number of UUIDs left in one can still be found with
+/// [`UuidRangeIter::remaining`].
+///
+/// On nightly Rust, building with `RUSTFLAGS="--cfg uuid_step_trait"` also
+/// implements the unstable `Step` trait for `Uuid`, so a `Range<Uuid>` is an
+/// iterator itself.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, range::UuidRangeIter};
+/// let start = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+/// let end = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0cb);
+///
+/// let uuids: Vec<Uuid> = UuidRangeIter::new(start..end).collect();
+///
+/// assert_eq!(3, uuids.len());
+/// assert_eq!("67e55044-10b1-426f-9247-bb680e5fe0ca", uuids[2].to_string());
+///
+/// // All the bound types of ranges are supported
+/// assert_eq!(4, UuidRangeIter::new(start..=end).count());
+/// assert_eq!(4, UuidRangeIter::new(..=Uuid::from_u128(3)).count());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UuidRangeIter {
+    // Both ends are inclusive, so a range that ends at the max UUID
+    // doesn't overflow
+    front: u128,
+    back: u128,
+    exhausted: bool,
+}
+
+impl UuidRangeIter {
+    /// Create an iterator over the UUIDs in a range.
+    pub fn new(range: impl RangeBounds<Uuid>) -> Self {
+        let front = match range.start_bound() {
+            Bound::Included(start) => Some(start.as_u128()),
+            Bound::Excluded(start) => start.as_u128().checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+
+        let back = match range.end_bound() {
+            Bound::Included(end) => Some(end.as_u128()),
+            Bound::Excluded(end) => end.as_u128().checked_sub(1),
+            Bound::Unbounded => Some(u128::MAX),
+        };
+
+        match (front, back) {
+            (Some(front), Some(back)) if front <= back => UuidRangeIter {
+                front,
+                back,
+                exhausted: false,
+            },
+            _ => UuidRangeIter {
+                front: 1,
+                back: 0,
+                exhausted: true,
+            },
+        }
+    }
+
+    /// The number of UUIDs left in the range.
+    ///
+    /// A range over every UUID contains 2<sup>128</sup> of them, which is
+    /// one more than fits in a `u128`, so this returns `None` in that case.
+    pub const fn remaining(&self) -> Option<u128> {
+        if self.exhausted {
+            Some(0)
+        } else {
+            (self.back - self.front).checked_add(1)
+        }
+    }
+
+    fn step(&mut self, n: u128, forwards: bool) -> Option<Uuid> {
+        if self.exhausted || n > self.back - self.front {
+            self.exhausted = true;
+            return None;
+        }
+
+        let uuid = if forwards {
+            self.front + n
+        } else {
+            self.back - n
+        };
+
+        if n == self.back - self.front {
+            self.exhausted = true;
+        } else if forwards {
+            self.front = uuid + 1;
+        } else {
+            self.back = uuid - 1;
+        }
+
+        Some(Uuid::from_u128(uuid))
+    }
+}
+
+impl Iterator for UuidRangeIter {
+    type Item = Uuid;
+
+    fn next(&mut self) -> Option<Uuid> {
+        self.step(0, true)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Uuid> {
+        self.step(n as u128, true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining() {
+            Some(remaining) if remaining <= usize::MAX as u128 => {
+                (remaining as usize, Some(remaining as usize))
+            }
+            _ => (usize::MAX, None),
+        }
+    }
+
+    fn last(mut self) -> Option<Uuid> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for UuidRangeIter {
+    fn next_back(&mut self) -> Option<Uuid> {
+        self.step(0, false)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Uuid> {
+        self.step(n as u128, false)
+    }
+}
+
+impl FusedIterator for UuidRangeIter {}
+
+/// An inclusive range of UUIDs, from `start` to `end`.
+///
+/// This is a boundary for keyset pagination or for the slice of a keyspace
+/// owned by a shard. Both ends are inclusive so a range can cover every UUID,
+/// from [`Uuid::nil`] to [`Uuid::max`]. A range whose `start` is greater than
+/// its `end` is empty.
+///
+/// When the `serde` feature is enabled, a range is serialized as a struct with
+/// `start` and `end` fields.
+///
+/// # Examples
+///
+/// Splitting the keyspace between shards:
+///
+/// ```
+/// # use uuid::{Uuid, range::UuidRange};
+/// let shards: Vec<UuidRange> = UuidRange::full().split(4).collect();
+///
+/// assert_eq!(4, shards.len());
+/// assert_eq!(Uuid::nil(), shards[0].start);
+/// assert_eq!("3fffffff-ffff-ffff-ffff-ffffffffffff", shards[0].end.to_string());
+/// assert_eq!(Uuid::max(), shards[3].end);
+///
+/// let id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+///
+/// assert!(shards[1].contains(&id));
+/// ```
+///
+/// [`Uuid::nil`]: ../struct.Uuid.html#method.nil
+/// [`Uuid::max`]: ../struct.Uuid.html#method.max
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UuidRange {
+    /// The first UUID in the range.
+    pub start: Uuid,
+    /// The last UUID in the range.
+    pub end: Uuid,
+}
+
+impl UuidRange {
+    /// Create a range from `start` to `end`, including both.
+    pub const fn new(start: Uuid, end: Uuid) -> Self {
+        UuidRange { start, end }
+    }
+
+    /// A range that contains every UUID.
+    pub const fn full() -> Self {
+        UuidRange::new(Uuid::nil(), Uuid::max())
+    }
+
+    /// Whether the range doesn't contain any UUIDs.
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// Whether the range contains a UUID.
+    pub fn contains(&self, uuid: &Uuid) -> bool {
+        self.start <= *uuid && *uuid <= self.end
+    }
+
+    /// Get the range of UUIDs contained in both this range and another.
+    ///
+    /// Returns `None` if the ranges don't overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, range::UuidRange};
+    /// let a = UuidRange::new(Uuid::from_u128(0), Uuid::from_u128(10));
+    /// let b = UuidRange::new(Uuid::from_u128(5), Uuid::from_u128(20));
+    ///
+    /// assert_eq!(
+    ///     Some(UuidRange::new(Uuid::from_u128(5), Uuid::from_u128(10))),
+    ///     a.intersect(&b),
+    /// );
+    /// ```
+    pub fn intersect(&self, other: &UuidRange) -> Option<UuidRange> {
+        let range = UuidRange::new(self.start.max(other.start), self.end.min(other.end));
+
+        if range.is_empty() {
+            None
+        } else {
+            Some(range)
+        }
+    }
+
+    /// Split the range at a UUID.
+    ///
+    /// The first range contains the UUIDs before `at`, and the second contains
+    /// `at` and the UUIDs after it. Either is `None` if it would be empty.
+    pub fn split_at(&self, at: Uuid) -> (Option<UuidRange>, Option<UuidRange>) {
+        let before = at
+            .as_u128()
+            .checked_sub(1)
+            .and_then(|end| self.intersect(&UuidRange::new(Uuid::nil(), Uuid::from_u128(end))));
+        let after = self.intersect(&UuidRange::new(at, Uuid::max()));
+
+        (before, after)
+    }
+
+    /// Split the range into `parts` contiguous ranges of nearly equal size.
+    ///
+    /// The ranges are yielded in ascending order. Their sizes differ by at
+    /// most one, with the larger ranges first. If the range contains fewer
+    /// than `parts` UUIDs, then each is yielded in a range of its own.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `parts` is zero.
+    pub fn split(&self, parts: usize) -> Split {
+        assert!(parts > 0, "a range can't be split into zero parts");
+
+        if self.is_empty() {
+            return Split {
+                next_start: 0,
+                parts: 0,
+                len: 0,
+                remainder: 0,
+            };
+        }
+
+        // The range holds `span + 1` UUIDs, which may not fit in a `u128`,
+        // so divide `span` and carry the extra UUID through the remainder
+        let parts = parts as u128;
+        let span = self.end.as_u128() - self.start.as_u128();
+
+        Split {
+            next_start: self.start.as_u128(),
+            parts,
+            len: span / parts,
+            remainder: span % parts + 1,
+        }
+    }
+
+    /// Iterate over every UUID in the range.
+    pub fn iter(&self) -> UuidRangeIter {
+        UuidRangeIter::new(self)
+    }
+}
+
+impl Default for UuidRange {
+    fn default() -> Self {
+        UuidRange::full()
+    }
+}
+
+impl From<RangeInclusive<Uuid>> for UuidRange {
+    fn from(range: RangeInclusive<Uuid>) -> Self {
+        UuidRange::new(*range.start(), *range.end())
+    }
+}
+
+impl RangeBounds<Uuid> for UuidRange {
+    fn start_bound(&self) -> Bound<&Uuid> {
+        Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&Uuid> {
+        Bound::Included(&self.end)
+    }
+}
+
+impl RangeBounds<Uuid> for &UuidRange {
+    fn start_bound(&self) -> Bound<&Uuid> {
+        Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&Uuid> {
+        Bound::Included(&self.end)
+    }
+}
+
+impl IntoIterator for UuidRange {
+    type Item = Uuid;
+    type IntoIter = UuidRangeIter;
+
+    fn into_iter(self) -> UuidRangeIter {
+        UuidRangeIter::new(self)
+    }
+}
+
+/// An iterator over the parts of a split [`UuidRange`].
+///
+/// This type is returned by [`UuidRange::split`].
+#[derive(Clone, Debug)]
+pub struct Split {
+    next_start: u128,
+    parts: u128,
+    // Each part has `len` UUIDs, and the first `remainder` have one more
+    len: u128,
+    remainder: u128,
+}
+
+impl Iterator for Split {
+    type Item = UuidRange;
+
+    fn next(&mut self) -> Option<UuidRange> {
+        // When there are fewer UUIDs than parts, the parts run out early
+        if self.parts == 0 || (self.len == 0 && self.remainder == 0) {
+            self.parts = 0;
+            return None;
+        }
+
+        // A part of the full range may hold 2^128 UUIDs, so work from the
+        // offset of its last UUID rather than its length
+        let start = self.next_start;
+        let end = start + (self.len - (self.remainder == 0) as u128);
+
+        self.parts -= 1;
+        self.remainder = self.remainder.saturating_sub(1);
+
+        // The last part may end at the max UUID
+        self.next_start = end.wrapping_add(1);
+
+        Some(UuidRange::new(Uuid::from_u128(start), Uuid::from_u128(end)))
+    }
+}
+
+impl FusedIterator for Split {}
+
+#[cfg(uuid_step_trait)]
+impl crate::std::iter::Step for Uuid {
+    fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+        if start > end {
+            return (0, None);
+        }
+
+        let steps = end.as_u128() - start.as_u128();
+
+        if steps <= usize::MAX as u128 {
+            (steps as usize, Some(steps as usize))
+        } else {
+            (usize::MAX, None)
+        }
+    }
+
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        start
+            .as_u128()
+            .checked_add(count as u128)
+            .map(Uuid::from_u128)
+    }
+
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        start
+            .as_u128()
+            .checked_sub(count as u128)
+            .map(Uuid::from_u128)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::std::vec::Vec;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    fn ids(range: impl RangeBounds<Uuid>) -> Vec<u128> {
+        UuidRangeIter::new(range)
+            .map(|uuid| uuid.as_u128())
+            .collect()
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_range_iter_bounds() {
+        let (a, b) = (Uuid::from_u128(5), Uuid::from_u128(8));
+
+        assert_eq!(vec![5, 6, 7], ids(a..b));
+        assert_eq!(vec![5, 6, 7, 8], ids(a..=b));
+        assert_eq!(vec![0, 1, 2, 3, 4], ids(..a));
+        assert_eq!(vec![6, 7], ids((Bound::Excluded(a), Bound::Excluded(b))));
+        assert!(ids(b..a).is_empty());
+        assert!(ids(a..a).is_empty());
+        assert_eq!(vec![5], ids(a..=a));
+
+        // Ranges at the edges of the keyspace don't overflow
+        let max = u128::MAX;
+        assert_eq!(vec![max - 1, max], ids(Uuid::from_u128(max - 1)..));
+        assert!(ids((Bound::Excluded(Uuid::max()), Bound::Unbounded)).is_empty());
+        assert!(ids(..Uuid::nil()).is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_range_iter_double_ended() {
+        let mut iter = UuidRangeIter::new(Uuid::from_u128(0)..Uuid::from_u128(10));
+
+        assert_eq!(Some(10), iter.remaining());
+        assert_eq!((10, Some(10)), iter.size_hint());
+
+        assert_eq!(Some(Uuid::from_u128(9)), iter.next_back());
+        assert_eq!(Some(Uuid::from_u128(2)), iter.nth(2));
+        assert_eq!(Some(Uuid::from_u128(6)), iter.nth_back(2));
+        assert_eq!(
+            vec![3, 4, 5],
+            iter.clone().map(|uuid| uuid.as_u128()).collect::<Vec<_>>()
+        );
+        assert_eq!(Some(Uuid::from_u128(5)), iter.clone().last());
+
+        assert_eq!(None, iter.nth(3));
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next_back());
+        assert_eq!(Some(0), iter.remaining());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_range_iter_full() {
+        let mut iter = UuidRangeIter::new(..);
+
+        assert_eq!(None, iter.remaining());
+        assert_eq!((usize::MAX, None), iter.size_hint());
+
+        assert_eq!(Some(Uuid::nil()), iter.next());
+        assert_eq!(Some(Uuid::max()), iter.next_back());
+        assert_eq!(Some(u128::MAX - 1), iter.remaining());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_range_contains_intersect() {
+        let range = |start, end| UuidRange::new(Uuid::from_u128(start), Uuid::from_u128(end));
+
+        let a = range(10, 20);
+
+        assert!(a.contains(&Uuid::from_u128(10)));
+        assert!(a.contains(&Uuid::from_u128(20)));
+        assert!(!a.contains(&Uuid::from_u128(21)));
+        assert!(!range(2, 1).contains(&Uuid::from_u128(1)));
+        assert!(range(2, 1).is_empty());
+
+        assert_eq!(Some(range(15, 20)), a.intersect(&range(15, 30)));
+        assert_eq!(Some(range(20, 20)), a.intersect(&range(20, 30)));
+        assert_eq!(None, a.intersect(&range(21, 30)));
+        assert_eq!(Some(a), a.intersect(&UuidRange::full()));
+
+        assert_eq!(
+            (Some(range(10, 14)), Some(range(15, 20))),
+            a.split_at(Uuid::from_u128(15))
+        );
+        assert_eq!((None, Some(a)), a.split_at(Uuid::nil()));
+        assert_eq!((Some(a), None), a.split_at(Uuid::from_u128(21)));
+
+        assert_eq!(vec![10, 11, 12], ids(range(10, 12)));
+        assert_eq!(
+            UuidRange::from(Uuid::nil()..=Uuid::max()),
+            UuidRange::full()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_range_split() {
+        let range = |start, end| UuidRange::new(Uuid::from_u128(start), Uuid::from_u128(end));
+        let split = |r: UuidRange, parts| r.split(parts).collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![range(0, 3), range(4, 6), range(7, 9)],
+            split(range(0, 9), 3)
+        );
+        assert_eq!(vec![range(5, 5), range(6, 6)], split(range(5, 6), 4));
+        assert_eq!(vec![range(5, 6)], split(range(5, 6), 1));
+        assert!(split(range(6, 5), 2).is_empty());
+
+        assert_eq!(vec![UuidRange::full()], split(UuidRange::full(), 1));
+
+        let halves = split(UuidRange::full(), 2);
+        assert_eq!(
+            vec![
+                range(0, u128::MAX >> 1),
+                range((u128::MAX >> 1) + 1, u128::MAX)
+            ],
+            halves
+        );
+
+        // Parts are contiguous and cover the whole range
+        let parts = split(range(3, 1_000), 7);
+        assert_eq!(7, parts.len());
+        assert_eq!(Uuid::from_u128(3), parts[0].start);
+        assert_eq!(Uuid::from_u128(1_000), parts[6].end);
+        for pair in parts.windows(2) {
+            assert_eq!(pair[0].end.as_u128() + 1, pair[1].start.as_u128());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_range_split_zero() {
+        let _ = UuidRange::full().split(0);
+    }
+
+    #[test]
+    #[cfg(uuid_step_trait)]
+    fn test_step() {
+        let (a, b) = (Uuid::from_u128(5), Uuid::from_u128(8));
+
+        assert_eq!(
+            vec![5, 6, 7],
+            (a..b).map(|uuid| uuid.as_u128()).collect::<Vec<_>>()
+        );
+        assert_eq!(4, (a..=b).count());
+        assert_eq!(Some(b), (a..).nth(3));
+    }
+}
@@ -1,10 +1,119 @@
-#[cfg(any(feature = "v4", feature = "v7"))]
+#[cfg(any(
+    feature = "v4",
+    feature = "rng-custom",
+    all(feature = "std", feature = "v7")
+))]
+use crate::error::*;
+
+// Fill `bytes` from the operating system's RNG, using `getrandom` 0.3 if the
+// `rng-getrandom03` feature is enabled, or 0.2 otherwise
+#[cfg(all(feature = "rng", not(feature = "rng-getrandom03")))]
+#[allow(dead_code)]
+fn fill(bytes: &mut [u8]) -> Result<(), impl crate::std::fmt::Display> {
+    getrandom::getrandom(bytes)
+}
+
+#[cfg(feature = "rng-getrandom03")]
+#[allow(dead_code)]
+fn fill(bytes: &mut [u8]) -> Result<(), impl crate::std::fmt::Display> {
+    getrandom03::fill(bytes)
+}
+
+// With only the `rng-custom` feature there's no operating system RNG to fall back
+// to if no source has been set with `entropy::set_random_source`
+#[cfg(all(not(any(feature = "rng", feature = "rng-getrandom03")), not(test)))]
+#[allow(dead_code)]
+fn fill(_: &mut [u8]) -> Result<(), impl crate::std::fmt::Display> {
+    Err("no source was set with `uuid::entropy::set_random_source`")
+}
+
+// Unit tests can't set a source before they run, so they use a stand-in for
+// the operating system's RNG instead
+#[cfg(all(not(any(feature = "rng", feature = "rng-getrandom03")), test))]
+#[allow(dead_code)]
+fn fill(bytes: &mut [u8]) -> Result<(), impl crate::std::fmt::Display> {
+    use crate::{
+        hash::SplitMix64,
+        std::sync::atomic::{AtomicU64, Ordering},
+    };
+
+    static STATE: AtomicU64 = AtomicU64::new(0);
+
+    for chunk in bytes.chunks_mut(8) {
+        let mut rng = SplitMix64(STATE.fetch_add(SplitMix64::GAMMA, Ordering::Relaxed));
+
+        chunk.copy_from_slice(&rng.next().to_le_bytes()[..chunk.len()]);
+    }
+
+    Ok::<_, &str>(())
+}
+
+// Read random bytes from the source set with `entropy::set_random_source`, if there is one
+#[allow(dead_code)]
+fn from_random_source<const N: usize>() -> Option<[u8; N]> {
+    let mut bytes = [0u8; N];
+
+    if crate::entropy::fill_from_random_source(&mut bytes) {
+        Some(bytes)
+    } else {
+        None
+    }
+}
+
+#[cfg(any(feature = "v4", feature = "v7", feature = "rng-custom"))]
 pub(crate) fn u128() -> u128 {
-    #[cfg(not(feature = "fast-rng"))]
+    #[cfg(feature = "simulation")]
+    let random = u128::from_le_bytes(crate::simulation::draw(|| system_u128().to_le_bytes()));
+
+    #[cfg(not(feature = "simulation"))]
+    let random = system_u128();
+
+    random
+}
+
+// Fill `dest` with random bytes, reading from the operating system's RNG once
+// for the whole buffer instead of once per UUID
+#[cfg(any(
+    feature = "v4",
+    feature = "rng-custom",
+    all(feature = "std", feature = "v7")
+))]
+pub(crate) fn fill_bytes(dest: &mut [u8]) {
+    // `rand` doesn't make a syscall per value, and simulations need to draw
+    // each UUID's bytes separately so they can be replayed
+    #[cfg(any(
+        feature = "simulation",
+        feature = "fast-rng",
+        feature = "fast-rng-rand09"
+    ))]
+    for chunk in dest.chunks_mut(16) {
+        chunk.copy_from_slice(&u128().to_ne_bytes()[..chunk.len()]);
+    }
+
+    #[cfg(not(any(
+        feature = "simulation",
+        feature = "fast-rng",
+        feature = "fast-rng-rand09"
+    )))]
+    if !crate::entropy::fill_from_random_source(dest) {
+        fill(dest).unwrap_or_else(|err| {
+            // NB: getrandom::Error has no source; this is adequate display
+            panic!("could not retrieve random bytes for uuid: {}", err)
+        });
+    }
+}
+
+#[cfg(any(feature = "v4", feature = "v7", feature = "rng-custom"))]
+fn system_u128() -> u128 {
+    if let Some(bytes) = from_random_source() {
+        return u128::from_ne_bytes(bytes);
+    }
+
+    #[cfg(not(any(feature = "fast-rng", feature = "fast-rng-rand09")))]
     {
         let mut bytes = [0u8; 16];
 
-        getrandom::getrandom(&mut bytes).unwrap_or_else(|err| {
+        fill(&mut bytes).unwrap_or_else(|err| {
             // NB: getrandom::Error has no source; this is adequate display
             panic!("could not retrieve random bytes for uuid: {}", err)
         });
@@ -12,19 +121,28 @@ pub(crate) fn u128() -> u128 {
         u128::from_ne_bytes(bytes)
     }
 
-    #[cfg(feature = "fast-rng")]
+    #[cfg(all(feature = "fast-rng", not(feature = "fast-rng-rand09")))]
     {
         rand::random()
     }
+
+    #[cfg(feature = "fast-rng-rand09")]
+    {
+        rand09::random()
+    }
 }
 
 #[cfg(any(feature = "v1", feature = "v6"))]
 pub(crate) fn u16() -> u16 {
-    #[cfg(not(feature = "fast-rng"))]
+    if let Some(bytes) = from_random_source() {
+        return u16::from_ne_bytes(bytes);
+    }
+
+    #[cfg(not(any(feature = "fast-rng", feature = "fast-rng-rand09")))]
     {
         let mut bytes = [0u8; 2];
 
-        getrandom::getrandom(&mut bytes).unwrap_or_else(|err| {
+        fill(&mut bytes).unwrap_or_else(|err| {
             // NB: getrandom::Error has no source; this is adequate display
             panic!("could not retrieve random bytes for uuid: {}", err)
         });
@@ -32,19 +150,39 @@ pub(crate) fn u16() -> u16 {
         u16::from_ne_bytes(bytes)
     }
 
-    #[cfg(feature = "fast-rng")]
+    #[cfg(all(feature = "fast-rng", not(feature = "fast-rng-rand09")))]
     {
         rand::random()
     }
+
+    #[cfg(feature = "fast-rng-rand09")]
+    {
+        rand09::random()
+    }
 }
 
-#[cfg(feature = "v7")]
+#[cfg(any(feature = "v7", feature = "rng-custom"))]
 pub(crate) fn u64() -> u64 {
-    #[cfg(not(feature = "fast-rng"))]
+    #[cfg(feature = "simulation")]
+    let random = u64::from_le_bytes(crate::simulation::draw(|| system_u64().to_le_bytes()));
+
+    #[cfg(not(feature = "simulation"))]
+    let random = system_u64();
+
+    random
+}
+
+#[cfg(any(feature = "v7", feature = "rng-custom"))]
+fn system_u64() -> u64 {
+    if let Some(bytes) = from_random_source() {
+        return u64::from_ne_bytes(bytes);
+    }
+
+    #[cfg(not(any(feature = "fast-rng", feature = "fast-rng-rand09")))]
     {
         let mut bytes = [0u8; 8];
 
-        getrandom::getrandom(&mut bytes).unwrap_or_else(|err| {
+        fill(&mut bytes).unwrap_or_else(|err| {
             // NB: getrandom::Error has no source; this is adequate display
             panic!("could not retrieve random bytes for uuid: {}", err)
         });
@@ -52,8 +190,137 @@ pub(crate) fn u64() -> u64 {
         u64::from_ne_bytes(bytes)
     }
 
-    #[cfg(feature = "fast-rng")]
+    #[cfg(all(feature = "fast-rng", not(feature = "fast-rng-rand09")))]
     {
         rand::random()
     }
+
+    #[cfg(feature = "fast-rng-rand09")]
+    {
+        rand09::random()
+    }
+}
+
+// NOTE: These fallible variants always go through `getrandom` directly, even with
+// the `fast-rng` feature, because `rand`'s thread-local generator panics if it
+// can't be seeded
+//
+// The OS error code is kept so callers can tell why randomness wasn't available
+#[cfg(all(
+    feature = "rng",
+    not(feature = "rng-getrandom03"),
+    any(
+        feature = "v4",
+        feature = "rng-custom",
+        all(feature = "std", feature = "v7")
+    )
+))]
+fn try_fill(bytes: &mut [u8]) -> Result<(), Error> {
+    getrandom::getrandom(bytes).map_err(|err| {
+        Error::new(ErrorKind::Rng {
+            raw_os_error: err.raw_os_error(),
+        })
+    })
+}
+
+#[cfg(all(
+    feature = "rng-getrandom03",
+    any(
+        feature = "v4",
+        feature = "rng-custom",
+        all(feature = "std", feature = "v7")
+    )
+))]
+fn try_fill(bytes: &mut [u8]) -> Result<(), Error> {
+    getrandom03::fill(bytes).map_err(|err| {
+        Error::new(ErrorKind::Rng {
+            // `getrandom` uses `usize` error codes on UEFI
+            #[allow(clippy::unnecessary_cast)]
+            raw_os_error: err.raw_os_error().map(|code| code as i32),
+        })
+    })
+}
+
+#[cfg(all(
+    not(any(feature = "rng", feature = "rng-getrandom03")),
+    not(test),
+    any(
+        feature = "v4",
+        feature = "rng-custom",
+        all(feature = "std", feature = "v7")
+    )
+))]
+fn try_fill(_: &mut [u8]) -> Result<(), Error> {
+    Err(Error::new(ErrorKind::Rng { raw_os_error: None }))
+}
+
+#[cfg(all(
+    not(any(feature = "rng", feature = "rng-getrandom03")),
+    test,
+    any(
+        feature = "v4",
+        feature = "rng-custom",
+        all(feature = "std", feature = "v7")
+    )
+))]
+fn try_fill(bytes: &mut [u8]) -> Result<(), Error> {
+    fill(bytes).unwrap_or_else(|_| unreachable!());
+
+    Ok(())
+}
+
+#[cfg(any(
+    feature = "v4",
+    feature = "rng-custom",
+    all(feature = "std", feature = "v7")
+))]
+pub(crate) fn try_u128() -> Result<u128, Error> {
+    #[cfg(feature = "simulation")]
+    let random = u128::from_le_bytes(crate::simulation::try_draw(|| {
+        system_try_u128().map(u128::to_le_bytes)
+    })?);
+
+    #[cfg(not(feature = "simulation"))]
+    let random = system_try_u128()?;
+
+    Ok(random)
+}
+
+#[cfg(any(
+    feature = "v4",
+    feature = "rng-custom",
+    all(feature = "std", feature = "v7")
+))]
+fn system_try_u128() -> Result<u128, Error> {
+    let mut bytes = [0u8; 16];
+
+    if !crate::entropy::fill_from_random_source(&mut bytes) {
+        try_fill(&mut bytes)?;
+    }
+
+    Ok(u128::from_ne_bytes(bytes))
+}
+
+#[cfg(all(feature = "std", any(feature = "v7", feature = "rng-custom")))]
+pub(crate) fn try_u64() -> Result<u64, Error> {
+    #[cfg(feature = "simulation")]
+    let random = u64::from_le_bytes(crate::simulation::try_draw(|| {
+        system_try_u64().map(u64::to_le_bytes)
+    })?);
+
+    #[cfg(not(feature = "simulation"))]
+    let random = system_try_u64()?;
+
+    Ok(random)
+}
+
+#[cfg(all(feature = "std", any(feature = "v7", feature = "rng-custom")))]
+fn system_try_u64() -> Result<u64, Error> {
+    let mut bytes = [0u8; 8];
+
+    if !crate::entropy::fill_from_random_source(&mut bytes) {
+        try_fill(&mut bytes)?;
+    }
+
+    Ok(u64::from_ne_bytes(bytes))
 }
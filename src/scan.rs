@@ -0,0 +1,218 @@
+//! Finding UUIDs embedded in text.
+
+use crate::{std::ops::Range, Uuid};
+
+/// Find all the UUIDs embedded in some text.
+///
+/// This finds UUIDs in the hyphenated, braced, and simple formats, in any
+/// case, and yields them along with the range of bytes they were found at in
+/// `text`. It's useful for processing logs, URLs, and other blobs of text that
+/// mention UUIDs, like when redacting or indexing them.
+///
+/// A UUID is only found if it isn't part of a longer run of letters or digits,
+/// so the first 32 characters of a longer hexadecimal string like a SHA-1 hash
+/// aren't mistaken for a simple UUID. Punctuation, including hyphens and
+/// underscores, can appear on either side of a UUID. The range of a braced UUID
+/// includes its braces.
+///
+/// UUIDs are yielded in the order they appear, and never overlap.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::Uuid;
+/// let log = "GET /users/67e55044-10b1-426f-9247-bb680e5fe0c8?session=A1A2A3A4B1B2C1C2D1D2D3D4D5D6D7D8 200";
+///
+/// let found: Vec<_> = uuid::scan(log).collect();
+///
+/// assert_eq!(
+///     vec![
+///         (11..47, Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()),
+///         (56..88, Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8").unwrap()),
+///     ],
+///     found,
+/// );
+/// ```
+///
+/// Redacting UUIDs:
+///
+/// ```
+/// let log = "user {67E55044-10B1-426F-9247-BB680E5FE0C8} logged in";
+///
+/// let mut redacted = String::new();
+/// let mut last = 0;
+///
+/// for (range, _) in uuid::scan(log) {
+///     redacted.push_str(&log[last..range.start]);
+///     redacted.push_str("[uuid]");
+///     last = range.end;
+/// }
+///
+/// redacted.push_str(&log[last..]);
+///
+/// assert_eq!("user [uuid] logged in", redacted);
+/// ```
+pub fn scan(text: &str) -> Scan<'_> {
+    Scan {
+        text: text.as_bytes(),
+        position: 0,
+    }
+}
+
+/// An iterator over the UUIDs embedded in some text.
+///
+/// This type is returned by [`scan`].
+#[derive(Clone, Debug)]
+pub struct Scan<'a> {
+    text: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Iterator for Scan<'a> {
+    type Item = (Range<usize>, Uuid);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.text;
+
+        while self.position < text.len() {
+            let start = self.position;
+
+            // A UUID can't start in the middle of a word
+            if start > 0 && text[start - 1].is_ascii_alphanumeric() {
+                self.position = skip_word(text, start);
+                continue;
+            }
+
+            if text[start] == b'{' {
+                if let Some(uuid) = parse_at(text, start, 38) {
+                    self.position = start + 38;
+                    return Some((start..start + 38, uuid));
+                }
+            } else if text[start].is_ascii_hexdigit() {
+                for len in [36, 32] {
+                    if let Some(uuid) = parse_at(text, start, len) {
+                        self.position = start + len;
+                        return Some((start..start + len, uuid));
+                    }
+                }
+
+                // Nothing else can start within this word
+                self.position = skip_word(text, start);
+                continue;
+            }
+
+            self.position += 1;
+        }
+
+        None
+    }
+}
+
+impl<'a> crate::std::iter::FusedIterator for Scan<'a> {}
+
+// Parse a UUID of exactly `len` bytes at `start`, as long as it's not
+// followed by more letters or digits
+fn parse_at(text: &[u8], start: usize, len: usize) -> Option<Uuid> {
+    let candidate = text.get(start..start + len)?;
+
+    if text
+        .get(start + len)
+        .map_or(false, |next| next.is_ascii_alphanumeric())
+    {
+        return None;
+    }
+
+    Uuid::try_parse_ascii(candidate).ok()
+}
+
+// Find the end of the run of letters and digits containing `position`
+fn skip_word(text: &[u8], position: usize) -> usize {
+    text[position..]
+        .iter()
+        .position(|b| !b.is_ascii_alphanumeric())
+        .map_or(text.len(), |len| position + len.max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::std::vec::Vec;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    const ID: Uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+    fn found(text: &str) -> Vec<(Range<usize>, Uuid)> {
+        scan(text).collect()
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_scan_formats() {
+        assert_eq!(
+            vec![(0..36, ID)],
+            found("67e55044-10b1-426f-9247-bb680e5fe0c8")
+        );
+        assert_eq!(vec![(0..32, ID)], found("67E5504410B1426F9247BB680E5FE0C8"));
+        assert_eq!(
+            vec![(4..42, ID)],
+            found("id: {67e55044-10b1-426f-9247-bb680e5fe0c8}.")
+        );
+        assert_eq!(
+            vec![(9..45, ID), (46..78, ID)],
+            found("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8,67e5504410b1426f9247bb680e5fe0c8")
+        );
+        assert_eq!(
+            vec![(6..42, ID)],
+            found("order-67e55044-10b1-426f-9247-bb680e5fe0c8_v2")
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_scan_boundaries() {
+        // Part of a longer hexadecimal string
+        assert_eq!(
+            Vec::<(Range<usize>, Uuid)>::new(),
+            found("da39a3ee5e6b4b0d3255bfef95601890afd80709")
+        );
+        assert!(found("x67e55044-10b1-426f-9247-bb680e5fe0c8").is_empty());
+        assert!(found("67e55044-10b1-426f-9247-bb680e5fe0c8a").is_empty());
+
+        // Almost UUIDs
+        assert!(found("67e55044-10b1-426f-9247-bb680e5fe0c").is_empty());
+        assert!(found("67e55044-10b1-426f-9247_bb680e5fe0c8").is_empty());
+        assert!(found("{67e55044-10b1-426f-9247-bb680e5fe0c8").len() == 1);
+        assert!(found("").is_empty());
+
+        // Non-ASCII text around UUIDs
+        let text = "ü67e55044-10b1-426f-9247-bb680e5fe0c8ü";
+        let found = found(text);
+
+        assert_eq!(vec![(2..38, ID)], found);
+        assert_eq!(
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            &text[found[0].0.clone()]
+        );
+    }
+}
@@ -12,3 +12,211 @@ pub(crate) fn hash(ns: &[u8], src: &[u8]) -> [u8; 16] {
 
     bytes
 }
+
+#[cfg(feature = "v5")]
+pub(crate) fn hash_parts(ns: &[u8], parts: &[&[u8]]) -> [u8; 16] {
+    use sha1_smol::Sha1;
+
+    let mut hasher = Sha1::new();
+
+    hasher.update(ns);
+
+    for part in parts {
+        hasher.update(&(part.len() as u64).to_be_bytes());
+        hasher.update(part);
+    }
+
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(&hasher.digest().bytes()[..16]);
+
+    bytes
+}
+
+#[cfg(feature = "v5")]
+pub(crate) fn hash_chunks<I>(ns: &[u8], chunks: I) -> [u8; 16]
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    use sha1_smol::Sha1;
+
+    let mut hasher = Sha1::new();
+
+    hasher.update(ns);
+
+    for chunk in chunks {
+        hasher.update(chunk.as_ref());
+    }
+
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(&hasher.digest().bytes()[..16]);
+
+    bytes
+}
+
+#[cfg(all(feature = "v5", feature = "std"))]
+pub(crate) fn hash_reader<R: std::io::Read>(ns: &[u8], mut reader: R) -> std::io::Result<[u8; 16]> {
+    use sha1_smol::Sha1;
+    use std::io::ErrorKind;
+
+    let mut hasher = Sha1::new();
+
+    hasher.update(ns);
+
+    let mut buf = [0; 8192];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(len) => hasher.update(&buf[..len]),
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(&hasher.digest().bytes()[..16]);
+
+    Ok(bytes)
+}
+
+// A `const` implementation of SHA-1, so namespaces can be derived at compile time.
+// It's much slower than `sha1_smol`, so it's only used where a `const fn` is needed
+#[cfg(feature = "v5")]
+pub(crate) const fn hash_const(ns: &[u8; 16], src: &[u8]) -> [u8; 16] {
+    let len = ns.len() + src.len();
+
+    // The message is padded with a 1 bit, then 0 bits, then its length in bits
+    // as a big-endian `u64`, to a multiple of the 64 byte block size
+    let padded_len = (len + 9 + 63) / 64 * 64;
+
+    let mut state = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+
+    let mut block = [0; 64];
+    let mut offset = 0;
+
+    while offset < padded_len {
+        let mut i = 0;
+
+        while i < 64 {
+            let index = offset + i;
+
+            block[i] = if index < ns.len() {
+                ns[index]
+            } else if index < len {
+                src[index - ns.len()]
+            } else if index == len {
+                0x80
+            } else if index >= padded_len - 8 {
+                ((len as u64 * 8) >> ((padded_len - 1 - index) * 8)) as u8
+            } else {
+                0
+            };
+
+            i += 1;
+        }
+
+        state = compress_const(state, &block);
+        offset += 64;
+    }
+
+    let mut bytes = [0; 16];
+    let mut i = 0;
+
+    while i < 16 {
+        bytes[i] = (state[i / 4] >> (24 - (i % 4) * 8)) as u8;
+        i += 1;
+    }
+
+    bytes
+}
+
+#[cfg(feature = "v5")]
+const fn compress_const(mut state: [u32; 5], block: &[u8; 64]) -> [u32; 5] {
+    let mut w = [0u32; 80];
+    let mut t = 0;
+
+    while t < 16 {
+        w[t] = u32::from_be_bytes([
+            block[t * 4],
+            block[t * 4 + 1],
+            block[t * 4 + 2],
+            block[t * 4 + 3],
+        ]);
+        t += 1;
+    }
+
+    while t < 80 {
+        w[t] = (w[t - 3] ^ w[t - 8] ^ w[t - 14] ^ w[t - 16]).rotate_left(1);
+        t += 1;
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e] = state;
+
+    t = 0;
+    while t < 80 {
+        let (f, k) = if t < 20 {
+            ((b & c) | (!b & d), 0x5A827999)
+        } else if t < 40 {
+            (b ^ c ^ d, 0x6ED9EBA1)
+        } else if t < 60 {
+            ((b & c) | (b & d) | (c & d), 0x8F1BBCDC)
+        } else {
+            (b ^ c ^ d, 0xCA62C1D6)
+        };
+
+        let temp = a
+            .rotate_left(5)
+            .wrapping_add(f)
+            .wrapping_add(e)
+            .wrapping_add(k)
+            .wrapping_add(w[t]);
+
+        e = d;
+        d = c;
+        c = b.rotate_left(30);
+        b = a;
+        a = temp;
+
+        t += 1;
+    }
+
+    state[0] = state[0].wrapping_add(a);
+    state[1] = state[1].wrapping_add(b);
+    state[2] = state[2].wrapping_add(c);
+    state[3] = state[3].wrapping_add(d);
+    state[4] = state[4].wrapping_add(e);
+
+    state
+}
+
+#[cfg(all(test, feature = "v5"))]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_hash_const_matches() {
+        let ns = [0xa5; 16];
+
+        // Cover names that end in every position of a block, and that need
+        // an extra block for the padding
+        let name = [0x3c; 200];
+        for len in 0..name.len() {
+            assert_eq!(hash(&ns, &name[..len]), hash_const(&ns, &name[..len]));
+        }
+    }
+}
@@ -0,0 +1,249 @@
+//! Deterministic UUID generation for simulation testing.
+//!
+//! Simulation testing runs a whole distributed system in a single process,
+//! replacing every source of nondeterminism with one driven by a seed, so a
+//! failing run can be reproduced exactly. While a simulation is running, the
+//! random bytes and the current time used by [`Uuid::new_v4`] and
+//! [`Uuid::now_v7`] come from the simulation instead of the operating system:
+//!
+//! * [`seed`] draws random bytes from a deterministic generator, so the same
+//!   seed always produces the same UUIDs.
+//! * [`record`] draws random bytes from the operating system, but keeps a
+//!   copy of them. The copy can be retrieved with [`take_recording`].
+//! * [`replay`] draws random bytes from a previous recording, reproducing its
+//!   UUIDs byte-for-byte.
+//!
+//! The simulated clock starts at the Unix epoch and only moves when it's set
+//! with [`set_time`] or [`advance_time`]. Starting a simulation also resets
+//! the shared state used to order version 7 UUIDs, so earlier UUIDs don't
+//! affect the simulation's.
+//!
+//! The simulation is global, so it affects UUIDs generated on every thread.
+//! UUIDs generated with a [`v7::Generator`] or through a [`Builder`] don't
+//! use the simulation unless they're given its clock or entropy explicitly.
+//!
+//! Note that usage of this module requires the `simulation` feature of this
+//! crate to be enabled.
+//!
+//! ## Example
+//!
+//! ```rust
+//! # #[cfg(feature = "v4")]
+//! # {
+//! use uuid::{simulation, Uuid};
+//!
+//! simulation::seed(42);
+//! let first = [Uuid::new_v4(), Uuid::new_v4()];
+//!
+//! simulation::seed(42);
+//! let second = [Uuid::new_v4(), Uuid::new_v4()];
+//!
+//! let recording = simulation::take_recording();
+//!
+//! simulation::replay(recording);
+//! let third = [Uuid::new_v4(), Uuid::new_v4()];
+//!
+//! simulation::stop();
+//!
+//! assert_eq!(first, second);
+//! assert_eq!(first, third);
+//! # }
+//! ```
+//!
+//! [`Uuid::new_v4`]: ../struct.Uuid.html#method.new_v4
+//! [`Uuid::now_v7`]: ../struct.Uuid.html#method.now_v7
+//! [`v7::Generator`]: ../v7/struct.Generator.html
+//! [`Builder`]: ../struct.Builder.html
+
+use crate::hash::SplitMix64;
+use crate::std::{
+    convert::Infallible,
+    mem,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, MutexGuard,
+    },
+    time::Duration,
+    vec::Vec,
+};
+
+static SIMULATION: Mutex<Option<Simulation>> = Mutex::new(None);
+
+// Checked before taking the lock so generation stays cheap outside a simulation
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+struct Simulation {
+    source: Source,
+    recording: Vec<u8>,
+    time: Duration,
+}
+
+enum Source {
+    Seeded(SplitMix64),
+    System,
+    Replay { bytes: Vec<u8>, position: usize },
+}
+
+/// Start a simulation that draws random bytes from a generator seeded with `seed`.
+///
+/// This replaces any simulation that's already running. The random bytes
+/// drawn are recorded, and can be retrieved with [`take_recording`].
+pub fn seed(seed: u64) {
+    start(Source::Seeded(SplitMix64(seed)));
+}
+
+/// Start a simulation that draws random bytes from the operating system and records them.
+///
+/// This replaces any simulation that's already running. The recorded bytes
+/// can be retrieved with [`take_recording`] and passed to [`replay`].
+pub fn record() {
+    start(Source::System);
+}
+
+/// Start a simulation that draws random bytes from a previous recording.
+///
+/// This replaces any simulation that's already running. Generating UUIDs in
+/// the same order, with the same simulated times, as the run that produced
+/// `recording` reproduces its UUIDs exactly.
+///
+/// # Panics
+///
+/// Generating a UUID panics if the recording doesn't have enough random
+/// bytes left for it.
+pub fn replay(recording: Vec<u8>) {
+    start(Source::Replay {
+        bytes: recording,
+        position: 0,
+    });
+}
+
+/// Stop the running simulation, if there is one.
+///
+/// UUIDs are generated from the operating system's randomness and clock
+/// again after this is called.
+pub fn stop() {
+    let mut simulation = lock();
+
+    ACTIVE.store(false, Ordering::Release);
+    *simulation = None;
+}
+
+/// Whether a simulation is running.
+pub fn is_running() -> bool {
+    ACTIVE.load(Ordering::Acquire)
+}
+
+/// Take the random bytes drawn by the running simulation so far.
+///
+/// The simulation keeps running, and starts a new recording from this point.
+/// This returns an empty `Vec` if there's no simulation running, or if it's
+/// replaying a recording.
+pub fn take_recording() -> Vec<u8> {
+    match *lock() {
+        Some(ref mut simulation) => mem::take(&mut simulation.recording),
+        None => Vec::new(),
+    }
+}
+
+/// Set the simulated time, as the duration since the Unix epoch.
+///
+/// This has no effect if there's no simulation running.
+pub fn set_time(since_unix_epoch: Duration) {
+    if let Some(ref mut simulation) = *lock() {
+        simulation.time = since_unix_epoch;
+    }
+}
+
+/// Move the simulated time forward by `duration`.
+///
+/// This has no effect if there's no simulation running.
+pub fn advance_time(duration: Duration) {
+    if let Some(ref mut simulation) = *lock() {
+        simulation.time += duration;
+    }
+}
+
+fn start(source: Source) {
+    let mut simulation = lock();
+
+    *simulation = Some(Simulation {
+        source,
+        recording: Vec::new(),
+        time: Duration::ZERO,
+    });
+    ACTIVE.store(true, Ordering::Release);
+
+    #[cfg(any(feature = "v7", feature = "rng-custom"))]
+    crate::timestamp::context::shared_context_v7().reset();
+}
+
+fn lock() -> MutexGuard<'static, Option<Simulation>> {
+    SIMULATION.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+/// The simulated time, if a simulation is running.
+pub(crate) fn now() -> Option<(u64, u32)> {
+    if !is_running() {
+        return None;
+    }
+
+    lock()
+        .as_ref()
+        .map(|simulation| (simulation.time.as_secs(), simulation.time.subsec_nanos()))
+}
+
+/// Draw random bytes from the running simulation, or from `system` if there isn't one.
+// This is unused when neither the `v4` nor `v7` features are enabled
+#[allow(dead_code)]
+pub(crate) fn draw<const N: usize>(system: impl FnOnce() -> [u8; N]) -> [u8; N] {
+    match try_draw(|| Ok::<_, Infallible>(system())) {
+        Ok(bytes) => bytes,
+        Err(err) => match err {},
+    }
+}
+
+/// Draw random bytes from the running simulation, or from `system` if there isn't one.
+#[allow(dead_code)]
+pub(crate) fn try_draw<const N: usize, E>(
+    system: impl FnOnce() -> Result<[u8; N], E>,
+) -> Result<[u8; N], E> {
+    if !is_running() {
+        return system();
+    }
+
+    let mut guard = lock();
+
+    let simulation = match *guard {
+        Some(ref mut simulation) => simulation,
+        None => {
+            drop(guard);
+            return system();
+        }
+    };
+
+    let bytes = match simulation.source {
+        Source::Seeded(ref mut rng) => rng.bytes(),
+        Source::System => system()?,
+        Source::Replay {
+            ref bytes,
+            ref mut position,
+        } => {
+            let end = *position + N;
+
+            assert!(
+                end <= bytes.len(),
+                "the simulation's recording ran out of random bytes"
+            );
+
+            let mut drawn = [0; N];
+            drawn.copy_from_slice(&bytes[*position..end]);
+            *position = end;
+
+            return Ok(drawn);
+        }
+    };
+
+    simulation.recording.extend_from_slice(&bytes);
+
+    Ok(bytes)
+}
@@ -0,0 +1,11 @@
+#[cfg(feature = "siphash")]
+pub(crate) fn hash(key: &[u8; 16], name: &[u8]) -> [u8; 16] {
+    use core::hash::Hasher;
+    use siphasher::sip128::{Hasher128, SipHasher24};
+
+    let mut hasher = SipHasher24::new_with_key(key);
+
+    hasher.write(name);
+
+    hasher.finish128().as_bytes()
+}
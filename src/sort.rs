@@ -0,0 +1,202 @@
+//! Sorting for large slices of UUIDs.
+//!
+//! UUIDs are fixed-width integers, so they can be sorted with a radix sort
+//! that distributes them into buckets by their leading bits, instead of
+//! comparing pairs of UUIDs over and over. For slices of millions of UUIDs
+//! this can be faster than [`slice::sort_unstable`]. See `benches/sort.rs`
+//! to compare the two on your own hardware.
+
+use crate::{std::vec::Vec, Uuid};
+
+// Slices at or below this size are sorted with a comparison sort, which is
+// faster than a radix pass when there aren't many UUIDs
+const SMALL_SLICE: usize = 1_024;
+
+// Each radix pass aims for buckets about this size, which are then small
+// enough to finish with a comparison sort
+const TARGET_BUCKET: usize = 32;
+
+// The bounds on the number of bits used for each radix pass. Wider digits
+// mean fewer passes, but more buckets to scatter UUIDs into
+const MIN_DIGIT_BITS: u32 = 8;
+const MAX_DIGIT_BITS: u32 = 16;
+
+/// Sort a slice of UUIDs in ascending order.
+///
+/// This produces the same order as [`slice::sort_unstable`], using a
+/// most-significant-digit-first radix sort. Leading bits that are the same
+/// in every UUID, like the timestamp prefix shared by version 7 UUIDs
+/// generated close together, are skipped, and input that's already sorted
+/// is detected in a single pass.
+///
+/// The sort allocates scratch space the same size as `uuids`. It isn't
+/// stable, but UUIDs that compare equal are identical, so that's not
+/// observable.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::Uuid;
+/// let mut uuids = [
+///     Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8),
+///     Uuid::max(),
+///     Uuid::from_u128(0x17e55044_10b1_426f_9247_bb680e5fe0c8),
+///     Uuid::nil(),
+/// ];
+///
+/// uuid::sort::sort_unstable(&mut uuids);
+///
+/// assert!(uuids.windows(2).all(|pair| pair[0] <= pair[1]));
+/// ```
+pub fn sort_unstable(uuids: &mut [Uuid]) {
+    if uuids.len() <= SMALL_SLICE {
+        uuids.sort_unstable();
+        return;
+    }
+
+    if uuids.windows(2).all(|pair| pair[0] <= pair[1]) {
+        return;
+    }
+
+    let mut scratch = Vec::new();
+    sort_with(uuids, &mut scratch);
+}
+
+fn sort_with(uuids: &mut [Uuid], scratch: &mut Vec<Uuid>) {
+    if uuids.len() <= SMALL_SLICE {
+        // UUIDs are ordered by their bytes, which is the same as ordering them
+        // as big-endian integers, and integers are much cheaper to compare
+        uuids.sort_unstable_by_key(Uuid::as_u128);
+        return;
+    }
+
+    // Find the most significant bit that differs between any of the UUIDs
+    let first = uuids[0].as_u128();
+    let differing = uuids
+        .iter()
+        .fold(0, |differing, uuid| differing | (uuid.as_u128() ^ first));
+
+    if differing == 0 {
+        return;
+    }
+
+    let significant_bits = 128 - differing.leading_zeros();
+
+    // Use a digit wide enough to split the UUIDs into small buckets
+    let digit_bits = (usize::BITS - (uuids.len() / TARGET_BUCKET).leading_zeros())
+        .clamp(MIN_DIGIT_BITS, MAX_DIGIT_BITS)
+        .min(significant_bits);
+
+    let shift = significant_bits - digit_bits;
+    let mask = (1 << digit_bits) - 1;
+    let digit = |uuid: &Uuid| ((uuid.as_u128() >> shift) & mask) as usize;
+
+    let mut offsets = crate::std::vec![0; 1 << digit_bits];
+
+    for uuid in uuids.iter() {
+        offsets[digit(uuid)] += 1;
+    }
+
+    let mut offset = 0;
+    for count in offsets.iter_mut() {
+        let start = offset;
+        offset += *count;
+        *count = start;
+    }
+
+    // Scatter the UUIDs into their buckets in the scratch buffer,
+    // then copy them back in bucket order
+    scratch.clear();
+    scratch.resize(uuids.len(), Uuid::nil());
+
+    for uuid in uuids.iter() {
+        let bucket = &mut offsets[digit(uuid)];
+
+        scratch[*bucket] = *uuid;
+        *bucket += 1;
+    }
+
+    uuids.copy_from_slice(scratch);
+
+    // Each offset is now the end of its bucket
+    let mut start = 0;
+    for end in offsets {
+        sort_with(&mut uuids[start..end], scratch);
+        start = end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::std::vec::Vec;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    fn random_uuids(len: usize, seed: u64, shared_prefix: u32) -> Vec<Uuid> {
+        crate::tests::random_uuids(len, seed)
+            .into_iter()
+            .map(|uuid| {
+                if shared_prefix == 0 {
+                    uuid
+                } else {
+                    let prefix = 0x017f_22e2_79b0_u128 << 80;
+                    Uuid::from_u128(prefix | (uuid.as_u128() >> shared_prefix))
+                }
+            })
+            .collect()
+    }
+
+    fn assert_sorts(mut uuids: Vec<Uuid>) {
+        let mut expected = uuids.clone();
+        expected.sort_unstable();
+
+        sort_unstable(&mut uuids);
+
+        assert_eq!(expected, uuids);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_sort_random() {
+        for len in [0, 1, 2, SMALL_SLICE, SMALL_SLICE + 1, 1_000, 10_000] {
+            assert_sorts(random_uuids(len, 0x2545_f491_4f6c_dd1d, 0));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_sort_shared_prefix() {
+        // Like version 7 UUIDs generated within a few milliseconds
+        assert_sorts(random_uuids(10_000, 42, 56));
+
+        // Lots of duplicates
+        assert_sorts(random_uuids(10_000, 42, 124));
+
+        let mut uuids = random_uuids(1_000, 7, 0);
+        uuids.extend_from_slice(&[Uuid::nil(); 500]);
+        uuids.extend_from_slice(&[Uuid::max(); 500]);
+
+        assert_sorts(uuids);
+    }
+}
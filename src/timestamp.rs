@@ -29,6 +29,10 @@ use crate::Uuid;
 /// (`1582-10-15 00:00:00`) and the Unix epoch (`1970-01-01 00:00:00`).
 pub const UUID_TICKS_BETWEEN_EPOCHS: u64 = 0x01B2_1DD2_1381_4000;
 
+/// The number of 100 nanosecond ticks between the RFC 9562 epoch
+/// (`1582-10-15 00:00:00`) and the Windows `FILETIME` epoch (`1601-01-01 00:00:00`).
+pub const FILETIME_TICKS_BETWEEN_EPOCHS: u64 = 0x0014_6BF3_3E42_C000;
+
 /// A timestamp that can be encoded into a UUID.
 ///
 /// This type abstracts the specific encoding, so versions 1, 6, and 7
@@ -121,6 +125,49 @@ impl Timestamp {
         }
     }
 
+    /// Construct a `Timestamp` from a Windows `FILETIME`, the number of 100 nanosecond ticks
+    /// since 00:00:00.00, 1 January 1601, and a counter.
+    ///
+    /// `FILETIME`s use the same resolution as the timestamps in versions 1 and 6 UUIDs, so no
+    /// precision is lost converting between them.
+    ///
+    /// # Overflow
+    ///
+    /// If conversion from `FILETIME` ticks to the internal timestamp format would overflow
+    /// it will wrap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{NoContext, Timestamp};
+    /// // 2022-02-22 19:22:22 UTC
+    /// let ts = Timestamp::from_filetime(NoContext, 132_900_313_420_000_000);
+    ///
+    /// assert_eq!((1_645_557_742, 0), ts.to_unix());
+    /// assert_eq!(132_900_313_420_000_000, ts.to_filetime());
+    /// ```
+    pub fn from_filetime(
+        context: impl ClockSequence<Output = impl Into<u128>>,
+        filetime: u64,
+    ) -> Self {
+        let (seconds, subsec_nanos) =
+            Self::gregorian_to_unix(filetime.wrapping_add(FILETIME_TICKS_BETWEEN_EPOCHS));
+
+        Self::from_unix(context, seconds, subsec_nanos)
+    }
+
+    /// Get the value of the timestamp as a Windows `FILETIME`, the number of 100 nanosecond
+    /// ticks since 00:00:00.00, 1 January 1601.
+    ///
+    /// # Overflow
+    ///
+    /// If conversion from the internal timestamp format to `FILETIME` ticks would overflow
+    /// then it will wrap.
+    pub const fn to_filetime(&self) -> u64 {
+        Self::unix_to_gregorian_ticks(self.seconds, self.subsec_nanos)
+            .wrapping_sub(FILETIME_TICKS_BETWEEN_EPOCHS)
+    }
+
     /// Get the value of the timestamp as the number of 100 nanosecond ticks since 00:00:00.00,
     /// 15 October 1582 and a 14-bit counter, as used in versions 1 and 6 UUIDs.
     ///
@@ -139,7 +186,7 @@ impl Timestamp {
 
     // NOTE: This method is not public; the usable counter bits are lost in a version 7 UUID
     // so can't be reliably recovered.
-    #[cfg(feature = "v7")]
+    #[cfg(any(feature = "v7", feature = "rng-custom"))]
     pub(crate) const fn counter(&self) -> (u128, u8) {
         (self.counter, self.usable_counter_bits)
     }
@@ -149,6 +196,65 @@ impl Timestamp {
         (self.seconds, self.subsec_nanos)
     }
 
+    /// Get the value of the timestamp as a [`Duration`] since the Unix epoch.
+    ///
+    /// Version 1 and 6 UUIDs can hold timestamps from as early as 1582. This method
+    /// returns `None` if the timestamp is before the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use uuid::{NoContext, Timestamp};
+    /// let ts = Timestamp::from_unix(NoContext, 1_645_557_742, 123_000_000);
+    ///
+    /// assert_eq!(Some(Duration::from_millis(1_645_557_742_123)), ts.to_unix_duration());
+    ///
+    /// // 00:00:00.00, 15 October 1582
+    /// let ts = Timestamp::from_gregorian(0, 0);
+    ///
+    /// assert_eq!(None, ts.to_unix_duration());
+    /// ```
+    ///
+    /// [`Duration`]: https://doc.rust-lang.org/core/time/struct.Duration.html
+    pub const fn to_unix_duration(&self) -> Option<core::time::Duration> {
+        // Timestamps before the Unix epoch wrap when they're converted from
+        // Gregorian ticks, so convert them back to find out
+        let ticks = Self::unix_to_gregorian_ticks(self.seconds, self.subsec_nanos);
+
+        match ticks.checked_sub(UUID_TICKS_BETWEEN_EPOCHS) {
+            Some(_) => Some(core::time::Duration::new(self.seconds, self.subsec_nanos)),
+            None => None,
+        }
+    }
+
+    /// Get the value of the timestamp as a [`SystemTime`].
+    ///
+    /// This method returns `None` if the timestamp is before the Unix epoch, or is
+    /// too far in the future to be represented by a `SystemTime` on the current platform.
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::{Duration, SystemTime};
+    /// # use uuid::{NoContext, Timestamp};
+    /// let ts = Timestamp::from_unix(NoContext, 1_645_557_742, 0);
+    ///
+    /// assert_eq!(
+    ///     Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_645_557_742)),
+    ///     ts.to_system_time(),
+    /// );
+    /// ```
+    ///
+    /// [`SystemTime`]: https://doc.rust-lang.org/std/time/struct.SystemTime.html
+    #[cfg(feature = "std")]
+    pub fn to_system_time(&self) -> Option<std::time::SystemTime> {
+        std::time::SystemTime::UNIX_EPOCH.checked_add(self.to_unix_duration()?)
+    }
+
     const fn unix_to_gregorian_ticks(seconds: u64, nanos: u32) -> u64 {
         UUID_TICKS_BETWEEN_EPOCHS
             .wrapping_add(seconds.wrapping_mul(10_000_000))
@@ -292,6 +398,66 @@ pub(crate) const fn encode_unix_timestamp_millis(
     Uuid::from_fields(millis_high, millis_low, counter_random_version, &d4)
 }
 
+pub(crate) const fn encode_unix_timestamp_counter(
+    millis: u64,
+    counter: u128,
+    counter_bits: u32,
+    random: u128,
+) -> Uuid {
+    // The 4 most significant bits are taken by the version field, so the
+    // counter is placed directly after them
+    encode_unix_timestamp_counter_at(millis, counter, counter_bits, random, 124)
+}
+
+// This is the layout used by `Uuid::new_v7`, which places the counter at the very
+// top of the counter and random bits. The version field overwrites the 4 most
+// significant bits of the counter
+#[cfg(any(feature = "v7", feature = "rng-custom"))]
+pub(crate) const fn encode_unix_timestamp_leading_counter(
+    millis: u64,
+    counter: u128,
+    counter_bits: u32,
+    random: u128,
+) -> Uuid {
+    encode_unix_timestamp_counter_at(millis, counter, counter_bits, random, 128)
+}
+
+const fn encode_unix_timestamp_counter_at(
+    millis: u64,
+    mut counter: u128,
+    counter_bits: u32,
+    random: u128,
+    end: u32,
+) -> Uuid {
+    let mut counter_bits = counter_bits;
+
+    // If the counter intersects the variant field then shift around it.
+    // This ensures that any bits set in the counter that would intersect
+    // the variant are still preserved
+    if counter_bits > 12 {
+        let mask = u128::MAX << (counter_bits - 12);
+
+        counter = (counter & !mask) | ((counter & mask) << 2);
+
+        counter_bits += 2;
+    }
+
+    let mut counter_and_random = random & u128::MAX.overflowing_shr(counter_bits + 128 - end).0;
+    counter_and_random |= counter.overflowing_shl(end.saturating_sub(counter_bits)).0;
+
+    let counter_and_random = counter_and_random.to_be_bytes();
+
+    let mut counter_random_bytes = [0; 10];
+    let mut i = 0;
+
+    while i < 10 {
+        counter_random_bytes[i] = counter_and_random[i];
+        i += 1;
+    }
+
+    encode_unix_timestamp_millis(millis, &counter_random_bytes)
+}
+
 pub(crate) const fn decode_unix_timestamp_millis(uuid: &Uuid) -> u64 {
     let bytes = uuid.as_bytes();
 
@@ -305,6 +471,58 @@ pub(crate) const fn decode_unix_timestamp_millis(uuid: &Uuid) -> u64 {
     millis
 }
 
+// The number of bits of a version 7 UUID's `rand_a` field used for a fraction
+// of a millisecond, as in RFC 9562's method 3
+pub(crate) const SUB_MILLIS_BITS: u32 = 12;
+
+pub(crate) const fn encode_sub_millis(sub_millis_nanos: u32) -> u16 {
+    let nanos = if sub_millis_nanos > 999_999 {
+        999_999
+    } else {
+        sub_millis_nanos
+    };
+
+    // Rounding down keeps the fraction below 1ms and preserves ordering
+    ((nanos as u64 * (1 << SUB_MILLIS_BITS)) / 1_000_000) as u16
+}
+
+pub(crate) const fn decode_sub_millis(uuid: &Uuid, units_per_milli: u64) -> u64 {
+    let bytes = uuid.as_bytes();
+
+    let fraction = ((bytes[6] & 0xF) as u64) << 8 | (bytes[7] as u64);
+
+    // Rounding up undoes the rounding down done when encoding, so whole units
+    // roundtrip exactly
+    let units = (fraction * units_per_milli + (1 << SUB_MILLIS_BITS) - 1) >> SUB_MILLIS_BITS;
+
+    // The largest fractions would round up into the next millisecond
+    if units >= units_per_milli {
+        units_per_milli - 1
+    } else {
+        units
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn now() -> (u64, u32) {
+    #[cfg(feature = "simulation")]
+    if let Some(now) = crate::simulation::now() {
+        return now;
+    }
+
+    system_now()
+}
+
+#[cfg(all(feature = "std", any(feature = "v7", feature = "rng-custom")))]
+pub(crate) fn try_now() -> Result<(u64, u32), crate::Error> {
+    #[cfg(feature = "simulation")]
+    if let Some(now) = crate::simulation::now() {
+        return Ok(now);
+    }
+
+    system_try_now()
+}
+
 #[cfg(all(
     feature = "std",
     feature = "js",
@@ -314,7 +532,7 @@ pub(crate) const fn decode_unix_timestamp_millis(uuid: &Uuid) -> u64 {
         target_os = "unknown"
     )
 ))]
-fn now() -> (u64, u32) {
+fn system_now() -> (u64, u32) {
     use wasm_bindgen::prelude::*;
 
     #[wasm_bindgen]
@@ -344,7 +562,7 @@ fn now() -> (u64, u32) {
         ))
     )
 ))]
-fn now() -> (u64, u32) {
+fn system_now() -> (u64, u32) {
     let dur = std::time::SystemTime::UNIX_EPOCH.elapsed().expect(
         "Getting elapsed time since UNIX_EPOCH. If this fails, we've somehow violated causality",
     );
@@ -352,8 +570,84 @@ fn now() -> (u64, u32) {
     (dur.as_secs(), dur.subsec_nanos())
 }
 
+#[cfg(all(
+    feature = "std",
+    any(feature = "v7", feature = "rng-custom"),
+    not(miri),
+    any(
+        not(feature = "js"),
+        not(all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ))
+    )
+))]
+fn system_try_now() -> Result<(u64, u32), crate::Error> {
+    try_since_unix_epoch(std::time::SystemTime::now())
+}
+
+#[cfg(all(
+    feature = "std",
+    any(feature = "v7", feature = "rng-custom"),
+    not(miri),
+    any(
+        not(feature = "js"),
+        not(all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ))
+    )
+))]
+pub(crate) fn try_since_unix_epoch(now: std::time::SystemTime) -> Result<(u64, u32), crate::Error> {
+    // `SystemTime` can always be read, so this only fails when the clock is
+    // set before 1970, like on devices whose RTC hasn't been set since boot
+    let dur = now
+        .duration_since(std::time::SystemTime::UNIX_EPOCH)
+        .map_err(|_| crate::Error::new(crate::error::ErrorKind::ClockBeforeEpoch))?;
+
+    Ok((dur.as_secs(), dur.subsec_nanos()))
+}
+
+#[cfg(all(
+    feature = "std",
+    any(feature = "v7", feature = "rng-custom"),
+    feature = "js",
+    all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    )
+))]
+fn system_try_now() -> Result<(u64, u32), crate::Error> {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = Date, catch)]
+        fn now() -> Result<f64, JsValue>;
+    }
+
+    let now = now().map_err(|_| crate::Error::new(crate::error::ErrorKind::Clock))?;
+
+    if now < 0.0 {
+        return Err(crate::Error::new(crate::error::ErrorKind::ClockBeforeEpoch));
+    }
+
+    let secs = (now / 1_000.0) as u64;
+    let nanos = ((now % 1_000.0) * 1_000_000.0) as u32;
+
+    Ok((secs, nanos))
+}
+
+#[cfg(all(feature = "std", any(feature = "v7", feature = "rng-custom"), miri))]
+fn system_try_now() -> Result<(u64, u32), crate::Error> {
+    Ok(system_now())
+}
+
 #[cfg(all(feature = "std", miri))]
-fn now() -> (u64, u32) {
+fn system_now() -> (u64, u32) {
     use std::{sync::Mutex, time::Duration};
 
     static TS: Mutex<u64> = Mutex::new(0);
@@ -418,7 +712,7 @@ pub trait ClockSequence {
     }
 }
 
-impl<'a, T: ClockSequence + ?Sized> ClockSequence for &'a T {
+impl<T: ClockSequence + ?Sized> ClockSequence for &T {
     type Output = T::Output;
 
     fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> Self::Output {
@@ -451,15 +745,15 @@ pub mod context {
 
         use atomic::{Atomic, Ordering};
 
-        #[cfg(all(feature = "std", feature = "rng"))]
+        #[cfg(all(feature = "std", any(feature = "rng", feature = "rng-custom")))]
         static CONTEXT: Context = Context {
             count: Atomic::new(0),
         };
 
-        #[cfg(all(feature = "std", feature = "rng"))]
+        #[cfg(all(feature = "std", any(feature = "rng", feature = "rng-custom")))]
         static CONTEXT_INITIALIZED: Atomic<bool> = Atomic::new(false);
 
-        #[cfg(all(feature = "std", feature = "rng"))]
+        #[cfg(all(feature = "std", any(feature = "rng", feature = "rng-custom")))]
         pub(crate) fn shared_context() -> &'static Context {
             // If the context is in its initial state then assign it to a random value
             // It doesn't matter if multiple threads observe `false` here and initialize the context
@@ -505,7 +799,7 @@ pub mod context {
             }
 
             /// Construct a new context that's initialized with a random value.
-            #[cfg(feature = "rng")]
+            #[cfg(any(feature = "rng", feature = "rng-custom"))]
             pub fn new_random() -> Self {
                 Self {
                     count: Atomic::<u16>::new(crate::rng::u16()),
@@ -664,11 +958,491 @@ pub mod context {
     #[cfg(feature = "std")]
     pub use std_support::*;
 
-    #[cfg(feature = "v7")]
+    #[cfg(all(feature = "std", any(feature = "v1", feature = "v6")))]
+    mod persistent_support {
+        use super::*;
+
+        use std::{
+            fs,
+            io::{self, Write},
+            path::{Path, PathBuf},
+            sync::Mutex,
+        };
+
+        const COUNTER_MASK: u16 = u16::MAX >> 2;
+
+        /// The state of a [`PersistentContext`] that's kept in a [`ClockStore`].
+        ///
+        /// The counter is the last one that may have been generated, which is
+        /// the end of the lease the context held when the state was saved.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct ClockState {
+            counter: u16,
+            seconds: u64,
+            subsec_nanos: u32,
+        }
+
+        impl ClockState {
+            /// Create a state from the last counter value and the Unix timestamp
+            /// it was generated for.
+            ///
+            /// Only the 14 least significant bits of `counter` are used.
+            pub const fn new(counter: u16, seconds: u64, subsec_nanos: u32) -> Self {
+                ClockState {
+                    counter: counter & COUNTER_MASK,
+                    seconds,
+                    subsec_nanos,
+                }
+            }
+
+            /// The last counter value that was generated.
+            pub const fn counter(&self) -> u16 {
+                self.counter
+            }
+
+            /// The Unix timestamp the last counter value was generated for,
+            /// as seconds and subsecond nanos.
+            pub const fn timestamp(&self) -> (u64, u32) {
+                (self.seconds, self.subsec_nanos)
+            }
+        }
+
+        /// Stable storage for the state of a [`PersistentContext`].
+        ///
+        /// Implement this trait to keep the clock sequence in a database, a
+        /// key-value store, or anywhere else that outlives the process. See
+        /// [`FileStore`] for an implementation that uses a file.
+        pub trait ClockStore {
+            /// Load the saved state, or `None` if no state has been saved yet.
+            fn load(&self) -> io::Result<Option<ClockState>>;
+
+            /// Save the state, replacing any state saved before.
+            fn store(&self, state: ClockState) -> io::Result<()>;
+        }
+
+        impl<T: ClockStore + ?Sized> ClockStore for &T {
+            fn load(&self) -> io::Result<Option<ClockState>> {
+                (**self).load()
+            }
+
+            fn store(&self, state: ClockState) -> io::Result<()> {
+                (**self).store(state)
+            }
+        }
+
+        /// A [`ClockStore`] that keeps its state in a file.
+        ///
+        /// The state is written to a temporary file next to the path, flushed
+        /// to disk, and then renamed over it, so a crash part way through a
+        /// write leaves the previous state in place.
+        #[derive(Debug, Clone)]
+        pub struct FileStore {
+            path: PathBuf,
+        }
+
+        impl FileStore {
+            /// Create a store that keeps its state in the file at `path`.
+            ///
+            /// The file doesn't need to exist until the state is first saved.
+            pub fn new(path: impl AsRef<Path>) -> Self {
+                FileStore {
+                    path: path.as_ref().to_path_buf(),
+                }
+            }
+
+            /// The path of the file the state is kept in.
+            pub fn path(&self) -> &Path {
+                &self.path
+            }
+        }
+
+        impl ClockStore for FileStore {
+            fn load(&self) -> io::Result<Option<ClockState>> {
+                let contents = match fs::read_to_string(&self.path) {
+                    Ok(contents) => contents,
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+                    Err(err) => return Err(err),
+                };
+
+                // The state is written as `{counter} {seconds}.{subsec_nanos}`
+                let invalid = || io::Error::new(io::ErrorKind::InvalidData, "invalid clock state");
+
+                let (counter, timestamp) = contents.trim().split_once(' ').ok_or_else(invalid)?;
+                let (seconds, subsec_nanos) = timestamp.split_once('.').ok_or_else(invalid)?;
+
+                Ok(Some(ClockState::new(
+                    counter.parse().map_err(|_| invalid())?,
+                    seconds.parse().map_err(|_| invalid())?,
+                    subsec_nanos.parse().map_err(|_| invalid())?,
+                )))
+            }
+
+            fn store(&self, state: ClockState) -> io::Result<()> {
+                let mut tmp = self.path.clone().into_os_string();
+                tmp.push(".tmp");
+
+                let mut file = fs::File::create(&tmp)?;
+                writeln!(
+                    file,
+                    "{} {}.{:09}",
+                    state.counter, state.seconds, state.subsec_nanos
+                )?;
+
+                // Make sure the new state is on disk before it replaces the old one,
+                // otherwise a crash could leave an empty file behind
+                file.sync_all()?;
+
+                fs::rename(&tmp, &self.path)
+            }
+        }
+
+        /// A 14-bit counter for versions 1 and 6 UUIDs that keeps its state in a
+        /// [`ClockStore`], so it carries on where it left off after a restart.
+        ///
+        /// Like [`Context`], the counter is incremented for each timestamp and
+        /// wraps once it overflows 14 bits. Rather than saving every counter, the
+        /// context leases a block of counters at a time, and saves the end of the
+        /// lease to the store before generating any counters from it. When a
+        /// context is created from a store that already has a state, the counter
+        /// resumes after the saved value, so a UUID generated after a restart
+        /// won't repeat the counter of a UUID generated before it with the same
+        /// timestamp. This is the stable storage described in
+        /// [RFC 9562 section 6.3](https://www.ietf.org/rfc/rfc9562.html#section-6.3).
+        ///
+        /// Leases are [`PersistentContext::DEFAULT_LEASE`] counters long by default,
+        /// which can be changed with [`PersistentContext::with_lease`]. A longer lease
+        /// writes to the store less often, but skips more counters after a restart.
+        ///
+        /// Errors saving the state can't be returned from the [`ClockSequence`]
+        /// methods. The counter is still generated, but isn't protected against a
+        /// restart until a lease is saved, which is retried with the next timestamp.
+        /// The error is kept and can be retrieved with [`PersistentContext::take_error`].
+        /// To handle errors as they happen instead, use
+        /// [`PersistentContext::try_generate_sequence`].
+        ///
+        /// # Examples
+        ///
+        /// ```no_run
+        /// # fn main() -> std::io::Result<()> {
+        /// # use uuid::{timestamp::context::{FileStore, PersistentContext}, Timestamp, Uuid};
+        /// let context = PersistentContext::new(FileStore::new("uuid-clock"), 0)?;
+        ///
+        /// let uuid = Uuid::new_v1(Timestamp::now(&context), &[1, 2, 3, 4, 5, 6]);
+        /// # let _ = uuid;
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[derive(Debug)]
+        pub struct PersistentContext<S> {
+            store: S,
+            lease_len: u16,
+            lease: Mutex<Lease>,
+        }
+
+        #[derive(Debug)]
+        struct Lease {
+            counter: u16,
+            remaining: u16,
+            error: Option<io::Error>,
+        }
+
+        impl<S: ClockStore> PersistentContext<S> {
+            /// The number of counters leased at a time by default.
+            pub const DEFAULT_LEASE: u16 = 1024;
+
+            /// Create a context that loads its state from `store`.
+            ///
+            /// If the store doesn't have a state yet, the counter starts from
+            /// `count`. The starting value should be a random number, so that UUIDs
+            /// from different systems with the same timestamps are less likely to
+            /// collide. When the `rng` feature is enabled, prefer the
+            /// [`PersistentContext::new_random`] method.
+            ///
+            /// # Errors
+            ///
+            /// This method will return an error if the state can't be loaded
+            /// from the store.
+            pub fn new(store: S, count: u16) -> io::Result<Self> {
+                let counter = match store.load()? {
+                    Some(saved) => saved.counter.wrapping_add(1),
+                    None => count,
+                };
+
+                Ok(PersistentContext {
+                    store,
+                    lease_len: Self::DEFAULT_LEASE,
+                    lease: Mutex::new(Lease {
+                        counter: counter & COUNTER_MASK,
+                        remaining: 0,
+                        error: None,
+                    }),
+                })
+            }
+
+            /// Lease `counters` counters at a time.
+            ///
+            /// A lease of `1` saves the state for every counter. Leases are
+            /// clamped between `1` and the number of 14-bit counters.
+            pub fn with_lease(mut self, counters: u16) -> Self {
+                self.lease_len = counters.clamp(1, COUNTER_MASK + 1);
+                self
+            }
+
+            /// Create a context that loads its state from `store`, starting from
+            /// a random value if it doesn't have one yet.
+            ///
+            /// # Errors
+            ///
+            /// This method will return an error if the state can't be loaded
+            /// from the store.
+            #[cfg(any(feature = "rng", feature = "rng-custom"))]
+            pub fn new_random(store: S) -> io::Result<Self> {
+                Self::new(store, crate::rng::u16())
+            }
+
+            /// Get a reference to the underlying store.
+            pub fn store(&self) -> &S {
+                &self.store
+            }
+
+            /// Generate a counter for the given timestamp, returning an error
+            /// if a new lease is needed and can't be saved to the store.
+            ///
+            /// If this method returns an error, no counter is generated.
+            pub fn try_generate_sequence(
+                &self,
+                seconds: u64,
+                subsec_nanos: u32,
+            ) -> io::Result<u16> {
+                let mut lease = self.lease.lock().unwrap_or_else(|err| err.into_inner());
+
+                self.next_counter(&mut lease, seconds, subsec_nanos)
+            }
+
+            /// Take the last error saving the state to the store from
+            /// [`ClockSequence::generate_sequence`], if there was one.
+            pub fn take_error(&self) -> Option<io::Error> {
+                self.lease
+                    .lock()
+                    .unwrap_or_else(|err| err.into_inner())
+                    .error
+                    .take()
+            }
+
+            fn next_counter(
+                &self,
+                lease: &mut Lease,
+                seconds: u64,
+                subsec_nanos: u32,
+            ) -> io::Result<u16> {
+                if lease.remaining == 0 {
+                    let last = lease.counter.wrapping_add(self.lease_len - 1);
+
+                    self.store
+                        .store(ClockState::new(last, seconds, subsec_nanos))?;
+                    lease.remaining = self.lease_len;
+                }
+
+                let counter = lease.counter;
+
+                lease.counter = counter.wrapping_add(1) & COUNTER_MASK;
+                lease.remaining -= 1;
+
+                Ok(counter)
+            }
+        }
+
+        impl<S: ClockStore> ClockSequence for PersistentContext<S> {
+            type Output = u16;
+
+            fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> Self::Output {
+                let mut lease = self.lease.lock().unwrap_or_else(|err| err.into_inner());
+
+                match self.next_counter(&mut lease, seconds, subsec_nanos) {
+                    Ok(counter) => counter,
+                    Err(err) => {
+                        // Without a lease the counter is still unique within this
+                        // process, it just isn't protected against a restart
+                        let counter = lease.counter;
+
+                        lease.counter = counter.wrapping_add(1) & COUNTER_MASK;
+                        lease.error = Some(err);
+
+                        counter
+                    }
+                }
+            }
+
+            fn usable_bits(&self) -> usize {
+                14
+            }
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use crate::Timestamp;
+
+            use super::*;
+
+            #[derive(Default)]
+            struct MemoryStore(Mutex<Option<ClockState>>);
+
+            impl ClockStore for MemoryStore {
+                fn load(&self) -> io::Result<Option<ClockState>> {
+                    Ok(*self.0.lock().unwrap())
+                }
+
+                fn store(&self, state: ClockState) -> io::Result<()> {
+                    *self.0.lock().unwrap() = Some(state);
+                    Ok(())
+                }
+            }
+
+            #[test]
+            fn persistent_context_resumes() {
+                let seconds = 1_496_854_535;
+                let subsec_nanos = 812_946_000;
+
+                let store = MemoryStore::default();
+
+                let context = PersistentContext::new(&store, 42).unwrap();
+                assert_eq!(
+                    42,
+                    Timestamp::from_unix(&context, seconds, subsec_nanos).counter
+                );
+                assert_eq!(
+                    43,
+                    Timestamp::from_unix(&context, seconds, subsec_nanos).counter
+                );
+                assert_eq!(
+                    Some(ClockState::new(42 + 1023, seconds, subsec_nanos)),
+                    store.load().unwrap()
+                );
+
+                // The restarted context carries on after the saved lease
+                let context = PersistentContext::new(&store, 42).unwrap();
+                assert_eq!(
+                    42 + 1024,
+                    Timestamp::from_unix(&context, seconds, subsec_nanos).counter
+                );
+            }
+
+            #[test]
+            fn persistent_context_leases() {
+                let store = MemoryStore::default();
+
+                let context = PersistentContext::new(&store, 42).unwrap().with_lease(4);
+
+                // The lease is saved before its first counter is generated
+                assert_eq!(42, context.generate_sequence(1_496_854_535, 0));
+                assert_eq!(
+                    Some(ClockState::new(45, 1_496_854_535, 0)),
+                    store.load().unwrap()
+                );
+
+                // The store isn't written again until the lease runs out
+                for counter in 43..=45 {
+                    assert_eq!(counter, context.generate_sequence(1_496_854_536, 0));
+                }
+                assert_eq!(
+                    Some(ClockState::new(45, 1_496_854_535, 0)),
+                    store.load().unwrap()
+                );
+
+                assert_eq!(46, context.generate_sequence(1_496_854_537, 0));
+                assert_eq!(
+                    Some(ClockState::new(49, 1_496_854_537, 0)),
+                    store.load().unwrap()
+                );
+
+                // A lease of 1 saves every counter
+                let context = PersistentContext::new(&store, 0).unwrap().with_lease(0);
+
+                assert_eq!(50, context.generate_sequence(1_496_854_538, 0));
+                assert_eq!(
+                    Some(ClockState::new(50, 1_496_854_538, 0)),
+                    store.load().unwrap()
+                );
+            }
+
+            #[test]
+            fn persistent_context_store_errors() {
+                struct FailingStore;
+
+                impl ClockStore for FailingStore {
+                    fn load(&self) -> io::Result<Option<ClockState>> {
+                        Ok(None)
+                    }
+
+                    fn store(&self, _: ClockState) -> io::Result<()> {
+                        Err(io::ErrorKind::PermissionDenied.into())
+                    }
+                }
+
+                let context = PersistentContext::new(FailingStore, 42).unwrap();
+
+                assert!(context.take_error().is_none());
+
+                // Counters are still generated, but the error is kept
+                assert_eq!(42, context.generate_sequence(1_496_854_535, 0));
+                assert_eq!(43, context.generate_sequence(1_496_854_535, 0));
+                assert_eq!(
+                    io::ErrorKind::PermissionDenied,
+                    context.take_error().unwrap().kind()
+                );
+                assert!(context.take_error().is_none());
+
+                // The fallible method doesn't generate a counter
+                assert_eq!(
+                    io::ErrorKind::PermissionDenied,
+                    context
+                        .try_generate_sequence(1_496_854_535, 0)
+                        .unwrap_err()
+                        .kind()
+                );
+                assert_eq!(44, context.generate_sequence(1_496_854_535, 0));
+            }
+
+            #[test]
+            fn persistent_context_wraps() {
+                let store = MemoryStore::default();
+                store
+                    .store(ClockState::new(u16::MAX >> 2, 1_496_854_535, 0))
+                    .unwrap();
+
+                let context = PersistentContext::new(&store, 42).unwrap();
+                assert_eq!(0, context.generate_sequence(1_496_854_535, 0));
+                assert_eq!(14, context.usable_bits());
+            }
+
+            #[test]
+            fn file_store_roundtrip() {
+                let path =
+                    std::env::temp_dir().join(format!("uuid-clock-state-{}", std::process::id()));
+                let store = FileStore::new(&path);
+
+                assert_eq!(None, store.load().unwrap());
+
+                let state = ClockState::new(1234, 1_496_854_535, 812_946_000);
+                store.store(state).unwrap();
+                assert_eq!(Some(state), store.load().unwrap());
+
+                fs::write(&path, "not a clock state").unwrap();
+                assert_eq!(io::ErrorKind::InvalidData, store.load().unwrap_err().kind());
+
+                fs::remove_file(&path).unwrap();
+            }
+        }
+    }
+
+    #[cfg(all(feature = "std", any(feature = "v1", feature = "v6")))]
+    pub use persistent_support::*;
+
+    #[cfg(any(feature = "v7", feature = "rng-custom"))]
     mod v7_support {
         use super::*;
 
-        use core::{cell::Cell, panic::RefUnwindSafe};
+        use core::{cell::Cell, convert::Infallible, panic::RefUnwindSafe};
 
         #[cfg(feature = "std")]
         static CONTEXT_V7: SharedContextV7 =
@@ -744,40 +1518,42 @@ pub mod context {
             }
         }
 
-        impl ClockSequence for ContextV7 {
-            type Output = u64;
-
-            fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> Self::Output {
-                self.generate_timestamp_sequence(seconds, subsec_nanos).0
+        impl Default for ContextV7 {
+            fn default() -> Self {
+                ContextV7::new()
             }
+        }
 
-            fn generate_timestamp_sequence(
+        impl ContextV7 {
+            // Generate the next counter, using `reseed` to get a new random value
+            // when the counter needs to be reseeded
+            fn try_generate_timestamp_sequence<E>(
                 &self,
                 seconds: u64,
                 subsec_nanos: u32,
-            ) -> (Self::Output, u64, u32) {
-                let millis = (seconds * 1_000).saturating_add(subsec_nanos as u64 / 1_000_000);
+                mut reseed: impl FnMut() -> Result<u64, E>,
+            ) -> Result<(u64, u64, u32), E> {
+                let millis = seconds
+                    .saturating_mul(1_000)
+                    .saturating_add(subsec_nanos as u64 / 1_000_000);
 
                 let last_reseed = self.last_reseed.get();
 
                 // If the observed system time has shifted forwards then regenerate the counter
                 if millis > last_reseed.millis {
+                    let counter = reseed()? & RESEED_MASK;
+
                     let last_reseed = LastReseed::from_millis(millis);
                     self.last_reseed.set(last_reseed);
-
-                    let counter = crate::rng::u64() & RESEED_MASK;
                     self.counter.set(counter);
 
-                    (counter, last_reseed.ts_seconds, last_reseed.ts_subsec_nanos)
+                    Ok((counter, last_reseed.ts_seconds, last_reseed.ts_subsec_nanos))
                 }
                 // If the observed system time has not shifted forwards then increment the counter
                 else {
                     // If the incoming timestamp is earlier than the last observed one then
                     // use it instead. This may happen if the system clock jitters, or if the counter
                     // has wrapped and the timestamp is artificially incremented
-                    let millis = ();
-                    let _ = millis;
-
                     // Guaranteed to never overflow u64
                     let counter = self.counter.get() + 1;
 
@@ -785,24 +1561,45 @@ pub mod context {
                     if counter <= MAX_COUNTER {
                         self.counter.set(counter);
 
-                        (counter, last_reseed.ts_seconds, last_reseed.ts_subsec_nanos)
+                        Ok((counter, last_reseed.ts_seconds, last_reseed.ts_subsec_nanos))
                     }
                     // Unlikely: If the counter has overflowed its 42-bit storage then wrap it
                     // and increment the timestamp. Until the observed system time shifts past
                     // this incremented value, all timestamps will use it to maintain monotonicity
                     else {
+                        // Reseed the counter
+                        let counter = reseed()? & RESEED_MASK;
+
                         // Increment the timestamp by 1 milli
                         let last_reseed = LastReseed::from_millis(last_reseed.millis + 1);
                         self.last_reseed.set(last_reseed);
-
-                        // Reseed the counter
-                        let counter = crate::rng::u64() & RESEED_MASK;
                         self.counter.set(counter);
 
-                        (counter, last_reseed.ts_seconds, last_reseed.ts_subsec_nanos)
+                        Ok((counter, last_reseed.ts_seconds, last_reseed.ts_subsec_nanos))
                     }
                 }
             }
+        }
+
+        impl ClockSequence for ContextV7 {
+            type Output = u64;
+
+            fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> Self::Output {
+                self.generate_timestamp_sequence(seconds, subsec_nanos).0
+            }
+
+            fn generate_timestamp_sequence(
+                &self,
+                seconds: u64,
+                subsec_nanos: u32,
+            ) -> (Self::Output, u64, u32) {
+                match self.try_generate_timestamp_sequence(seconds, subsec_nanos, || {
+                    Ok::<_, Infallible>(crate::rng::u64())
+                }) {
+                    Ok(sequence) => sequence,
+                    Err(never) => match never {},
+                }
+            }
 
             fn usable_bits(&self) -> usize {
                 USABLE_BITS
@@ -812,6 +1609,30 @@ pub mod context {
         #[cfg(feature = "std")]
         pub(crate) struct SharedContextV7(std::sync::Mutex<ContextV7>);
 
+        #[cfg(feature = "std")]
+        impl SharedContextV7 {
+            pub(crate) fn try_generate_timestamp_sequence(
+                &self,
+                seconds: u64,
+                subsec_nanos: u32,
+            ) -> Result<(u64, u64, u32), crate::Error> {
+                self.0
+                    .lock()
+                    .unwrap_or_else(|err| err.into_inner())
+                    .try_generate_timestamp_sequence(seconds, subsec_nanos, crate::rng::try_u64)
+            }
+
+            // Hold the lock while generating many timestamp sequences in a row
+            pub(crate) fn with<R>(&self, f: impl FnOnce(&ContextV7) -> R) -> R {
+                f(&self.0.lock().unwrap_or_else(|err| err.into_inner()))
+            }
+
+            #[cfg(feature = "simulation")]
+            pub(crate) fn reset(&self) {
+                *self.0.lock().unwrap_or_else(|err| err.into_inner()) = ContextV7::new();
+            }
+        }
+
         #[cfg(feature = "std")]
         impl ClockSequence for SharedContextV7 {
             type Output = u64;
@@ -903,7 +1724,7 @@ pub mod context {
         }
     }
 
-    #[cfg(feature = "v7")]
+    #[cfg(any(feature = "v7", feature = "rng-custom"))]
     pub use v7_support::*;
 
     /// An empty counter that will always return the value `0`.
@@ -975,4 +1796,37 @@ mod tests {
 
         assert_eq!((123, u16::MAX >> 2), ts.to_gregorian());
     }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn filetime_round_trip() {
+        // The Unix epoch as a `FILETIME`
+        let ts = Timestamp::from_filetime(context::NoContext, 116_444_736_000_000_000);
+
+        assert_eq!((0, 0), ts.to_unix());
+        assert_eq!(UUID_TICKS_BETWEEN_EPOCHS, ts.to_gregorian().0);
+
+        let ts = Timestamp::from_filetime(context::NoContext, 0);
+
+        assert_eq!(FILETIME_TICKS_BETWEEN_EPOCHS, ts.to_gregorian().0);
+        assert_eq!(0, ts.to_filetime());
+
+        let ts = Timestamp::from_gregorian(0x1EC_9414_C232_AB00 + 1, 0);
+
+        assert_eq!(
+            ts.to_gregorian().0 - FILETIME_TICKS_BETWEEN_EPOCHS,
+            ts.to_filetime()
+        );
+        assert_eq!(
+            ts.to_gregorian(),
+            Timestamp::from_filetime(context::NoContext, ts.to_filetime()).to_gregorian()
+        );
+    }
 }
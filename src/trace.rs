@@ -0,0 +1,253 @@
+//! Interop with [W3C Trace Context].
+//!
+//! The `traceparent` header carries a 16 byte trace-id and an 8 byte span-id
+//! (called the parent-id) as lowercase hex without hyphens. Neither may be
+//! all zeros. A UUID fits in a trace-id exactly, so request and correlation
+//! IDs that are already UUIDs can be carried as trace-ids directly.
+//!
+//! [W3C Trace Context]: https://www.w3.org/TR/trace-context/
+
+use crate::{
+    error::{Error, ErrorKind, InvalidUuid},
+    fmt::Simple,
+    parser,
+    std::fmt,
+    Uuid,
+};
+
+impl Uuid {
+    /// Get the trace-id for this UUID, formatted as 32 lowercase hex
+    /// characters without hyphens.
+    ///
+    /// The all-zero trace-id is invalid, so this method returns `None`
+    /// for the nil UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("4BF92F35-77B3-4DA6-A3CE-929D0E0E4736")?;
+    ///
+    /// assert_eq!(
+    ///     "4bf92f3577b34da6a3ce929d0e0e4736",
+    ///     uuid.to_trace_id_hex().unwrap().to_string(),
+    /// );
+    ///
+    /// assert!(Uuid::nil().to_trace_id_hex().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn to_trace_id_hex(&self) -> Option<Simple> {
+        if self.is_nil() {
+            None
+        } else {
+            Some(Simple::from_uuid(*self))
+        }
+    }
+
+    /// Parse a trace-id, formatted as 32 lowercase hex characters
+    /// without hyphens, into a `Uuid`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input isn't exactly 32
+    /// lowercase hex characters, or if it's the all-zero trace-id.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::from_trace_id_hex("4bf92f3577b34da6a3ce929d0e0e4736")?;
+    ///
+    /// assert_eq!(Uuid::parse_str("4bf92f35-77b3-4da6-a3ce-929d0e0e4736")?, uuid);
+    ///
+    /// assert!(Uuid::from_trace_id_hex("4BF92F3577B34DA6A3CE929D0E0E4736").is_err());
+    /// assert!(Uuid::from_trace_id_hex("00000000000000000000000000000000").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_trace_id_hex(input: &str) -> Result<Uuid, Error> {
+        let result = parse_trace_id(input.as_bytes());
+
+        #[cfg(feature = "std")]
+        let result = result.map_err(|err| err.with_input(input));
+
+        result
+    }
+
+    /// Derive a span-id from this UUID.
+    ///
+    /// The span-id is the big-endian bytes of [`Uuid::xor_fold64`], so it
+    /// includes all the random bits of a version 4 or version 7 UUID. This
+    /// is useful for deriving a span-id from an ID that's already unique to
+    /// the span, like a request ID.
+    ///
+    /// The all-zero span-id is invalid, so this method returns `None` when
+    /// the two halves of the UUID are equal, such as for the nil UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("4bf92f35-77b3-4da6-a3ce-929d0e0e4736")?;
+    ///
+    /// assert_eq!("e837bda879bd0a90", uuid.to_span_id().unwrap().to_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn to_span_id(&self) -> Option<SpanId> {
+        SpanId::from_bytes(self.xor_fold64().to_be_bytes())
+    }
+}
+
+fn parse_trace_id(input: &[u8]) -> Result<Uuid, Error> {
+    if input.len() != 32 {
+        return Err(Error::new(ErrorKind::SimpleLength { len: input.len() }));
+    }
+
+    // Trace-ids are always lowercase
+    if let Some(index) = input.iter().position(u8::is_ascii_uppercase) {
+        return Err(Error::new(ErrorKind::Char {
+            character: input[index] as char,
+            index: index + 1,
+        }));
+    }
+
+    let uuid = Uuid::from_bytes(parser::parse_simple(input).map_err(InvalidUuid::into_err)?);
+
+    if uuid.is_nil() {
+        return Err(Error::new(ErrorKind::Nil));
+    }
+
+    Ok(uuid)
+}
+
+/// An 8 byte span-id derived from a [`Uuid`].
+///
+/// A span-id is never all zeros. It's formatted as 16 lowercase hex
+/// characters, as in the `traceparent` header.
+///
+/// This type is returned by [`Uuid::to_span_id`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SpanId([u8; 8]);
+
+impl SpanId {
+    /// Create a span-id from its bytes.
+    ///
+    /// This method returns `None` if the bytes are all zeros.
+    pub const fn from_bytes(bytes: [u8; 8]) -> Option<Self> {
+        if u64::from_be_bytes(bytes) == 0 {
+            None
+        } else {
+            Some(SpanId(bytes))
+        }
+    }
+
+    /// Get the bytes of the span-id.
+    pub const fn as_bytes(&self) -> &[u8; 8] {
+        &self.0
+    }
+
+    /// Get the span-id as a big-endian 64bit value.
+    pub const fn as_u64(&self) -> u64 {
+        u64::from_be_bytes(self.0)
+    }
+}
+
+impl fmt::Display for SpanId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.as_u64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_trace_id_roundtrip() {
+        let uuid = crate::tests::new();
+
+        let mut buf = [0; 32];
+        let trace_id = uuid.to_trace_id_hex().unwrap().encode_lower(&mut buf);
+
+        assert_eq!(Ok(uuid), Uuid::from_trace_id_hex(trace_id));
+        assert_eq!(None, Uuid::nil().to_trace_id_hex());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_from_trace_id_hex_invalid() {
+        assert_eq!(
+            Err(Error::new(ErrorKind::Nil)),
+            Uuid::from_trace_id_hex("00000000000000000000000000000000")
+        );
+        assert_eq!(
+            Err(Error::new(ErrorKind::Char {
+                character: 'F',
+                index: 3,
+            })),
+            Uuid::from_trace_id_hex("4bF92f3577b34da6a3ce929d0e0e4736")
+        );
+        assert_eq!(
+            Err(Error::new(ErrorKind::SimpleLength { len: 36 })),
+            Uuid::from_trace_id_hex("4bf92f35-77b3-4da6-a3ce-929d0e0e4736")
+        );
+        assert!(Uuid::from_trace_id_hex("4bf92f3577b34da6a3ce929d0e0e473g").is_err());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_span_id() {
+        use crate::std::string::ToString;
+
+        let uuid = crate::tests::new();
+        let span_id = uuid.to_span_id().unwrap();
+
+        assert_eq!(uuid.xor_fold64(), span_id.as_u64());
+        assert_eq!(Some(span_id), SpanId::from_bytes(*span_id.as_bytes()));
+
+        assert_eq!(None, Uuid::nil().to_span_id());
+        assert_eq!(None, Uuid::from_u64_pair(42, 42).to_span_id());
+        assert_eq!(None, SpanId::from_bytes([0; 8]));
+
+        assert_eq!(
+            "0000000000000001",
+            SpanId::from_bytes([0, 0, 0, 0, 0, 0, 0, 1])
+                .unwrap()
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,294 @@
+//! Converting between UUIDs and ULIDs.
+//!
+//! A [ULID](https://github.com/ulid/spec) is a 128-bit identifier made of a
+//! 48-bit Unix timestamp in milliseconds followed by 80 random bits, written
+//! as 26 characters of Crockford's base 32. Since a ULID is the same size as
+//! a UUID, ULIDs are often stored in UUID columns. [`Ulid`] converts to and
+//! from [`Uuid`] without changing any bits, so the two forms can be used
+//! interchangeably.
+//!
+//! Note that you need to enable the `ulid` Cargo feature
+//! in order to use this module.
+//!
+//! # Examples
+//!
+//! ```
+//! # use uuid::{ulid::Ulid, Uuid};
+//! # fn main() -> Result<(), uuid::Error> {
+//! let ulid: Ulid = "01ARZ3NDEKTSV4RRFFQ69G5FAV".parse()?;
+//!
+//! // Store the ULID in a UUID column
+//! let uuid = Uuid::from(ulid);
+//! assert_eq!("01563e3a-b5d3-d676-4c61-efb99302bd5b", uuid.to_string());
+//!
+//! // And read it back out
+//! assert_eq!("01ARZ3NDEKTSV4RRFFQ69G5FAV", uuid.to_ulid().to_string());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    error::*,
+    fmt::Base32,
+    std::{fmt, str},
+    Builder, Uuid,
+};
+
+/// A ULID, made of a 48-bit timestamp and 80 bits of randomness.
+///
+/// See [the module documentation](index.html) for more details.
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Ulid(u128);
+
+impl Ulid {
+    /// The length of a ULID string.
+    pub const LENGTH: usize = 26;
+
+    /// Creates a ULID from a Unix timestamp in milliseconds and random bits.
+    ///
+    /// Only the lower 48 bits of `timestamp_ms` and the lower 80 bits of
+    /// `random` are used.
+    pub const fn from_parts(timestamp_ms: u64, random: u128) -> Self {
+        Ulid((((timestamp_ms & 0xffff_ffff_ffff) as u128) << 80) | (random & RANDOM_MASK))
+    }
+
+    /// Creates a ULID from a 128-bit value.
+    pub const fn from_u128(v: u128) -> Self {
+        Ulid(v)
+    }
+
+    /// Creates a ULID from 16 big-endian bytes.
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Ulid(u128::from_be_bytes(bytes))
+    }
+
+    /// Creates a ULID with the same bits as a [`Uuid`].
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Ulid(uuid.as_u128())
+    }
+
+    /// Parses a ULID from its 26 character string.
+    ///
+    /// Case is ignored, and `I`, `L`, and `O` are read as `1`, `1`, and `0`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input isn't 26 characters
+    /// long, if it contains a character that isn't a base 32 symbol, or if its
+    /// first character is larger than `7`, so its value doesn't fit in 128 bits.
+    pub fn parse_str(input: &str) -> Result<Ulid, Error> {
+        if input.len() != Self::LENGTH {
+            return Err(Error::new(ErrorKind::UlidLength { len: input.len() }));
+        }
+
+        Uuid::parse_base32(input).map(Ulid::from_uuid)
+    }
+
+    /// The 48-bit Unix timestamp of the ULID, in milliseconds.
+    pub const fn timestamp_ms(&self) -> u64 {
+        (self.0 >> 80) as u64
+    }
+
+    /// The 80 random bits of the ULID.
+    pub const fn random(&self) -> u128 {
+        self.0 & RANDOM_MASK
+    }
+
+    /// The ULID as a 128-bit value.
+    pub const fn as_u128(&self) -> u128 {
+        self.0
+    }
+
+    /// The ULID as 16 big-endian bytes.
+    pub const fn to_bytes(&self) -> [u8; 16] {
+        self.0.to_be_bytes()
+    }
+
+    /// Converts the ULID into a [`Uuid`] with the same bits.
+    ///
+    /// The result usually isn't a valid version 7 UUID, since ULIDs don't
+    /// reserve any bits for the version and variant. Use
+    /// [`Uuid::new_v7_from_ulid`] to make one.
+    pub const fn to_uuid(&self) -> Uuid {
+        Uuid::from_u128(self.0)
+    }
+
+    /// Writes the ULID string to `buffer`, and returns the subslice of the
+    /// buffer that contains it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`](#associatedconstant.LENGTH).
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        Base32::from_uuid(self.to_uuid()).encode_upper(buffer)
+    }
+}
+
+const RANDOM_MASK: u128 = (1 << 80) - 1;
+
+impl Uuid {
+    /// Creates a UUID with the same bits as a [`Ulid`].
+    pub const fn from_ulid(ulid: Ulid) -> Self {
+        ulid.to_uuid()
+    }
+
+    /// Converts the UUID into a [`Ulid`] with the same bits.
+    pub const fn to_ulid(&self) -> Ulid {
+        Ulid::from_uuid(*self)
+    }
+
+    /// Creates a version 7 UUID from the timestamp and randomness of a [`Ulid`].
+    ///
+    /// The UUID has the same timestamp as the ULID, so it sorts the same way
+    /// against other version 7 UUIDs. The random bits of the ULID fill the
+    /// rest of the UUID, except for the 6 bits that are replaced by the
+    /// version and variant, so the ULID can't be recovered from the UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{ulid::Ulid, Uuid, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let ulid = Ulid::parse_str("01ARZ3NDEKTSV4RRFFQ69G5FAV")?;
+    ///
+    /// let uuid = Uuid::new_v7_from_ulid(ulid);
+    ///
+    /// assert_eq!(Some(Version::SortRand), uuid.get_version());
+    /// assert_eq!(
+    ///     Some(ulid.timestamp_ms()),
+    ///     uuid.get_timestamp().map(|ts| {
+    ///         let (secs, nanos) = ts.to_unix();
+    ///         secs * 1_000 + nanos as u64 / 1_000_000
+    ///     }),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn new_v7_from_ulid(ulid: Ulid) -> Self {
+        let bytes = ulid.to_bytes();
+
+        let random = [
+            bytes[6], bytes[7], bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13],
+            bytes[14], bytes[15],
+        ];
+
+        Builder::from_unix_timestamp_millis(ulid.timestamp_ms(), &random).into_uuid()
+    }
+}
+
+impl fmt::Display for Ulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.encode(&mut [0; Self::LENGTH]))
+    }
+}
+
+impl fmt::Debug for Ulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl str::FromStr for Ulid {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ulid::parse_str(input)
+    }
+}
+
+impl From<Uuid> for Ulid {
+    #[inline]
+    fn from(uuid: Uuid) -> Self {
+        Ulid::from_uuid(uuid)
+    }
+}
+
+impl From<Ulid> for Uuid {
+    #[inline]
+    fn from(ulid: Ulid) -> Self {
+        ulid.to_uuid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{std::string::ToString, Variant, Version};
+
+    #[test]
+    fn test_parts() {
+        let ulid = Ulid::parse_str("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+
+        assert_eq!(1_469_922_850_259, ulid.timestamp_ms());
+        assert_eq!(ulid, Ulid::from_parts(ulid.timestamp_ms(), ulid.random()));
+        assert_eq!(ulid, Ulid::from_bytes(ulid.to_bytes()));
+        assert_eq!(ulid, Ulid::from_u128(ulid.as_u128()));
+
+        assert_eq!(
+            Ulid::from_parts(0xffff_ffff_ffff, RANDOM_MASK),
+            Ulid::from_parts(u64::MAX, u128::MAX)
+        );
+    }
+
+    #[test]
+    fn test_uuid_roundtrip() {
+        for uuid in [
+            Uuid::nil(),
+            Uuid::max(),
+            Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8),
+        ] {
+            let ulid = uuid.to_ulid();
+
+            assert_eq!(uuid, Uuid::from(ulid));
+            assert_eq!(ulid, ulid.to_string().parse().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_string() {
+        let ulid = Ulid::parse_str("01arz3ndektsv4rrffq69g5fav").unwrap();
+
+        assert_eq!("01ARZ3NDEKTSV4RRFFQ69G5FAV", ulid.to_string());
+        assert_eq!("01ARZ3NDEKTSV4RRFFQ69G5FAV", format!("{:?}", ulid));
+        assert_eq!("0".repeat(26), Ulid::default().to_string());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            Error::new(ErrorKind::UlidLength { len: 25 }),
+            Ulid::parse_str(&"0".repeat(25)).unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::RadixOverflow),
+            Ulid::parse_str("8ZZZZZZZZZZZZZZZZZZZZZZZZZ").unwrap_err()
+        );
+        assert_eq!(
+            Error::new(ErrorKind::RadixChar {
+                character: 'U',
+                index: 26
+            }),
+            Ulid::parse_str("0000000000000000000000000U").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_new_v7_from_ulid() {
+        let ulid = Ulid::parse_str("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+        let uuid = Uuid::new_v7_from_ulid(ulid);
+
+        assert_eq!(Some(Version::SortRand), uuid.get_version());
+        assert_eq!(Variant::RFC4122, uuid.get_variant());
+        assert_eq!(ulid.timestamp_ms(), uuid.to_ulid().timestamp_ms());
+
+        // Only the version and variant bits differ
+        let version_variant = 0x0000_0000_0000_f000_c000_0000_0000_0000;
+        assert_eq!(
+            ulid.as_u128() & !version_variant,
+            uuid.as_u128() & !version_variant
+        );
+    }
+}
@@ -5,6 +5,11 @@
 
 use crate::{Builder, Uuid};
 
+#[cfg(feature = "std")]
+use crate::timestamp::{self, UUID_TICKS_BETWEEN_EPOCHS};
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
 #[deprecated(note = "use types from the crate root instead")]
 pub use crate::{timestamp::context::Context, Timestamp};
 
@@ -18,7 +23,7 @@ impl Uuid {
     ///
     /// Note that usage of this method requires the `v1`, `std`, and `rng` features of this crate
     /// to be enabled.
-    #[cfg(all(feature = "std", feature = "rng"))]
+    #[cfg(all(feature = "std", any(feature = "rng", feature = "rng-custom")))]
     pub fn now_v1(node_id: &[u8; 6]) -> Self {
         let ts = Timestamp::now(crate::timestamp::context::shared_context());
 
@@ -91,10 +96,99 @@ impl Uuid {
     pub fn new_v1(ts: Timestamp, node_id: &[u8; 6]) -> Self {
         let (ticks, counter) = ts.to_gregorian();
 
-        Builder::from_gregorian_timestamp(ticks, counter, node_id).into_uuid()
+        let uuid = Builder::from_gregorian_timestamp(ticks, counter, node_id).into_uuid();
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::Mac);
+
+        uuid
+    }
+
+    /// Create the smallest version 1 UUID for the millisecond containing `time`.
+    ///
+    /// This is equivalent to Cassandra's `minTimeuuid` function. Combined with
+    /// [`Uuid::max_timeuuid_for`], it can be used as the bounds of a range query
+    /// over a `timeuuid` column. The clock sequence and node ID are filled with
+    /// `0x80`, which Cassandra treats as the smallest value, so the returned UUID
+    /// should only be used for querying, not stored as an identifier.
+    ///
+    /// Note that usage of this method requires the `v1` and `std` features of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// // 2013-01-01 00:05:00 UTC
+    /// let time = UNIX_EPOCH + Duration::from_millis(1_356_998_700_000);
+    ///
+    /// assert_eq!(
+    ///     "e23f1e00-53a6-11e2-8080-808080808080",
+    ///     Uuid::min_timeuuid_for(time).hyphenated().to_string(),
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn min_timeuuid_for(time: SystemTime) -> Self {
+        let ticks = gregorian_ticks(unix_millis(time) * 10_000);
+
+        timeuuid(ticks, [0x80; 8])
+    }
+
+    /// Create the largest version 1 UUID for the millisecond containing `time`.
+    ///
+    /// This is equivalent to Cassandra's `maxTimeuuid` function. The timestamp
+    /// is the last 100ns tick within the millisecond, and the clock sequence and
+    /// node ID are filled with `0x7f`, which Cassandra treats as the largest value.
+    /// Like [`Uuid::min_timeuuid_for`], the returned UUID should only be used for
+    /// querying, not stored as an identifier.
+    ///
+    /// Note that usage of this method requires the `v1` and `std` features of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// // 2013-01-01 00:05:00 UTC
+    /// let time = UNIX_EPOCH + Duration::from_millis(1_356_998_700_000);
+    ///
+    /// assert_eq!(
+    ///     "e23f450f-53a6-11e2-7f7f-7f7f7f7f7f7f",
+    ///     Uuid::max_timeuuid_for(time).hyphenated().to_string(),
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn max_timeuuid_for(time: SystemTime) -> Self {
+        let ticks = gregorian_ticks((unix_millis(time) + 1) * 10_000 - 1);
+
+        timeuuid(ticks, [0x7f; 8])
     }
 }
 
+// Cassandra works with signed milliseconds, so times before the Unix epoch
+// round down to the start of their millisecond too.
+#[cfg(feature = "std")]
+fn unix_millis(time: SystemTime) -> i128 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(dur) => dur.as_millis() as i128,
+        Err(err) => -(((err.duration().as_nanos() + 999_999) / 1_000_000) as i128),
+    }
+}
+
+#[cfg(feature = "std")]
+fn gregorian_ticks(unix_ticks: i128) -> u64 {
+    (UUID_TICKS_BETWEEN_EPOCHS as i128 + unix_ticks).clamp(0, 0x0FFF_FFFF_FFFF_FFFF) as u64
+}
+
+#[cfg(feature = "std")]
+fn timeuuid(ticks: u64, clock_seq_and_node: [u8; 8]) -> Uuid {
+    let (d1, d2, d3, _) = timestamp::encode_gregorian_timestamp(ticks, 0, &[0; 6]).as_fields();
+
+    Uuid::from_fields(d1, d2, d3, &clock_seq_and_node)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,7 +251,7 @@ mod tests {
         ),
         wasm_bindgen_test
     )]
-    #[cfg(all(feature = "std", feature = "rng"))]
+    #[cfg(all(feature = "std", any(feature = "rng", feature = "rng-custom")))]
     fn test_now() {
         let node = [1, 2, 3, 4, 5, 6];
 
@@ -166,4 +260,70 @@ mod tests {
         assert_eq!(uuid.get_version(), Some(Version::Mac));
         assert_eq!(uuid.get_variant(), Variant::RFC4122);
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_timeuuid_bounds() {
+        use std::time::Duration;
+
+        let time = UNIX_EPOCH + Duration::from_nanos(1_356_998_700_123_456_789);
+
+        let min = Uuid::min_timeuuid_for(time);
+        let max = Uuid::max_timeuuid_for(time);
+
+        assert_eq!(Some(Version::Mac), min.get_version());
+        assert_eq!(Some(Version::Mac), max.get_version());
+
+        let (min_ticks, _) = min.get_timestamp().unwrap().to_gregorian();
+        let (max_ticks, _) = max.get_timestamp().unwrap().to_gregorian();
+
+        assert_eq!(
+            UUID_TICKS_BETWEEN_EPOCHS + 13_569_987_001_230_000,
+            min_ticks
+        );
+        assert_eq!(min_ticks + 9_999, max_ticks);
+
+        assert_eq!(&[0x80; 8], &min.as_bytes()[8..]);
+        assert_eq!(&[0x7f; 8], &max.as_bytes()[8..]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_timeuuid_bounds_before_epoch() {
+        use std::time::Duration;
+
+        let time = UNIX_EPOCH - Duration::from_micros(1_500);
+
+        let (min_ticks, _) = Uuid::min_timeuuid_for(time)
+            .get_timestamp()
+            .unwrap()
+            .to_gregorian();
+
+        assert_eq!(UUID_TICKS_BETWEEN_EPOCHS - 20_000, min_ticks);
+
+        // Times before the Gregorian epoch are clamped
+        let (min_ticks, _) =
+            Uuid::min_timeuuid_for(UNIX_EPOCH - Duration::from_secs(u32::MAX as u64 * 8))
+                .get_timestamp()
+                .unwrap()
+                .to_gregorian();
+
+        assert_eq!(0, min_ticks);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::Uuid;
+use crate::{Builder, Error, Uuid, Variant};
 
 impl Uuid {
     /// Creates a random UUID.
@@ -33,16 +33,132 @@ impl Uuid {
     pub fn new_v4() -> Uuid {
         // This is an optimized method for generating random UUIDs that just masks
         // out the bits for the version and variant and sets them both together
-        Uuid::from_u128(
+        let uuid = Uuid::from_u128(
             crate::rng::u128() & 0xFFFFFFFFFFFF4FFFBFFFFFFFFFFFFFFF | 0x40008000000000000000,
-        )
+        );
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::Random);
+
+        uuid
+    }
+
+    /// Creates a random UUID, returning an error if the operating system's
+    /// RNG can't produce random bytes.
+    ///
+    /// [`Uuid::new_v4`] panics when random bytes aren't available, which can
+    /// happen early in boot or in sandboxed environments before the OS RNG is
+    /// ready. This method returns the error instead, which carries the
+    /// underlying OS error code from [`Error::raw_os_error`] when there is one.
+    ///
+    /// Unlike [`Uuid::new_v4`], this method always uses the OS RNG directly,
+    /// even when the `fast-rng` feature is enabled.
+    ///
+    /// Note that usage of this method requires the `v4` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// match Uuid::try_new_v4() {
+    ///     Ok(uuid) => assert_eq!(Some(Version::Random), uuid.get_version()),
+    ///     Err(err) => eprintln!("no randomness available: {}", err),
+    /// }
+    /// ```
+    ///
+    /// [`Error::raw_os_error`]: struct.Error.html#method.raw_os_error
+    pub fn try_new_v4() -> Result<Uuid, Error> {
+        let uuid = Uuid::from_u128(
+            crate::rng::try_u128()? & 0xFFFFFFFFFFFF4FFFBFFFFFFFFFFFFFFF | 0x40008000000000000000,
+        );
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::Random);
+
+        Ok(uuid)
+    }
+
+    /// Creates a random UUID that uses the [`Variant::Microsoft`] variant.
+    ///
+    /// This is like [`Uuid::new_v4`], but sets the variant bits reserved for
+    /// Microsoft's backwards compatibility instead of the RFC 9562 variant. It
+    /// should only be used to produce GUIDs for legacy COM components that
+    /// check for this variant. Since the Microsoft variant uses 3 bits instead
+    /// of 2, these UUIDs have 121 random bits instead of 122.
+    ///
+    /// Note that usage of this method requires the `v4` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Variant, Version};
+    /// let uuid = Uuid::new_v4_microsoft();
+    ///
+    /// assert_eq!(Some(Version::Random), uuid.get_version());
+    /// assert_eq!(Variant::Microsoft, uuid.get_variant());
+    /// ```
+    ///
+    /// [`Variant::Microsoft`]: enum.Variant.html#variant.Microsoft
+    pub fn new_v4_microsoft() -> Uuid {
+        let uuid = Builder::from_random_bytes(crate::rng::u128().to_be_bytes())
+            .with_variant(Variant::Microsoft)
+            .into_uuid();
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::Random);
+
+        uuid
+    }
+
+    /// Fills a slice with random UUIDs.
+    ///
+    /// This is like calling [`Uuid::new_v4`] for each element of `uuids`, but
+    /// random bytes are read from the operating system's RNG for many UUIDs
+    /// at once, which is much faster when generating a lot of UUIDs.
+    ///
+    /// Note that usage of this method requires the `v4` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let mut uuids = [Uuid::nil(); 100];
+    ///
+    /// Uuid::new_v4_batch(&mut uuids);
+    ///
+    /// assert!(uuids.iter().all(|uuid| uuid.get_version() == Some(Version::Random)));
+    /// ```
+    pub fn new_v4_batch(uuids: &mut [Uuid]) {
+        const BATCH_LEN: usize = 64;
+
+        let mut bytes = [0; BATCH_LEN * 16];
+
+        for batch in uuids.chunks_mut(BATCH_LEN) {
+            let bytes = &mut bytes[..batch.len() * 16];
+            crate::rng::fill_bytes(bytes);
+
+            for (uuid, random) in batch.iter_mut().zip(bytes.chunks_exact(16)) {
+                let mut random_bytes = [0; 16];
+                random_bytes.copy_from_slice(random);
+
+                *uuid = Builder::from_random_bytes(random_bytes).into_uuid();
+
+                #[cfg(feature = "observe")]
+                {
+                    *uuid = crate::observe::generated(*uuid, crate::Version::Random);
+                }
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Variant, Version};
+    use crate::Version;
 
     #[cfg(all(
         target_arch = "wasm32",
@@ -67,6 +183,71 @@ mod tests {
         assert_eq!(uuid.get_variant(), Variant::RFC4122);
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_try_new() {
+        let uuid = Uuid::try_new_v4().unwrap();
+
+        assert_eq!(uuid.get_version(), Some(Version::Random));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_try_new_error() {
+        use crate::{error::ErrorKind, std::string::ToString};
+
+        let err = Error::new(ErrorKind::Rng {
+            raw_os_error: Some(2),
+        });
+
+        assert_eq!(Some(2), err.raw_os_error());
+        assert_eq!("failed to get random bytes (os error 2)", err.to_string());
+
+        let err = Error::new(ErrorKind::Rng { raw_os_error: None });
+
+        assert_eq!(None, err.raw_os_error());
+        assert_eq!("failed to get random bytes", err.to_string());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_batch() {
+        // Not a multiple of the internal batch size
+        let mut uuids = [Uuid::nil(); 150];
+
+        Uuid::new_v4_batch(&mut uuids);
+
+        for (i, uuid) in uuids.iter().enumerate() {
+            assert_eq!(uuid.get_version(), Some(Version::Random));
+            assert_eq!(uuid.get_variant(), Variant::RFC4122);
+            assert!(uuids[..i].iter().all(|other| other != uuid));
+        }
+
+        Uuid::new_v4_batch(&mut []);
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -82,4 +263,20 @@ mod tests {
         assert_eq!(uuid.get_version(), Some(Version::Random));
         assert_eq!(uuid.get_version_num(), 4)
     }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_microsoft() {
+        let uuid = Uuid::new_v4_microsoft();
+
+        assert_eq!(uuid.get_version(), Some(Version::Random));
+        assert_eq!(uuid.get_variant(), Variant::Microsoft);
+    }
 }
@@ -10,6 +10,9 @@ impl Uuid {
     /// * [`NAMESPACE_URL`]
     /// * [`NAMESPACE_X500`]
     ///
+    /// These are also available as [`Namespace`](crate::Namespace) values, which can't be
+    /// accidentally swapped with the name. See [`Namespace::uuid_v5`](crate::Namespace::uuid_v5).
+    ///
     /// Note that usage of this method requires the `v5` feature of this crate
     /// to be enabled.
     ///
@@ -34,7 +37,183 @@ impl Uuid {
     /// [`NAMESPACE_URL`]: struct.Uuid.html#associatedconst.NAMESPACE_URL
     /// [`NAMESPACE_X500`]: struct.Uuid.html#associatedconst.NAMESPACE_X500
     pub fn new_v5(namespace: &Uuid, name: &[u8]) -> Uuid {
-        crate::Builder::from_sha1_bytes(crate::sha1::hash(namespace.as_bytes(), name)).into_uuid()
+        let uuid = crate::Builder::from_sha1_bytes(crate::sha1::hash(namespace.as_bytes(), name))
+            .into_uuid();
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::Sha1);
+
+        uuid
+    }
+
+    /// Creates a UUID using a name made up of multiple parts from a namespace,
+    /// based on the SHA-1 hash.
+    ///
+    /// Each part is prefixed with its length as a big-endian `u64` before hashing,
+    /// so different ways of splitting the same bytes produce different UUIDs.
+    /// Concatenating the parts and passing them to [`Uuid::new_v5`] can't tell
+    /// `["ab", "c"]` apart from `["a", "bc"]`.
+    ///
+    /// Because of the length prefixes, the result is not the same as calling
+    /// [`Uuid::new_v5`] with any single name.
+    ///
+    /// Note that usage of this method requires the `v5` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let uuid = Uuid::new_v5_parts(&Uuid::NAMESPACE_URL, &[b"ab", b"c"]);
+    ///
+    /// assert_eq!(Some(Version::Sha1), uuid.get_version());
+    /// assert_ne!(uuid, Uuid::new_v5_parts(&Uuid::NAMESPACE_URL, &[b"a", b"bc"]));
+    /// ```
+    pub fn new_v5_parts(namespace: &Uuid, parts: &[&[u8]]) -> Uuid {
+        let uuid =
+            crate::Builder::from_sha1_bytes(crate::sha1::hash_parts(namespace.as_bytes(), parts))
+                .into_uuid();
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::Sha1);
+
+        uuid
+    }
+
+    /// Creates a UUID using a name from a namespace, based on the SHA-1 hash,
+    /// where the name is supplied in chunks.
+    ///
+    /// The chunks are hashed one after another, so the result is the same as
+    /// calling [`Uuid::new_v5`] with all of the chunks concatenated, but without
+    /// needing the whole name in memory at once. This is useful for deriving
+    /// identifiers from large content, like file contents or canonicalized
+    /// documents, produced piece by piece.
+    ///
+    /// Note that usage of this method requires the `v5` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let chunks = ["rust", "-lang", ".org"];
+    ///
+    /// assert_eq!(
+    ///     Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"rust-lang.org"),
+    ///     Uuid::new_v5_from_chunks(&Uuid::NAMESPACE_DNS, chunks),
+    /// );
+    /// ```
+    pub fn new_v5_from_chunks<I>(namespace: &Uuid, chunks: I) -> Uuid
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let uuid =
+            crate::Builder::from_sha1_bytes(crate::sha1::hash_chunks(namespace.as_bytes(), chunks))
+                .into_uuid();
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::Sha1);
+
+        uuid
+    }
+
+    /// Creates a UUID using a name from a namespace, based on the SHA-1 hash,
+    /// where the name is read from `reader` until it's exhausted.
+    ///
+    /// The result is the same as calling [`Uuid::new_v5`] with everything read
+    /// from `reader`, but the name is hashed as it's read instead of being
+    /// buffered in memory first.
+    ///
+    /// Note that usage of this method requires the `v5` and `std` features of
+    /// this crate to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// This method will return any error returned by `reader`, other than
+    /// [`ErrorKind::Interrupted`], which is retried.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> std::io::Result<()> {
+    /// let contents: &[u8] = b"rust-lang.org";
+    ///
+    /// assert_eq!(
+    ///     Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"rust-lang.org"),
+    ///     Uuid::new_v5_from_reader(&Uuid::NAMESPACE_DNS, contents)?,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ErrorKind::Interrupted`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.Interrupted
+    #[cfg(feature = "std")]
+    pub fn new_v5_from_reader<R: std::io::Read>(
+        namespace: &Uuid,
+        reader: R,
+    ) -> std::io::Result<Uuid> {
+        let uuid = crate::Builder::from_sha1_bytes(crate::sha1::hash_reader(
+            namespace.as_bytes(),
+            reader,
+        )?)
+        .into_uuid();
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::Sha1);
+
+        Ok(uuid)
+    }
+
+    /// Creates a UUID for a name, using this UUID as its namespace.
+    ///
+    /// This method is equivalent to [`Uuid::new_v5`] with `self` as the namespace.
+    /// It can be used to build hierarchies of deterministic identifiers, where each
+    /// level is derived from its parent.
+    ///
+    /// Note that usage of this method requires the `v5` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let tenant = Uuid::NAMESPACE_URL.child(b"tenant");
+    ///
+    /// assert_eq!(Uuid::new_v5(&tenant, b"project"), tenant.child(b"project"));
+    /// ```
+    pub fn child(&self, name: &[u8]) -> Uuid {
+        Uuid::new_v5(self, name)
+    }
+
+    /// Creates a UUID by chaining [`Uuid::child`] over each segment of a path.
+    ///
+    /// Each segment uses the UUID derived from the previous one as its namespace,
+    /// starting from `self`. An empty path returns `self`.
+    ///
+    /// Note that usage of this method requires the `v5` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let root = Uuid::NAMESPACE_URL;
+    /// let resource = root.derive_path(["tenant", "project", "resource"]);
+    ///
+    /// assert_eq!(
+    ///     root.child(b"tenant").child(b"project").child(b"resource"),
+    ///     resource
+    /// );
+    /// ```
+    pub fn derive_path<I>(&self, path: I) -> Uuid
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        path.into_iter()
+            .fold(*self, |parent, segment| parent.child(segment.as_ref()))
     }
 }
 
@@ -185,4 +364,124 @@ mod tests {
             assert_eq!(Ok(uuid), u.parse());
         }
     }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_child() {
+        let uuid = Uuid::NAMESPACE_DNS.child(b"rust-lang.org");
+
+        assert_eq!(Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"rust-lang.org"), uuid);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_derive_path() {
+        let root = Uuid::NAMESPACE_URL;
+
+        assert_eq!(root, root.derive_path(crate::std::iter::empty::<&[u8]>()));
+        assert_eq!(root.child(b"a"), root.derive_path(["a"]));
+        assert_eq!(
+            root.child(b"a").child(b"b").child(b"c"),
+            root.derive_path([b"a", b"b", b"c"])
+        );
+        assert_ne!(root.derive_path(["a", "b"]), root.derive_path(["b", "a"]));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_parts() {
+        let ns = &Uuid::NAMESPACE_DNS;
+
+        let uuid = Uuid::new_v5_parts(ns, &[b"ab", b"c"]);
+
+        assert_eq!(Some(Version::Sha1), uuid.get_version());
+        assert_eq!(Variant::RFC4122, uuid.get_variant());
+        assert_eq!(uuid, Uuid::new_v5_parts(ns, &[b"ab", b"c"]));
+
+        assert_ne!(uuid, Uuid::new_v5_parts(ns, &[b"a", b"bc"]));
+        assert_ne!(uuid, Uuid::new_v5_parts(ns, &[b"abc"]));
+        assert_ne!(uuid, Uuid::new_v5(ns, b"abc"));
+        assert_ne!(Uuid::new_v5_parts(ns, &[]), Uuid::new_v5_parts(ns, &[b""]));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_from_chunks() {
+        for &(ns, name, _) in FIXTURE {
+            let (a, b) = name.as_bytes().split_at(name.len() / 2);
+
+            assert_eq!(
+                Uuid::new_v5(ns, name.as_bytes()),
+                Uuid::new_v5_from_chunks(ns, [a, b])
+            );
+        }
+
+        assert_eq!(
+            Uuid::new_v5(&Uuid::NAMESPACE_DNS, b""),
+            Uuid::new_v5_from_chunks(&Uuid::NAMESPACE_DNS, crate::std::iter::empty::<&[u8]>())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_new_from_reader() {
+        use std::io::Read;
+
+        for &(ns, name, _) in FIXTURE {
+            assert_eq!(
+                Uuid::new_v5(ns, name.as_bytes()),
+                Uuid::new_v5_from_reader(ns, name.as_bytes()).unwrap()
+            );
+        }
+
+        // Large names are read in multiple chunks
+        let name = [0xab; 20_000];
+        assert_eq!(
+            Uuid::new_v5(&Uuid::NAMESPACE_OID, &name),
+            Uuid::new_v5_from_reader(&Uuid::NAMESPACE_OID, &name[..]).unwrap()
+        );
+
+        struct Failing;
+
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::BrokenPipe.into())
+            }
+        }
+
+        assert_eq!(
+            std::io::ErrorKind::BrokenPipe,
+            Uuid::new_v5_from_reader(&Uuid::NAMESPACE_OID, Failing)
+                .unwrap_err()
+                .kind()
+        );
+    }
 }
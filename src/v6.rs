@@ -15,7 +15,7 @@ impl Uuid {
     ///
     /// Note that usage of this method requires the `v6`, `std`, and `rng` features of this crate
     /// to be enabled.
-    #[cfg(all(feature = "std", feature = "rng"))]
+    #[cfg(all(feature = "std", any(feature = "rng", feature = "rng-custom")))]
     pub fn now_v6(node_id: &[u8; 6]) -> Self {
         let ts = Timestamp::now(crate::timestamp::context::shared_context());
 
@@ -92,7 +92,12 @@ impl Uuid {
     pub fn new_v6(ts: Timestamp, node_id: &[u8; 6]) -> Self {
         let (ticks, counter) = ts.to_gregorian();
 
-        Builder::from_sorted_gregorian_timestamp(ticks, counter, node_id).into_uuid()
+        let uuid = Builder::from_sorted_gregorian_timestamp(ticks, counter, node_id).into_uuid();
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::SortMac);
+
+        uuid
     }
 }
 
@@ -159,7 +164,7 @@ mod tests {
         ),
         wasm_bindgen_test
     )]
-    #[cfg(all(feature = "std", feature = "rng"))]
+    #[cfg(all(feature = "std", any(feature = "rng", feature = "rng-custom")))]
     fn test_now() {
         let node = [1, 2, 3, 4, 5, 6];
 
@@ -1,9 +1,24 @@
 //! The implementation for Version 7 UUIDs.
 //!
+//! This module also contains a configurable [`Generator`] for services that need
+//! control over how version 7 UUIDs are ordered, and can reserve ordered blocks
+//! of UUIDs for bulk inserts.
+//!
 //! Note that you need to enable the `v7` Cargo feature
 //! in order to use this module.
 
-use crate::{rng, std::convert::TryInto, timestamp::Timestamp, Builder, Uuid};
+#[cfg(feature = "std")]
+use crate::ClockSequence;
+use crate::{
+    entropy::{self, EntropySource, SystemEntropy},
+    rng,
+    std::time::Duration,
+    timestamp::{self, Timestamp},
+    Uuid, Version,
+};
+
+#[cfg(feature = "embassy")]
+pub use crate::external::embassy_support::EmbassyClock;
 
 impl Uuid {
     /// Create a new version 7 UUID using the current time value.
@@ -18,6 +33,139 @@ impl Uuid {
         ))
     }
 
+    /// Create a new version 7 UUID using the current time value, returning
+    /// an error instead of panicking if it can't be generated.
+    ///
+    /// This method is like [`Uuid::now_v7`], but fails if the system clock
+    /// can't be read or is set before the Unix epoch, or if random bytes
+    /// can't be produced. It never panics.
+    ///
+    /// A clock set before the Unix epoch, like the unset RTC of an embedded
+    /// device that has just booted, is reported with its own error message so
+    /// it can be told apart from a clock that can't be read.
+    ///
+    /// Random bytes bypass the `fast-rng` feature and are read from the
+    /// operating system's RNG, unless a simulation is running or a source
+    /// has been installed with [`entropy::set_random_source`].
+    ///
+    /// UUIDs generated by this method are ordered with those generated by
+    /// [`Uuid::now_v7`].
+    ///
+    /// Note that usage of this method requires the `v7` and `std` features of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::try_now_v7()?;
+    ///
+    /// assert_eq!(Some(Version::SortRand), uuid.get_version());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`entropy::set_random_source`]: entropy/fn.set_random_source.html
+    #[cfg(feature = "std")]
+    pub fn try_now_v7() -> Result<Self, crate::Error> {
+        Self::try_now_v7_from(timestamp::try_now())
+    }
+
+    #[cfg(feature = "std")]
+    fn try_now_v7_from(now: Result<(u64, u32), crate::Error>) -> Result<Self, crate::Error> {
+        let (seconds, subsec_nanos) = now?;
+
+        let context = timestamp::context::shared_context_v7();
+
+        let (counter, seconds, subsec_nanos) =
+            context.try_generate_timestamp_sequence(seconds, subsec_nanos)?;
+
+        let millis = seconds
+            .saturating_mul(1_000)
+            .saturating_add(subsec_nanos as u64 / 1_000_000);
+
+        let uuid = timestamp::encode_unix_timestamp_leading_counter(
+            millis,
+            counter as u128,
+            context.usable_bits() as u32,
+            rng::try_u128()?,
+        );
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::SortRand);
+
+        Ok(uuid)
+    }
+
+    /// Fills a slice with version 7 UUIDs using the current time value.
+    ///
+    /// This is like calling [`Uuid::now_v7`] for each element of `uuids`, but
+    /// the system clock is only read once, and random bytes are read from the
+    /// operating system's RNG for many UUIDs at once, which is much faster when
+    /// generating a lot of UUIDs.
+    ///
+    /// The UUIDs are ordered by their position in the slice, and are ordered
+    /// with UUIDs generated through [`Uuid::now_v7`] before and after them.
+    ///
+    /// Note that usage of this method requires the `v7` and `std` features of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let mut uuids = [Uuid::nil(); 100];
+    ///
+    /// Uuid::now_v7_batch(&mut uuids);
+    ///
+    /// assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn now_v7_batch(uuids: &mut [Uuid]) {
+        const BATCH_LEN: usize = 64;
+
+        let (seconds, subsec_nanos) = timestamp::now();
+
+        let mut bytes = [0; BATCH_LEN * 16];
+
+        for batch in uuids.chunks_mut(BATCH_LEN) {
+            // Random bytes are read before taking the lock on the shared context, so a
+            // slow source of randomness doesn't block other threads generating UUIDs
+            let bytes = &mut bytes[..batch.len() * 16];
+            rng::fill_bytes(bytes);
+
+            timestamp::context::shared_context_v7().with(|context| {
+                for (uuid, random) in batch.iter_mut().zip(bytes.chunks_exact(16)) {
+                    let mut random_bytes = [0; 16];
+                    random_bytes.copy_from_slice(random);
+
+                    // The context moves the timestamp forward if the counter overflows
+                    let (counter, seconds, subsec_nanos) =
+                        context.generate_timestamp_sequence(seconds, subsec_nanos);
+
+                    let millis = seconds
+                        .saturating_mul(1_000)
+                        .saturating_add(subsec_nanos as u64 / 1_000_000);
+
+                    *uuid = timestamp::encode_unix_timestamp_leading_counter(
+                        millis,
+                        counter as u128,
+                        context.usable_bits() as u32,
+                        u128::from_ne_bytes(random_bytes),
+                    );
+                }
+            });
+
+            // The observer is only called once the lock is released, so it can
+            // generate UUIDs of its own
+            #[cfg(feature = "observe")]
+            for uuid in batch.iter_mut() {
+                *uuid = crate::observe::generated(*uuid, crate::Version::SortRand);
+            }
+        }
+    }
+
     /// Create a new version 7 UUID using a time value and random bytes.
     ///
     /// When the `std` feature is enabled, you can also use [`Uuid::now_v7`].
@@ -64,43 +212,604 @@ impl Uuid {
         let (secs, nanos) = ts.to_unix();
         let millis = (secs * 1000).saturating_add(nanos as u64 / 1_000_000);
 
-        let mut counter_and_random = rng::u128();
-
-        let (mut counter, counter_bits) = ts.counter();
+        let (counter, counter_bits) = ts.counter();
 
         debug_assert!(counter_bits <= 128);
 
-        let mut counter_bits = counter_bits as u32;
+        let uuid = timestamp::encode_unix_timestamp_leading_counter(
+            millis,
+            counter,
+            counter_bits as u32,
+            rng::u128(),
+        );
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::SortRand);
+
+        uuid
+    }
+
+    /// Truncate a version 7 UUID to the start of a time bucket.
+    ///
+    /// The timestamp is rounded down to a multiple of `bucket` since the Unix
+    /// epoch, and all of the counter and random bits are zeroed. Every UUID
+    /// generated within the same bucket truncates to the same value, which makes
+    /// it a stable partition key for time-partitioned storage that's derived
+    /// directly from the ID.
+    ///
+    /// The bucket has millisecond precision. Buckets shorter than a millisecond
+    /// are treated as a single millisecond.
+    ///
+    /// This method returns `None` if the UUID isn't version 7.
+    ///
+    /// # Examples
+    ///
+    /// Using hourly buckets:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # use std::time::Duration;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")?;
+    ///
+    /// let bucket = uuid.truncate_to(Duration::from_secs(60 * 60));
+    ///
+    /// assert_eq!(
+    ///     Some(Uuid::parse_str("017f22cd-ff80-7000-8000-000000000000")?),
+    ///     bucket,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn truncate_to(&self, bucket: Duration) -> Option<Uuid> {
+        if self.get_version() != Some(Version::SortRand) {
+            return None;
+        }
+
+        let bucket = bucket.as_millis().clamp(1, u64::MAX as u128) as u64;
+        let millis = timestamp::decode_unix_timestamp_millis(self);
+
+        Some(timestamp::encode_unix_timestamp_millis(
+            millis - millis % bucket,
+            &[0; 10],
+        ))
+    }
+}
+
+/// The maximum number of counter bits that fit in a version 7 UUID.
+///
+/// These are the 74 bits of `rand_a` and `rand_b`, without the version and variant.
+pub const MAX_COUNTER_BITS: u32 = 74;
+
+/// A source of the current time for a [`Generator`].
+///
+/// This trait is implemented for any `FnMut() -> u64` closure, which makes
+/// it easy to drive a generator from a fake clock in tests.
+pub trait Clock {
+    /// Get the number of milliseconds since the Unix epoch.
+    fn now_millis(&mut self) -> u64;
+}
+
+impl<F: FnMut() -> u64> Clock for F {
+    fn now_millis(&mut self) -> u64 {
+        self()
+    }
+}
+
+/// A [`Clock`] that reads the current system time.
+///
+/// Note that usage of this type requires the `std` feature of this crate
+/// to be enabled.
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now_millis(&mut self) -> u64 {
+        let (seconds, subsec_nanos) = timestamp::now();
+
+        (seconds * 1_000).saturating_add(subsec_nanos as u64 / 1_000_000)
+    }
+}
+
+/// What a [`Generator`] does when its clock goes backwards.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RollbackPolicy {
+    /// Keep using the last timestamp and increment the counter until the clock
+    /// catches up.
+    ///
+    /// This preserves the order of generated UUIDs regardless of how far the
+    /// clock moves. This is the default.
+    #[default]
+    Hold,
+    /// Use the earlier timestamp and reseed the counter.
+    ///
+    /// UUIDs generated after the clock goes backwards will sort before
+    /// ones generated earlier.
+    Follow,
+    /// Hold the last timestamp as in [`RollbackPolicy::Hold`] if the clock goes
+    /// backwards by up to this many milliseconds. For bigger jumps, follow the clock
+    /// as in [`RollbackPolicy::Follow`].
+    HoldUpTo(u64),
+}
+
+/// A stateful generator for version 7 UUIDs.
+///
+/// Where [`Uuid::now_v7`] uses a fixed strategy for keeping UUIDs ordered, a
+/// `Generator` can be configured with:
+///
+/// * The width of the counter that orders UUIDs generated in the same millisecond,
+///   with [`Generator::with_counter_bits`].
+/// * How often the counter is reseeded with random data, with
+///   [`Generator::with_reseed_interval`].
+/// * How far the counter moves between UUIDs, with [`Generator::with_random_increment`].
+/// * The [`Clock`] that timestamps are read from, with [`Generator::with_clock`].
+/// * What happens when that clock goes backwards, with [`Generator::with_rollback_policy`].
+/// * The [`EntropySource`] that random data is read from, with
+///   [`Generator::with_clock_and_entropy`].
+///
+/// Each UUID is generated from the counter, padded with random data. If the counter
+/// overflows within a single millisecond, the timestamp is incremented so
+/// UUIDs generated by the same `Generator` always sort in the order they were
+/// generated, unless the [`RollbackPolicy`] allows the clock to go backwards.
+///
+/// A `Generator` isn't synchronized. Wrap it in a `Mutex` to share it between threads.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::v7::{Generator, RollbackPolicy};
+/// # fn main() {
+/// # #[cfg(feature = "std")] {
+/// let mut generator = Generator::new()
+///     .with_counter_bits(12)
+///     .with_rollback_policy(RollbackPolicy::HoldUpTo(10_000));
+///
+/// let uuid1 = generator.generate();
+/// let uuid2 = generator.generate();
+///
+/// assert!(uuid1 < uuid2);
+/// # }
+/// # }
+/// ```
+///
+/// Using the monotonic random method from RFC 9562, where all 74 bits after
+/// the timestamp are a counter that's incremented by a random amount:
+///
+/// ```
+/// # use uuid::v7::{Generator, MAX_COUNTER_BITS};
+/// let mut generator = Generator::with_clock(|| 1_645_557_742_000)
+///     .with_counter_bits(MAX_COUNTER_BITS)
+///     .with_random_increment(1 << 32);
+///
+/// let uuid1 = generator.generate();
+/// let uuid2 = generator.generate();
+///
+/// assert!(uuid1 < uuid2);
+/// ```
+///
+/// Using a fake clock:
+///
+/// ```
+/// # use uuid::v7::Generator;
+/// let mut generator = Generator::with_clock(|| 1_645_557_742_000);
+///
+/// let uuid = generator.generate();
+///
+/// assert!(uuid.to_string().starts_with("017f22e2-79b0-7"));
+/// ```
+///
+/// Using a hardware RNG instead of the operating system's:
+///
+/// ```
+/// # use uuid::v7::Generator;
+/// # struct Hrng;
+/// # impl Hrng { fn fill(&mut self, dest: &mut [u8]) { dest.fill(0x7e) } }
+/// # let ticks = || 1_645_557_742_000;
+/// let mut hrng = Hrng;
+///
+/// let mut generator =
+///     Generator::with_clock_and_entropy(ticks, move |dest: &mut [u8]| hrng.fill(dest));
+///
+/// let uuid = generator.generate();
+/// ```
+///
+/// # References
+///
+/// * [Monotonicity and Counters in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-6.2)
+#[derive(Debug, Clone)]
+pub struct Generator<C, E = SystemEntropy> {
+    clock: C,
+    entropy: E,
+    counter_bits: u32,
+    reseed_interval: u64,
+    rollback_policy: RollbackPolicy,
+    last_clock: u64,
+    last_millis: u64,
+    last_reseed_millis: u64,
+    counter: u128,
+    max_increment: u64,
+}
+
+#[cfg(feature = "std")]
+impl Generator<SystemClock> {
+    /// Create a generator that uses the system clock.
+    ///
+    /// The generator uses a 42-bit counter that's reseeded every millisecond,
+    /// and holds its timestamp if the clock goes backwards. This is the same
+    /// strategy used by [`ContextV7`](crate::ContextV7).
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    pub fn new() -> Self {
+        Generator::with_clock(SystemClock)
+    }
+}
 
-        // If the counter intersects the variant field then shift around it.
-        // This ensures that any bits set in the counter that would intersect
-        // the variant are still preserved
-        if counter_bits > 12 {
-            let mask = u128::MAX << (counter_bits - 12);
+#[cfg(feature = "std")]
+impl Default for Generator<SystemClock> {
+    fn default() -> Self {
+        Generator::new()
+    }
+}
 
-            counter = (counter & !mask) | ((counter & mask) << 2);
+impl<C: Clock> Generator<C> {
+    /// Create a generator that uses the given clock.
+    ///
+    /// The generator is configured in the same way as [`Generator::new`].
+    pub fn with_clock(clock: C) -> Self {
+        Generator::with_clock_and_entropy(clock, SystemEntropy)
+    }
+}
 
-            counter_bits += 2;
+impl<C: Clock, E: EntropySource> Generator<C, E> {
+    /// Create a generator that uses the given clock and source of random data.
+    ///
+    /// The generator is configured in the same way as [`Generator::new`]. Random
+    /// data for reseeding the counter and padding UUIDs is read from `entropy`,
+    /// including the UUIDs of blocks reserved with [`Generator::reserve`].
+    pub fn with_clock_and_entropy(clock: C, entropy: E) -> Self {
+        Generator {
+            clock,
+            entropy,
+            counter_bits: 42,
+            reseed_interval: 1,
+            rollback_policy: RollbackPolicy::Hold,
+            last_clock: 0,
+            last_millis: 0,
+            last_reseed_millis: 0,
+            counter: 0,
+            max_increment: 1,
         }
+    }
+
+    /// Set the number of bits used by the counter.
+    ///
+    /// A wider counter can order more UUIDs within the same millisecond before
+    /// the timestamp has to be incremented, but leaves fewer bits for random data.
+    /// With a counter width of zero, UUIDs are entirely random after the
+    /// timestamp, and UUIDs generated in the same millisecond aren't ordered.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `counter_bits` is greater than [`MAX_COUNTER_BITS`].
+    pub fn with_counter_bits(mut self, counter_bits: u32) -> Self {
+        assert!(
+            counter_bits <= MAX_COUNTER_BITS,
+            "a version 7 UUID can't fit more than {} counter bits",
+            MAX_COUNTER_BITS
+        );
 
-        counter_and_random &= u128::MAX.overflowing_shr(counter_bits).0;
-        counter_and_random |= counter
-            .overflowing_shl(128u32.saturating_sub(counter_bits))
-            .0;
+        self.counter_bits = counter_bits;
+        self.counter = 0;
+        self
+    }
 
-        Builder::from_unix_timestamp_millis(
-            millis,
-            &counter_and_random.to_be_bytes()[..10].try_into().unwrap(),
+    /// Set the minimum number of milliseconds between reseeding the counter.
+    ///
+    /// When the timestamp moves forwards by at least this many milliseconds since
+    /// the counter was last reseeded, it's reseeded with a random value. Otherwise,
+    /// it keeps incrementing from its previous value. This means fewer random numbers
+    /// need to be generated, but also makes UUIDs from the same generator more predictable.
+    ///
+    /// The counter is also reseeded whenever it overflows. An interval of zero is
+    /// treated as one, which reseeds the counter every millisecond. This is the default.
+    pub fn with_reseed_interval(mut self, millis: u64) -> Self {
+        self.reseed_interval = millis.max(1);
+        self
+    }
+
+    /// Set the largest amount the counter is incremented by between UUIDs
+    /// generated in the same millisecond.
+    ///
+    /// By default, the counter is incremented by one, which is the fixed-length
+    /// dedicated counter method from RFC 9562. With a larger maximum, the counter
+    /// is incremented by a random amount between one and `max`, which is the
+    /// monotonic random method. Consecutive UUIDs are then harder to guess, but
+    /// the counter overflows sooner. Combine this with a counter width of
+    /// [`MAX_COUNTER_BITS`] to use all the bits after the timestamp as the counter.
+    ///
+    /// A maximum of zero is treated as one. UUIDs from a block reserved with
+    /// [`Generator::reserve`] are always incremented by one.
+    pub fn with_random_increment(mut self, max: u64) -> Self {
+        self.max_increment = max.max(1);
+        self
+    }
+
+    /// Set what to do when the clock goes backwards.
+    pub fn with_rollback_policy(mut self, rollback_policy: RollbackPolicy) -> Self {
+        self.rollback_policy = rollback_policy;
+        self
+    }
+
+    /// Generate a version 7 UUID.
+    pub fn generate(&mut self) -> Uuid {
+        self.advance();
+
+        let uuid = timestamp::encode_unix_timestamp_counter(
+            self.last_millis,
+            self.counter,
+            self.counter_bits,
+            entropy::u128(&mut self.entropy),
+        );
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::SortRand);
+
+        uuid
+    }
+
+    /// Reserve a contiguous block of `n` UUIDs.
+    ///
+    /// The returned [`BlockIter`] yields `n` UUIDs in ascending order. The block
+    /// is allocated up front by advancing the counter past it, so it can be split
+    /// with [`BlockIter::split_at`] and handed to parallel workers, and its UUIDs
+    /// will still sort after any generated before the call and before any generated
+    /// after it. If the block doesn't fit in the rest of the counter's space for
+    /// the current millisecond, it continues into the following milliseconds.
+    ///
+    /// With a counter width of zero, each UUID in the block uses its own millisecond,
+    /// but UUIDs generated in the same millisecond as the end of the block aren't
+    /// ordered after it.
+    ///
+    /// The block pads its UUIDs with random data from a clone of the generator's
+    /// [`EntropySource`], so the source should be a handle to a shared RNG rather
+    /// than an RNG's state. Cloning the state would repeat the same random data in
+    /// every block split from it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::v7::Generator;
+    /// let mut generator = Generator::with_clock(|| 1_645_557_742_000);
+    ///
+    /// let before = generator.generate();
+    /// let (first, second) = generator.reserve(1_000).split_at(500);
+    /// let after = generator.generate();
+    ///
+    /// let first: Vec<_> = first.collect();
+    /// let second: Vec<_> = second.collect();
+    ///
+    /// assert!(before < first[0]);
+    /// assert!(first[499] < second[0]);
+    /// assert!(second[499] < after);
+    /// ```
+    pub fn reserve(&mut self, n: usize) -> BlockIter<E>
+    where
+        E: Clone,
+    {
+        let counter_bits = self.counter_bits;
+
+        if n == 0 {
+            return BlockIter {
+                start: 0,
+                end: 0,
+                counter_bits,
+                entropy: self.entropy.clone(),
+            };
+        }
+
+        self.advance();
+
+        if counter_bits == 0 {
+            // Without a counter, only the timestamp can order UUIDs
+            self.last_millis += 1;
+        }
+
+        // Treat the timestamp and counter as a single number so the
+        // block can carry from the counter into the timestamp
+        let start = ((self.last_millis as u128) << counter_bits) | self.counter;
+        let end = start + n as u128;
+
+        let last = end - 1;
+        self.last_millis = (last >> counter_bits) as u64;
+        self.counter = last & !(u128::MAX << counter_bits);
+
+        BlockIter {
+            start,
+            end,
+            counter_bits,
+            entropy: self.entropy.clone(),
+        }
+    }
+
+    fn advance(&mut self) {
+        let now = self.clock.now_millis();
+
+        // The timestamp can run ahead of the clock when the counter overflows
+        // into it, so only compare against the clock's own readings to tell
+        // whether it's actually gone backwards
+        let follow = now < self.last_clock
+            && match self.rollback_policy {
+                RollbackPolicy::Hold => false,
+                RollbackPolicy::Follow => true,
+                RollbackPolicy::HoldUpTo(max) => self.last_clock - now > max,
+            };
+
+        if follow {
+            self.last_clock = now;
+            self.last_millis = now;
+            self.reseed();
+
+            return;
+        }
+
+        self.last_clock = self.last_clock.max(now);
+
+        if now > self.last_millis {
+            self.last_millis = now;
+
+            // The counter may keep incrementing across milliseconds, but is
+            // reseeded if it overflows
+            if now - self.last_reseed_millis >= self.reseed_interval || !self.increment() {
+                self.reseed();
+            }
+        } else if !self.increment() {
+            // If the counter overflows then borrow from the next millisecond
+            self.last_millis += 1;
+            self.reseed();
+        }
+    }
+
+    fn reseed(&mut self) {
+        self.last_reseed_millis = self.last_millis;
+
+        // Leave the most significant bit unset so the counter always has
+        // room to increment before it overflows
+        self.counter = entropy::u128(&mut self.entropy)
+            & u128::MAX.checked_shr(129 - self.counter_bits).unwrap_or(0);
+    }
+
+    fn increment(&mut self) -> bool {
+        // Without a counter there's nothing to order UUIDs within a millisecond
+        if self.counter_bits == 0 {
+            return true;
+        }
+
+        let step = if self.max_increment > 1 {
+            entropy::u128(&mut self.entropy) % self.max_increment as u128 + 1
+        } else {
+            1
+        };
+
+        // The counter is at most 74 bits and the step at most 64, so this can't overflow
+        let counter = self.counter + step;
+
+        if counter >> self.counter_bits == 0 {
+            self.counter = counter;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// A block of version 7 UUIDs reserved by [`Generator::reserve`].
+///
+/// The UUIDs are yielded in ascending order. Each one is padded with fresh
+/// random data from the generator's [`EntropySource`] as it's produced.
+#[derive(Debug, Clone)]
+pub struct BlockIter<E = SystemEntropy> {
+    start: u128,
+    end: u128,
+    counter_bits: u32,
+    entropy: E,
+}
+
+impl<E: EntropySource + Clone> BlockIter<E> {
+    /// Split the block into two blocks at an index.
+    ///
+    /// The first block contains the first `mid` UUIDs, and the second contains the
+    /// rest. Every UUID in the first block sorts before every UUID in the second.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `mid` is greater than the number of UUIDs left in the block.
+    pub fn split_at(self, mid: usize) -> (BlockIter<E>, BlockIter<E>) {
+        assert!(mid <= self.len(), "the split index is out of bounds");
+
+        let mid = self.start + mid as u128;
+
+        (
+            BlockIter {
+                start: self.start,
+                end: mid,
+                counter_bits: self.counter_bits,
+                entropy: self.entropy.clone(),
+            },
+            BlockIter {
+                start: mid,
+                end: self.end,
+                counter_bits: self.counter_bits,
+                entropy: self.entropy,
+            },
         )
-        .into_uuid()
     }
 }
 
+impl<E: EntropySource> BlockIter<E> {
+    fn encode(&mut self, position: u128) -> Uuid {
+        let uuid = timestamp::encode_unix_timestamp_counter(
+            (position >> self.counter_bits) as u64,
+            position & !(u128::MAX << self.counter_bits),
+            self.counter_bits,
+            entropy::u128(&mut self.entropy),
+        );
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::SortRand);
+
+        uuid
+    }
+}
+
+impl<E: EntropySource> Iterator for BlockIter<E> {
+    type Item = Uuid;
+
+    fn next(&mut self) -> Option<Uuid> {
+        if self.start == self.end {
+            return None;
+        }
+
+        let uuid = self.encode(self.start);
+        self.start += 1;
+
+        Some(uuid)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.end - self.start) as usize;
+
+        (len, Some(len))
+    }
+}
+
+impl<E: EntropySource> DoubleEndedIterator for BlockIter<E> {
+    fn next_back(&mut self) -> Option<Uuid> {
+        if self.start == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+
+        Some(self.encode(self.end))
+    }
+}
+
+impl<E: EntropySource> ExactSizeIterator for BlockIter<E> {}
+
+impl<E: EntropySource> crate::std::iter::FusedIterator for BlockIter<E> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::{std::string::ToString, ClockSequence, NoContext, Variant, Version};
+    use crate::{
+        std::{cell::Cell, string::ToString},
+        Builder, ClockSequence, NoContext, Variant,
+    };
 
     #[cfg(all(
         target_arch = "wasm32",
@@ -154,6 +863,35 @@ mod tests {
         assert_eq!(uuid.get_variant(), Variant::RFC4122);
     }
 
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    #[cfg(feature = "std")]
+    fn test_now_batch() {
+        let before = Uuid::now_v7();
+
+        // Not a multiple of the internal batch size
+        let mut uuids = [Uuid::nil(); 150];
+        Uuid::now_v7_batch(&mut uuids);
+
+        let after = Uuid::now_v7();
+
+        assert!(uuids
+            .iter()
+            .all(|uuid| uuid.get_version() == Some(Version::SortRand)));
+        assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(before < uuids[0]);
+        assert!(uuids[149] < after);
+
+        Uuid::now_v7_batch(&mut []);
+    }
+
     #[test]
     #[cfg_attr(
         all(
@@ -239,4 +977,725 @@ mod tests {
 
         assert_eq!(ts.to_unix(), decoded_ts.to_unix());
     }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_counter_layout() {
+        // The counter starts at the top of the counter and random bits, under the version
+        let uuid = Uuid::new_v7(Timestamp::from_unix_time(
+            1_496_854_535,
+            0,
+            0x123_4567_89ab,
+            42,
+        ));
+
+        assert_eq!(0x78d0, uuid.as_fields().2);
+        assert_eq!(0x96, uuid.as_fields().3[0]);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_from_unix_timestamp_with_counter() {
+        let millis = 1_645_557_742_000;
+
+        let uuid =
+            Builder::from_unix_timestamp_with_counter(millis, (1 << 42) - 1, &[0; 10]).into_uuid();
+        assert_eq!("017f22e2-79b0-7fff-bfff-ffff00000000", uuid.to_string());
+
+        let uuid = Builder::from_unix_timestamp_with_counter(millis, 0, &[0xff; 10]).into_uuid();
+        assert_eq!("017f22e2-79b0-7000-8000-0000ffffffff", uuid.to_string());
+
+        // The counter is truncated to 42 bits
+        let uuid = Builder::from_unix_timestamp_with_counter(millis, 1 << 42, &[0; 10]).into_uuid();
+        assert_eq!("017f22e2-79b0-7000-8000-000000000000", uuid.to_string());
+
+        let mut last = Uuid::nil();
+        for counter in (0..(1u64 << 42)).step_by(1 << 30).chain([(1 << 42) - 1]) {
+            let uuid =
+                Builder::from_unix_timestamp_with_counter(millis, counter, &[0xab; 10]).into_uuid();
+
+            assert_eq!(Some(Version::SortRand), uuid.get_version());
+            assert_eq!(Variant::RFC4122, uuid.get_variant());
+            assert_eq!(millis, uuid.get_timestamp().unwrap().to_unix().0 * 1000);
+            assert!(last < uuid);
+
+            last = uuid;
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_from_unix_timestamp_sub_millis() {
+        let millis = 1_645_557_742_000;
+
+        let uuid = Builder::from_unix_timestamp_sub_millis(millis, 999_999, &[0; 10]).into_uuid();
+        assert_eq!("017f22e2-79b0-7fff-8000-000000000000", uuid.to_string());
+
+        // Whole milliseconds or more are clamped rather than wrapped
+        let uuid = Builder::from_unix_timestamp_sub_millis(millis, 1_000_000, &[0; 10]).into_uuid();
+        assert_eq!("017f22e2-79b0-7fff-8000-000000000000", uuid.to_string());
+
+        let mut last = Uuid::nil();
+        for nanos in (0..1_000_000).step_by(250) {
+            let uuid =
+                Builder::from_unix_timestamp_sub_millis(millis, nanos, &[0xff; 10]).into_uuid();
+
+            assert_eq!(Some(Version::SortRand), uuid.get_version());
+            assert_eq!(Variant::RFC4122, uuid.get_variant());
+            assert_eq!((millis / 1000, 0), uuid.get_timestamp().unwrap().to_unix());
+
+            // The decoded timestamp is within the resolution of the fraction
+            let (seconds, decoded) = uuid.get_timestamp_sub_millis().unwrap().to_unix();
+            assert_eq!(millis / 1000, seconds);
+            assert!(decoded <= nanos && nanos - decoded < 245);
+
+            assert!(last < uuid);
+            last = uuid;
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_from_unix_timestamp_micros() {
+        let start = 1_645_557_742_000_000u64;
+
+        let mut last = Uuid::nil();
+        for micros in start..start + 2_000 {
+            let uuid = Builder::from_unix_timestamp_micros(micros, &[0xab; 10]).into_uuid();
+
+            assert_eq!(Some(micros), uuid.get_unix_timestamp_micros());
+            assert_eq!(
+                micros / 1_000,
+                uuid.get_timestamp().unwrap().to_unix().0 * 1_000
+                    + uuid.get_timestamp().unwrap().to_unix().1 as u64 / 1_000_000
+            );
+            assert!(last < uuid);
+
+            last = uuid;
+        }
+
+        assert_eq!(None, Uuid::nil().get_unix_timestamp_micros());
+        assert!(Uuid::nil().get_timestamp_sub_millis().is_none());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_sub_millis_max_fraction() {
+        let millis = 1_645_557_742_000;
+
+        // A fraction of `0xfff` rounds up to a whole millisecond if it isn't clamped
+        let uuid = Builder::from_unix_timestamp_sub_millis(millis, 999_999, &[0; 10]).into_uuid();
+        assert_eq!(
+            0xfff,
+            u16::from_be_bytes([uuid.as_bytes()[6], uuid.as_bytes()[7]]) & 0xfff
+        );
+
+        assert_eq!(Some(millis * 1_000 + 999), uuid.get_unix_timestamp_micros());
+
+        let (seconds, nanos) = uuid.get_timestamp_sub_millis().unwrap().to_unix();
+        assert_eq!(millis / 1_000, seconds);
+        assert!(nanos < 1_000_000);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_generator_ordering() {
+        let mut generator = Generator::with_clock(|| 1_645_557_742_000);
+
+        let mut last = Uuid::nil();
+        for _ in 0..1_000 {
+            let uuid = generator.generate();
+
+            assert_eq!(Some(Version::SortRand), uuid.get_version());
+            assert_eq!(Variant::RFC4122, uuid.get_variant());
+            assert_eq!(
+                1_645_557_742_000,
+                timestamp::decode_unix_timestamp_millis(&uuid)
+            );
+            assert!(last < uuid);
+
+            last = uuid;
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_generator_counter_overflow() {
+        let mut generator = Generator::with_clock(|| 1_645_557_742_000).with_counter_bits(1);
+
+        let uuid1 = generator.generate();
+        let uuid2 = generator.generate();
+        let uuid3 = generator.generate();
+
+        // The counter has a single bit, so the third UUID overflows into the next millisecond
+        assert_eq!(
+            1_645_557_742_000,
+            timestamp::decode_unix_timestamp_millis(&uuid2)
+        );
+        assert_eq!(
+            1_645_557_742_001,
+            timestamp::decode_unix_timestamp_millis(&uuid3)
+        );
+        assert!(uuid1 < uuid2);
+        assert!(uuid2 < uuid3);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_generator_random_increment() {
+        let max = 1 << 16;
+
+        let mut generator = Generator::with_clock(|| 1_645_557_742_000)
+            .with_counter_bits(MAX_COUNTER_BITS)
+            .with_random_increment(max);
+
+        // With every bit after the timestamp used as the counter, it can be read back
+        let counter = |uuid: Uuid| {
+            let bits = uuid.as_u128();
+            ((bits >> 64) & 0xfff) << 62 | (bits & (u128::MAX >> 66))
+        };
+
+        let mut last = generator.generate();
+        let mut stepped = false;
+        for _ in 0..1_000 {
+            let uuid = generator.generate();
+
+            assert!(last < uuid);
+            assert_eq!(
+                1_645_557_742_000,
+                timestamp::decode_unix_timestamp_millis(&uuid)
+            );
+
+            let step = counter(uuid) - counter(last);
+            assert!(step >= 1 && step <= max as u128);
+            stepped |= step > 1;
+
+            last = uuid;
+        }
+
+        assert!(stepped);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_try_now_clock_errors_differ() {
+        use crate::{error::ErrorKind, std::string::ToString, Error};
+
+        let before_epoch = Error::new(ErrorKind::ClockBeforeEpoch);
+        let unreadable = Error::new(ErrorKind::Clock);
+
+        assert_ne!(before_epoch, unreadable);
+        assert!(before_epoch.to_string().contains("before the Unix epoch"));
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "std",
+        not(miri),
+        any(
+            not(feature = "js"),
+            not(all(
+                target_arch = "wasm32",
+                target_vendor = "unknown",
+                target_os = "unknown"
+            ))
+        )
+    ))]
+    fn test_try_now_before_epoch() {
+        use crate::{error::ErrorKind, Error};
+        use std::time::SystemTime;
+
+        let now = SystemTime::UNIX_EPOCH - Duration::from_secs(1);
+
+        assert_eq!(
+            Err(Error::new(ErrorKind::ClockBeforeEpoch)),
+            Uuid::try_now_v7_from(timestamp::try_since_unix_epoch(now))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_generator_counter_bits() {
+        for counter_bits in [0, 12, 42, MAX_COUNTER_BITS] {
+            let mut generator =
+                Generator::with_clock(|| 1_645_557_742_000).with_counter_bits(counter_bits);
+
+            for _ in 0..10 {
+                let uuid = generator.generate();
+
+                assert_eq!(Some(Version::SortRand), uuid.get_version());
+                assert_eq!(Variant::RFC4122, uuid.get_variant());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_generator_counter_bits_too_wide() {
+        let _ = Generator::with_clock(|| 0).with_counter_bits(MAX_COUNTER_BITS + 1);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_generator_reseed_interval() {
+        let now = Cell::new(1_645_557_742_000);
+
+        let mut generator = Generator::with_clock(|| now.get())
+            .with_counter_bits(12)
+            .with_reseed_interval(10);
+
+        let counter = |uuid: Uuid| uuid.as_fields().2 & 0x0fff;
+
+        let uuid1 = generator.generate();
+
+        // The counter keeps incrementing across milliseconds until it's reseeded
+        now.set(now.get() + 1);
+        let uuid2 = generator.generate();
+
+        assert_eq!(counter(uuid1) + 1, counter(uuid2));
+        assert!(uuid1 < uuid2);
+
+        now.set(now.get() + 10);
+        let uuid3 = generator.generate();
+
+        assert_eq!(
+            1_645_557_742_011,
+            timestamp::decode_unix_timestamp_millis(&uuid3)
+        );
+        assert!(uuid2 < uuid3);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_generator_entropy() {
+        let fills = Cell::new(0);
+        let mut generator = Generator::with_clock_and_entropy(
+            || 1_645_557_742_000,
+            |dest: &mut [u8]| {
+                fills.set(fills.get() + 1);
+                dest.fill(0);
+            },
+        )
+        .with_counter_bits(12);
+
+        assert_eq!(
+            "017f22e2-79b0-7000-8000-000000000000",
+            generator.generate().to_string()
+        );
+        assert_eq!(
+            "017f22e2-79b0-7001-8000-000000000000",
+            generator.generate().to_string()
+        );
+
+        // One fill to seed the counter, and one to pad each UUID
+        assert_eq!(3, fills.get());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_generator_rollback() {
+        let now = Cell::new(1_645_557_742_000);
+
+        let generate = |rollback_policy, rollback| {
+            now.set(1_645_557_742_000);
+
+            let mut generator =
+                Generator::with_clock(|| now.get()).with_rollback_policy(rollback_policy);
+
+            let uuid1 = generator.generate();
+
+            now.set(now.get() - rollback);
+            let uuid2 = generator.generate();
+
+            (
+                uuid1,
+                timestamp::decode_unix_timestamp_millis(&uuid2),
+                uuid2,
+            )
+        };
+
+        let (uuid1, millis, uuid2) = generate(RollbackPolicy::Hold, 5_000);
+        assert_eq!(1_645_557_742_000, millis);
+        assert!(uuid1 < uuid2);
+
+        let (uuid1, millis, uuid2) = generate(RollbackPolicy::Follow, 5);
+        assert_eq!(1_645_557_741_995, millis);
+        assert!(uuid1 > uuid2);
+
+        let (uuid1, millis, uuid2) = generate(RollbackPolicy::HoldUpTo(10), 5);
+        assert_eq!(1_645_557_742_000, millis);
+        assert!(uuid1 < uuid2);
+
+        let (_, millis, _) = generate(RollbackPolicy::HoldUpTo(10), 11);
+        assert_eq!(1_645_557_741_989, millis);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_generator_rollback_counter_overflow() {
+        // The clock never moves, so overflowing the counter isn't a rollback
+        for rollback_policy in [RollbackPolicy::Follow, RollbackPolicy::HoldUpTo(0)] {
+            let mut generator = Generator::with_clock(|| 1_645_557_742_000)
+                .with_counter_bits(2)
+                .with_rollback_policy(rollback_policy);
+
+            let mut last = generator.generate();
+            for _ in 0..100 {
+                let uuid = generator.generate();
+                assert!(last < uuid);
+
+                last = uuid;
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_generator_now() {
+        let mut generator = Generator::new();
+
+        let uuid1 = generator.generate();
+        let uuid2 = generator.generate();
+
+        assert_eq!(Some(Version::SortRand), uuid1.get_version());
+        assert!(uuid1 < uuid2);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_try_now() {
+        let uuid1 = Uuid::try_now_v7().unwrap();
+        let uuid2 = Uuid::now_v7();
+        let uuid3 = Uuid::try_now_v7().unwrap();
+
+        assert_eq!(Some(Version::SortRand), uuid1.get_version());
+        assert_eq!(Variant::RFC4122, uuid1.get_variant());
+        assert!(uuid1 < uuid2);
+        assert!(uuid2 < uuid3);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_generator_reserve() {
+        let mut generator = Generator::with_clock(|| 1_645_557_742_000);
+
+        let before = generator.generate();
+        let block = generator.reserve(1_000);
+        let after = generator.generate();
+
+        assert_eq!(1_000, block.len());
+
+        let mut last = before;
+        for uuid in block {
+            assert_eq!(Some(Version::SortRand), uuid.get_version());
+            assert_eq!(Variant::RFC4122, uuid.get_variant());
+            assert!(last < uuid);
+
+            last = uuid;
+        }
+
+        assert!(last < after);
+
+        assert_eq!(0, generator.reserve(0).count());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_generator_reserve_entropy() {
+        let fills = Cell::new(0);
+        let mut generator = Generator::with_clock_and_entropy(
+            || 1_645_557_742_000,
+            |dest: &mut [u8]| {
+                fills.set(fills.get() + 1);
+                dest.fill(0);
+            },
+        )
+        .with_counter_bits(12);
+
+        let (first, second) = generator.reserve(2).split_at(1);
+        let fills_before = fills.get();
+
+        assert_eq!(
+            vec![
+                "017f22e2-79b0-7000-8000-000000000000",
+                "017f22e2-79b0-7001-8000-000000000000",
+            ],
+            first
+                .chain(second)
+                .map(|uuid| uuid.to_string())
+                .collect::<crate::std::vec::Vec<_>>()
+        );
+
+        // Each UUID in the block is padded from the generator's source
+        assert_eq!(fills_before + 2, fills.get());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_generator_reserve_overflow() {
+        for counter_bits in [0, 1, 12] {
+            let mut generator =
+                Generator::with_clock(|| 1_645_557_742_000).with_counter_bits(counter_bits);
+
+            let before = generator.generate();
+            let block: crate::std::vec::Vec<_> = generator.reserve(100).collect();
+            let after = generator.generate();
+
+            assert!(before < block[0]);
+            assert!(block.windows(2).all(|w| w[0] < w[1]));
+
+            // Without a counter, UUIDs generated in the same millisecond aren't ordered
+            if counter_bits > 0 {
+                assert!(block[99] < after);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_generator_reserve_spill_rollback() {
+        // A block that spills past the current millisecond isn't a clock rollback
+        for rollback_policy in [RollbackPolicy::Follow, RollbackPolicy::HoldUpTo(1)] {
+            let mut generator = Generator::with_clock(|| 1_645_557_742_000)
+                .with_counter_bits(4)
+                .with_rollback_policy(rollback_policy);
+
+            let block: crate::std::vec::Vec<_> = generator.reserve(100).collect();
+            let after = generator.generate();
+
+            assert!(block[99] < after);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_block_split() {
+        let mut generator = Generator::with_clock(|| 1_645_557_742_000).with_counter_bits(4);
+
+        let (first, second) = generator.reserve(40).split_at(25);
+
+        assert_eq!(25, first.len());
+        assert_eq!(15, second.len());
+
+        let last_first = first.clone().next_back().unwrap();
+        let first_second = second.clone().next().unwrap();
+
+        assert!(last_first < first_second);
+
+        // The block doesn't fit in a 4-bit counter, so it spans several milliseconds
+        assert!(
+            timestamp::decode_unix_timestamp_millis(&first.clone().next().unwrap())
+                < timestamp::decode_unix_timestamp_millis(&second.clone().next_back().unwrap())
+        );
+
+        let (empty, rest) = second.split_at(0);
+
+        assert_eq!(0, empty.len());
+        assert_eq!(15, rest.rev().count());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_truncate_to() {
+        let hour = Duration::from_secs(60 * 60);
+
+        let uuid1 = Uuid::new_v7(Timestamp::from_unix(NoContext, 1_645_556_400, 0));
+        let uuid2 = Uuid::new_v7(Timestamp::from_unix(NoContext, 1_645_559_999, 999_999_999));
+        let uuid3 = Uuid::new_v7(Timestamp::from_unix(NoContext, 1_645_560_000, 0));
+
+        let bucket = uuid1.truncate_to(hour).unwrap();
+
+        assert_eq!(Some(Version::SortRand), bucket.get_version());
+        assert_eq!(Variant::RFC4122, bucket.get_variant());
+        assert_eq!(
+            1_645_556_400_000,
+            timestamp::decode_unix_timestamp_millis(&bucket)
+        );
+
+        assert_eq!(Some(bucket), uuid2.truncate_to(hour));
+        assert_ne!(Some(bucket), uuid3.truncate_to(hour));
+        assert!(bucket < uuid3.truncate_to(hour).unwrap());
+
+        // Sub-millisecond buckets only clear the random bits
+        assert_eq!(
+            uuid1.truncate_to(Duration::from_millis(1)),
+            uuid1.truncate_to(Duration::from_nanos(1))
+        );
+        assert_eq!(
+            uuid2
+                .truncate_to(Duration::ZERO)
+                .map(|uuid| timestamp::decode_unix_timestamp_millis(&uuid)),
+            Some(1_645_559_999_999)
+        );
+
+        assert_eq!(None, Uuid::nil().truncate_to(hour));
+        assert_eq!(None, Uuid::max().truncate_to(hour));
+    }
 }
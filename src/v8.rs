@@ -1,4 +1,13 @@
-use crate::{Builder, Uuid};
+//! The implementation for Version 8 UUIDs.
+//!
+//! This module also contains the [`V8Payload`] trait for defining structured
+//! layouts inside version 8 UUIDs, and the [`v8_payload!`](crate::v8_payload)
+//! macro for implementing it.
+//!
+//! Note that you need to enable the `v8` Cargo feature
+//! in order to use this module.
+
+use crate::{error::*, Builder, Uuid, Variant, Version};
 
 impl Uuid {
     /// Creates a custom UUID comprised almost entirely of user-supplied bytes.
@@ -28,14 +37,422 @@ impl Uuid {
     pub fn new_v8(buf: [u8; 16]) -> Uuid {
         Builder::from_custom_bytes(buf).into_uuid()
     }
+
+    /// Creates a UUID from a keyed HMAC-SHA-256 of some data.
+    ///
+    /// The same key and data always produce the same UUID, but without the key
+    /// it's not feasible to predict the UUID from the data. This makes it
+    /// useful for deterministic identifiers derived from public inputs, like
+    /// per-user resource IDs, that third parties shouldn't be able to guess.
+    ///
+    /// The first 16 bytes of the HMAC output are used, with the version and variant
+    /// set as described by [`Uuid::new_v8`]. This isn't a standardized algorithm,
+    /// so other implementations won't necessarily produce the same UUIDs.
+    ///
+    /// Note that usage of this method requires the `hmac` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let key = b"a secret key";
+    ///
+    /// let uuid = Uuid::new_v8_hmac(key, b"user-42/avatar");
+    ///
+    /// assert_eq!(Some(Version::Custom), uuid.get_version());
+    /// assert_eq!(uuid, Uuid::new_v8_hmac(key, b"user-42/avatar"));
+    /// assert_ne!(uuid, Uuid::new_v8_hmac(b"another key", b"user-42/avatar"));
+    /// ```
+    #[cfg(feature = "hmac")]
+    pub fn new_v8_hmac(key: &[u8], data: &[u8]) -> Uuid {
+        let uuid = Builder::from_custom_bytes(crate::hmac::hash(key, data)).into_uuid();
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::Custom);
+
+        uuid
+    }
+
+    /// Creates a UUID from a keyed 128-bit SipHash-2-4 of a name.
+    ///
+    /// This is much faster than name-based UUIDs from [`Uuid::new_v5`], which
+    /// makes it a good fit for things like internal caching or deduplication keys.
+    /// SipHash isn't a cryptographic hash, so these UUIDs shouldn't be used
+    /// anywhere an attacker could benefit from finding collisions.
+    ///
+    /// The version and variant are set as described by [`Uuid::new_v8`]. This
+    /// isn't a standardized algorithm, so other implementations won't necessarily
+    /// produce the same UUIDs.
+    ///
+    /// Note that usage of this method requires the `siphash` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// const KEY: [u8; 16] = *b"cache-key-v1\0\0\0\0";
+    ///
+    /// let uuid = Uuid::new_v8_siphash(&KEY, b"/index.html");
+    ///
+    /// assert_eq!(Some(Version::Custom), uuid.get_version());
+    /// assert_eq!(uuid, Uuid::new_v8_siphash(&KEY, b"/index.html"));
+    /// ```
+    #[cfg(feature = "siphash")]
+    pub fn new_v8_siphash(key: &[u8; 16], name: &[u8]) -> Uuid {
+        let uuid = Builder::from_custom_bytes(crate::siphash::hash(key, name)).into_uuid();
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::Custom);
+
+        uuid
+    }
+
+    /// Creates a UUID using a name from a namespace, based on any hash that
+    /// implements [`digest::Digest`].
+    ///
+    /// This works like [`Uuid::new_v5`], but lets you pick the hash algorithm,
+    /// such as SHA-256 from the `sha2` crate. The namespace and name are hashed
+    /// together, the first 16 bytes of the output are used, and the version and
+    /// variant are set as described by [`Uuid::new_v8`]. With SHA-256, this
+    /// matches the name-based example in RFC 9562.
+    ///
+    /// The result is always a version 8 UUID, even if `D` is MD5 or SHA-1. Use
+    /// [`Uuid::new_v3`] or [`Uuid::new_v5`] for standard name-based UUIDs.
+    ///
+    /// Note that usage of this method requires the `digest` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `D` produces fewer than 16 bytes of output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// use sha2::Sha256;
+    ///
+    /// let uuid = Uuid::new_name_based::<Sha256>(&Uuid::NAMESPACE_DNS, b"www.example.com");
+    ///
+    /// assert_eq!(
+    ///     "5c146b14-3c52-8afd-938a-375d0df1fbf6",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Name-Based UUIDs with SHA-256 in RFC 9562](https://www.ietf.org/rfc/rfc9562.html#appendix-B.2)
+    #[cfg(feature = "digest")]
+    pub fn new_name_based<D: digest::Digest>(namespace: &Uuid, name: &[u8]) -> Uuid {
+        let mut hasher = D::new();
+
+        hasher.update(namespace.as_bytes());
+        hasher.update(name);
+
+        let hash = hasher.finalize();
+
+        assert!(
+            hash.len() >= 16,
+            "the digest must produce at least 16 bytes of output"
+        );
+
+        let mut bytes = [0; 16];
+        bytes.copy_from_slice(&hash[..16]);
+
+        let uuid = Builder::from_custom_bytes(bytes).into_uuid();
+
+        #[cfg(feature = "observe")]
+        let uuid = crate::observe::generated(uuid, crate::Version::Custom);
+
+        uuid
+    }
+
+    /// Creates a version 8 UUID for an entity owned by a tenant.
+    ///
+    /// This is a ready-made layout for multi-tenant systems that want to
+    /// recover the owner and type of an entity directly from its ID:
+    ///
+    /// * The first 4 bytes are the big-endian `tenant` ID.
+    /// * The 5th byte is the `kind` of entity.
+    /// * The remaining bytes are `random_bytes`, except for the version and
+    ///   variant bits, which are set as described by [`Uuid::new_v8`]. That
+    ///   leaves 82 random bits.
+    ///
+    /// UUIDs in this layout sort by tenant, then kind. Use [`Uuid::tenant_id`]
+    /// and [`Uuid::entity_kind`] to read the fields back.
+    ///
+    /// Note that usage of this method requires the `v8` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// # let random_bytes = [0x42; 11];
+    /// let uuid = Uuid::new_v8_packed(1234, 7, &random_bytes);
+    ///
+    /// assert_eq!(Some(Version::Custom), uuid.get_version());
+    /// assert_eq!(Some(1234), uuid.tenant_id());
+    /// assert_eq!(Some(7), uuid.entity_kind());
+    /// ```
+    pub const fn new_v8_packed(tenant: u32, kind: u8, random_bytes: &[u8; 11]) -> Uuid {
+        let tenant = tenant.to_be_bytes();
+
+        let mut bytes = [0; 16];
+        bytes[0] = tenant[0];
+        bytes[1] = tenant[1];
+        bytes[2] = tenant[2];
+        bytes[3] = tenant[3];
+        bytes[4] = kind;
+
+        let mut i = 0;
+        while i < 11 {
+            bytes[5 + i] = random_bytes[i];
+            i += 1;
+        }
+
+        Builder::from_custom_bytes(bytes).into_uuid()
+    }
+
+    /// Returns the tenant ID of a UUID created by [`Uuid::new_v8_packed`].
+    ///
+    /// This method returns `None` if the UUID isn't version 8. There's no way to
+    /// tell whether a version 8 UUID actually uses the packed layout, so this
+    /// method should only be used on UUIDs known to be created by
+    /// [`Uuid::new_v8_packed`].
+    pub const fn tenant_id(&self) -> Option<u32> {
+        match self.get_version() {
+            Some(Version::Custom) => Some(u32::from_be_bytes([
+                self.0[0], self.0[1], self.0[2], self.0[3],
+            ])),
+            _ => None,
+        }
+    }
+
+    /// Returns the entity kind of a UUID created by [`Uuid::new_v8_packed`].
+    ///
+    /// Like [`Uuid::tenant_id`], this method returns `None` if the UUID isn't
+    /// version 8.
+    pub const fn entity_kind(&self) -> Option<u8> {
+        match self.get_version() {
+            Some(Version::Custom) => Some(self.0[4]),
+            _ => None,
+        }
+    }
+}
+
+/// A structured layout for the contents of a version 8 UUID.
+///
+/// Implementations only need to pack their fields into the 122 bits of a UUID
+/// that aren't reserved for its version and variant, and unpack them again.
+/// The provided [`encode`](V8Payload::encode) and [`decode`](V8Payload::decode)
+/// methods take care of the reserved bits and validation, so a layout is
+/// defined once and used symmetrically.
+///
+/// The [`v8_payload!`](crate::v8_payload) macro can implement this trait for
+/// a struct of unsigned integer fields.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{v8::V8Payload, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// #[derive(Debug, PartialEq)]
+/// struct Shard {
+///     region: u8,
+///     id: u64,
+/// }
+///
+/// impl V8Payload for Shard {
+///     fn to_payload_bits(&self) -> u128 {
+///         ((self.region as u128) << 64) | self.id as u128
+///     }
+///
+///     fn from_payload_bits(bits: u128) -> Option<Self> {
+///         if bits >> 72 != 0 {
+///             return None;
+///         }
+///
+///         Some(Shard {
+///             region: (bits >> 64) as u8,
+///             id: bits as u64,
+///         })
+///     }
+/// }
+///
+/// let shard = Shard { region: 3, id: 42 };
+/// let uuid = shard.to_uuid();
+///
+/// assert_eq!(shard, Shard::decode(uuid)?);
+/// # Ok(())
+/// # }
+/// ```
+pub trait V8Payload: Sized {
+    /// Pack the payload into the least significant 122 bits of a `u128`.
+    ///
+    /// Any bits above the 122nd are ignored.
+    fn to_payload_bits(&self) -> u128;
+
+    /// Unpack the payload from the least significant 122 bits of a `u128`.
+    ///
+    /// This method should return `None` if the bits don't describe a valid payload.
+    fn from_payload_bits(bits: u128) -> Option<Self>;
+
+    /// Encode the payload as the bytes of a version 8 UUID.
+    ///
+    /// The payload is spread around the version and variant fields, which are
+    /// set as described by [`Uuid::new_v8`].
+    fn encode(&self) -> [u8; 16] {
+        self.to_uuid().into_bytes()
+    }
+
+    /// Encode the payload as a version 8 UUID.
+    fn to_uuid(&self) -> Uuid {
+        Builder::from_payload_bits(Version::Custom, self.to_payload_bits()).into_uuid()
+    }
+
+    /// Decode the payload from a version 8 UUID.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if `uuid` isn't a version 8 UUID using
+    /// the variant described by RFC 9562, or if its payload isn't valid.
+    fn decode(uuid: Uuid) -> Result<Self, Error> {
+        if uuid.get_version() != Some(Version::Custom) || uuid.get_variant() != Variant::RFC4122 {
+            return Err(Error::new(ErrorKind::Payload));
+        }
+
+        Self::from_payload_bits(uuid.payload_bits()).ok_or(Error::new(ErrorKind::Payload))
+    }
+}
+
+/// Define a struct that's encoded into version 8 UUIDs.
+///
+/// Each field is an unsigned integer followed by the number of bits it uses
+/// in the UUID, like a C bit-field. Fields are packed from the most significant
+/// end of the UUID in the order they're declared, around the version and
+/// variant bits, and can use up to 122 bits in total. The macro defines the
+/// struct and implements [`V8Payload`](crate::v8::V8Payload) for it.
+///
+/// Decoding a UUID fails if any bits after the last field are set, since the
+/// UUID can't have been produced by encoding the struct.
+///
+/// Note that usage of this macro requires the `v8` feature of this crate
+/// to be enabled.
+///
+/// # Panics
+///
+/// Encoding panics if the value of a field doesn't fit in its number of bits.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{v8::V8Payload, v8_payload};
+/// # fn main() -> Result<(), uuid::Error> {
+/// v8_payload! {
+///     #[derive(Debug, PartialEq)]
+///     pub struct Entity {
+///         pub region: u16: 10,
+///         pub kind: u8: 6,
+///         pub sequence: u64: 48,
+///     }
+/// }
+///
+/// let entity = Entity { region: 513, kind: 7, sequence: 42 };
+/// let uuid = entity.to_uuid();
+///
+/// assert_eq!("80470000-0000-8002-a800-000000000000", uuid.to_string());
+/// assert_eq!(entity, Entity::decode(uuid)?);
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! v8_payload {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident : $ty:ty : $bits:literal
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                $field_vis $field: $ty,
+            )+
+        }
+
+        impl $crate::v8::V8Payload for $name {
+            fn to_payload_bits(&self) -> u128 {
+                const _: () = assert!(
+                    0 $(+ $bits)+ <= 122,
+                    "a version 8 payload can use at most 122 bits",
+                );
+
+                let mut payload = 0u128;
+
+                $(
+                    let value = self.$field as u128;
+
+                    assert!(
+                        value >> $bits == 0,
+                        concat!("the value of `", stringify!($field), "` doesn't fit in ", $bits, " bits"),
+                    );
+
+                    payload = (payload << $bits) | value;
+                )+
+
+                payload << (122 $(- $bits)+)
+            }
+
+            fn from_payload_bits(payload: u128) -> Option<Self> {
+                let mut shift = 122;
+
+                // Any bits after the last field must be unset
+                if payload.trailing_zeros() < 122 $(- $bits)+ {
+                    return None;
+                }
+
+                Some($name {
+                    $(
+                        $field: {
+                            shift -= $bits;
+                            ((payload >> shift) & ((1u128 << $bits) - 1)) as $ty
+                        },
+                    )+
+                })
+            }
+        }
+    };
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Variant, Version};
     use std::string::ToString;
 
+    crate::v8_payload! {
+        #[derive(Debug, PartialEq)]
+        struct Packed {
+            a: u8: 4,
+            b: u64: 64,
+            c: u64: 54,
+        }
+    }
+
+    crate::v8_payload! {
+        #[derive(Debug, PartialEq)]
+        struct Partial {
+            a: u16: 12,
+        }
+    }
+
     #[cfg(all(
         target_arch = "wasm32",
         target_vendor = "unknown",
@@ -65,4 +482,192 @@ mod tests {
             "0f0e0d0c-0b0a-8908-8706-050403020100"
         );
     }
+
+    #[test]
+    #[cfg(feature = "hmac")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_hmac() {
+        // From RFC 4231, test case 2
+        let uuid = Uuid::new_v8_hmac(b"Jefe", b"what do ya want for nothing?");
+
+        assert_eq!(uuid.get_version(), Some(Version::Custom));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "5bdcc146-bf60-854e-aa04-2426089575c7"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "siphash")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_siphash() {
+        // From the SipHash reference implementation's 128-bit test vectors
+        let key = [
+            0x0, 0x1, 0x2, 0x3, 0x4, 0x5, 0x6, 0x7, 0x8, 0x9, 0xa, 0xb, 0xc, 0xd, 0xe, 0xf,
+        ];
+        let uuid = Uuid::new_v8_siphash(&key, b"");
+
+        assert_eq!(uuid.get_version(), Some(Version::Custom));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "a3817f04-ba25-88e6-adf6-7214c7550293"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "digest")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_name_based() {
+        let uuid = Uuid::new_name_based::<sha2::Sha256>(&Uuid::NAMESPACE_DNS, b"www.example.com");
+
+        assert_eq!(uuid.get_version(), Some(Version::Custom));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "5c146b14-3c52-8afd-938a-375d0df1fbf6"
+        );
+
+        assert_ne!(
+            uuid,
+            Uuid::new_name_based::<sha2::Sha512>(&Uuid::NAMESPACE_DNS, b"www.example.com")
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_payload_roundtrip() {
+        let packed = Packed {
+            a: 0xf,
+            b: u64::MAX,
+            c: (1 << 54) - 1,
+        };
+
+        let uuid = packed.to_uuid();
+
+        assert_eq!(Some(Version::Custom), uuid.get_version());
+        assert_eq!(Variant::RFC4122, uuid.get_variant());
+        assert_eq!(uuid.into_bytes(), packed.encode());
+        assert_eq!((1 << 122) - 1, uuid.payload_bits());
+        assert_eq!(packed, Packed::decode(uuid).unwrap());
+
+        let partial = Partial { a: 0xabc };
+
+        assert_eq!(
+            "abc00000-0000-8000-8000-000000000000",
+            partial.to_uuid().to_string()
+        );
+        assert_eq!(partial, Partial::decode(partial.to_uuid()).unwrap());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_payload_decode_invalid() {
+        // Not a version 8 UUID
+        assert!(Partial::decode(Uuid::nil()).is_err());
+        assert!(
+            Partial::decode(Builder::from_payload_bits(Version::Random, 0).into_uuid()).is_err()
+        );
+
+        // Bits after the last field are set
+        assert!(
+            Partial::decode(Builder::from_payload_bits(Version::Custom, 1).into_uuid()).is_err()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_payload_overflow() {
+        let _ = Partial { a: 0x1000 }.to_uuid();
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_new_packed() {
+        let uuid = Uuid::new_v8_packed(0x01020304, 0xab, &[0xff; 11]);
+
+        assert_eq!(Some(Version::Custom), uuid.get_version());
+        assert_eq!(Variant::RFC4122, uuid.get_variant());
+        assert_eq!(
+            "01020304-abff-8fff-bfff-ffffffffffff",
+            uuid.hyphenated().to_string()
+        );
+        assert_eq!(Some(0x01020304), uuid.tenant_id());
+        assert_eq!(Some(0xab), uuid.entity_kind());
+
+        let uuid = Uuid::new_v8_packed(u32::MAX, 0, &[0; 11]);
+
+        assert_eq!(Some(u32::MAX), uuid.tenant_id());
+        assert_eq!(Some(0), uuid.entity_kind());
+
+        // Packed UUIDs sort by tenant first
+        assert!(Uuid::new_v8_packed(1, u8::MAX, &[0xff; 11]) < Uuid::new_v8_packed(2, 0, &[0; 11]));
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_packed_not_v8() {
+        let uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+        assert_eq!(None, uuid.tenant_id());
+        assert_eq!(None, uuid.entity_kind());
+    }
 }
@@ -0,0 +1,343 @@
+//! Consistency diagnostics for UUIDs.
+//!
+//! UUIDs imported from other systems aren't always well-formed. They may
+//! use a non-standard variant, an unassigned version, or carry a timestamp
+//! that can't be right. [`Uuid::validate`] inspects a UUID and reports on
+//! each of these so bad identifiers can be triaged.
+
+use crate::{
+    timestamp::{self, Timestamp, UUID_TICKS_BETWEEN_EPOCHS},
+    Uuid, Variant, Version,
+};
+
+/// Structured diagnostics about the consistency of a UUID.
+///
+/// This type is returned by [`Uuid::validate`] and [`Uuid::validate_at`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Validation {
+    variant: Variant,
+    version_num: usize,
+    version: Option<Version>,
+    timestamp: TimestampValidity,
+}
+
+/// The plausibility of the timestamp embedded in a time-based UUID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimestampValidity {
+    /// The UUID isn't time-based, so it doesn't have a timestamp to check.
+    NotTimeBased,
+    /// The timestamp is plausible.
+    Plausible,
+    /// The timestamp is zero, so it's at the very start of its epoch.
+    ///
+    /// This is usually a sign that the timestamp was never set.
+    Unset,
+    /// The timestamp is before the Unix epoch (`1970-01-01 00:00:00`).
+    BeforeUnixEpoch,
+    /// The timestamp is later than the current time.
+    Future,
+}
+
+impl Validation {
+    /// The variant of the UUID.
+    pub const fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    /// Whether the UUID uses the variant described by RFC 9562.
+    ///
+    /// The nil and max UUIDs are special-cased by RFC 9562 and don't
+    /// use this variant.
+    pub const fn is_rfc_variant(&self) -> bool {
+        matches!(self.variant, Variant::RFC4122)
+    }
+
+    /// The raw value of the version bits of the UUID.
+    pub const fn version_num(&self) -> usize {
+        self.version_num
+    }
+
+    /// Whether the version bits of the UUID are a version assigned by RFC 9562.
+    pub const fn is_known_version(&self) -> bool {
+        self.version.is_some()
+    }
+
+    /// The plausibility of the timestamp in a time-based UUID.
+    pub const fn timestamp(&self) -> TimestampValidity {
+        self.timestamp
+    }
+
+    /// Whether the UUID is consistent.
+    ///
+    /// A UUID is consistent if it's the nil or max UUID, or if it uses the variant
+    /// described by RFC 9562 with a known version and, for time-based versions,
+    /// a plausible timestamp.
+    pub const fn is_valid(&self) -> bool {
+        match self.version {
+            Some(Version::Nil) | Some(Version::Max) => true,
+            Some(_) => {
+                self.is_rfc_variant()
+                    && matches!(
+                        self.timestamp,
+                        TimestampValidity::NotTimeBased | TimestampValidity::Plausible
+                    )
+            }
+            None => false,
+        }
+    }
+}
+
+impl Uuid {
+    /// Check whether this UUID is well-formed.
+    ///
+    /// The returned [`Validation`] describes whether the variant is the one
+    /// described by RFC 9562, whether the version is known, and whether the
+    /// timestamp of a time-based UUID is plausible compared to the current
+    /// system time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, validate::TimestampValidity};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")?;
+    /// let validation = uuid.validate();
+    ///
+    /// assert!(validation.is_valid());
+    /// assert_eq!(TimestampValidity::Plausible, validation.timestamp());
+    ///
+    /// let uuid = Uuid::parse_str("00000000-0000-7cc3-98c4-dc0c0c07398f")?;
+    ///
+    /// assert!(!uuid.validate().is_valid());
+    /// assert_eq!(TimestampValidity::Unset, uuid.validate().timestamp());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn validate(&self) -> Validation {
+        let (seconds, subsec_nanos) = timestamp::now();
+
+        self.validate_at(Timestamp::from_unix_time(seconds, subsec_nanos, 0, 0))
+    }
+
+    /// Check whether this UUID is well-formed, using `now` as the current time.
+    ///
+    /// This method is like [`Uuid::validate`], but doesn't need a system clock.
+    /// Timestamps in time-based UUIDs are compared to `now` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Timestamp, validate::TimestampValidity};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")?;
+    ///
+    /// // The UUID was generated in early 2022
+    /// let before = Timestamp::from_unix_time(1_600_000_000, 0, 0, 0);
+    ///
+    /// assert_eq!(TimestampValidity::Future, uuid.validate_at(before).timestamp());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_at(&self, now: Timestamp) -> Validation {
+        let (now_seconds, now_subsec_nanos) = now.to_unix();
+
+        let version = self.get_version();
+
+        let timestamp = match version {
+            Some(Version::Mac) | Some(Version::SortMac) => {
+                let (ticks, _) = if let Some(Version::Mac) = version {
+                    timestamp::decode_gregorian_timestamp(self)
+                } else {
+                    timestamp::decode_sorted_gregorian_timestamp(self)
+                };
+
+                let now_ticks = (now_seconds as u128) * 10_000_000
+                    + (now_subsec_nanos as u128) / 100
+                    + UUID_TICKS_BETWEEN_EPOCHS as u128;
+
+                if ticks == 0 {
+                    TimestampValidity::Unset
+                } else if ticks < UUID_TICKS_BETWEEN_EPOCHS {
+                    TimestampValidity::BeforeUnixEpoch
+                } else if ticks as u128 > now_ticks {
+                    TimestampValidity::Future
+                } else {
+                    TimestampValidity::Plausible
+                }
+            }
+            Some(Version::SortRand) => {
+                let millis = timestamp::decode_unix_timestamp_millis(self);
+
+                let now_millis =
+                    (now_seconds as u128) * 1_000 + (now_subsec_nanos as u128) / 1_000_000;
+
+                if millis == 0 {
+                    TimestampValidity::Unset
+                } else if millis as u128 > now_millis {
+                    TimestampValidity::Future
+                } else {
+                    TimestampValidity::Plausible
+                }
+            }
+            _ => TimestampValidity::NotTimeBased,
+        };
+
+        Validation {
+            variant: self.get_variant(),
+            version_num: self.get_version_num(),
+            version,
+            timestamp,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    use wasm_bindgen_test::*;
+
+    // 2024-01-01 00:00:00
+    const NOW: Timestamp = Timestamp::from_unix_time(1_704_067_200, 0, 0, 0);
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_validate_random() {
+        let validation = crate::tests::new().validate_at(NOW);
+
+        assert!(validation.is_valid());
+        assert!(validation.is_rfc_variant());
+        assert!(validation.is_known_version());
+        assert_eq!(4, validation.version_num());
+        assert_eq!(TimestampValidity::NotTimeBased, validation.timestamp());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_validate_nil_max() {
+        for uuid in [Uuid::nil(), Uuid::max()] {
+            let validation = uuid.validate_at(NOW);
+
+            assert!(validation.is_valid());
+            assert!(!validation.is_rfc_variant());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_validate_variant_version() {
+        // Microsoft variant
+        let validation = Uuid::from_u128(0x67e55044_10b1_426f_d247_bb680e5fe0c8).validate_at(NOW);
+
+        assert!(!validation.is_valid());
+        assert_eq!(Variant::Microsoft, validation.variant());
+
+        // Unassigned version
+        let validation = Uuid::from_u128(0x67e55044_10b1_b26f_9247_bb680e5fe0c8).validate_at(NOW);
+
+        assert!(!validation.is_valid());
+        assert!(!validation.is_known_version());
+        assert_eq!(0xb, validation.version_num());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_validate_gregorian_timestamp() {
+        let node_id = [1, 2, 3, 4, 5, 6];
+
+        let cases = [
+            (0, TimestampValidity::Unset),
+            (1, TimestampValidity::BeforeUnixEpoch),
+            (
+                NOW.to_gregorian().0 - 10_000_000,
+                TimestampValidity::Plausible,
+            ),
+            (NOW.to_gregorian().0 + 10_000_000, TimestampValidity::Future),
+        ];
+
+        for (ticks, expected) in cases {
+            let v1 = timestamp::encode_gregorian_timestamp(ticks, 0, &node_id);
+            let v6 = timestamp::encode_sorted_gregorian_timestamp(ticks, 0, &node_id);
+
+            assert_eq!(expected, v1.validate_at(NOW).timestamp());
+            assert_eq!(expected, v6.validate_at(NOW).timestamp());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_validate_unix_timestamp() {
+        let now_millis = NOW.to_unix().0 * 1_000;
+
+        let cases = [
+            (0, TimestampValidity::Unset),
+            (now_millis - 1, TimestampValidity::Plausible),
+            (now_millis, TimestampValidity::Plausible),
+            (now_millis + 1, TimestampValidity::Future),
+        ];
+
+        for (millis, expected) in cases {
+            let uuid = timestamp::encode_unix_timestamp_millis(millis, &[0; 10]);
+
+            assert_eq!(expected, uuid.validate_at(NOW).timestamp());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(
+        all(
+            target_arch = "wasm32",
+            target_vendor = "unknown",
+            target_os = "unknown"
+        ),
+        wasm_bindgen_test
+    )]
+    fn test_validate_now() {
+        let uuid = timestamp::encode_unix_timestamp_millis(u64::MAX >> 16, &[0; 10]);
+
+        assert_eq!(TimestampValidity::Future, uuid.validate().timestamp());
+    }
+}
@@ -0,0 +1,90 @@
+//! Functions documented as never panicking, checked at link time with `no-panic`.
+//!
+//! Each wrapper fails to link if the optimizer can't remove every panic from it.
+
+use no_panic::no_panic;
+use uuid::{
+    fmt::{Braced, Hyphenated, Simple, Urn},
+    Builder, Error, Uuid,
+};
+
+#[no_panic]
+fn try_parse(input: &str) -> Result<Uuid, Error> {
+    Uuid::try_parse(input)
+}
+
+#[no_panic]
+fn try_parse_ascii(input: &[u8]) -> Result<Uuid, Error> {
+    Uuid::try_parse_ascii(input)
+}
+
+#[no_panic]
+fn encode_hyphenated(uuid: Uuid, buffer: &mut [u8]) -> Result<&mut str, Error> {
+    Hyphenated::from_uuid(uuid).encode_lower_checked(buffer)
+}
+
+#[no_panic]
+fn encode_simple(uuid: Uuid, buffer: &mut [u8]) -> Result<&mut str, Error> {
+    Simple::from_uuid(uuid).encode_upper_checked(buffer)
+}
+
+#[no_panic]
+fn encode_urn(uuid: Uuid, buffer: &mut [u8]) -> Result<&mut str, Error> {
+    Urn::from_uuid(uuid).encode_lower_checked(buffer)
+}
+
+#[no_panic]
+fn encode_braced(uuid: Uuid, buffer: &mut [u8]) -> Result<&mut str, Error> {
+    Braced::from_uuid(uuid).encode_upper_checked(buffer)
+}
+
+#[no_panic]
+fn try_new_v4() -> Result<Uuid, Error> {
+    Uuid::try_new_v4()
+}
+
+#[no_panic]
+fn with_byte_checked(uuid: Uuid, index: usize, value: u8) -> Option<Uuid> {
+    Builder::from_u128(uuid.as_u128())
+        .with_byte_checked(index, value)
+        .map(Builder::into_uuid)
+}
+
+#[no_panic]
+fn with_nibble_checked(uuid: Uuid, index: usize, value: u8) -> Option<Uuid> {
+    Builder::from_u128(uuid.as_u128())
+        .with_nibble_checked(index, value)
+        .map(Builder::into_uuid)
+}
+
+#[no_panic]
+fn with_bits_checked(uuid: Uuid, start: usize, end: usize, value: u128) -> Option<Uuid> {
+    Builder::from_u128(uuid.as_u128())
+        .with_bits_checked(start..end, value)
+        .map(Builder::into_uuid)
+}
+
+#[no_panic]
+fn try_build(uuid: Uuid) -> Result<Uuid, Error> {
+    Builder::from_u128(uuid.as_u128()).try_build()
+}
+
+fn main() {
+    let input = std::env::args().nth(1).unwrap_or_default();
+    let mut buffer = [0; 64];
+
+    let uuid = try_parse(&input)
+        .or_else(|_| try_parse_ascii(input.as_bytes()))
+        .or_else(|_| try_new_v4())
+        .unwrap_or_default();
+
+    let _ = encode_hyphenated(uuid, &mut buffer[..input.len()]);
+    let _ = encode_simple(uuid, &mut buffer[..input.len()]);
+    let _ = encode_urn(uuid, &mut buffer[..input.len()]);
+    let _ = encode_braced(uuid, &mut buffer[..input.len()]);
+
+    let _ = with_byte_checked(uuid, input.len(), 0xa1);
+    let _ = with_nibble_checked(uuid, input.len(), 0xa);
+    let _ = with_bits_checked(uuid, 0, input.len(), u128::MAX);
+    let _ = try_build(uuid);
+}
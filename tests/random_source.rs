@@ -0,0 +1,59 @@
+#![cfg(any(feature = "v4", feature = "rng-custom"))]
+
+// The random source is global, so these tests live in their own binary where
+// nothing else generates UUIDs, and hold a lock so they don't run concurrently
+
+use std::sync::Mutex;
+
+use uuid::{entropy, Builder, Uuid, Version};
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+#[test]
+fn new_v4() {
+    let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    entropy::set_random_source(|dest| dest.fill(0x5a));
+
+    let uuid = Uuid::new_v4();
+    let try_uuid = Uuid::try_new_v4().unwrap();
+
+    entropy::clear_random_source();
+
+    let expected = Builder::from_random_bytes([0x5a; 16]).into_uuid();
+
+    assert_eq!(expected, uuid);
+    assert_eq!(expected, try_uuid);
+    assert_eq!(Some(Version::Random), uuid.get_version());
+}
+
+#[test]
+#[cfg(all(any(feature = "v7", feature = "rng-custom"), feature = "std"))]
+fn now_v7() {
+    let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    entropy::set_random_source(|dest| dest.fill(0));
+
+    let uuid = Uuid::now_v7();
+    let try_uuid = Uuid::try_now_v7().unwrap();
+
+    entropy::clear_random_source();
+
+    assert_eq!(Some(Version::SortRand), uuid.get_version());
+    assert!(uuid < try_uuid);
+
+    // The random bytes after the counter all come from the source
+    assert_eq!([0; 4], uuid.as_bytes()[12..]);
+    assert_eq!([0; 4], try_uuid.as_bytes()[12..]);
+}
+
+#[test]
+#[cfg(not(feature = "rng"))]
+fn no_source() {
+    let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    let err = Uuid::try_new_v4().unwrap_err();
+
+    assert_eq!(None, err.raw_os_error());
+    assert!(std::panic::catch_unwind(Uuid::new_v4).is_err());
+}
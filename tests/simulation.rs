@@ -0,0 +1,91 @@
+#![cfg(all(feature = "simulation", feature = "v4", feature = "v7"))]
+
+// The simulation is global, so these tests live in their own binary where
+// nothing else generates UUIDs, and hold a lock so they don't run concurrently
+
+use std::{sync::Mutex, time::Duration};
+
+use uuid::{simulation, Uuid, Version};
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn generate() -> Vec<Uuid> {
+    let mut uuids = Vec::new();
+
+    for _ in 0..4 {
+        uuids.push(Uuid::new_v4());
+        uuids.push(Uuid::now_v7());
+        uuids.push(Uuid::try_now_v7().unwrap());
+
+        simulation::advance_time(Duration::from_micros(700));
+    }
+
+    uuids
+}
+
+#[test]
+fn seeded() {
+    let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    simulation::seed(7);
+    simulation::set_time(Duration::from_millis(1_645_557_742_000));
+    let first = generate();
+
+    simulation::seed(7);
+    simulation::set_time(Duration::from_millis(1_645_557_742_000));
+    let second = generate();
+
+    simulation::seed(8);
+    simulation::set_time(Duration::from_millis(1_645_557_742_000));
+    let third = generate();
+
+    simulation::stop();
+
+    assert_eq!(first, second);
+    assert_ne!(first, third);
+
+    assert_eq!(Some(Version::Random), first[0].get_version());
+    assert_eq!(Some(Version::SortRand), first[1].get_version());
+    assert_eq!(
+        Some(1_645_557_742_000),
+        first[1]
+            .get_timestamp()
+            .map(|ts| ts.to_unix())
+            .map(|(secs, nanos)| secs * 1000 + nanos as u64 / 1_000_000)
+    );
+}
+
+#[test]
+fn record_and_replay() {
+    let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    simulation::record();
+    simulation::set_time(Duration::from_millis(1_645_557_742_000));
+    let recorded = generate();
+
+    let recording = simulation::take_recording();
+    assert!(!recording.is_empty());
+
+    simulation::replay(recording);
+    simulation::set_time(Duration::from_millis(1_645_557_742_000));
+    let replayed = generate();
+
+    simulation::stop();
+
+    assert_eq!(recorded, replayed);
+    assert!(!simulation::is_running());
+    assert_ne!(recorded[0], Uuid::new_v4());
+}
+
+#[test]
+fn replay_exhausted() {
+    let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    simulation::replay(vec![0; 8]);
+
+    let result = std::panic::catch_unwind(Uuid::new_v4);
+
+    simulation::stop();
+
+    assert!(result.is_err());
+}